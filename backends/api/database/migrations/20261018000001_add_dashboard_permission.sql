@@ -0,0 +1,17 @@
+-- 관리자 대시보드 조회 권한 추가
+-- /api/admin/* 라우트가 RBAC 권한 체크를 거치면서 대시보드에도 권한이 필요해짐
+INSERT INTO permissions (id, name, description, resource, action, is_active)
+SELECT gen_random_uuid(), 'dashboard.read', '대시보드 조회', 'dashboard', 'read', true
+WHERE NOT EXISTS (SELECT 1 FROM permissions WHERE name = 'dashboard.read');
+
+-- 기본 역할 모두에 대시보드 조회 권한 부여
+INSERT INTO role_permissions (id, role_id, permission_id)
+SELECT gen_random_uuid(), r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE p.name = 'dashboard.read'
+AND r.name IN ('super_admin', 'admin', 'moderator', 'editor', 'viewer')
+AND NOT EXISTS (
+    SELECT 1 FROM role_permissions rp
+    WHERE rp.role_id = r.id AND rp.permission_id = p.id
+);
@@ -0,0 +1,19 @@
+-- 공지 알림 발송 권한 추가
+INSERT INTO permissions (id, name, description, resource, action, is_active)
+SELECT gen_random_uuid(), 'notifications.create', '공지 알림 발송', 'notifications', 'create', true
+WHERE NOT EXISTS (SELECT 1 FROM permissions WHERE name = 'notifications.create');
+
+-- 관리자 역할에 공지 알림 발송 권한 부여
+INSERT INTO role_permissions (id, role_id, permission_id)
+SELECT gen_random_uuid(), r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE p.name = 'notifications.create'
+AND r.name IN ('super_admin', 'admin')
+AND NOT EXISTS (
+    SELECT 1 FROM role_permissions rp
+    WHERE rp.role_id = r.id AND rp.permission_id = p.id
+);
+
+-- 사용자별 알림 조회용 인덱스
+CREATE INDEX IF NOT EXISTS idx_notifications_user_created ON notifications (user_id, created_at DESC);
@@ -0,0 +1,103 @@
+-- 신고 처리 대기열 조회/중복 신고 확인용 인덱스
+CREATE INDEX IF NOT EXISTS idx_reports_status_created ON reports (status, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_reports_entity ON reports (entity_type, entity_id);
+
+-- 같은 신고자가 같은 대상에 처리 대기 신고를 중복으로 남기지 않도록 부분 유니크 인덱스 추가
+-- (기존 중복 대기 신고는 가장 먼저 접수된 건만 남기고 나머지는 기각 처리)
+UPDATE reports r SET status = 'dismissed', admin_note = COALESCE(r.admin_note, '중복 신고'), resolved_at = NOW()
+WHERE r.status = 'pending' AND EXISTS (
+    SELECT 1 FROM reports r2
+    WHERE r2.reporter_id = r.reporter_id AND r2.entity_type = r.entity_type AND r2.entity_id = r.entity_id
+    AND r2.status = 'pending'
+    AND (COALESCE(r2.created_at, '-infinity'), r2.id) < (COALESCE(r.created_at, '-infinity'), r.id)
+);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_reports_pending_unique
+    ON reports (reporter_id, entity_type, entity_id) WHERE status = 'pending';
+
+-- 댓글 숨김 이력 테이블 (댓글 ID/처리자는 UUID, 신고 누적 자동 숨김은 처리자 NULL)
+CREATE TABLE IF NOT EXISTS comment_hide_history (
+    id SERIAL PRIMARY KEY,
+    comment_id UUID NOT NULL REFERENCES comments(id) ON DELETE CASCADE,
+    hide_reason TEXT,
+    hide_category VARCHAR(50) NOT NULL,
+    hide_tags TEXT[],
+    hidden_by UUID REFERENCES users(id) ON DELETE CASCADE,
+    hidden_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    hide_location VARCHAR(20) NOT NULL DEFAULT 'admin' CHECK (hide_location IN ('site', 'admin')),
+    is_hidden BOOLEAN DEFAULT TRUE
+);
+
+-- 정수 ID로 생성된 기존 테이블이 있으면 UUID 컬럼을 새로 만들어 옮김
+-- (UUID로 해석되지 않거나 대상이 없는 댓글 이력은 삭제, 처리자는 NULL로 남김)
+DO $$
+BEGIN
+    IF EXISTS (
+        SELECT 1 FROM information_schema.columns
+        WHERE table_schema = current_schema() AND table_name = 'comment_hide_history'
+        AND column_name = 'comment_id' AND data_type <> 'uuid'
+    ) THEN
+        ALTER TABLE comment_hide_history ADD COLUMN comment_uuid UUID;
+        UPDATE comment_hide_history SET comment_uuid = CASE
+            WHEN comment_id::text ~* '^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$'
+            THEN comment_id::text::uuid
+        END;
+        DELETE FROM comment_hide_history h
+        WHERE h.comment_uuid IS NULL OR NOT EXISTS (SELECT 1 FROM comments c WHERE c.id = h.comment_uuid);
+        ALTER TABLE comment_hide_history DROP COLUMN comment_id;
+        ALTER TABLE comment_hide_history RENAME COLUMN comment_uuid TO comment_id;
+        ALTER TABLE comment_hide_history ALTER COLUMN comment_id SET NOT NULL;
+        ALTER TABLE comment_hide_history ADD CONSTRAINT comment_hide_history_comment_id_fkey
+            FOREIGN KEY (comment_id) REFERENCES comments(id) ON DELETE CASCADE;
+    END IF;
+
+    IF EXISTS (
+        SELECT 1 FROM information_schema.columns
+        WHERE table_schema = current_schema() AND table_name = 'comment_hide_history'
+        AND column_name = 'hidden_by' AND data_type <> 'uuid'
+    ) THEN
+        ALTER TABLE comment_hide_history ADD COLUMN hidden_by_uuid UUID;
+        UPDATE comment_hide_history h SET hidden_by_uuid = u.id
+        FROM users u
+        WHERE u.id = CASE
+            WHEN h.hidden_by::text ~* '^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$'
+            THEN h.hidden_by::text::uuid
+        END;
+        ALTER TABLE comment_hide_history DROP COLUMN hidden_by;
+        ALTER TABLE comment_hide_history RENAME COLUMN hidden_by_uuid TO hidden_by;
+        ALTER TABLE comment_hide_history ADD CONSTRAINT comment_hide_history_hidden_by_fkey
+            FOREIGN KEY (hidden_by) REFERENCES users(id) ON DELETE CASCADE;
+    END IF;
+END $$;
+
+-- 신고 누적 자동 숨김은 처리자 없이 기록
+ALTER TABLE comment_hide_history ALTER COLUMN hidden_by DROP NOT NULL;
+ALTER TABLE post_hide_history ALTER COLUMN hidden_by DROP NOT NULL;
+
+-- 숨김 해제 이력('해제') 기록을 허용하도록 카테고리 제약 재정의
+ALTER TABLE comment_hide_history DROP CONSTRAINT IF EXISTS comment_hide_history_hide_category_check;
+ALTER TABLE comment_hide_history ADD CONSTRAINT comment_hide_history_hide_category_check
+    CHECK (hide_category IN ('광고', '음란물', '욕설비방', '기타 정책위반', 'inappropriate', 'spam', 'duplicate', 'violation', 'other', 'quick_hide', '해제'));
+
+CREATE INDEX IF NOT EXISTS idx_comment_hide_history_comment_id ON comment_hide_history(comment_id);
+CREATE INDEX IF NOT EXISTS idx_comment_hide_history_is_hidden ON comment_hide_history(is_hidden);
+
+-- 신고 관리 권한 추가
+INSERT INTO permissions (id, name, description, resource, action, is_active)
+SELECT gen_random_uuid(), v.name, v.description, 'reports', v.action, true
+FROM (VALUES
+    ('reports.read', '신고 목록 조회', 'read'),
+    ('reports.moderate', '신고 처리', 'moderate')
+) AS v(name, description, action)
+WHERE NOT EXISTS (SELECT 1 FROM permissions p WHERE p.name = v.name);
+
+-- 관리자/운영자 역할에 신고 관리 권한 부여
+INSERT INTO role_permissions (id, role_id, permission_id)
+SELECT gen_random_uuid(), r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE p.name IN ('reports.read', 'reports.moderate')
+AND r.name IN ('super_admin', 'admin', 'moderator')
+AND NOT EXISTS (
+    SELECT 1 FROM role_permissions rp
+    WHERE rp.role_id = r.id AND rp.permission_id = p.id
+);
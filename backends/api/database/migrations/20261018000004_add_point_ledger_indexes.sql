@@ -0,0 +1,7 @@
+-- 포인트 내역 조회 인덱스
+CREATE INDEX IF NOT EXISTS idx_point_transactions_user_created
+    ON point_transactions (user_id, created_at DESC);
+
+-- 최초 열람/다운로드 포인트 중복 확인 인덱스
+CREATE INDEX IF NOT EXISTS idx_point_transactions_user_type_reference
+    ON point_transactions (user_id, type, reference_id);
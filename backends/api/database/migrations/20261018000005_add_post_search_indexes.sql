@@ -0,0 +1,34 @@
+-- 검색용 본문 텍스트 추출 (HTML 태그 제거, 남은 꺾쇠 이스케이프, 공백 정리)
+-- 블록 태그는 공백으로, 인라인 태그는 제거해 '<b>검색</b>을' 같은 어절이 나뉘지 않도록 함
+-- 인덱스 표현식에 사용하므로 IMMUTABLE
+CREATE OR REPLACE FUNCTION strip_html(input TEXT) RETURNS TEXT AS $$
+    SELECT btrim(regexp_replace(
+        replace(replace(replace(
+            regexp_replace(
+                regexp_replace(COALESCE(input, ''), '</?(p|div|br|li|ul|ol|h[1-6]|tr|td|th|table|blockquote|pre|hr)([\s/][^>]*)?>', ' ', 'gi'),
+                '<[^>]*>', '', 'g'),
+            '&nbsp;', ' '), '<', '&lt;'), '>', '&gt;'),
+        '\s+', ' ', 'g'))
+$$ LANGUAGE SQL IMMUTABLE PARALLEL SAFE;
+
+-- 게시글 제목/본문 전문 검색 인덱스 (접두 일치)
+CREATE INDEX IF NOT EXISTS idx_posts_title_tsv
+    ON posts USING GIN (to_tsvector('simple', title));
+CREATE INDEX IF NOT EXISTS idx_posts_content_tsv
+    ON posts USING GIN (to_tsvector('simple', strip_html(content)));
+
+-- 게시글 제목/본문 부분 일치 인덱스 (pg_trgm)
+CREATE INDEX IF NOT EXISTS idx_posts_title_trgm
+    ON posts USING GIN (title gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_posts_content_trgm
+    ON posts USING GIN (strip_html(content) gin_trgm_ops);
+
+-- 댓글 내용 검색 인덱스
+CREATE INDEX IF NOT EXISTS idx_comments_content_tsv
+    ON comments USING GIN (to_tsvector('simple', content));
+CREATE INDEX IF NOT EXISTS idx_comments_content_trgm
+    ON comments USING GIN (content gin_trgm_ops);
+
+-- 작성자 이름 검색 인덱스
+CREATE INDEX IF NOT EXISTS idx_users_name_trgm
+    ON users USING GIN (name gin_trgm_ops);
@@ -0,0 +1,7 @@
+-- 사용자 전체 토큰 폐기 확인 인덱스
+CREATE INDEX IF NOT EXISTS idx_token_blacklist_user_expires
+    ON token_blacklist (user_id, expires_at);
+
+-- 만료 항목 정리 인덱스
+CREATE INDEX IF NOT EXISTS idx_token_blacklist_expires_at
+    ON token_blacklist (expires_at);
@@ -0,0 +1,2 @@
+-- 게시판별 이메일 인증 회원 작성 제한
+ALTER TABLE boards ADD COLUMN IF NOT EXISTS require_email_verified BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,2 @@
+-- 파일별 다운로드 횟수
+ALTER TABLE files ADD COLUMN IF NOT EXISTS download_count INTEGER NOT NULL DEFAULT 0;
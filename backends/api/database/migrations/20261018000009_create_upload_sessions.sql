@@ -0,0 +1,31 @@
+-- 이어 올리기 가능한 청크 업로드 세션
+CREATE TABLE IF NOT EXISTS upload_sessions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    board_id UUID REFERENCES boards(id) ON DELETE SET NULL,
+    draft_id UUID REFERENCES drafts(id) ON DELETE SET NULL,
+    original_name VARCHAR(255) NOT NULL,
+    total_size BIGINT NOT NULL CHECK (total_size > 0),
+    chunk_size INTEGER NOT NULL CHECK (chunk_size > 0),
+    total_parts INTEGER NOT NULL CHECK (total_parts > 0),
+    sha256 CHAR(64) NOT NULL, -- 전체 파일 SHA-256 (hex)
+    status VARCHAR(20) NOT NULL DEFAULT 'uploading' CHECK (status IN ('uploading', 'completing', 'completed')),
+    file_id UUID REFERENCES files(id) ON DELETE SET NULL,
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+);
+
+-- 수신한 조각 (조각 데이터는 서버 로컬 임시 디렉터리에 저장)
+CREATE TABLE IF NOT EXISTS upload_session_parts (
+    session_id UUID NOT NULL REFERENCES upload_sessions(id) ON DELETE CASCADE,
+    part_index INTEGER NOT NULL CHECK (part_index >= 0),
+    size INTEGER NOT NULL,
+    sha256 CHAR(64) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    PRIMARY KEY (session_id, part_index)
+);
+
+-- 만료 세션 정리용 인덱스
+CREATE INDEX IF NOT EXISTS idx_upload_sessions_expires_at ON upload_sessions (expires_at);
+CREATE INDEX IF NOT EXISTS idx_upload_sessions_user_id ON upload_sessions (user_id);
@@ -0,0 +1,8 @@
+-- 고아 파일 정리: 고아 상태로 전환된 시각 (유예 기간 계산용)
+ALTER TABLE files ADD COLUMN IF NOT EXISTS orphaned_at TIMESTAMPTZ;
+
+-- 이미 고아 상태인 파일은 전환 시각을 알 수 없으므로 지금부터 유예 기간 적용
+UPDATE files SET orphaned_at = NOW() WHERE status = 'orphaned' AND orphaned_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_files_orphaned_at ON files (orphaned_at) WHERE status = 'orphaned';
+CREATE INDEX IF NOT EXISTS idx_files_status_created_at ON files (status, created_at);
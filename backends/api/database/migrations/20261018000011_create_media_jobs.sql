@@ -0,0 +1,26 @@
+-- 썸네일/미디어 후처리 작업 큐 (서버 재시작 후에도 이어서 처리)
+-- process_file: 업로드 후 바이러스 검사 + 썸네일 생성 + files.processing_status 갱신
+-- thumbnail: 누락된 썸네일만 다시 생성
+CREATE TABLE IF NOT EXISTS media_jobs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    job_type VARCHAR(30) NOT NULL CHECK (job_type IN ('process_file', 'thumbnail')),
+    file_id UUID REFERENCES files(id) ON DELETE CASCADE,
+    file_key VARCHAR(500) NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'queued' CHECK (status IN ('queued', 'running', 'completed', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    max_attempts INTEGER NOT NULL DEFAULT 5,
+    run_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(), -- 재시도 시 백오프 적용 시각
+    locked_at TIMESTAMP WITH TIME ZONE,
+    last_error TEXT,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+);
+
+-- 같은 파일에 대기/실행 중인 작업은 하나만
+CREATE UNIQUE INDEX IF NOT EXISTS idx_media_jobs_active ON media_jobs (job_type, file_key)
+    WHERE status IN ('queued', 'running');
+
+-- 작업 가져오기 / 실패 목록 / 파일별 상태 조회용 인덱스
+CREATE INDEX IF NOT EXISTS idx_media_jobs_queued ON media_jobs (run_at) WHERE status = 'queued';
+CREATE INDEX IF NOT EXISTS idx_media_jobs_status_updated_at ON media_jobs (status, updated_at);
+CREATE INDEX IF NOT EXISTS idx_media_jobs_file_id ON media_jobs (file_id);
@@ -0,0 +1,3 @@
+-- 썸네일 변형(원본 형식, WebP, AVIF) 기록: 파일별 조회와 원본 경로로 srcset 조회
+CREATE INDEX IF NOT EXISTS idx_image_sizes_file_id ON image_sizes (file_id);
+CREATE INDEX IF NOT EXISTS idx_files_file_path ON files (file_path);
@@ -0,0 +1,7 @@
+-- 동영상 첨부파일 후처리 결과 (ffprobe 메타데이터, ffmpeg 포스터 프레임)
+ALTER TABLE files ADD COLUMN IF NOT EXISTS duration_seconds DOUBLE PRECISION;
+ALTER TABLE files ADD COLUMN IF NOT EXISTS width INTEGER;
+ALTER TABLE files ADD COLUMN IF NOT EXISTS height INTEGER;
+ALTER TABLE files ADD COLUMN IF NOT EXISTS poster_path VARCHAR(500);
+-- 코덱, 프레임 레이트, 비트레이트 등
+ALTER TABLE files ADD COLUMN IF NOT EXISTS media_info JSONB;
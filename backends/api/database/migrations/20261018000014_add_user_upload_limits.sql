@@ -0,0 +1,14 @@
+-- 사용자별 저장 공간 / 일일 업로드 한도 (NULL이면 역할 기본값, 0이면 무제한)
+ALTER TABLE users ADD COLUMN IF NOT EXISTS storage_quota_bytes BIGINT;
+ALTER TABLE users ADD COLUMN IF NOT EXISTS daily_upload_limit INTEGER;
+
+-- 일별 업로드 횟수 (게시글/프로필/사이트 파일 업로드, 업로드 세션 생성)
+CREATE TABLE IF NOT EXISTS user_upload_counts (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    upload_date DATE NOT NULL,
+    upload_count INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (user_id, upload_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_upload_counts_upload_date ON user_upload_counts(upload_date);
+CREATE INDEX IF NOT EXISTS idx_upload_sessions_user_id_status ON upload_sessions(user_id, status);
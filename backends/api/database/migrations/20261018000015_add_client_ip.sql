@@ -0,0 +1,4 @@
+-- 작성자 / 로그인 IP (신뢰하는 프록시의 X-Forwarded-For, X-Real-IP 반영, IPv6 최대 45자)
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS ip_address VARCHAR(45);
+ALTER TABLE comments ADD COLUMN IF NOT EXISTS ip_address VARCHAR(45);
+ALTER TABLE users ADD COLUMN IF NOT EXISTS last_login_ip VARCHAR(45);
@@ -0,0 +1,19 @@
+-- 게시판 수정/삭제 제한 기준
+-- comments: 달린 댓글(댓글은 답글) 수가 제한값 이상이면 불가, minutes: 작성 후 제한값(분)이 지나면 불가
+ALTER TABLE boards ADD COLUMN IF NOT EXISTS comment_limit_unit VARCHAR(20) NOT NULL DEFAULT 'comments';
+ALTER TABLE boards DROP CONSTRAINT IF EXISTS boards_comment_limit_unit_check;
+ALTER TABLE boards ADD CONSTRAINT boards_comment_limit_unit_check
+    CHECK (comment_limit_unit IN ('comments', 'minutes'));
+
+-- 게시글/댓글 수정 이력 (수정 직전 내용을 보관, 현재 내용은 원본 테이블)
+CREATE TABLE IF NOT EXISTS content_revisions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    entity_type VARCHAR(20) NOT NULL CHECK (entity_type IN ('post', 'comment')),
+    entity_id UUID NOT NULL,
+    title VARCHAR(200), -- 댓글은 NULL
+    content TEXT NOT NULL,
+    edited_by UUID REFERENCES users(id) ON DELETE SET NULL, -- 이 내용을 바꾼 사용자
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_content_revisions_entity ON content_revisions (entity_type, entity_id, created_at DESC);
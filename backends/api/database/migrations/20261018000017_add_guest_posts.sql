@@ -0,0 +1,9 @@
+-- 비회원(손님) 게시글: 작성자 대신 닉네임과 비밀번호 해시로 본인 확인
+ALTER TABLE posts ALTER COLUMN user_id DROP NOT NULL;
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS guest_name VARCHAR(50);
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS guest_password_hash VARCHAR(255);
+
+-- 회원 글이거나, 닉네임/비밀번호가 있는 비회원 글
+ALTER TABLE posts DROP CONSTRAINT IF EXISTS posts_author_check;
+ALTER TABLE posts ADD CONSTRAINT posts_author_check
+    CHECK (user_id IS NOT NULL OR (guest_name IS NOT NULL AND guest_password_hash IS NOT NULL));
@@ -0,0 +1,5 @@
+-- markdown 게시판 글의 Markdown 원문 (content에는 변환 후 정리한 HTML 저장, 수정 시 원문으로 다시 변환)
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS content_source TEXT;
+
+-- 수정 이력에도 원문 보관 (복원 시 원문도 함께 되돌림)
+ALTER TABLE content_revisions ADD COLUMN IF NOT EXISTS content_source TEXT;
@@ -0,0 +1,2 @@
+-- markdown 게시판 임시저장 글의 Markdown 원문 (content에는 변환 후 정리한 HTML 저장)
+ALTER TABLE drafts ADD COLUMN IF NOT EXISTS content_source TEXT;
@@ -47,6 +47,205 @@ async fn main() -> Result<(), sqlx::Error> {
         }
     }
     
+    // 대시보드 권한 마이그레이션 실행
+    let dashboard_permission_sql = include_str!("../../database/migrations/20261018000001_add_dashboard_permission.sql");
+    
+    match pool.execute(dashboard_permission_sql).await {
+        Ok(_) => println!("✅ 대시보드 권한 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 대시보드 권한 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+    
+    // 알림 권한 마이그레이션 실행
+    let notification_permission_sql = include_str!("../../database/migrations/20261018000002_add_notification_permission.sql");
+    
+    match pool.execute(notification_permission_sql).await {
+        Ok(_) => println!("✅ 알림 권한 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 알림 권한 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+    
+    // 신고/모더레이션 마이그레이션 실행
+    let report_moderation_sql = include_str!("../../database/migrations/20261018000003_create_report_moderation.sql");
+    
+    match pool.execute(report_moderation_sql).await {
+        Ok(_) => println!("✅ 신고/모더레이션 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 신고/모더레이션 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+    
+    // 포인트 내역 인덱스 마이그레이션 실행
+    let point_ledger_sql = include_str!("../../database/migrations/20261018000004_add_point_ledger_indexes.sql");
+    
+    match pool.execute(point_ledger_sql).await {
+        Ok(_) => println!("✅ 포인트 내역 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 포인트 내역 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+    
+    // 게시글 검색 인덱스 마이그레이션 실행
+    let post_search_sql = include_str!("../../database/migrations/20261018000005_add_post_search_indexes.sql");
+    
+    match pool.execute(post_search_sql).await {
+        Ok(_) => println!("✅ 게시글 검색 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 게시글 검색 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+    
+    // 토큰 폐기 목록 인덱스 마이그레이션 실행
+    let token_blacklist_sql = include_str!("../../database/migrations/20261018000006_add_token_blacklist_indexes.sql");
+    
+    match pool.execute(token_blacklist_sql).await {
+        Ok(_) => println!("✅ 토큰 폐기 목록 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 토큰 폐기 목록 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+    
+    // 게시판 이메일 인증 작성 제한 마이그레이션 실행
+    let board_email_verified_sql = include_str!("../../database/migrations/20261018000007_add_board_require_email_verified.sql");
+    
+    match pool.execute(board_email_verified_sql).await {
+        Ok(_) => println!("✅ 게시판 이메일 인증 설정 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 게시판 이메일 인증 설정 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+    
+    // 파일 다운로드 횟수 마이그레이션 실행
+    let file_download_count_sql = include_str!("../../database/migrations/20261018000008_add_file_download_count.sql");
+    
+    match pool.execute(file_download_count_sql).await {
+        Ok(_) => println!("✅ 파일 다운로드 횟수 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 파일 다운로드 횟수 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+    
+    // 업로드 세션 마이그레이션 실행
+    let upload_sessions_sql = include_str!("../../database/migrations/20261018000009_create_upload_sessions.sql");
+    
+    match pool.execute(upload_sessions_sql).await {
+        Ok(_) => println!("✅ 업로드 세션 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 업로드 세션 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+    
+    let file_orphaned_at_sql = include_str!("../../database/migrations/20261018000010_add_file_orphaned_at.sql");
+    
+    match pool.execute(file_orphaned_at_sql).await {
+        Ok(_) => println!("✅ 고아 파일 정리 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 고아 파일 정리 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+    
+    let media_jobs_sql = include_str!("../../database/migrations/20261018000011_create_media_jobs.sql");
+    
+    match pool.execute(media_jobs_sql).await {
+        Ok(_) => println!("✅ 미디어 작업 큐 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 미디어 작업 큐 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+    
+    let image_sizes_sql = include_str!("../../database/migrations/20261018000012_add_image_sizes_indexes.sql");
+    
+    match pool.execute(image_sizes_sql).await {
+        Ok(_) => println!("✅ 이미지 변형 인덱스 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 이미지 변형 인덱스 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+    
+    let video_metadata_sql = include_str!("../../database/migrations/20261018000013_add_file_video_metadata.sql");
+    
+    match pool.execute(video_metadata_sql).await {
+        Ok(_) => println!("✅ 동영상 메타데이터 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 동영상 메타데이터 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+    
+    let upload_limits_sql = include_str!("../../database/migrations/20261018000014_add_user_upload_limits.sql");
+    
+    match pool.execute(upload_limits_sql).await {
+        Ok(_) => println!("✅ 업로드 한도 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 업로드 한도 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+    
+    let client_ip_sql = include_str!("../../database/migrations/20261018000015_add_client_ip.sql");
+    
+    match pool.execute(client_ip_sql).await {
+        Ok(_) => println!("✅ 작성자 IP 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 작성자 IP 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+
+    let content_revisions_sql = include_str!("../../database/migrations/20261018000016_create_content_revisions.sql");
+
+    match pool.execute(content_revisions_sql).await {
+        Ok(_) => println!("✅ 수정 이력 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 수정 이력 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+
+    let guest_posts_sql = include_str!("../../database/migrations/20261018000017_add_guest_posts.sql");
+
+    match pool.execute(guest_posts_sql).await {
+        Ok(_) => println!("✅ 비회원 게시글 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 비회원 게시글 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+
+    let content_source_sql = include_str!("../../database/migrations/20261018000018_add_post_content_source.sql");
+
+    match pool.execute(content_source_sql).await {
+        Ok(_) => println!("✅ 게시글 Markdown 원문 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 게시글 Markdown 원문 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+
+    let draft_content_source_sql = include_str!("../../database/migrations/20261018000019_add_draft_content_source.sql");
+
+    match pool.execute(draft_content_source_sql).await {
+        Ok(_) => println!("✅ 임시저장 Markdown 원문 마이그레이션이 성공적으로 실행되었습니다."),
+        Err(e) => {
+            eprintln!("❌ 임시저장 Markdown 원문 마이그레이션 실행 중 오류 발생: {}", e);
+            return Err(e);
+        }
+    }
+
     println!("모든 마이그레이션이 완료되었습니다.");
     Ok(())
 }
@@ -1,5 +1,155 @@
 use std::env;
 
+use crate::utils::client_ip::{parse_trusted_proxies, TrustedProxy};
+
+// 소셜 로그인 제공자 설정 (엔드포인트는 테스트용 mock 서버로 교체 가능)
+#[derive(Debug, Clone)]
+pub struct OAuthProviderConfig {
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_uri: String,
+    pub authorize_url: String,
+    pub token_url: String,
+    pub userinfo_url: String,
+    pub scope: String,
+}
+
+// 지원하는 소셜 로그인 제공자와 기본 엔드포인트 (이름, 인증, 토큰, 사용자 정보, scope)
+const OAUTH_PROVIDER_DEFAULTS: [(&str, &str, &str, &str, &str); 3] = [
+    (
+        "kakao",
+        "https://kauth.kakao.com/oauth/authorize",
+        "https://kauth.kakao.com/oauth/token",
+        "https://kapi.kakao.com/v2/user/me",
+        "profile_nickname,account_email",
+    ),
+    (
+        "naver",
+        "https://nid.naver.com/oauth2.0/authorize",
+        "https://nid.naver.com/oauth2.0/token",
+        "https://openapi.naver.com/v1/nid/me",
+        "",
+    ),
+    (
+        "google",
+        "https://accounts.google.com/o/oauth2/v2/auth",
+        "https://oauth2.googleapis.com/token",
+        "https://openidconnect.googleapis.com/v1/userinfo",
+        "openid email profile",
+    ),
+];
+
+// 메일 발송 방식 (이외의 값이면 시작하지 않음)
+const MAIL_TRANSPORTS: [&str; 3] = ["smtp", "file", "log"];
+
+// 메일 발송 설정
+// transport: smtp(실제 발송), file(file_dir에 .eml 저장), log(로그 출력, 로컬 테스트용)
+#[derive(Debug, Clone)]
+pub struct MailConfig {
+    pub transport: String,
+    pub log_body: bool, // MAIL_TRANSPORT=log를 직접 지정한 경우에만 본문(인증 링크 포함)까지 로그에 출력
+    pub from: String,
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub smtp_username: Option<String>,
+    pub smtp_password: Option<String>,
+    pub smtp_security: String, // starttls, tls, none
+    pub file_dir: String,
+}
+
+// 업로드 파일 저장소 설정
+// backend: local(로컬 디스크, 기본값), s3(S3 호환 오브젝트 스토리지, MinIO 포함)
+// download_mode: proxy(API 서버가 전달), presigned(서명 URL로 리다이렉트, s3 전용)
+#[derive(Debug, Clone)]
+pub struct StorageConfig {
+    pub backend: String,
+    pub local_root: String,
+    pub temp_dir: String, // 청크 업로드 임시 디렉터리 (항상 로컬 디스크)
+    pub upload_session_ttl_hours: i64, // 마지막 조각 수신 후 세션 유지 시간
+    pub max_chunk_size: usize,
+    pub orphan_grace_hours: i64, // 참조 없는 파일을 고아로 전환/삭제하기까지 유예 시간
+    pub file_gc_interval_minutes: u64, // 고아 파일 정리 주기 (0이면 자동 정리 안 함)
+    pub s3_endpoint: String,
+    pub s3_region: String,
+    pub s3_bucket: String,
+    pub s3_access_key: String,
+    pub s3_secret_key: String,
+    pub s3_path_style: bool, // MinIO 등은 path-style 주소 사용
+    pub download_mode: String,
+    pub presign_expiry_seconds: u64,
+}
+
+// 업로드 파일 바이러스 검사 설정
+// backend: none(검사 안 함, 기본값), clamd(clamd 데몬 INSTREAM)
+#[derive(Debug, Clone)]
+pub struct VirusScanConfig {
+    pub backend: String,
+    pub clamd_address: String, // unix:/경로 또는 tcp://host:port
+    pub timeout_seconds: u64,
+}
+
+// 썸네일/미디어 후처리 작업 큐 설정
+// 실패한 작업은 retry_base_seconds * 2^(시도-1) 후 재시도, max_attempts 넘으면 failed
+#[derive(Debug, Clone)]
+pub struct MediaJobConfig {
+    pub concurrency: usize, // 서버당 동시 처리 작업 수 (0이면 작업자 실행 안 함)
+    pub max_attempts: i32,
+    pub retry_base_seconds: i64,
+    pub poll_interval_seconds: u64,
+}
+
+// 이미지 후처리 / 크기 변환 프록시(/api/img) 설정
+// AVIF 변환은 avif 기능(cargo --features avif)으로 빌드한 경우에만 동작
+#[derive(Debug, Clone)]
+pub struct ImageConfig {
+    pub strip_metadata: bool, // 원본의 EXIF(GPS 등) 제거, 방향 값만 유지
+    pub webp_variants: bool,
+    pub webp_quality: f32, // 0~100
+    pub avif_variants: bool,
+    pub proxy_secret: String, // 변환 파라미터 서명 키 (비어 있으면 /api/img 비활성)
+    pub proxy_max_dimension: u32,
+    pub cache_dir: String, // 변환 결과 디스크 캐시 (항상 로컬 디스크)
+    pub cache_max_mb: u64, // 넘으면 오래 사용하지 않은 파일부터 삭제
+}
+
+// 동영상 후처리 설정 (ffprobe/ffmpeg가 없으면 메타데이터/포스터 없이 처리 완료)
+#[derive(Debug, Clone)]
+pub struct VideoConfig {
+    pub ffmpeg_path: String,
+    pub ffprobe_path: String,
+    pub poster_seconds: f64, // 포스터로 사용할 프레임 위치 (영상이 짧으면 중간 지점)
+    pub timeout_seconds: u64,
+}
+
+// 업로드 한도 (역할별 기본값, 사용자별 값은 users 테이블에서 덮어씀, 0이면 무제한)
+#[derive(Debug, Clone)]
+pub struct UploadLimitConfig {
+    pub user_storage_quota_mb: i64,
+    pub admin_storage_quota_mb: i64,
+    pub user_daily_uploads: i32,
+    pub admin_daily_uploads: i32,
+}
+
+// 캡차 설정 (use_captcha 게시판의 글/답글/댓글 작성 시 검증)
+// provider: math(자체 수식 이미지, 외부 연결 없음) | hcaptcha | recaptcha | turnstile | none(검증 안 함)
+#[derive(Debug, Clone)]
+pub struct CaptchaConfig {
+    pub provider: String,
+    pub site_key: String,   // 외부 제공자 위젯용 (프론트엔드에 전달)
+    pub secret_key: String, // 외부 제공자 서버 검증용
+    pub verify_url: String, // 비어 있으면 제공자 기본 주소
+    pub min_score: f64,     // reCAPTCHA v3 점수 기준 (점수가 없는 응답은 무시)
+    pub ttl_seconds: u64,   // 수식 문제 / 검증 완료 토큰 유효 시간
+    pub timeout_seconds: u64,
+}
+
+// 비회원 글쓰기 제한 (allow_anonymous 게시판, IP별 1시간 기준, 0이면 제한 없음)
+#[derive(Debug, Clone)]
+pub struct GuestPostConfig {
+    pub posts_per_hour: u32,
+    pub password_attempts_per_hour: u32, // 비회원 글 수정/삭제 비밀번호 확인 시도
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub database_url: String,
@@ -11,6 +161,20 @@ pub struct Config {
     pub api_base_url: String,
     pub redis_url: String,
     pub rust_log: String,
+    pub report_auto_hide_threshold: i64, // 0이면 자동 숨김 비활성
+    pub oauth_providers: Vec<(String, OAuthProviderConfig)>, // 설정된 소셜 로그인 제공자
+    pub site_url: String, // 메일 링크에 사용하는 사이트 주소
+    pub mail: MailConfig,
+    pub require_email_verification: bool, // 전체 게시판 글/댓글 작성에 이메일 인증 필요
+    pub storage: StorageConfig,
+    pub virus_scan: VirusScanConfig,
+    pub media_job: MediaJobConfig,
+    pub image: ImageConfig,
+    pub video: VideoConfig,
+    pub upload_limit: UploadLimitConfig,
+    pub captcha: CaptchaConfig,
+    pub trusted_proxies: Vec<TrustedProxy>, // X-Forwarded-For / X-Real-IP를 믿을 프록시 (nginx)
+    pub guest_post: GuestPostConfig,
 }
 
 impl Config {
@@ -34,6 +198,20 @@ impl Config {
             _ => "debug"
         };
 
+        // 운영 환경은 SMTP, 개발 환경은 로그 출력이 기본
+        let default_mail_transport = match node_env.as_str() {
+            "production" => "smtp",
+            _ => "log"
+        };
+
+        let mail_transport_env = env::var("MAIL_TRANSPORT").ok();
+        let mail_transport = mail_transport_env
+            .clone()
+            .unwrap_or_else(|| default_mail_transport.to_string());
+        if !MAIL_TRANSPORTS.contains(&mail_transport.as_str()) {
+            panic!("MAIL_TRANSPORT must be one of smtp, file, log (got {})", mail_transport);
+        }
+
         Self {
             database_url: env::var("DATABASE_URL")
                 .expect("DATABASE_URL environment variable is required"),
@@ -59,9 +237,215 @@ impl Config {
                 .expect("REDIS_URL environment variable is required"),
             rust_log: env::var("RUST_LOG_LEVEL")
                 .unwrap_or_else(|_| default_rust_log.to_string()),
+            report_auto_hide_threshold: env::var("REPORT_AUTO_HIDE_THRESHOLD")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()
+                .expect("REPORT_AUTO_HIDE_THRESHOLD must be a number"),
+            oauth_providers: OAUTH_PROVIDER_DEFAULTS
+                .iter()
+                .filter_map(|defaults| oauth_provider_from_env(*defaults))
+                .collect(),
+            site_url: env::var("SITE_URL")
+                .unwrap_or_else(|_| "http://localhost:5173".to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            mail: MailConfig {
+                transport: mail_transport,
+                log_body: mail_transport_env.as_deref() == Some("log"),
+                from: env::var("MAIL_FROM")
+                    .unwrap_or_else(|_| "MinCenter <no-reply@localhost>".to_string()),
+                smtp_host: env::var("SMTP_HOST")
+                    .unwrap_or_else(|_| "localhost".to_string()),
+                smtp_port: env::var("SMTP_PORT")
+                    .unwrap_or_else(|_| "587".to_string())
+                    .parse()
+                    .expect("SMTP_PORT must be a number"),
+                smtp_username: env::var("SMTP_USERNAME").ok().filter(|v| !v.is_empty()),
+                smtp_password: env::var("SMTP_PASSWORD").ok().filter(|v| !v.is_empty()),
+                smtp_security: env::var("SMTP_SECURITY")
+                    .unwrap_or_else(|_| "starttls".to_string()),
+                file_dir: env::var("MAIL_FILE_DIR")
+                    .unwrap_or_else(|_| "static/mail".to_string()),
+            },
+            require_email_verification: env::var("REQUIRE_EMAIL_VERIFICATION")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            storage: StorageConfig {
+                backend: env::var("STORAGE_BACKEND")
+                    .unwrap_or_else(|_| "local".to_string()),
+                local_root: env::var("STORAGE_LOCAL_ROOT")
+                    .unwrap_or_else(|_| "static/uploads".to_string())
+                    .trim_end_matches('/')
+                    .to_string(),
+                temp_dir: env::var("UPLOAD_TEMP_DIR")
+                    .unwrap_or_else(|_| "static/uploads/temp".to_string()),
+                upload_session_ttl_hours: env::var("UPLOAD_SESSION_TTL_HOURS")
+                    .unwrap_or_else(|_| "24".to_string())
+                    .parse()
+                    .expect("UPLOAD_SESSION_TTL_HOURS must be a number"),
+                max_chunk_size: env::var("UPLOAD_MAX_CHUNK_SIZE")
+                    .unwrap_or_else(|_| "10485760".to_string())
+                    .parse()
+                    .expect("UPLOAD_MAX_CHUNK_SIZE must be a number"),
+                orphan_grace_hours: env::var("FILE_GC_GRACE_HOURS")
+                    .unwrap_or_else(|_| "24".to_string())
+                    .parse()
+                    .expect("FILE_GC_GRACE_HOURS must be a number"),
+                file_gc_interval_minutes: env::var("FILE_GC_INTERVAL_MINUTES")
+                    .unwrap_or_else(|_| "60".to_string())
+                    .parse()
+                    .expect("FILE_GC_INTERVAL_MINUTES must be a number"),
+                s3_endpoint: env::var("S3_ENDPOINT")
+                    .unwrap_or_else(|_| "http://localhost:9000".to_string())
+                    .trim_end_matches('/')
+                    .to_string(),
+                s3_region: env::var("S3_REGION")
+                    .unwrap_or_else(|_| "us-east-1".to_string()),
+                s3_bucket: env::var("S3_BUCKET")
+                    .unwrap_or_else(|_| "mincenter-uploads".to_string()),
+                s3_access_key: env::var("S3_ACCESS_KEY").unwrap_or_default(),
+                s3_secret_key: env::var("S3_SECRET_KEY").unwrap_or_default(),
+                s3_path_style: env::var("S3_PATH_STYLE")
+                    .map(|v| v == "true" || v == "1")
+                    .unwrap_or(true),
+                download_mode: env::var("STORAGE_DOWNLOAD_MODE")
+                    .unwrap_or_else(|_| "proxy".to_string()),
+                presign_expiry_seconds: env::var("STORAGE_PRESIGN_EXPIRY_SECONDS")
+                    .unwrap_or_else(|_| "300".to_string())
+                    .parse()
+                    .expect("STORAGE_PRESIGN_EXPIRY_SECONDS must be a number"),
+            },
+            virus_scan: VirusScanConfig {
+                backend: env::var("VIRUS_SCAN")
+                    .unwrap_or_else(|_| "none".to_string()),
+                clamd_address: env::var("CLAMD_ADDRESS")
+                    .unwrap_or_else(|_| "tcp://localhost:3310".to_string()),
+                timeout_seconds: env::var("VIRUS_SCAN_TIMEOUT_SECONDS")
+                    .unwrap_or_else(|_| "60".to_string())
+                    .parse()
+                    .expect("VIRUS_SCAN_TIMEOUT_SECONDS must be a number"),
+            },
+            media_job: MediaJobConfig {
+                concurrency: env::var("MEDIA_JOB_CONCURRENCY")
+                    .unwrap_or_else(|_| "2".to_string())
+                    .parse()
+                    .expect("MEDIA_JOB_CONCURRENCY must be a number"),
+                max_attempts: env::var("MEDIA_JOB_MAX_ATTEMPTS")
+                    .unwrap_or_else(|_| "5".to_string())
+                    .parse()
+                    .expect("MEDIA_JOB_MAX_ATTEMPTS must be a number"),
+                retry_base_seconds: env::var("MEDIA_JOB_RETRY_BASE_SECONDS")
+                    .unwrap_or_else(|_| "30".to_string())
+                    .parse()
+                    .expect("MEDIA_JOB_RETRY_BASE_SECONDS must be a number"),
+                poll_interval_seconds: env::var("MEDIA_JOB_POLL_INTERVAL_SECONDS")
+                    .unwrap_or_else(|_| "5".to_string())
+                    .parse()
+                    .expect("MEDIA_JOB_POLL_INTERVAL_SECONDS must be a number"),
+            },
+            image: ImageConfig {
+                strip_metadata: env::var("IMAGE_STRIP_METADATA")
+                    .map(|v| v == "true" || v == "1")
+                    .unwrap_or(true),
+                webp_variants: env::var("IMAGE_WEBP_VARIANTS")
+                    .map(|v| v == "true" || v == "1")
+                    .unwrap_or(true),
+                webp_quality: env::var("IMAGE_WEBP_QUALITY")
+                    .unwrap_or_else(|_| "80".to_string())
+                    .parse()
+                    .expect("IMAGE_WEBP_QUALITY must be a number"),
+                avif_variants: env::var("IMAGE_AVIF_VARIANTS")
+                    .map(|v| v == "true" || v == "1")
+                    .unwrap_or(false),
+                proxy_secret: env::var("IMAGE_PROXY_SECRET").unwrap_or_default(),
+                proxy_max_dimension: env::var("IMAGE_PROXY_MAX_DIMENSION")
+                    .unwrap_or_else(|_| "2048".to_string())
+                    .parse()
+                    .expect("IMAGE_PROXY_MAX_DIMENSION must be a number"),
+                cache_dir: env::var("IMAGE_CACHE_DIR")
+                    .unwrap_or_else(|_| "static/cache/images".to_string()),
+                cache_max_mb: env::var("IMAGE_CACHE_MAX_MB")
+                    .unwrap_or_else(|_| "512".to_string())
+                    .parse()
+                    .expect("IMAGE_CACHE_MAX_MB must be a number"),
+            },
+            video: VideoConfig {
+                ffmpeg_path: env::var("FFMPEG_PATH")
+                    .unwrap_or_else(|_| "ffmpeg".to_string()),
+                ffprobe_path: env::var("FFPROBE_PATH")
+                    .unwrap_or_else(|_| "ffprobe".to_string()),
+                poster_seconds: env::var("VIDEO_POSTER_SECONDS")
+                    .unwrap_or_else(|_| "1".to_string())
+                    .parse()
+                    .expect("VIDEO_POSTER_SECONDS must be a number"),
+                timeout_seconds: env::var("VIDEO_PROCESS_TIMEOUT_SECONDS")
+                    .unwrap_or_else(|_| "120".to_string())
+                    .parse()
+                    .expect("VIDEO_PROCESS_TIMEOUT_SECONDS must be a number"),
+            },
+            upload_limit: UploadLimitConfig {
+                user_storage_quota_mb: env::var("USER_STORAGE_QUOTA_MB")
+                    .unwrap_or_else(|_| "1024".to_string())
+                    .parse()
+                    .expect("USER_STORAGE_QUOTA_MB must be a number"),
+                admin_storage_quota_mb: env::var("ADMIN_STORAGE_QUOTA_MB")
+                    .unwrap_or_else(|_| "0".to_string())
+                    .parse()
+                    .expect("ADMIN_STORAGE_QUOTA_MB must be a number"),
+                user_daily_uploads: env::var("USER_DAILY_UPLOAD_LIMIT")
+                    .unwrap_or_else(|_| "100".to_string())
+                    .parse()
+                    .expect("USER_DAILY_UPLOAD_LIMIT must be a number"),
+                admin_daily_uploads: env::var("ADMIN_DAILY_UPLOAD_LIMIT")
+                    .unwrap_or_else(|_| "0".to_string())
+                    .parse()
+                    .expect("ADMIN_DAILY_UPLOAD_LIMIT must be a number"),
+            },
+            captcha: CaptchaConfig {
+                provider: env::var("CAPTCHA_PROVIDER")
+                    .unwrap_or_else(|_| "math".to_string())
+                    .to_lowercase(),
+                site_key: env::var("CAPTCHA_SITE_KEY").unwrap_or_default(),
+                secret_key: env::var("CAPTCHA_SECRET_KEY").unwrap_or_default(),
+                verify_url: env::var("CAPTCHA_VERIFY_URL").unwrap_or_default(),
+                min_score: env::var("CAPTCHA_MIN_SCORE")
+                    .unwrap_or_else(|_| "0.5".to_string())
+                    .parse()
+                    .expect("CAPTCHA_MIN_SCORE must be a number"),
+                ttl_seconds: env::var("CAPTCHA_TTL_SECONDS")
+                    .unwrap_or_else(|_| "300".to_string())
+                    .parse()
+                    .expect("CAPTCHA_TTL_SECONDS must be a number"),
+                timeout_seconds: env::var("CAPTCHA_TIMEOUT_SECONDS")
+                    .unwrap_or_else(|_| "10".to_string())
+                    .parse()
+                    .expect("CAPTCHA_TIMEOUT_SECONDS must be a number"),
+            },
+            trusted_proxies: parse_trusted_proxies(
+                &env::var("TRUSTED_PROXIES")
+                    .unwrap_or_else(|_| "127.0.0.0/8,::1,10.0.0.0/8,172.16.0.0/12,192.168.0.0/16".to_string()),
+            ),
+            guest_post: GuestPostConfig {
+                posts_per_hour: env::var("GUEST_POSTS_PER_HOUR")
+                    .unwrap_or_else(|_| "5".to_string())
+                    .parse()
+                    .expect("GUEST_POSTS_PER_HOUR must be a number"),
+                password_attempts_per_hour: env::var("GUEST_PASSWORD_ATTEMPTS_PER_HOUR")
+                    .unwrap_or_else(|_| "20".to_string())
+                    .parse()
+                    .expect("GUEST_PASSWORD_ATTEMPTS_PER_HOUR must be a number"),
+            },
         }
     }
 
+    /// 설정된 소셜 로그인 제공자 조회
+    pub fn oauth_provider(&self, name: &str) -> Option<&OAuthProviderConfig> {
+        self.oauth_providers
+            .iter()
+            .find(|(provider, _)| provider == name)
+            .map(|(_, config)| config)
+    }
+
     pub fn is_production(&self) -> bool {
         env::var("NODE_ENV").unwrap_or_else(|_| "development".to_string()) == "production"
     }
@@ -69,4 +453,25 @@ impl Config {
     pub fn is_development(&self) -> bool {
         !self.is_production()
     }
-} 
\ No newline at end of file
+}
+
+/// OAUTH_{제공자}_* 환경변수에서 제공자 설정 읽기
+/// CLIENT_ID, CLIENT_SECRET, REDIRECT_URI가 모두 있어야 활성화
+fn oauth_provider_from_env(
+    (name, authorize_url, token_url, userinfo_url, scope): (&str, &str, &str, &str, &str),
+) -> Option<(String, OAuthProviderConfig)> {
+    let prefix = format!("OAUTH_{}_", name.to_uppercase());
+    let var = |key: &str| env::var(format!("{}{}", prefix, key)).ok().filter(|v| !v.is_empty());
+
+    let config = OAuthProviderConfig {
+        client_id: var("CLIENT_ID")?,
+        client_secret: var("CLIENT_SECRET")?,
+        redirect_uri: var("REDIRECT_URI")?,
+        authorize_url: var("AUTHORIZE_URL").unwrap_or_else(|| authorize_url.to_string()),
+        token_url: var("TOKEN_URL").unwrap_or_else(|| token_url.to_string()),
+        userinfo_url: var("USERINFO_URL").unwrap_or_else(|| userinfo_url.to_string()),
+        scope: var("SCOPE").unwrap_or_else(|| scope.to_string()),
+    };
+
+    Some((name.to_string(), config))
+}
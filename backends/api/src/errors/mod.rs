@@ -31,6 +31,12 @@ pub enum ApiError {
   
   #[error("Forbidden: {0}")]
   Forbidden(String),
+
+  #[error("Payload too large: {0}")]
+  PayloadTooLarge(String),
+
+  #[error("Too many requests: {0}")]
+  TooManyRequests(String),
 }
 
 impl From<StatusCode> for ApiError {
@@ -40,6 +46,8 @@ impl From<StatusCode> for ApiError {
             StatusCode::FORBIDDEN => ApiError::Forbidden("Forbidden".to_string()),
             StatusCode::NOT_FOUND => ApiError::NotFound("Not found".to_string()),
             StatusCode::BAD_REQUEST => ApiError::BadRequest("Bad request".to_string()),
+            StatusCode::PAYLOAD_TOO_LARGE => ApiError::PayloadTooLarge("Payload too large".to_string()),
+            StatusCode::TOO_MANY_REQUESTS => ApiError::TooManyRequests("Too many requests".to_string()),
             _ => ApiError::Internal(format!("HTTP Error: {}", status)),
         }
     }
@@ -56,6 +64,8 @@ impl IntoResponse for ApiError {
           ApiError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
           ApiError::Database(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
           ApiError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+          ApiError::PayloadTooLarge(msg) => (StatusCode::PAYLOAD_TOO_LARGE, msg),
+          ApiError::TooManyRequests(msg) => (StatusCode::TOO_MANY_REQUESTS, msg),
       };
 
       let body = Json(json!({
@@ -1,19 +1,21 @@
 use axum::{
     extract::{Path, Query, State, Extension},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     Json,
 };
 use serde::{Deserialize, Serialize};
 use tracing::{info, error, warn, debug};
 use crate::{
     models::response::ApiResponse,
-    models::user::User,
+    models::user::{User, UserStatus},
     models::site::menu::{Menu, CreateMenuRequest, UpdateMenuRequest, MenuType},
     models::site::page::{Page, CreatePageRequest, UpdatePageRequest},
     models::admin::board::Board,
     models::site::community::{CommentDetail},
+    services::storage_usage,
     utils::auth::{generate_tokens, hash_refresh_token, get_current_user, Claims},
     utils::uuid_compression::compress_uuid_to_base62,
+    utils::client_ip::ClientIp,
     AppState,
     errors::ApiError,
 };
@@ -79,6 +81,7 @@ pub struct UsersResponse {
 // Admin 로그인
 pub async fn admin_login(
     State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
     Json(data): Json<AdminLoginRequest>,
 ) -> Result<Json<ApiResponse<AdminAuthResponse>>, StatusCode> {
     info!("Admin login attempt for email: {}", data.email);
@@ -115,6 +118,7 @@ pub async fn admin_login(
             return Ok(Json(ApiResponse::<AdminAuthResponse>::error("관리자 권한이 없습니다.")));
         }
     }
+    crate::handlers::site::auth::record_login(&state, admin_user_db.id, client_ip.as_deref()).await;
 
     // 관리자 사용자 정보 생성
     let admin_user = AdminUser {
@@ -429,6 +433,16 @@ pub async fn get_user(
             .unwrap_or(Some(0))
             .unwrap_or(0);
 
+            // 업로드 한도 (사용자별 값이 없으면 역할 기본값), 마지막 로그인 IP
+            let (storage_quota_bytes, daily_upload_limit, last_login_ip) = sqlx::query_as::<_, (Option<i64>, Option<i32>, Option<String>)>(
+                "SELECT storage_quota_bytes, daily_upload_limit, last_login_ip FROM users WHERE id = $1"
+            )
+            .bind(user_id)
+            .fetch_one(&state.pool)
+            .await
+            .unwrap_or_default();
+            let storage = storage_usage(&state.pool, &state.config.upload_limit, user_id).await.ok();
+
             // JSON 응답 구성
             let user_data = serde_json::json!({
                 "id": user.id,
@@ -439,10 +453,14 @@ pub async fn get_user(
                 "status": user.status.map(|s| format!("{:?}", s).to_lowercase()),
                 "profile_image": user.profile_image,
                 "last_login_at": user.last_login_at,
+                "last_login_ip": last_login_ip,
                 "created_at": user.created_at,
                 "updated_at": user.updated_at,
                 "post_count": post_count,
-                "point_balance": point_balance
+                "point_balance": point_balance,
+                "storage_quota_bytes": storage_quota_bytes,
+                "daily_upload_limit": daily_upload_limit,
+                "storage_usage": storage
             });
 
             info!("User details retrieved successfully for user ID: {}", user_id);
@@ -463,6 +481,8 @@ pub struct UpdateUserRequest {
     pub phone: Option<String>,
     pub role: Option<String>,
     pub status: Option<String>,
+    pub storage_quota_bytes: Option<i64>, // 사용자별 저장 공간 한도 (0: 무제한, 음수: 역할 기본값 사용)
+    pub daily_upload_limit: Option<i32>,  // 사용자별 일일 업로드 수 (0: 무제한, 음수: 역할 기본값 사용)
 }
 
 pub async fn update_user(
@@ -524,6 +544,26 @@ pub async fn update_user(
         params.push(status);
     }
 
+    if let Some(quota) = data.storage_quota_bytes {
+        if quota < 0 {
+            updates.push("storage_quota_bytes = NULL".to_string());
+        } else {
+            param_count += 1;
+            updates.push(format!("storage_quota_bytes = ${}::bigint", param_count));
+            params.push(quota.to_string());
+        }
+    }
+
+    if let Some(limit) = data.daily_upload_limit {
+        if limit < 0 {
+            updates.push("daily_upload_limit = NULL".to_string());
+        } else {
+            param_count += 1;
+            updates.push(format!("daily_upload_limit = ${}::integer", param_count));
+            params.push(limit.to_string());
+        }
+    }
+
     // updated_at 필드 추가 (파라미터 없음)
     updates.push("updated_at = NOW()".to_string());
 
@@ -559,6 +599,19 @@ pub async fn update_user(
             StatusCode::INTERNAL_SERVER_ERROR
         })?;
 
+    // 정지/비활성화된 사용자는 발급된 토큰을 모두 폐기
+    let was_active = existing_user.as_ref().is_some_and(|user| !matches!(user.status, Some(UserStatus::Inactive | UserStatus::Suspended)));
+    if was_active && matches!(updated_user.status, Some(UserStatus::Inactive | UserStatus::Suspended)) {
+        state.token_blacklist
+            .revoke_all_for_user(user_id, state.config.access_token_expiry)
+            .await
+            .map_err(|e| {
+                error!("Failed to revoke tokens for user {}: {:?}", user_id, e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+        info!("Revoked all tokens for deactivated user: {}", user_id);
+    }
+
     info!("User updated successfully with ID: {}", user_id);
     Ok(Json(ApiResponse::success(updated_user, "사용자 정보가 수정되었습니다.")))
 }
@@ -601,7 +654,7 @@ pub async fn get_comments(
     let mut sql = r#"
         SELECT 
             c.id, c.post_id, c.user_id, c.parent_id, c.content, c.likes, 
-            c.status, c.created_at, c.updated_at,
+            c.status, c.created_at, c.updated_at, c.ip_address,
             u.name as user_name
         FROM comments c
         LEFT JOIN users u ON c.user_id = u.id
@@ -649,12 +702,35 @@ pub async fn get_comments(
 pub async fn admin_logout(
     State(state): State<AppState>,
     Extension(claims): Extension<Claims>,
+    headers: HeaderMap,
 ) -> Result<Json<ApiResponse<String>>, StatusCode> {
     info!("Admin logout for user: {}", claims.sub);
-    
-    // 리프레시 토큰 삭제 (선택사항)
-    // 실제로는 클라이언트에서 토큰을 삭제하므로 서버에서는 로그만 남김
-    
+
+    // 현재 액세스 토큰 폐기 (admin_middleware를 통과했으므로 헤더에 토큰이 있음)
+    if let Some(token) = headers
+        .get("Authorization")
+        .and_then(|header| header.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "))
+    {
+        state.token_blacklist
+            .revoke_token(token, &claims)
+            .await
+            .map_err(|e| {
+                error!("Failed to revoke access token: {:?}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+    }
+
+    // 관리자 리프레시 토큰 무효화
+    sqlx::query("UPDATE refresh_tokens SET is_revoked = TRUE WHERE user_id = $1 AND service_type = 'admin' AND is_revoked = FALSE")
+        .bind(claims.sub)
+        .execute(&state.pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to revoke refresh tokens: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
     info!("Admin logout completed for user: {}", claims.sub);
     
     Ok(Json(ApiResponse::success("로그아웃 성공".to_string(), "관리자 로그아웃 성공")))
@@ -1,6 +1,6 @@
 use crate::{
     errors::ApiError,
-    models::admin::board::{Board, Category, CreateBoardRequest, UpdateBoardRequest, CreateCategoryRequest, UpdateCategoryRequest, BoardResponse, CategoryResponse},
+    models::admin::board::{Board, Category, COMMENT_LIMIT_UNIT_COMMENTS, COMMENT_LIMIT_UNIT_MINUTES, EDITOR_TYPE_MARKDOWN, EDITOR_TYPE_RICH, EDITOR_TYPE_SIMPLE, CreateBoardRequest, UpdateBoardRequest, CreateCategoryRequest, UpdateCategoryRequest, BoardResponse, CategoryResponse},
     models::response::ApiResponse,
     utils::uuid_compression::compress_uuid_to_base62,
 };
@@ -50,6 +50,7 @@ struct BoardRaw {
     pub show_ip: bool,
     pub edit_comment_limit: i32,
     pub delete_comment_limit: i32,
+    pub comment_limit_unit: String,
     pub use_sns: bool,
     pub use_captcha: bool,
     pub title_length: i32,
@@ -59,6 +60,7 @@ struct BoardRaw {
     pub comment_point: i32,
     pub download_point: i32,
     pub allowed_iframe_domains: Option<String>, // 다시 문자열로 변경
+    pub require_email_verified: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -68,6 +70,21 @@ fn parse_csv_option(s: &Option<String>) -> Option<Vec<String>> {
         .map(|raw| raw.split(',').map(|v| v.trim().to_string()).filter(|v| !v.is_empty()).collect())
 }
 
+// 수정/삭제 제한 기준 값 확인
+fn validate_comment_limit_unit(unit: Option<&str>) -> Result<(), ApiError> {
+    match unit {
+        None | Some(COMMENT_LIMIT_UNIT_COMMENTS) | Some(COMMENT_LIMIT_UNIT_MINUTES) => Ok(()),
+        Some(_) => Err(ApiError::Validation("수정/삭제 제한 기준은 comments 또는 minutes만 가능합니다.".to_string())),
+    }
+}
+
+fn validate_editor_type(editor_type: Option<&str>) -> Result<(), ApiError> {
+    match editor_type {
+        None | Some(EDITOR_TYPE_RICH) | Some(EDITOR_TYPE_SIMPLE) | Some(EDITOR_TYPE_MARKDOWN) => Ok(()),
+        Some(_) => Err(ApiError::Validation("에디터 종류는 rich, simple, markdown만 가능합니다.".to_string())),
+    }
+}
+
 fn convert_board_raw_to_board(raw: BoardRaw) -> Board {
     use crate::utils::uuid_compression::compress_uuid_to_base62;
     
@@ -104,6 +121,7 @@ fn convert_board_raw_to_board(raw: BoardRaw) -> Board {
         show_ip: raw.show_ip,
         edit_comment_limit: raw.edit_comment_limit,
         delete_comment_limit: raw.delete_comment_limit,
+        comment_limit_unit: raw.comment_limit_unit,
         use_sns: raw.use_sns,
         use_captcha: raw.use_captcha,
         title_length: raw.title_length,
@@ -113,6 +131,7 @@ fn convert_board_raw_to_board(raw: BoardRaw) -> Board {
         comment_point: raw.comment_point,
         download_point: raw.download_point,
         allowed_iframe_domains: parse_csv_option(&raw.allowed_iframe_domains),
+        require_email_verified: raw.require_email_verified,
         created_at: raw.created_at,
         updated_at: raw.updated_at,
     }
@@ -201,6 +220,9 @@ pub async fn create_board(
     State(state): State<AppState>,
     Json(board_data): Json<CreateBoardRequest>,
 ) -> Result<Json<ApiResponse<Board>>, ApiError> {
+    validate_comment_limit_unit(board_data.comment_limit_unit.as_deref())?;
+    validate_editor_type(board_data.editor_type.as_deref())?;
+
     // 배열을 콤마 문자열로 변환
     let allowed_file_types_str = board_data.allowed_file_types
         .as_ref()
@@ -219,12 +241,12 @@ pub async fn create_board(
             hide_list, editor_type, allow_search, allow_recommend, allow_disrecommend,
             show_author_name, show_ip, edit_comment_limit, delete_comment_limit,
             use_sns, use_captcha, title_length, posts_per_page, read_point, write_point,
-            comment_point, download_point, allowed_iframe_domains
+            comment_point, download_point, allowed_iframe_domains, require_email_verified, comment_limit_unit
         )
         VALUES (
             $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, NOW(), NOW(),
             $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30,
-            $31, $32, $33, $34, $35, $36, $37, $38, $39
+            $31, $32, $33, $34, $35, $36, $37, $38, $39, $40, $41
         )
         RETURNING *
         "#,
@@ -251,7 +273,7 @@ pub async fn create_board(
     .bind(board_data.comment_permission.as_deref().unwrap_or("member"))
     .bind(board_data.download_permission.as_deref().unwrap_or("member"))
     .bind(board_data.hide_list.unwrap_or(false))
-    .bind(board_data.editor_type.as_deref().unwrap_or("rich"))
+    .bind(board_data.editor_type.as_deref().unwrap_or(EDITOR_TYPE_RICH))
     .bind(board_data.allow_search.unwrap_or(true))
     .bind(board_data.allow_recommend.unwrap_or(true))
     .bind(board_data.allow_disrecommend.unwrap_or(false))
@@ -268,6 +290,8 @@ pub async fn create_board(
     .bind(board_data.comment_point.unwrap_or(0))
     .bind(board_data.download_point.unwrap_or(0))
     .bind(allowed_iframe_domains_str.as_deref())
+    .bind(board_data.require_email_verified.unwrap_or(false))
+    .bind(board_data.comment_limit_unit.as_deref().unwrap_or(COMMENT_LIMIT_UNIT_COMMENTS))
     .fetch_one(&state.pool)
     .await?;
 
@@ -297,6 +321,9 @@ pub async fn update_board(
     Path(id): Path<Uuid>,
     Json(board_data): Json<UpdateBoardRequest>,
 ) -> Result<Json<ApiResponse<Board>>, ApiError> {
+    validate_comment_limit_unit(board_data.comment_limit_unit.as_deref())?;
+    validate_editor_type(board_data.editor_type.as_deref())?;
+
     // 배열을 콤마 문자열로 변환
     let allowed_file_types_str = board_data.allowed_file_types
         .as_ref()
@@ -347,8 +374,10 @@ pub async fn update_board(
             comment_point = COALESCE($37, comment_point),
             download_point = COALESCE($38, download_point),
             allowed_iframe_domains = COALESCE($39, allowed_iframe_domains),
+            require_email_verified = COALESCE($40, require_email_verified),
+            comment_limit_unit = COALESCE($41, comment_limit_unit),
             updated_at = NOW()
-        WHERE id = $40
+        WHERE id = $42
         RETURNING *
         "#,
     )
@@ -391,6 +420,8 @@ pub async fn update_board(
     .bind(board_data.comment_point)
     .bind(board_data.download_point)
     .bind(allowed_iframe_domains_str.as_deref())
+    .bind(board_data.require_email_verified)
+    .bind(board_data.comment_limit_unit.as_deref())
     .bind(id)
     .fetch_one(&state.pool)
     .await?;
@@ -1,9 +1,9 @@
 use axum::{
     extract::{Path, State, Extension},
-    http::StatusCode,
     Json,
 };
 use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::{
@@ -22,34 +22,34 @@ pub struct CommentHideRequest {
 
 #[derive(Debug, Serialize)]
 pub struct CommentHideResponse {
-    pub comment_id: i32,
-    pub hidden_by: Uuid,
+    pub comment_id: Uuid,
+    pub hidden_by: Option<Uuid>, // 신고 누적 자동 숨김은 NULL
     pub hide_reason: String,
     pub hidden_at: chrono::DateTime<chrono::Utc>,
 }
 
-/// 댓글 숨김 (관리자 전용)
-pub async fn hide_comment(
-    Path(comment_id): Path<i32>,
-    State(state): State<AppState>,
-    Extension(claims): Extension<Claims>, // 관리자 권한은 미들웨어에서 체크됨
-    Json(payload): Json<CommentHideRequest>,
-) -> Result<Json<ApiResponse<CommentHideResponse>>, ApiError> {
-    println!("🔒 댓글 숨김 요청: comment_id={}, admin_id={}", comment_id, claims.sub);
-
+/// 댓글 숨김 처리 (숨김 이력 기록 후 status를 hidden으로 변경, is_deleted는 작성자 삭제 전용)
+/// 관리자 숨김과 신고 처리에서 함께 사용 (hidden_by가 None이면 신고 누적 자동 숨김)
+pub async fn apply_comment_hide(
+    pool: &PgPool,
+    comment_id: Uuid,
+    hidden_by: Option<Uuid>,
+    payload: &CommentHideRequest,
+    hide_location: &str,
+) -> Result<CommentHideResponse, ApiError> {
     // 댓글 존재 확인
     let comment = sqlx::query_as::<_, Comment>(
         "SELECT * FROM comments WHERE id = $1 AND is_deleted = false"
     )
     .bind(comment_id)
-    .fetch_optional(&state.pool)
+    .fetch_optional(pool)
     .await
     .map_err(|e| {
         println!("❌ 댓글 조회 실패: {:?}", e);
         e
     })
     .map_err(ApiError::from)?;
-    
+
     let comment = match comment {
         Some(c) => c,
         None => {
@@ -58,7 +58,7 @@ pub async fn hide_comment(
         }
     };
 
-    println!("✅ 댓글 확인: content={}", &comment.content[..comment.content.len().min(50)]);
+    println!("✅ 댓글 확인: content={}", comment.content.chars().take(50).collect::<String>());
 
     // 숨김 사유 생성 (카테고리 + 상세 사유)
     let full_reason = match payload.hide_reason.as_ref() {
@@ -68,29 +68,30 @@ pub async fn hide_comment(
         _ => format!("[{}]", payload.hide_category)
     };
 
+    let mut tx = pool.begin().await?;
+
     // 댓글 숨김 이력 기록 (comment_hide_history 테이블 사용)
-    let hide_record = sqlx::query!(
+    let hidden_at = sqlx::query_scalar::<_, chrono::DateTime<chrono::Utc>>(
         r#"
         INSERT INTO comment_hide_history (
-            comment_id, 
-            hide_reason, 
-            hide_category, 
-            hide_tags, 
-            hidden_by, 
+            comment_id,
+            hide_reason,
+            hide_category,
+            hide_tags,
+            hidden_by,
             hide_location,
             is_hidden
-        ) VALUES ($1, $2, $3, $4, $5, $6, $7) 
-        RETURNING id, hidden_at
-        "#,
-        comment_id,
-        payload.hide_reason.as_deref(),
-        payload.hide_category,
-        payload.hide_tags.as_deref().unwrap_or(&Vec::new()) as &[String],
-        claims.sub,
-        "admin",
-        true
+        ) VALUES ($1, $2, $3, $4, $5, $6, true)
+        RETURNING hidden_at
+        "#
     )
-    .fetch_one(&state.pool)
+    .bind(comment_id)
+    .bind(payload.hide_reason.as_deref())
+    .bind(&payload.hide_category)
+    .bind(payload.hide_tags.clone().unwrap_or_default())
+    .bind(hidden_by)
+    .bind(hide_location)
+    .fetch_one(&mut *tx)
     .await
     .map_err(|e| {
         println!("❌ 댓글 숨김 이력 저장 실패: {:?}", e);
@@ -98,83 +99,80 @@ pub async fn hide_comment(
     })
     .map_err(ApiError::from)?;
 
-    // 댓글을 삭제 상태로 변경 (소프트 삭제)
-    sqlx::query("UPDATE comments SET is_deleted = true, updated_at = NOW() WHERE id = $1")
+    // 댓글 상태를 숨김으로 변경 (게시글 숨김과 동일하게 사이트 목록에서 제외)
+    sqlx::query("UPDATE comments SET status = 'hidden', updated_at = NOW() WHERE id = $1")
         .bind(comment_id)
-        .execute(&state.pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| {
-            println!("❌ 댓글 삭제 상태 변경 실패: {:?}", e);
+            println!("❌ 댓글 숨김 상태 변경 실패: {:?}", e);
             e
         })
         .map_err(ApiError::from)?;
 
+    tx.commit().await?;
+
     println!("✅ 댓글 숨김 완료: comment_id={}", comment_id);
 
-    let response = CommentHideResponse {
+    Ok(CommentHideResponse {
         comment_id,
-        hidden_by: claims.sub,
+        hidden_by,
         hide_reason: full_reason,
-        hidden_at: hide_record.hidden_at.unwrap(),
-    };
-
-    Ok(Json(ApiResponse {
-        success: true,
-        message: "댓글이 성공적으로 숨겨졌습니다.".to_string(),
-        data: Some(response),
-        pagination: None,
-    }))
+        hidden_at,
+    })
 }
 
-/// 댓글 숨김 해제 (관리자 전용)
-pub async fn unhide_comment(
-    Path(comment_id): Path<i32>,
-    State(state): State<AppState>,
-    Extension(claims): Extension<Claims>, // 관리자 권한은 미들웨어에서 체크됨
-) -> Result<Json<ApiResponse<()>>, ApiError> {
-    println!("🔓 댓글 숨김 해제 요청: comment_id={}, admin_id={}", comment_id, claims.sub);
-
+/// 댓글 숨김 해제 처리 (해제 이력 기록 후 숨김 상태였던 댓글만 복원)
+pub async fn apply_comment_unhide(
+    pool: &PgPool,
+    comment_id: Uuid,
+    unhidden_by: Uuid,
+    hide_location: &str,
+) -> Result<(), ApiError> {
     // 댓글 존재 확인 (숨겨진 댓글 포함)
-    let comment = sqlx::query_as::<_, Comment>(
-        "SELECT * FROM comments WHERE id = $1"
+    let exists = sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS(SELECT 1 FROM comments WHERE id = $1)"
     )
     .bind(comment_id)
-    .fetch_optional(&state.pool)
+    .fetch_one(pool)
     .await
     .map_err(|e| {
         println!("❌ 댓글 조회 실패: {:?}", e);
         e
     })
     .map_err(ApiError::from)?;
-    
-    let comment = match comment {
-        Some(c) => c,
-        None => {
-            println!("❌ 댓글을 찾을 수 없음: comment_id={}", comment_id);
-            return Err(ApiError::NotFound("댓글을 찾을 수 없습니다.".to_string()));
-        }
-    };
 
-    // 댓글 숨김 해제 이력 기록
-    sqlx::query!(
+    if !exists {
+        println!("❌ 댓글을 찾을 수 없음: comment_id={}", comment_id);
+        return Err(ApiError::NotFound("댓글을 찾을 수 없습니다.".to_string()));
+    }
+
+    let mut tx = pool.begin().await?;
+
+    // 기존 숨김 이력 해제 후 해제 이력 기록
+    sqlx::query("UPDATE comment_hide_history SET is_hidden = false WHERE comment_id = $1 AND is_hidden = true")
+        .bind(comment_id)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query(
         r#"
         INSERT INTO comment_hide_history (
-            comment_id, 
-            hide_reason, 
-            hide_category, 
-            hidden_by, 
+            comment_id,
+            hide_reason,
+            hide_category,
+            hidden_by,
             hide_location,
             is_hidden
-        ) VALUES ($1, $2, $3, $4, $5, $6) 
-        "#,
-        comment_id,
-        "관리자에 의한 숨김 해제",
-        "해제",
-        claims.sub,
-        "admin",
-        false
+        ) VALUES ($1, $2, $3, $4, $5, false)
+        "#
     )
-    .execute(&state.pool)
+    .bind(comment_id)
+    .bind("관리자에 의한 숨김 해제")
+    .bind("해제")
+    .bind(unhidden_by)
+    .bind(hide_location)
+    .execute(&mut *tx)
     .await
     .map_err(|e| {
         println!("❌ 댓글 숨김 해제 이력 저장 실패: {:?}", e);
@@ -182,10 +180,10 @@ pub async fn unhide_comment(
     })
     .map_err(ApiError::from)?;
 
-    // 댓글 숨김 해제 (소프트 삭제 해제)
-    sqlx::query("UPDATE comments SET is_deleted = false, updated_at = NOW() WHERE id = $1")
+    // 댓글 숨김 해제 (작성자가 삭제한 댓글은 그대로 유지)
+    sqlx::query("UPDATE comments SET status = 'published', updated_at = NOW() WHERE id = $1 AND status = 'hidden'")
         .bind(comment_id)
-        .execute(&state.pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| {
             println!("❌ 댓글 숨김 해제 실패: {:?}", e);
@@ -193,8 +191,42 @@ pub async fn unhide_comment(
         })
         .map_err(ApiError::from)?;
 
+    tx.commit().await?;
+
     println!("✅ 댓글 숨김 해제 완료: comment_id={}", comment_id);
 
+    Ok(())
+}
+
+/// 댓글 숨김 (관리자 전용)
+pub async fn hide_comment(
+    Path(comment_id): Path<Uuid>,
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>, // 관리자 권한은 미들웨어에서 체크됨
+    Json(payload): Json<CommentHideRequest>,
+) -> Result<Json<ApiResponse<CommentHideResponse>>, ApiError> {
+    println!("🔒 댓글 숨김 요청: comment_id={}, admin_id={}", comment_id, claims.sub);
+
+    let response = apply_comment_hide(&state.pool, comment_id, Some(claims.sub), &payload, "admin").await?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: "댓글이 성공적으로 숨겨졌습니다.".to_string(),
+        data: Some(response),
+        pagination: None,
+    }))
+}
+
+/// 댓글 숨김 해제 (관리자 전용)
+pub async fn unhide_comment(
+    Path(comment_id): Path<Uuid>,
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>, // 관리자 권한은 미들웨어에서 체크됨
+) -> Result<Json<ApiResponse<()>>, ApiError> {
+    println!("🔓 댓글 숨김 해제 요청: comment_id={}, admin_id={}", comment_id, claims.sub);
+
+    apply_comment_unhide(&state.pool, comment_id, claims.sub, "admin").await?;
+
     Ok(Json(ApiResponse {
         success: true,
         message: "댓글 숨김이 해제되었습니다.".to_string(),
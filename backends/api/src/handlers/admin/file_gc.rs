@@ -0,0 +1,37 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use crate::{
+    errors::ApiError,
+    models::ApiResponse,
+    models::file::{FileGcQuery, FileGcReport},
+    services::FileGcService,
+    AppState,
+};
+
+// 고아 파일 정리 미리보기 (변경 없이 대상과 회수 용량만 집계)
+pub async fn preview_file_gc(
+    State(state): State<AppState>,
+    Query(query): Query<FileGcQuery>,
+) -> Result<Json<ApiResponse<FileGcReport>>, ApiError> {
+    let grace_hours = query.grace_hours.unwrap_or(state.config.storage.orphan_grace_hours);
+    let report = FileGcService::new(state.pool.clone(), state.storage.clone())
+        .run(grace_hours, true)
+        .await?;
+
+    Ok(Json(ApiResponse::success(report, "고아 파일 정리 대상을 조회했습니다.")))
+}
+
+// 고아 파일 정리 실행
+pub async fn run_file_gc(
+    State(state): State<AppState>,
+    Query(query): Query<FileGcQuery>,
+) -> Result<Json<ApiResponse<FileGcReport>>, ApiError> {
+    let grace_hours = query.grace_hours.unwrap_or(state.config.storage.orphan_grace_hours);
+    let report = FileGcService::new(state.pool.clone(), state.storage.clone())
+        .run(grace_hours, false)
+        .await?;
+
+    Ok(Json(ApiResponse::success(report, "고아 파일을 정리했습니다.")))
+}
@@ -0,0 +1,52 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+use crate::{
+    errors::ApiError,
+    models::{ApiResponse, PaginationInfo},
+    models::file::{MediaJob, MediaJobQuery},
+    services::{list_media_jobs, retry_media_job, JOB_STATUSES},
+    AppState,
+};
+
+// 미디어 후처리 작업 목록 (기본: 실패한 작업)
+pub async fn get_media_jobs(
+    State(state): State<AppState>,
+    Query(query): Query<MediaJobQuery>,
+) -> Result<Json<ApiResponse<Vec<MediaJob>>>, ApiError> {
+    let page = query.page.unwrap_or(1).max(1);
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+    let offset = (page - 1) * limit;
+
+    let status = query.status.as_deref().unwrap_or("failed");
+    if !JOB_STATUSES.contains(&status) {
+        return Err(ApiError::BadRequest("잘못된 작업 상태입니다.".to_string()));
+    }
+
+    let (jobs, total) = list_media_jobs(&state.pool, status, limit as i64, offset as i64).await?;
+    let total_pages = ((total as f64) / (limit as f64)).ceil() as u32;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: "미디어 작업 목록을 조회했습니다.".to_string(),
+        data: Some(jobs),
+        pagination: Some(PaginationInfo {
+            page,
+            limit,
+            total: total as u64,
+            total_pages,
+        }),
+    }))
+}
+
+// 실패한 작업 재시도 (시도 횟수 초기화)
+pub async fn retry_failed_media_job(
+    State(state): State<AppState>,
+    Path(job_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MediaJob>>, ApiError> {
+    let job = retry_media_job(&state.pool, job_id).await?;
+
+    Ok(Json(ApiResponse::success(job, "작업을 다시 대기열에 넣었습니다.")))
+}
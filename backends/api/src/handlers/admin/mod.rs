@@ -6,6 +6,13 @@ pub mod post_management;
 pub mod menu;
 pub mod upload;
 pub mod post;
+pub mod notification;
+pub mod comment_management;
+pub mod report;
+pub mod revision;
+pub mod point;
+pub mod file_gc;
+pub mod media_job;
 
 pub use admin::*;
 pub use board::*;
@@ -14,4 +21,11 @@ pub use rbac::*;
 pub use post_management::*;
 pub use menu::*;
 pub use upload::*;
-pub use post::*; 
\ No newline at end of file
+pub use post::*;
+pub use notification::*;
+pub use comment_management::*;
+pub use report::*;
+pub use revision::*;
+pub use point::*;
+pub use file_gc::*;
+pub use media_job::*; 
\ No newline at end of file
@@ -0,0 +1,37 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    Json,
+};
+use tracing::error;
+use crate::{
+    models::{BroadcastAnnouncementRequest, BroadcastAnnouncementResponse},
+    models::response::ApiResponse,
+    services::NotificationService,
+    AppState,
+};
+
+// 공지 알림 발송 (전체 또는 특정 역할)
+pub async fn broadcast_announcement(
+    State(state): State<AppState>,
+    Json(payload): Json<BroadcastAnnouncementRequest>,
+) -> Result<Json<ApiResponse<BroadcastAnnouncementResponse>>, StatusCode> {
+    if payload.title.trim().is_empty() || payload.message.trim().is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let role = payload.role.as_deref().map(str::trim).filter(|r| !r.is_empty());
+
+    let sent_count = NotificationService::new(state.pool.clone(), state.realtime.clone())
+        .broadcast_announcement(payload.title.trim(), payload.message.trim(), role)
+        .await
+        .map_err(|e| {
+            error!("공지 알림 발송 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        BroadcastAnnouncementResponse { sent_count },
+        "공지 알림을 발송했습니다.",
+    )))
+}
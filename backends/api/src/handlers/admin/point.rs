@@ -0,0 +1,67 @@
+use axum::{
+    extract::{Path, Query, State, Extension},
+    Json,
+};
+use uuid::Uuid;
+use crate::{
+    errors::ApiError,
+    models::{ApiResponse, PaginationInfo},
+    models::{AdjustPointsRequest, PointHistoryQuery, PointHistoryResponse, POINT_TYPE_ADMIN_GRANT, POINT_TYPE_ADMIN_REVOKE},
+    services::PointService,
+    utils::auth::Claims,
+    AppState,
+};
+
+// 회원 포인트 잔액 및 거래 내역
+pub async fn get_user_points(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+    Query(query): Query<PointHistoryQuery>,
+) -> Result<Json<ApiResponse<PointHistoryResponse>>, ApiError> {
+    let page = query.page.unwrap_or(1).max(1);
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+    let offset = (page - 1) * limit;
+
+    let service = PointService::new(state.pool.clone());
+    let balance = service.balance(user_id).await?;
+    let (transactions, total) = service.history(user_id, limit as i64, offset as i64).await?;
+
+    let total_pages = ((total as f64) / (limit as f64)).ceil() as u32;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: "포인트 내역을 조회했습니다.".to_string(),
+        data: Some(PointHistoryResponse { balance, transactions }),
+        pagination: Some(PaginationInfo {
+            page,
+            limit,
+            total: total as u64,
+            total_pages,
+        }),
+    }))
+}
+
+// 회원 포인트 지급/차감 (amount가 음수면 차감)
+pub async fn adjust_user_points(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(user_id): Path<Uuid>,
+    Json(payload): Json<AdjustPointsRequest>,
+) -> Result<Json<ApiResponse<i32>>, ApiError> {
+    if payload.amount == 0 {
+        return Err(ApiError::Validation("포인트는 0이 아닌 값이어야 합니다.".to_string()));
+    }
+
+    let reason = payload.reason.trim();
+    if reason.is_empty() || reason.chars().count() > 255 {
+        return Err(ApiError::Validation("사유는 1~255자로 입력해주세요.".to_string()));
+    }
+
+    let transaction_type = if payload.amount > 0 { POINT_TYPE_ADMIN_GRANT } else { POINT_TYPE_ADMIN_REVOKE };
+
+    let balance = PointService::new(state.pool.clone())
+        .adjust(user_id, payload.amount, transaction_type, reason, claims.sub)
+        .await?;
+
+    Ok(Json(ApiResponse::success(balance, "포인트가 반영되었습니다.")))
+}
@@ -11,11 +11,15 @@ use tracing::{info, error, warn};
 use std::str::FromStr;
 
 use crate::{
+    handlers::site::community::{find_board, find_post_board},
     models::{
         response::ApiResponse,
         site::community::{Post, PostDetail, PostStatus, UpdatePostRequest},
     },
+    services::RevisionService,
     utils::auth::Claims,
+    utils::html_sanitize::prepare_post_content,
+    utils::client_ip::ClientIp,
     AppState,
 };
 use crate::utils::url_id::generate_post_url_id;
@@ -46,9 +50,10 @@ pub async fn get_posts(
             p.updated_at,
             p.depth,
             p.reply_count,
+            p.ip_address,
             b.name as board_name,
             c.name as category_name,
-            u.name as user_name,
+            COALESCE(u.name, p.guest_name) as user_name,
             COALESCE(comment_count.count, 0) as comment_count
         FROM posts p
         LEFT JOIN boards b ON p.board_id = b.id
@@ -163,6 +168,8 @@ pub async fn get_posts(
             attached_files: None,
             thumbnail_urls: None,
             is_liked: None,
+            ip_address: post_raw.ip_address,
+            content_source: None,
         };
         posts.push(post);
     }
@@ -196,6 +203,7 @@ pub async fn get_post(
             p.parent_id,
             p.title,
             p.content,
+            p.content_source,
             p.views,
             p.likes,
             p.is_notice,
@@ -204,9 +212,10 @@ pub async fn get_post(
             p.updated_at,
             p.depth,
             p.reply_count,
+            p.ip_address,
             b.name as board_name,
             c.name as category_name,
-            u.name as user_name,
+            COALESCE(u.name, p.guest_name) as user_name,
             COALESCE(comment_count.count, 0) as comment_count
         FROM posts p
         LEFT JOIN boards b ON p.board_id = b.id
@@ -265,6 +274,8 @@ pub async fn get_post(
         attached_files: None,
         thumbnail_urls: None,
         is_liked: None,
+        ip_address: post_raw.ip_address,
+        content_source: post_raw.content_source,
     };
 
     Ok(Json(ApiResponse::success(post, "게시글을 성공적으로 조회했습니다.")))
@@ -274,15 +285,20 @@ pub async fn get_post(
 pub async fn create_post(
     State(state): State<AppState>,
     Extension(claims): Extension<Claims>,
+    ClientIp(client_ip): ClientIp,
     Json(request): Json<CreatePostRequest>,
 ) -> Result<Json<ApiResponse<PostDetail>>, StatusCode> {
     info!("게시글 생성 요청: user_id={}, title={}", claims.sub, request.title);
 
+    // 작성할 게시판 설정으로 본문 정리
+    let board = find_board(&state, request.board_id).await?;
+    let post_content = prepare_post_content(&board, &request.content);
+
     // 게시글 생성
     let post_result = sqlx::query_as::<_, PostDetailRaw>(
         r#"
-        INSERT INTO posts (board_id, category_id, user_id, title, content, is_notice, created_at)
-        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        INSERT INTO posts (board_id, category_id, user_id, title, content, content_source, is_notice, created_at, ip_address)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
         RETURNING 
             id,
             board_id,
@@ -291,6 +307,7 @@ pub async fn create_post(
             parent_id,
             title,
             content,
+            content_source,
             views,
             likes,
             is_notice,
@@ -299,9 +316,10 @@ pub async fn create_post(
             updated_at,
             depth,
             reply_count,
+            ip_address,
             (SELECT name FROM boards WHERE id = board_id) as board_name,
             (SELECT name FROM categories WHERE id = category_id) as category_name,
-            (SELECT name FROM users WHERE id = user_id) as user_name,
+            COALESCE((SELECT name FROM users WHERE id = user_id), guest_name) as user_name,
             0 as comment_count
         "#
     )
@@ -309,9 +327,11 @@ pub async fn create_post(
     .bind(&request.category_id)
     .bind(&claims.sub)
     .bind(&request.title)
-    .bind(&request.content)
+    .bind(&post_content.content)
+    .bind(&post_content.content_source)
     .bind(&request.is_notice.unwrap_or(false))
     .bind(&request.created_at.unwrap_or_else(|| Utc::now()))
+    .bind(&client_ip)
     .fetch_one(&state.pool)
     .await
     .map_err(|e| {
@@ -347,6 +367,8 @@ pub async fn create_post(
         attached_files: None,
         thumbnail_urls: None,
         is_liked: None,
+        ip_address: post_result.ip_address,
+        content_source: post_result.content_source,
     };
 
     Ok(Json(ApiResponse::success(post, "게시글이 성공적으로 생성되었습니다.")))
@@ -378,6 +400,23 @@ pub async fn update_post(
         return Err(StatusCode::NOT_FOUND);
     }
 
+    // 옮길 게시판(없으면 현재 게시판) 설정으로 본문 정리
+    let board = match request.board_id {
+        Some(board_id) => find_board(&state, board_id).await?,
+        None => find_post_board(&state, post_id).await?,
+    };
+    let post_content = request.content.as_deref().map(|content| prepare_post_content(&board, content));
+    let content = post_content.as_ref().map(|c| c.content.as_str());
+
+    // 수정 전 내용 보관과 수정을 한 트랜잭션에서 처리
+    let mut tx = state.pool.begin().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    RevisionService::snapshot_post(&mut tx, post_id, Some(claims.sub), request.title.as_deref(), content)
+        .await
+        .map_err(|e| {
+            error!("게시글 수정 이력 저장 실패: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
     // 게시글 수정
     let updated_post_raw = sqlx::query_as::<_, PostDetailRaw>(
         r#"
@@ -387,6 +426,7 @@ pub async fn update_post(
             category_id = COALESCE($3, category_id),
             title = COALESCE($4, title),
             content = COALESCE($5, content),
+            content_source = CASE WHEN $5 IS NULL THEN content_source ELSE $7 END,
             is_notice = COALESCE($6, is_notice),
             updated_at = NOW()
         WHERE id = $1
@@ -398,6 +438,7 @@ pub async fn update_post(
             parent_id,
             title,
             content,
+            content_source,
             views,
             likes,
             is_notice,
@@ -406,9 +447,10 @@ pub async fn update_post(
             updated_at,
             depth,
             reply_count,
+            ip_address,
             (SELECT name FROM boards WHERE id = board_id) as board_name,
             (SELECT name FROM categories WHERE id = category_id) as category_name,
-            (SELECT name FROM users WHERE id = user_id) as user_name,
+            COALESCE((SELECT name FROM users WHERE id = user_id), guest_name) as user_name,
             (SELECT COUNT(*) FROM comments WHERE post_id = posts.id AND status = 'active') as comment_count
         "#
     )
@@ -416,14 +458,16 @@ pub async fn update_post(
     .bind(&request.board_id)
     .bind(&request.category_id)
     .bind(&request.title)
-    .bind(&request.content)
+    .bind(content)
     .bind(&request.is_notice)
-    .fetch_one(&state.pool)
+    .bind(post_content.as_ref().and_then(|c| c.content_source.as_deref()))
+    .fetch_one(&mut *tx)
     .await
     .map_err(|e| {
         error!("게시글 수정 실패: {}", e);
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
+    tx.commit().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     let url_id = generate_post_url_id(&state.pool, &updated_post_raw.id).await.ok();
 
@@ -453,6 +497,8 @@ pub async fn update_post(
         attached_files: None,
         thumbnail_urls: None,
         is_liked: None,
+        ip_address: updated_post_raw.ip_address,
+        content_source: updated_post_raw.content_source,
     };
 
     Ok(Json(ApiResponse::success(updated_post, "게시글이 성공적으로 수정되었습니다.")))
@@ -540,7 +586,7 @@ struct PostDetailRaw {
     pub id: Uuid,
     pub board_id: Uuid,
     pub category_id: Option<Uuid>,
-    pub user_id: Uuid,
+    pub user_id: Option<Uuid>,
     pub parent_id: Option<Uuid>,
     pub title: String,
     pub content: String,
@@ -556,6 +602,9 @@ struct PostDetailRaw {
     pub category_name: Option<String>,
     pub user_name: Option<String>,
     pub comment_count: Option<i64>,
+    pub ip_address: Option<String>,
+    #[sqlx(default)]
+    pub content_source: Option<String>,
 }
 
 // 게시글 목록 조회용 Raw 구조체
@@ -564,7 +613,7 @@ struct PostListRaw {
     pub id: Uuid,
     pub board_id: Uuid,
     pub category_id: Option<Uuid>,
-    pub user_id: Uuid,
+    pub user_id: Option<Uuid>,
     pub parent_id: Option<Uuid>,
     pub title: String,
     pub content: String,
@@ -580,4 +629,5 @@ struct PostListRaw {
     pub category_name: Option<String>,
     pub user_name: Option<String>,
     pub comment_count: Option<i64>,
+    pub ip_address: Option<String>,
 }
@@ -86,7 +86,7 @@ pub async fn hide_post(
     require_role(&user, "admin")?;
     
     let service = PostManagementService::new(state.pool);
-    let result = service.hide_post(request, user.id).await?;
+    let result = service.hide_post(request, Some(user.id)).await?;
     
     Ok((StatusCode::OK, Json(result)))
 }
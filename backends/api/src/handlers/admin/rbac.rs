@@ -7,6 +7,7 @@ use uuid::Uuid;
 use crate::{
     models::response::ApiResponse,
     models::rbac::*,
+    services::RbacService,
     AppState,
 };
 use serde::Deserialize;
@@ -170,6 +171,12 @@ pub async fn update_role(
 
     tx.commit().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    // 역할을 가진 사용자들의 권한 캐시 무효화
+    RbacService::new(state.pool.clone(), state.redis.clone())
+        .invalidate_role(role_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
     // 수정된 역할의 상세 정보 조회
     let permissions = sqlx::query_as::<_, Permission>(
         r#"
@@ -217,6 +224,12 @@ pub async fn delete_role(
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    // 역할을 가진 사용자들의 권한 캐시 무효화
+    RbacService::new(state.pool.clone(), state.redis.clone())
+        .invalidate_role(role_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
     Ok(Json(ApiResponse::success((), "역할이 성공적으로 삭제되었습니다.")))
 }
 
@@ -303,6 +316,11 @@ pub async fn update_permission(
     .await
     .map_err(|_| StatusCode::NOT_FOUND)?;
 
+    // 권한 정의가 바뀌었으므로 전체 권한 캐시 무효화
+    RbacService::new(state.pool.clone(), state.redis.clone())
+        .invalidate_all()
+        .await;
+
     Ok(Json(ApiResponse::success(permission, "권한이 성공적으로 수정되었습니다.")))
 }
 
@@ -337,6 +355,11 @@ pub async fn delete_permission(
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    // 권한 정의가 바뀌었으므로 전체 권한 캐시 무효화
+    RbacService::new(state.pool.clone(), state.redis.clone())
+        .invalidate_all()
+        .await;
+
     Ok(Json(ApiResponse::success((), "권한이 성공적으로 삭제되었습니다.")))
 }
 
@@ -386,13 +409,14 @@ pub async fn get_user_permissions(
 // 사용자 역할 할당
 pub async fn assign_user_roles(
     State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
     Json(payload): Json<AssignUserRoleRequest>,
 ) -> Result<Json<ApiResponse<UserPermissions>>, StatusCode> {
     let mut tx = state.pool.begin().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     // 기존 역할 삭제
     sqlx::query("DELETE FROM user_roles WHERE user_id = $1")
-        .bind(payload.user_id)
+        .bind(user_id)
         .execute(&mut *tx)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
@@ -402,7 +426,7 @@ pub async fn assign_user_roles(
         sqlx::query(
             "INSERT INTO user_roles (user_id, role_id) VALUES ($1, $2)"
         )
-        .bind(payload.user_id)
+        .bind(user_id)
         .bind(role_id)
         .execute(&mut *tx)
         .await
@@ -411,6 +435,11 @@ pub async fn assign_user_roles(
 
     tx.commit().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    // 사용자 권한 캐시 무효화
+    RbacService::new(state.pool.clone(), state.redis.clone())
+        .invalidate_user(user_id)
+        .await;
+
     // 할당된 사용자 권한 조회
     let roles = sqlx::query_as::<_, Role>(
         r#"
@@ -420,7 +449,7 @@ pub async fn assign_user_roles(
         ORDER BY r.name
         "#
     )
-    .bind(payload.user_id)
+    .bind(user_id)
     .fetch_all(&state.pool)
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
@@ -434,13 +463,13 @@ pub async fn assign_user_roles(
         ORDER BY p.resource, p.action
         "#
     )
-    .bind(payload.user_id)
+    .bind(user_id)
     .fetch_all(&state.pool)
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     let user_permissions = UserPermissions {
-        user_id: payload.user_id,
+        user_id,
         roles,
         permissions,
     };
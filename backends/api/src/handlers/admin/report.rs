@@ -0,0 +1,58 @@
+use axum::{
+    extract::{Path, Query, State, Extension},
+    Json,
+};
+use uuid::Uuid;
+use crate::{
+    errors::ApiError,
+    models::{ApiResponse, PaginationInfo},
+    models::{Report, ReportListItem, ReportQuery, ResolveReportRequest},
+    services::ReportService,
+    utils::auth::Claims,
+    AppState,
+};
+
+// 신고 목록 (처리 대기열)
+pub async fn get_reports(
+    State(state): State<AppState>,
+    Query(query): Query<ReportQuery>,
+) -> Result<Json<ApiResponse<Vec<ReportListItem>>>, ApiError> {
+    let page = query.page.unwrap_or(1).max(1);
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+    let offset = (page - 1) * limit;
+
+    let status = query.status.as_deref().filter(|s| !s.is_empty() && *s != "all");
+    let entity_type = query.entity_type.as_deref().filter(|s| !s.is_empty() && *s != "all");
+
+    let (reports, total) = ReportService::new(state.pool.clone())
+        .list_reports(status, entity_type, limit as i64, offset as i64)
+        .await?;
+
+    let total_pages = ((total as f64) / (limit as f64)).ceil() as u32;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: "신고 목록을 조회했습니다.".to_string(),
+        data: Some(reports),
+        pagination: Some(PaginationInfo {
+            page,
+            limit,
+            total: total as u64,
+            total_pages,
+        }),
+    }))
+}
+
+// 신고 처리 (숨김/기각/복원)
+pub async fn resolve_report(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(report_id): Path<Uuid>,
+    Json(payload): Json<ResolveReportRequest>,
+) -> Result<Json<ApiResponse<Report>>, ApiError> {
+    let report = ReportService::new(state.pool.clone())
+        .resolve_report(report_id, claims.sub, payload)
+        .await?;
+
+    Ok(Json(ApiResponse::success(report, "신고가 처리되었습니다.")))
+}
@@ -0,0 +1,62 @@
+use axum::{
+    extract::{Extension, Path, State},
+    Json,
+};
+use uuid::Uuid;
+use crate::{
+    errors::ApiError,
+    models::{ApiResponse, ContentRevision, REVISION_ENTITY_COMMENT, REVISION_ENTITY_POST},
+    services::RevisionService,
+    utils::auth::Claims,
+    AppState,
+};
+
+// 게시글 수정 이력 목록
+pub async fn get_post_revisions(
+    State(state): State<AppState>,
+    Path(post_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ContentRevision>>>, ApiError> {
+    let revisions = RevisionService::new(state.pool.clone())
+        .list_revisions(REVISION_ENTITY_POST, post_id)
+        .await?;
+
+    Ok(Json(ApiResponse::success(revisions, "게시글 수정 이력을 조회했습니다.")))
+}
+
+// 게시글을 이전 버전으로 복원
+pub async fn restore_post_revision(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((post_id, revision_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<ContentRevision>>, ApiError> {
+    let revision = RevisionService::new(state.pool.clone())
+        .restore_revision(REVISION_ENTITY_POST, post_id, revision_id, claims.sub)
+        .await?;
+
+    Ok(Json(ApiResponse::success(revision, "게시글을 이전 버전으로 복원했습니다.")))
+}
+
+// 댓글 수정 이력 목록
+pub async fn get_comment_revisions(
+    State(state): State<AppState>,
+    Path(comment_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ContentRevision>>>, ApiError> {
+    let revisions = RevisionService::new(state.pool.clone())
+        .list_revisions(REVISION_ENTITY_COMMENT, comment_id)
+        .await?;
+
+    Ok(Json(ApiResponse::success(revisions, "댓글 수정 이력을 조회했습니다.")))
+}
+
+// 댓글을 이전 버전으로 복원
+pub async fn restore_comment_revision(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((comment_id, revision_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<ContentRevision>>, ApiError> {
+    let revision = RevisionService::new(state.pool.clone())
+        .restore_revision(REVISION_ENTITY_COMMENT, comment_id, revision_id, claims.sub)
+        .await?;
+
+    Ok(Json(ApiResponse::success(revision, "댓글을 이전 버전으로 복원했습니다.")))
+}
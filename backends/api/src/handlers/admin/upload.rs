@@ -10,6 +10,9 @@ use crate::{
     AppState,
     models::response::ApiResponse,
     models::file::{File, FileType, FileStatus, ProcessingStatus, FileEntity, EntityType, FilePurpose, FileInfo},
+    handlers::site::upload::scan_upload,
+    services::upload_url,
+    utils::file_sniff::content_mime_type,
 };
 
 // 파일 업로드 응답
@@ -29,7 +32,6 @@ pub async fn upload_site_file(
     mut multipart: Multipart,
 ) -> Result<Json<ApiResponse<UploadResponse>>, StatusCode> {
     let mut filename = String::new();
-    let mut file_path = String::new();
     let mut size = 0u64;
     let mut mime_type = String::new();
     let mut file_type = String::new();
@@ -96,24 +98,26 @@ pub async fn upload_site_file(
                 return Err(StatusCode::PAYLOAD_TOO_LARGE);
             }
 
+            // 내용 기준 MIME 타입 확인 후 저장 전에 바이러스 검사
+            mime_type = content_mime_type(&extension, &file_data)
+                .ok_or(StatusCode::BAD_REQUEST)?
+                .to_string();
+            scan_upload(&state, &file_data).await?;
+
             // 파일 타입 결정 (hero, background, logo, banner)
             file_type = "logo".to_string(); // 로고 업로드용
 
             // 파일명 생성
             let timestamp = Utc::now().timestamp();
             filename = format!("{}_{}.{}", file_type, timestamp, extension);
-            file_path = format!("static/uploads/site/{}/{}", file_type, filename);
-            
-            // 디렉토리 생성
-            std::fs::create_dir_all(format!("static/uploads/site/{}", file_type))
-                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            let file_key = format!("site/{}/{}", file_type, filename);
+
+            size = file_data.len() as u64;
 
             // 파일 저장
-            std::fs::write(&file_path, &file_data)
+            state.storage.put(&file_key, file_data, &mime_type)
+                .await
                 .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-            size = file_data.len() as u64;
-            mime_type = get_mime_type(&extension);
         }
     }
 
@@ -121,7 +125,7 @@ pub async fn upload_site_file(
         return Err(StatusCode::BAD_REQUEST);
     }
 
-    let url = format!("/uploads/site/{}/{}", file_type, filename);
+    let url = upload_url(&format!("site/{}/{}", file_type, filename));
 
     Ok(Json(ApiResponse {
         success: true,
@@ -150,17 +154,3 @@ pub async fn upload_site_file(
 fn is_image_file(extension: &str) -> bool {
     matches!(extension, "jpg" | "jpeg" | "png" | "gif" | "webp" | "svg" | "bmp" | "ico")
 }
-
-// MIME 타입 반환
-fn get_mime_type(extension: &str) -> String {
-    match extension {
-        "jpg" | "jpeg" => "image/jpeg".to_string(),
-        "png" => "image/png".to_string(),
-        "gif" => "image/gif".to_string(),
-        "webp" => "image/webp".to_string(),
-        "svg" => "image/svg+xml".to_string(),
-        "bmp" => "image/bmp".to_string(),
-        "ico" => "image/x-icon".to_string(),
-        _ => "application/octet-stream".to_string(),
-    }
-}
@@ -4,11 +4,24 @@ pub mod site;
 pub mod admin;
 
 // Site handlers
+pub use site::account;
 pub use site::auth;
+pub use site::captcha;
 pub use site::community;
+pub use site::draft;
+pub use site::guest_post;
+pub use site::notification;
+pub use site::oauth;
+pub use site::point;
+pub use site::realtime;
+pub use site::report;
+pub use site::revision;
+pub use site::search;
 pub use site::menu as site_menu;
 pub use site::page;
 pub use site::upload;
+pub use site::upload_session;
+pub use site::image_proxy;
 pub use site::calendar;
 pub use site::site_info;
 
@@ -0,0 +1,64 @@
+use axum::{
+    extract::{State, Extension},
+    Json,
+};
+use crate::{
+    errors::ApiError,
+    models::ApiResponse,
+    models::{ForgotPasswordRequest, ResetPasswordRequest, VerifyEmailRequest},
+    services::AccountEmailService,
+    utils::auth::Claims,
+    AppState,
+};
+
+fn account_email_service(state: &AppState) -> AccountEmailService {
+    AccountEmailService::new(state.pool.clone(), state.redis.clone(), state.config.clone(), state.token_blacklist.clone())
+}
+
+// 이메일 인증 메일 (재)발송
+pub async fn request_email_verification(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+) -> Result<Json<ApiResponse<()>>, ApiError> {
+    let claims = claims.ok_or_else(|| ApiError::Authentication("로그인이 필요합니다.".to_string()))?;
+
+    account_email_service(&state).send_verification(claims.sub).await?;
+
+    Ok(Json(ApiResponse::success((), "인증 메일을 발송했습니다.")))
+}
+
+// 이메일 인증 확인 (메일 링크의 토큰)
+pub async fn verify_email(
+    State(state): State<AppState>,
+    Json(payload): Json<VerifyEmailRequest>,
+) -> Result<Json<ApiResponse<()>>, ApiError> {
+    account_email_service(&state).verify_email(&payload.token).await?;
+
+    Ok(Json(ApiResponse::success((), "이메일 인증이 완료되었습니다.")))
+}
+
+// 비밀번호 재설정 메일 발송
+pub async fn forgot_password(
+    State(state): State<AppState>,
+    Json(payload): Json<ForgotPasswordRequest>,
+) -> Result<Json<ApiResponse<()>>, ApiError> {
+    if payload.email.trim().is_empty() {
+        return Err(ApiError::Validation("이메일을 입력해주세요.".to_string()));
+    }
+
+    account_email_service(&state).send_password_reset(&payload.email).await?;
+
+    Ok(Json(ApiResponse::success((), "가입된 이메일이면 비밀번호 재설정 메일이 발송됩니다.")))
+}
+
+// 비밀번호 재설정 (메일 링크의 토큰)
+pub async fn reset_password(
+    State(state): State<AppState>,
+    Json(payload): Json<ResetPasswordRequest>,
+) -> Result<Json<ApiResponse<()>>, ApiError> {
+    account_email_service(&state)
+        .reset_password(&payload.token, &payload.new_password)
+        .await?;
+
+    Ok(Json(ApiResponse::success((), "비밀번호가 변경되었습니다. 다시 로그인해주세요.")))
+}
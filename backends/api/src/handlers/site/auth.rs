@@ -1,6 +1,6 @@
 use axum::{
     extract::{State, Json, Extension},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     response::Json as AxumJson,
 };
 use bcrypt::{hash, verify, DEFAULT_COST};
@@ -12,9 +12,11 @@ use sqlx::PgPool;
 use uuid::Uuid;
 use crate::{
     config::Config,
-    models::user::{User, LoginRequest, RegisterRequest, RefreshRequest, AuthResponse, RefreshResponse},
+    models::user::{User, LoginRequest, RegisterRequest, RefreshRequest, ChangePasswordRequest, AuthResponse, RefreshResponse},
     models::response::ApiResponse,
-    utils::auth::{generate_tokens, hash_refresh_token, Claims},
+    services::AccountEmailService,
+    utils::auth::{generate_tokens, hash_refresh_token, verify_token, Claims},
+    utils::client_ip::ClientIp,
     AppState,
 };
 
@@ -81,6 +83,15 @@ pub async fn register(
   eprintln!("✅ 리프레시 토큰 저장 성공");
 
   eprintln!("✅ 회원가입 완료: user_id={}", user.id);
+
+  // 이메일 인증 메일 발송 (발송 실패가 가입을 막지 않도록 백그라운드 처리)
+  let account_email = AccountEmailService::new(state.pool.clone(), state.redis.clone(), state.config.clone(), state.token_blacklist.clone());
+  let user_id = user.id;
+  tokio::spawn(async move {
+      if let Err(e) = account_email.send_verification(user_id).await {
+          eprintln!("❌ 인증 메일 발송 실패: user_id={}, error={:?}", user_id, e);
+      }
+  });
   
   // AuthResponse 생성
   let auth_response = AuthResponse {
@@ -100,6 +111,7 @@ pub async fn register(
 
 pub async fn login(
   State(state): State<AppState>,
+  ClientIp(client_ip): ClientIp,
   Json(data): Json<LoginRequest>,
 ) -> Result<AxumJson<ApiResponse<AuthResponse>>, StatusCode> {
   let user = match sqlx::query_as::<_, User>(
@@ -125,6 +137,7 @@ pub async fn login(
   if !crate::utils::auth::verify_password(&data.password, password_hash) {
       return Ok(AxumJson(ApiResponse::<AuthResponse>::error("이메일 또는 비밀번호가 올바르지 않습니다.")));
   }
+  record_login(&state, user.id, client_ip.as_deref()).await;
   
   eprintln!("비밀번호 검증 성공, 토큰 생성 시작");
   eprintln!("사용자 정보: id={}, email={:?}, role={:?}", user.id, user.email, user.role);
@@ -274,9 +287,27 @@ pub async fn refresh(
 
 pub async fn logout(
   State(state): State<AppState>,
+  headers: HeaderMap,
   Json(data): Json<RefreshRequest>,
 ) -> Result<AxumJson<ApiResponse<()>>, StatusCode> {
   eprintln!("로그아웃 요청 시작: service_type={:?}", data.service_type);
+
+  // 액세스 토큰도 만료 전까지 사용할 수 없도록 폐기 목록에 등록
+  let access_token = headers
+      .get("Authorization")
+      .and_then(|header| header.to_str().ok())
+      .and_then(|header| header.strip_prefix("Bearer "));
+  if let Some(token) = access_token {
+      if let Ok(claims) = verify_token(token, &state.config) {
+          state.token_blacklist
+              .revoke_token(token, &claims)
+              .await
+              .map_err(|e| {
+                  eprintln!("액세스 토큰 폐기 실패: {:?}", e);
+                  StatusCode::INTERNAL_SERVER_ERROR
+              })?;
+      }
+  }
   
   let refresh_token_hash = hash_refresh_token(&data.refresh_token);
   let service_type = data.service_type.unwrap_or_else(|| "site".to_string());
@@ -315,4 +346,107 @@ pub async fn me(
   .ok_or_else(|| StatusCode::NOT_FOUND)?;
 
   Ok(AxumJson(ApiResponse::success(user, "사용자 정보")))
-} 
\ No newline at end of file
+}
+
+/// 비밀번호 변경 (변경 후 모든 기기의 토큰 폐기)
+pub async fn change_password(
+  State(state): State<AppState>,
+  Extension(claims): Extension<Option<Claims>>,
+  Json(data): Json<ChangePasswordRequest>,
+) -> Result<AxumJson<ApiResponse<()>>, StatusCode> {
+  let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+  if data.new_password.len() < 8 {
+      return Err(StatusCode::BAD_REQUEST);
+  }
+
+  let password_hash = sqlx::query_scalar::<_, Option<String>>("SELECT password_hash FROM users WHERE id = $1")
+      .bind(claims.sub)
+      .fetch_optional(&state.pool)
+      .await
+      .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+      .ok_or(StatusCode::NOT_FOUND)?;
+
+  // 소셜 로그인 전용 계정은 현재 비밀번호 없이 설정 가능
+  if let Some(password_hash) = password_hash {
+      if !crate::utils::auth::verify_password(&data.current_password, &password_hash) {
+          return Err(StatusCode::UNAUTHORIZED);
+      }
+  }
+
+  let new_hash = crate::utils::auth::hash_password(&data.new_password);
+  sqlx::query("UPDATE users SET password_hash = $1, updated_at = NOW() WHERE id = $2")
+      .bind(new_hash)
+      .bind(claims.sub)
+      .execute(&state.pool)
+      .await
+      .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+  state.token_blacklist
+      .revoke_all_for_user(claims.sub, state.config.access_token_expiry)
+      .await
+      .map_err(|e| {
+          eprintln!("토큰 폐기 실패: {:?}", e);
+          StatusCode::INTERNAL_SERVER_ERROR
+      })?;
+
+  Ok(AxumJson(ApiResponse::success((), "비밀번호가 변경되었습니다. 다시 로그인해주세요.")))
+}
+/// 마지막 로그인 시각 / IP 기록 (실패해도 로그인은 계속 진행)
+pub(crate) async fn record_login(state: &AppState, user_id: Uuid, client_ip: Option<&str>) {
+  if let Err(e) = sqlx::query("UPDATE users SET last_login_at = NOW(), last_login_ip = $2 WHERE id = $1")
+      .bind(user_id)
+      .bind(client_ip)
+      .execute(&state.pool)
+      .await
+  {
+      eprintln!("로그인 기록 실패: {:?}", e);
+  }
+}
+
+/// 로그인 토큰 발급 (같은 서비스의 기존 리프레시 토큰은 무효화)
+/// 비밀번호 로그인 외 로그인 경로(소셜 로그인 등)에서 사용
+pub(crate) async fn issue_auth_response(
+  state: &AppState,
+  user: User,
+  service_type: &str,
+  client_ip: Option<&str>,
+) -> Result<AuthResponse, StatusCode> {
+  record_login(state, user.id, client_ip).await;
+
+  let (access_token, refresh_token) = generate_tokens(&state.config, user.id, user.role.as_ref().map(|r| r.to_string().to_lowercase()).unwrap_or_else(|| "user".to_string()))
+      .map_err(|e| {
+          eprintln!("토큰 생성 실패: {:?}", e);
+          StatusCode::INTERNAL_SERVER_ERROR
+      })?;
+  let expires_in = state.config.access_token_expiry * 60; // minutes to seconds
+
+  sqlx::query("UPDATE refresh_tokens SET is_revoked = TRUE WHERE user_id = $1 AND service_type = $2")
+      .bind(user.id)
+      .bind(service_type)
+      .execute(&state.pool)
+      .await
+      .map_err(|e| {
+          eprintln!("기존 토큰 무효화 실패: {:?}", e);
+          StatusCode::INTERNAL_SERVER_ERROR
+      })?;
+
+  sqlx::query("INSERT INTO refresh_tokens (user_id, token_hash, service_type, expires_at) VALUES ($1, $2, $3, $4)")
+      .bind(user.id)
+      .bind(hash_refresh_token(&refresh_token))
+      .bind(service_type)
+      .bind(Utc::now() + Duration::days(state.config.refresh_token_expiry))
+      .execute(&state.pool)
+      .await
+      .map_err(|e| {
+          eprintln!("리프레시 토큰 저장 실패: {:?}", e);
+          StatusCode::INTERNAL_SERVER_ERROR
+      })?;
+
+  Ok(AuthResponse {
+      user,
+      access_token,
+      refresh_token,
+      expires_in,
+  })
+}
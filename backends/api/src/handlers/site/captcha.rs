@@ -0,0 +1,33 @@
+use axum::{
+    extract::State,
+    Json,
+};
+use crate::{
+    errors::ApiError,
+    models::{ApiResponse, CaptchaChallenge, CaptchaSubmission, CaptchaVerifyResponse},
+    services::CaptchaService,
+    AppState,
+};
+
+fn captcha_service(state: &AppState) -> Result<CaptchaService, ApiError> {
+    CaptchaService::new(state.redis.clone(), &state.config.captcha)
+}
+
+// 캡차 문제 발급 (외부 제공자는 위젯용 site_key만 반환)
+pub async fn issue_captcha_challenge(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<CaptchaChallenge>>, ApiError> {
+    let challenge = captcha_service(&state)?.issue_challenge().await?;
+
+    Ok(Json(ApiResponse::success(challenge, "캡차 문제를 발급했습니다.")))
+}
+
+// 캡차 확인 후 글/댓글 작성에 사용할 1회용 토큰 발급
+pub async fn verify_captcha(
+    State(state): State<AppState>,
+    Json(payload): Json<CaptchaSubmission>,
+) -> Result<Json<ApiResponse<CaptchaVerifyResponse>>, ApiError> {
+    let response = captcha_service(&state)?.issue_pass(&payload, None).await?;
+
+    Ok(Json(ApiResponse::success(response, "캡차 인증에 성공했습니다.")))
+}
@@ -15,17 +15,23 @@ use crate::{
     models::site::community,
     models::admin::board::{Board, Category, CreateBoardRequest, UpdateBoardRequest},
     models::response::{ApiResponse, PaginationInfo},
-    models::{FilePurpose, EntityType},
+    models::file::ImageSrcsets,
+    models::{CaptchaSubmission, FilePurpose, EntityType, REVISION_ENTITY_COMMENT, REVISION_ENTITY_POST, POINT_TYPE_POST_READ, POINT_TYPE_POST_WRITE, POINT_TYPE_COMMENT_WRITE},
     errors::ApiError,
     utils::auth::Claims,
+    utils::client_ip::{display_ip, ClientIp},
+    utils::html_sanitize::prepare_post_content,
     utils::url_id::{resolve_post_uuid, generate_post_url_id},
     utils::uuid_compression::compress_uuid_to_base62,
-    services::thumbnail::ThumbnailService,
+    services::thumbnail::{thumbnail_key, ThumbnailService},
+    services::{
+        count_replies, enqueue_media_job, image_srcsets, is_video_key, key_from_url, public_url, upload_url, video_poster_key,
+        within_change_limit, CaptchaService, NotificationService, PointService, RevisionService, JOB_THUMBNAIL,
+    },
     AppState,
 };
 use chrono::{DateTime, Utc};
 use community::{Post, PostDetail, Comment, CommentDetail, CreatePostRequest, CreateReplyRequest, UpdatePostRequest, CreateCommentRequest, UpdateCommentRequest, PostFilter, PostListResponse, CommentListResponse, RecentPostsResponse, BoardStats, PostQuery, PostSummary, ThumbnailUrls, PostStatus, PostSummaryDb, AttachedFile, PostDetailResponse, PostSummaryResponse, CategoryResponse};
-use ammonia::clean;
 use std::str::FromStr;
 
 // 권한 체크 유틸리티 함수들
@@ -40,7 +46,7 @@ fn can_list_board(board: &Board, user_role: Option<&str>) -> bool {
     }
 }
 
-fn can_read_post(board: &Board, user_role: Option<&str>) -> bool {
+pub(crate) fn can_read_post(board: &Board, user_role: Option<&str>) -> bool {
     let permission = &board.read_permission;
     
     match permission.as_str() {
@@ -63,7 +69,7 @@ fn can_create_reply(board: &Board, user_role: Option<&str>) -> bool {
     }
 }
 
-fn can_write_post(board: &Board, user_role: Option<&str>) -> bool {
+pub(crate) fn can_write_post(board: &Board, user_role: Option<&str>) -> bool {
     let permission = &board.write_permission;
     
     // 익명 작성 허용 체크
@@ -95,7 +101,7 @@ fn can_create_comment(board: &Board, user_role: Option<&str>) -> bool {
     }
 }
 
-fn can_download_file(board: &Board, user_role: Option<&str>) -> bool {
+pub(crate) fn can_download_file(board: &Board, user_role: Option<&str>) -> bool {
     let permission = &board.download_permission;
     
     match permission.as_str() {
@@ -106,9 +112,156 @@ fn can_download_file(board: &Board, user_role: Option<&str>) -> bool {
     }
 }
 
+// 이메일 인증 회원만 작성 가능한 경우(전체 설정 또는 게시판 설정) 인증 여부 확인
+pub(crate) async fn ensure_email_verified(state: &AppState, board: &Board, claims: &crate::utils::auth::Claims) -> Result<(), StatusCode> {
+    if !(state.config.require_email_verification || board.require_email_verified) || claims.role == "admin" {
+        return Ok(());
+    }
+
+    let verified = crate::services::is_email_verified(&state.pool, claims.sub).await.map_err(|e| {
+        error!("이메일 인증 여부 조회 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    if !verified {
+        error!("이메일 미인증 사용자 작성 거부: user_id={}", claims.sub);
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    Ok(())
+}
+
+// 캡차 사용 게시판은 작성 전에 캡차 확인 (관리자 제외, 비회원은 claims가 None)
+pub(crate) async fn ensure_captcha(state: &AppState, board: &Board, claims: Option<&Claims>, captcha: &CaptchaSubmission) -> Result<(), StatusCode> {
+    if !board.use_captcha || claims.is_some_and(|c| c.role == "admin") {
+        return Ok(());
+    }
+
+    // 캡차 설정 오류도 검증 실패로 처리 (작성 거부)
+    let service = CaptchaService::new(state.redis.clone(), &state.config.captcha).map_err(|e| {
+        error!("캡차 설정 오류: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    let passed = service
+        .verify(captcha, None)
+        .await
+        .map_err(|e| {
+            error!("캡차 확인 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    if !passed {
+        error!("캡차 미확인 작성 거부: board={}, user_id={:?}", board.slug, claims.map(|c| c.sub));
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    Ok(())
+}
+
+// 게시판의 수정/삭제 제한 확인 (관리자 제외, 비회원은 claims가 None)
+pub(crate) async fn ensure_change_limit(
+    state: &AppState,
+    board: &Board,
+    claims: Option<&Claims>,
+    limit: i32,
+    entity_type: &str,
+    entity_id: Uuid,
+    created_at: Option<DateTime<Utc>>,
+) -> Result<(), StatusCode> {
+    if limit <= 0 || claims.is_some_and(|c| c.role == "admin") {
+        return Ok(());
+    }
+
+    let comment_count = count_replies(&state.pool, entity_type, entity_id).await.map_err(|e| {
+        error!("수정/삭제 제한용 댓글 수 조회 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    if !within_change_limit(limit, &board.comment_limit_unit, created_at, comment_count, Utc::now()) {
+        error!(
+            "수정/삭제 제한으로 거부: {} {}, 제한={} ({}), 댓글 수={}",
+            entity_type, entity_id, limit, board.comment_limit_unit, comment_count
+        );
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    Ok(())
+}
+
+// 게시글이 속한 게시판 조회
+pub(crate) async fn find_post_board(state: &AppState, post_id: Uuid) -> Result<Board, StatusCode> {
+    sqlx::query_as::<_, BoardRaw>("SELECT b.* FROM boards b JOIN posts p ON p.board_id = b.id WHERE p.id = $1")
+        .bind(post_id)
+        .fetch_optional(&state.pool)
+        .await
+        .map_err(|e| {
+            error!("게시글 게시판 조회 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .map(convert_board_raw_to_board)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+// 공개 중인 게시글의 게시판 조회 (숨김/삭제/비공개 게시글은 404)
+pub(crate) async fn find_visible_post_board(state: &AppState, post_id: Uuid) -> Result<Board, StatusCode> {
+    sqlx::query_as::<_, BoardRaw>(
+        r#"
+        SELECT b.* FROM boards b JOIN posts p ON p.board_id = b.id
+        WHERE p.id = $1 AND p.status IN ('active', 'published') AND COALESCE(p.is_deleted, false) = false
+        "#
+    )
+    .bind(post_id)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|e| {
+        error!("게시글 게시판 조회 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .map(convert_board_raw_to_board)
+    .ok_or(StatusCode::NOT_FOUND)
+}
+
+pub(crate) async fn find_board(state: &AppState, board_id: Uuid) -> Result<Board, StatusCode> {
+    sqlx::query_as::<_, BoardRaw>("SELECT * FROM boards WHERE id = $1")
+        .bind(board_id)
+        .fetch_optional(&state.pool)
+        .await
+        .map_err(|e| {
+            error!("게시판 조회 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .map(convert_board_raw_to_board)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+// 지정한 카테고리가 게시판의 사용 중인 카테고리인지 확인 (다른 게시판 카테고리면 400)
+pub(crate) async fn ensure_board_category(state: &AppState, board: &Board, category_id: Option<Uuid>) -> Result<(), StatusCode> {
+    let Some(category_id) = category_id else {
+        return Ok(());
+    };
+    let exists = sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS(SELECT 1 FROM categories WHERE id = $1 AND board_id = $2 AND is_active = true)"
+    )
+    .bind(category_id)
+    .bind(board.id)
+    .fetch_one(&state.pool)
+    .await
+    .map_err(|e| {
+        error!("카테고리 조회 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    if !exists {
+        error!("게시판에 없는 카테고리: board_id={}, category_id={}", board.id, category_id);
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    Ok(())
+}
+
+// 응답에 표시할 작성자 IP (관리자는 전체, show_ip 게시판은 일부 가림)
+pub(crate) fn author_ip(board: &Board, claims: Option<&Claims>, ip: Option<&str>) -> Option<String> {
+    display_ip(ip, board.show_ip, claims.is_some_and(|c| c.role == "admin"))
+}
+
 // DB에서 가져온 raw Board 구조체
 #[derive(Debug, sqlx::FromRow)]
-struct BoardRaw {
+pub(crate) struct BoardRaw {
     pub id: Uuid,
     pub slug: String,
     pub name: String,
@@ -140,6 +293,7 @@ struct BoardRaw {
     pub show_ip: bool,
     pub edit_comment_limit: i32,
     pub delete_comment_limit: i32,
+    pub comment_limit_unit: String,
     pub use_sns: bool,
     pub use_captcha: bool,
     pub title_length: i32,
@@ -149,6 +303,7 @@ struct BoardRaw {
     pub comment_point: i32,
     pub download_point: i32,
     pub allowed_iframe_domains: Option<String>, // DB에서는 문자열
+    pub require_email_verified: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -159,7 +314,7 @@ struct PostDetailRaw {
     pub id: Uuid,
     pub title: String,
     pub content: String, // NOT NULL로 변경됨
-    pub user_id: Uuid,
+    pub user_id: Option<Uuid>,
     pub board_id: Uuid,
     pub category_id: Option<Uuid>,
     pub parent_id: Option<Uuid>,
@@ -187,7 +342,7 @@ fn parse_csv_option(s: &Option<String>) -> Option<Vec<String>> {
         .map(|raw| raw.split(',').map(|v| v.trim().to_string()).filter(|v| !v.is_empty()).collect())
 }
 
-fn convert_board_raw_to_board(raw: BoardRaw) -> Board {
+pub(crate) fn convert_board_raw_to_board(raw: BoardRaw) -> Board {
     use crate::utils::uuid_compression::compress_uuid_to_base62;
     
     Board {
@@ -223,6 +378,7 @@ fn convert_board_raw_to_board(raw: BoardRaw) -> Board {
         show_ip: raw.show_ip,
         edit_comment_limit: raw.edit_comment_limit,
         delete_comment_limit: raw.delete_comment_limit,
+        comment_limit_unit: raw.comment_limit_unit,
         use_sns: raw.use_sns,
         use_captcha: raw.use_captcha,
         title_length: raw.title_length,
@@ -232,6 +388,7 @@ fn convert_board_raw_to_board(raw: BoardRaw) -> Board {
         comment_point: raw.comment_point,
         download_point: raw.download_point,
         allowed_iframe_domains: parse_csv_option(&raw.allowed_iframe_domains),
+        require_email_verified: raw.require_email_verified,
         created_at: raw.created_at,
         updated_at: raw.updated_at,
     }
@@ -273,6 +430,7 @@ pub async fn get_boards(
             COALESCE(show_ip, false) as show_ip,
             COALESCE(edit_comment_limit, 0) as edit_comment_limit,
             COALESCE(delete_comment_limit, 0) as delete_comment_limit,
+            COALESCE(comment_limit_unit, 'comments') as comment_limit_unit,
             COALESCE(use_sns, false) as use_sns,
             COALESCE(use_captcha, false) as use_captcha,
             COALESCE(title_length, 200) as title_length,
@@ -282,6 +440,7 @@ pub async fn get_boards(
             COALESCE(comment_point, 0) as comment_point,
             COALESCE(download_point, 0) as download_point,
             allowed_iframe_domains,
+            COALESCE(require_email_verified, false) as require_email_verified,
             created_at, updated_at 
         FROM boards 
         WHERE COALESCE(is_public, true) = true 
@@ -348,6 +507,7 @@ pub async fn get_board(
             COALESCE(show_ip, false) as show_ip,
             COALESCE(edit_comment_limit, 0) as edit_comment_limit,
             COALESCE(delete_comment_limit, 0) as delete_comment_limit,
+            COALESCE(comment_limit_unit, 'comments') as comment_limit_unit,
             COALESCE(use_sns, false) as use_sns,
             COALESCE(use_captcha, false) as use_captcha,
             COALESCE(title_length, 200) as title_length,
@@ -357,6 +517,7 @@ pub async fn get_board(
             COALESCE(comment_point, 0) as comment_point,
             COALESCE(download_point, 0) as download_point,
             allowed_iframe_domains,
+            COALESCE(require_email_verified, false) as require_email_verified,
             created_at, updated_at
         FROM boards
         WHERE id = $1 AND COALESCE(is_public, true) = true
@@ -452,6 +613,7 @@ pub async fn get_categories_by_slug(
             COALESCE(show_ip, false) as show_ip,
             COALESCE(edit_comment_limit, 0) as edit_comment_limit,
             COALESCE(delete_comment_limit, 0) as delete_comment_limit,
+            COALESCE(comment_limit_unit, 'comments') as comment_limit_unit,
             COALESCE(use_sns, false) as use_sns,
             COALESCE(use_captcha, false) as use_captcha,
             COALESCE(title_length, 200) as title_length,
@@ -461,6 +623,7 @@ pub async fn get_categories_by_slug(
             COALESCE(comment_point, 0) as comment_point,
             COALESCE(download_point, 0) as download_point,
             allowed_iframe_domains,
+            COALESCE(require_email_verified, false) as require_email_verified,
             created_at, updated_at
         FROM boards 
         WHERE slug = $1 AND COALESCE(is_public, true) = true
@@ -531,6 +694,11 @@ pub async fn get_posts(
         if !can_read_post(&board, user_role) {
             return Err(StatusCode::FORBIDDEN);
         }
+
+        // 검색을 허용하지 않는 게시판
+        if !board.allow_search && query.search.as_deref().is_some_and(|s| !s.trim().is_empty()) {
+            return Err(StatusCode::FORBIDDEN);
+        }
     }
 
     // 기본값 설정
@@ -555,12 +723,12 @@ pub async fn get_posts(
             if let Some(category_id) = query.category_id {
                 sqlx::query_scalar!(
                     r#"SELECT COUNT(*) as total FROM posts p
-                        JOIN users u ON p.user_id = u.id
+                        LEFT JOIN users u ON p.user_id = u.id
                         WHERE p.status IN ('active', 'published') AND p.board_id = $1 AND p.category_id = $2
                         AND (
                             p.title ILIKE $3 OR
-                            p.content ILIKE $3 OR
-                            u.name ILIKE $3 OR
+                            strip_html(p.content) ILIKE $3 OR
+                            COALESCE(u.name, p.guest_name) ILIKE $3 OR
                             EXISTS (SELECT 1 FROM comments c WHERE c.post_id = p.id AND c.content ILIKE $3)
                         )
                     "#,
@@ -576,12 +744,12 @@ pub async fn get_posts(
             } else {
                 sqlx::query_scalar!(
                     r#"SELECT COUNT(*) as total FROM posts p
-                        JOIN users u ON p.user_id = u.id
+                        LEFT JOIN users u ON p.user_id = u.id
                         WHERE p.status IN ('active', 'published') AND p.board_id = $1
                         AND (
                             p.title ILIKE $2 OR
-                            p.content ILIKE $2 OR
-                            u.name ILIKE $2 OR
+                            strip_html(p.content) ILIKE $2 OR
+                            COALESCE(u.name, p.guest_name) ILIKE $2 OR
                             EXISTS (SELECT 1 FROM comments c WHERE c.post_id = p.id AND c.content ILIKE $2)
                         )
                     "#,
@@ -598,12 +766,12 @@ pub async fn get_posts(
         } else {
             sqlx::query_scalar!(
                 r#"SELECT COUNT(*) as total FROM posts p
-                    JOIN users u ON p.user_id = u.id
+                    LEFT JOIN users u ON p.user_id = u.id
                     WHERE p.status IN ('active', 'published')
                     AND (
                         p.title ILIKE $1 OR
-                        p.content ILIKE $1 OR
-                        u.name ILIKE $1 OR
+                        strip_html(p.content) ILIKE $1 OR
+                        COALESCE(u.name, p.guest_name) ILIKE $1 OR
                         EXISTS (SELECT 1 FROM comments c WHERE c.post_id = p.id AND c.content ILIKE $1)
                     )
                 "#,
@@ -665,20 +833,20 @@ pub async fn get_posts(
                 sqlx::query_as!(
                     PostSummaryDb,
                     r#"
-                    SELECT p.id, p.title, u.name as user_name, p.board_id, b.name as board_name, b.slug as board_slug, p.created_at,
+                    SELECT p.id, p.title, COALESCE(u.name, p.guest_name) as user_name, p.board_id, b.name as board_name, b.slug as board_slug, p.created_at,
                            COALESCE((SELECT COUNT(*) FROM comments WHERE post_id = p.id AND status IN ('active', 'published')), 0) as comment_count,
                            p.content, p.views, p.likes, p.is_notice,
                            COALESCE(c.name, NULL) as category_name,
                            p.parent_id, p.depth, p.reply_count, p.thumbnail_urls
                     FROM posts p
-                    JOIN users u ON p.user_id = u.id
+                    LEFT JOIN users u ON p.user_id = u.id
                     JOIN boards b ON p.board_id = b.id
                     LEFT JOIN categories c ON p.category_id = c.id
                     WHERE p.status IN ('active', 'published') AND p.board_id = $1 AND p.category_id = $2
                         AND (
                             p.title ILIKE $3 OR
-                            p.content ILIKE $3 OR
-                            u.name ILIKE $3 OR
+                            strip_html(p.content) ILIKE $3 OR
+                            COALESCE(u.name, p.guest_name) ILIKE $3 OR
                             EXISTS (SELECT 1 FROM comments c WHERE c.post_id = p.id AND c.content ILIKE $3)
                         )
                     ORDER BY p.is_notice DESC, p.created_at DESC
@@ -692,20 +860,20 @@ pub async fn get_posts(
                 sqlx::query_as!(
                     PostSummaryDb,
                     r#"
-                    SELECT p.id, p.title, u.name as user_name, p.board_id, b.name as board_name, b.slug as board_slug, p.created_at,
+                    SELECT p.id, p.title, COALESCE(u.name, p.guest_name) as user_name, p.board_id, b.name as board_name, b.slug as board_slug, p.created_at,
                            COALESCE((SELECT COUNT(*) FROM comments WHERE post_id = p.id AND status IN ('active', 'published')), 0) as comment_count,
                            p.content, p.views, p.likes, p.is_notice,
                            COALESCE(c.name, NULL) as category_name,
                            p.parent_id, p.depth, p.reply_count, p.thumbnail_urls
                     FROM posts p
-                    JOIN users u ON p.user_id = u.id
+                    LEFT JOIN users u ON p.user_id = u.id
                     JOIN boards b ON p.board_id = b.id
                     LEFT JOIN categories c ON p.category_id = c.id
                     WHERE p.status IN ('active', 'published') AND p.board_id = $1
                         AND (
                             p.title ILIKE $2 OR
-                            p.content ILIKE $2 OR
-                            u.name ILIKE $2 OR
+                            strip_html(p.content) ILIKE $2 OR
+                            COALESCE(u.name, p.guest_name) ILIKE $2 OR
                             EXISTS (SELECT 1 FROM comments c WHERE c.post_id = p.id AND c.content ILIKE $2)
                         )
                     ORDER BY p.is_notice DESC, p.created_at DESC
@@ -720,20 +888,20 @@ pub async fn get_posts(
             sqlx::query_as!(
                 PostSummaryDb,
                 r#"
-                SELECT p.id, p.title, u.name as user_name, p.board_id, b.name as board_name, b.slug as board_slug, p.created_at,
+                SELECT p.id, p.title, COALESCE(u.name, p.guest_name) as user_name, p.board_id, b.name as board_name, b.slug as board_slug, p.created_at,
                        COALESCE((SELECT COUNT(*) FROM comments WHERE post_id = p.id AND status IN ('active', 'published')), 0) as comment_count,
                        p.content, p.views, p.likes, p.is_notice,
                        COALESCE(c.name, NULL) as category_name,
                        p.parent_id, p.depth, p.reply_count, p.thumbnail_urls
                 FROM posts p
-                JOIN users u ON p.user_id = u.id
+                LEFT JOIN users u ON p.user_id = u.id
                 JOIN boards b ON p.board_id = b.id
                 LEFT JOIN categories c ON p.category_id = c.id
                 WHERE p.status IN ('active', 'published')
                     AND (
                         p.title ILIKE $1 OR
-                        p.content ILIKE $1 OR
-                        u.name ILIKE $1 OR
+                        strip_html(p.content) ILIKE $1 OR
+                        COALESCE(u.name, p.guest_name) ILIKE $1 OR
                         EXISTS (SELECT 1 FROM comments c WHERE c.post_id = p.id AND c.content ILIKE $1)
                     )
                 ORDER BY p.is_notice DESC, p.created_at DESC
@@ -750,13 +918,13 @@ pub async fn get_posts(
                 sqlx::query_as!(
                     PostSummaryDb,
                     r#"
-                    SELECT p.id, p.title, u.name as user_name, p.board_id, b.name as board_name, b.slug as board_slug, p.created_at,
+                    SELECT p.id, p.title, COALESCE(u.name, p.guest_name) as user_name, p.board_id, b.name as board_name, b.slug as board_slug, p.created_at,
                            COALESCE((SELECT COUNT(*) FROM comments WHERE post_id = p.id AND status IN ('active', 'published')), 0) as comment_count,
                            p.content, p.views, p.likes, p.is_notice,
                            COALESCE(c.name, NULL) as category_name,
                            p.parent_id, p.depth, p.reply_count, p.thumbnail_urls
                     FROM posts p
-                    JOIN users u ON p.user_id = u.id
+                    LEFT JOIN users u ON p.user_id = u.id
                     JOIN boards b ON p.board_id = b.id
                     LEFT JOIN categories c ON p.category_id = c.id
                     WHERE p.status IN ('active', 'published') AND p.board_id = $1 AND p.category_id = $2
@@ -771,13 +939,13 @@ pub async fn get_posts(
                 sqlx::query_as!(
                     PostSummaryDb,
                     r#"
-                    SELECT p.id, p.title, u.name as user_name, p.board_id, b.name as board_name, b.slug as board_slug, p.created_at,
+                    SELECT p.id, p.title, COALESCE(u.name, p.guest_name) as user_name, p.board_id, b.name as board_name, b.slug as board_slug, p.created_at,
                            COALESCE((SELECT COUNT(*) FROM comments WHERE post_id = p.id AND status IN ('active', 'published')), 0) as comment_count,
                            p.content, p.views, p.likes, p.is_notice,
                            COALESCE(c.name, NULL) as category_name,
                            p.parent_id, p.depth, p.reply_count, p.thumbnail_urls
                     FROM posts p
-                    JOIN users u ON p.user_id = u.id
+                    LEFT JOIN users u ON p.user_id = u.id
                     JOIN boards b ON p.board_id = b.id
                     LEFT JOIN categories c ON p.category_id = c.id
                     WHERE p.status IN ('active', 'published') AND p.board_id = $1
@@ -793,13 +961,13 @@ pub async fn get_posts(
             sqlx::query_as!(
                 PostSummaryDb,
                 r#"
-                SELECT p.id, p.title, u.name as user_name, p.board_id, b.name as board_name, b.slug as board_slug, p.created_at,
+                SELECT p.id, p.title, COALESCE(u.name, p.guest_name) as user_name, p.board_id, b.name as board_name, b.slug as board_slug, p.created_at,
                        COALESCE((SELECT COUNT(*) FROM comments WHERE post_id = p.id AND status IN ('active', 'published')), 0) as comment_count,
                        p.content, p.views, p.likes, p.is_notice,
                        COALESCE(c.name, NULL) as category_name,
                        p.parent_id, p.depth, p.reply_count, p.thumbnail_urls
                 FROM posts p
-                JOIN users u ON p.user_id = u.id
+                LEFT JOIN users u ON p.user_id = u.id
                 JOIN boards b ON p.board_id = b.id
                 LEFT JOIN categories c ON p.category_id = c.id
                 WHERE p.status IN ('active', 'published')
@@ -841,7 +1009,7 @@ pub async fn get_posts(
             Some(attached_files)
         };
 
-        let thumbnail_urls = generate_thumbnail_urls(&attached_files_option).await;
+        let thumbnail_urls = generate_thumbnail_urls(&state, &attached_files_option).await;
 
         // URL ID 생성
         let url_id = generate_post_url_id(&state.pool, &post.id).await.ok();
@@ -999,7 +1167,7 @@ pub async fn get_post(
     
     // 먼저 기본 게시글 정보만 조회해서 테스트 (status를 text로 캐스팅)
     let post_basic = sqlx::query!(
-        "SELECT id, board_id, category_id, user_id, parent_id, depth, reply_count, title, content, views, likes, dislikes, is_notice, status::text as status, created_at, updated_at FROM posts WHERE id = $1 AND status IN ('active', 'published')",
+        "SELECT id, board_id, category_id, user_id, parent_id, depth, reply_count, title, content, content_source, views, likes, dislikes, is_notice, status::text as status, created_at, updated_at, ip_address, guest_name FROM posts WHERE id = $1 AND status IN ('active', 'published')",
         post_id
     )
     .fetch_optional(&state.pool)
@@ -1030,16 +1198,55 @@ pub async fn get_post(
         return Err(StatusCode::FORBIDDEN);
     }
 
+    let mut tx = state.pool.begin().await.map_err(|e| {
+        eprintln!("Transaction begin error: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    // 열람 포인트 (작성자 본인 제외, 게시글당 최초 1회)
+    if board.read_point != 0 {
+        match claims.as_ref().map(|c| c.sub) {
+            Some(user_id) if Some(user_id) != post_basic.user_id => {
+                let applied = PointService::apply_once(
+                    &mut tx,
+                    user_id,
+                    board.read_point,
+                    POINT_TYPE_POST_READ,
+                    "게시글 열람",
+                    "post",
+                    post_id,
+                )
+                .await
+                .map_err(|e| {
+                    eprintln!("Read point error: {:?}", e);
+                    StatusCode::INTERNAL_SERVER_ERROR
+                })?;
+                if !applied {
+                    return Err(StatusCode::PAYMENT_REQUIRED);
+                }
+            }
+            Some(_) => {}
+            // 포인트가 차감되는 게시판은 로그인 필요
+            None if board.read_point < 0 => return Err(StatusCode::UNAUTHORIZED),
+            None => {}
+        }
+    }
+
     // 조회수 증가
     sqlx::query("UPDATE posts SET views = COALESCE(views, 0) + 1 WHERE id = $1")
         .bind(post_id)
-        .execute(&state.pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| {
             eprintln!("Update views error: {:?}", e);
             StatusCode::INTERNAL_SERVER_ERROR
         })?;
 
+    tx.commit().await.map_err(|e| {
+        eprintln!("Transaction commit error: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
     // 사용자 정보 조회
     let user_info = sqlx::query!("SELECT name FROM users WHERE id = $1", post_basic.user_id)
         .fetch_optional(&state.pool)
@@ -1087,7 +1294,7 @@ pub async fn get_post(
     let attached_files = sqlx::query!(
         r#"
         SELECT f.id, f.original_name, f.stored_name, f.file_path, f.file_size, f.mime_type, 
-               fe.display_order
+               fe.display_order, f.download_count, f.duration_seconds, f.width, f.height, f.poster_path
         FROM file_entities fe
         JOIN files f ON fe.file_id = f.id
         WHERE fe.entity_id = $1
@@ -1105,11 +1312,16 @@ pub async fn get_post(
     .map(|file| AttachedFile {
         id: file.id,
         original_name: file.original_name,
-        file_path: format!("/uploads/{}", file.file_path.trim_start_matches("static/uploads/")),
+        file_path: public_url(state.storage.as_ref(), &file.file_path),
         file_size: file.file_size,
         mime_type: file.mime_type,
         file_purpose: Some(FilePurpose::Attachment), // 기본값으로 설정
         display_order: Some(file.display_order.unwrap_or(0)),
+        download_count: file.download_count,
+        duration_seconds: file.duration_seconds,
+        width: file.width,
+        height: file.height,
+        poster_url: file.poster_path.map(|poster_path| public_url(state.storage.as_ref(), &poster_path)),
     })
     .collect::<Vec<AttachedFile>>();
 
@@ -1146,7 +1358,7 @@ pub async fn get_post(
         status: post_basic.status.and_then(|s| s.parse::<PostStatus>().ok()),
         created_at: post_basic.created_at,
         updated_at: post_basic.updated_at,
-        user_name: user_info.map(|u| u.name),
+        user_name: user_info.map(|u| u.name).or(post_basic.guest_name),
         user_email: None, // user_info에는 email 필드가 없음
         board_name: board_info.as_ref().map(|b| b.name.clone()),
         board_slug: board_info.map(|b| b.slug),
@@ -1155,6 +1367,8 @@ pub async fn get_post(
         attached_files: Some(attached_files), // 첨부 파일 정보 포함
         thumbnail_urls: None, // 기본값
         is_liked: Some(is_liked), // 좋아요 상태 포함
+        ip_address: author_ip(&board, claims.as_ref(), post_basic.ip_address.as_deref()),
+        content_source: post_basic.content_source,
     };
 
     Ok(Json(ApiResponse {
@@ -1169,6 +1383,7 @@ pub async fn get_post(
 pub async fn create_post(
     State(state): State<AppState>,
     Extension(claims): Extension<Option<crate::utils::auth::Claims>>,
+    ClientIp(client_ip): ClientIp,
     Json(payload): Json<CreatePostRequest>,
 ) -> Result<Json<ApiResponse<PostDetail>>, StatusCode> {
     // 인증 확인
@@ -1193,27 +1408,79 @@ pub async fn create_post(
         error!("create_post 권한 없음: role={}", claims.role);
         return Err(StatusCode::FORBIDDEN);
     }
+    ensure_email_verified(&state, &board, &claims).await?;
+    ensure_board_category(&state, &board, payload.category_id).await?;
+    ensure_captcha(&state, &board, Some(&claims), &payload.captcha).await?;
+
+    // 임시저장 글에서 발행하는 경우 같은 게시판의 본인 글인지 확인
+    if let Some(draft_id) = payload.draft_id {
+        let draft = crate::handlers::site::draft::find_my_draft(&state, draft_id, claims.sub).await?;
+        if draft.board_id != board.id {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+    }
     
-    let sanitized_content = clean(&payload.content);
+    let post_content = prepare_post_content(&board, &payload.content);
     
+    let mut tx = state.pool.begin().await.map_err(|e| {
+        error!("create_post 트랜잭션 시작 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
     // 먼저 게시글을 생성
     let post_result = sqlx::query!(
-        "INSERT INTO posts (board_id, category_id, user_id, title, content, is_notice, status)
-         VALUES ($1, $2, $3, $4, $5, $6, 'published')
-         RETURNING id, board_id, category_id, user_id, parent_id, depth, reply_count, title, content, views, likes, dislikes, is_notice, status::text, created_at, updated_at",
+        "INSERT INTO posts (board_id, category_id, user_id, title, content, content_source, is_notice, status, ip_address)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, 'published', $8)
+         RETURNING id, board_id, category_id, user_id, parent_id, depth, reply_count, title, content, content_source, views, likes, dislikes, is_notice, status::text, created_at, updated_at",
         payload.board_id,
         payload.category_id,
         claims.sub,
         payload.title,
-        sanitized_content,
-        payload.is_notice.unwrap_or(false)
+        post_content.content,
+        post_content.content_source,
+        payload.is_notice.unwrap_or(false),
+        client_ip
     )
-    .fetch_one(&state.pool)
+    .fetch_one(&mut *tx)
     .await
     .map_err(|e| {
         error!("create_post DB INSERT 실패: {:?}", e);
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
+
+    // 작성 포인트 (차감 시 잔액 부족이면 작성 거절)
+    let applied = PointService::apply(
+        &mut tx,
+        claims.sub,
+        board.write_point,
+        POINT_TYPE_POST_WRITE,
+        "게시글 작성",
+        Some("post"),
+        Some(post_result.id),
+    )
+    .await
+    .map_err(|e| {
+        error!("create_post 포인트 처리 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    if applied.is_none() {
+        return Err(StatusCode::PAYMENT_REQUIRED);
+    }
+
+    // 임시저장 첨부파일을 게시글로 옮기고 임시저장 글 삭제 (게시글 작성과 함께 처리)
+    if let Some(draft_id) = payload.draft_id {
+        crate::handlers::site::draft::promote_draft_files(&mut tx, draft_id, post_result.id)
+            .await
+            .map_err(|e| {
+                error!("임시저장 글 발행 처리 실패: {:?}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+    }
+
+    tx.commit().await.map_err(|e| {
+        error!("create_post 트랜잭션 커밋 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
     
     // 사용자 정보 조회
     let user_info = sqlx::query!("SELECT name, email FROM users WHERE id = $1", claims.sub)
@@ -1245,7 +1512,7 @@ pub async fn create_post(
     
     // 첨부파일에서 썸네일 URL 생성
     let thumbnail_urls = if let Some(ref attached_files) = payload.attached_files {
-        generate_thumbnail_urls(&Some(attached_files.clone())).await
+        generate_thumbnail_urls(&state, &Some(attached_files.clone())).await
     } else {
         None
     };
@@ -1297,6 +1564,8 @@ pub async fn create_post(
         attached_files: None,
         thumbnail_urls,
         is_liked: None,
+        ip_address: author_ip(&board, Some(&claims), client_ip.as_deref()),
+        content_source: post_result.content_source,
     };
     
 
@@ -1438,10 +1707,19 @@ pub async fn update_post(
     .ok_or(StatusCode::NOT_FOUND)?;
 
     // 게시글 작성자만 수정 가능
-    if post.user_id != claims.sub {
-        eprintln!("권한 없음: post_user_id={}, current_user_id={}", post.user_id, claims.sub);
+    if post.user_id != Some(claims.sub) {
+        eprintln!("권한 없음: post_user_id={:?}, current_user_id={}", post.user_id, claims.sub);
         return Err(StatusCode::FORBIDDEN);
     }
+    let board = find_post_board(&state, post_id).await?;
+    ensure_change_limit(&state, &board, Some(&claims), board.edit_comment_limit, REVISION_ENTITY_POST, post_id, post.created_at).await?;
+    // 다른 게시판으로 옮기면 옮길 게시판 설정으로 본문 정리
+    let target_board = match payload.board_id {
+        Some(board_id) if board_id != board.id => Some(find_board(&state, board_id).await?),
+        _ => None,
+    };
+    ensure_board_category(&state, target_board.as_ref().unwrap_or(&board), payload.category_id).await?;
+    let post_content = payload.content.as_deref().map(|content| prepare_post_content(target_board.as_ref().unwrap_or(&board), content));
 
     // 업데이트할 필드들
     let mut updates = Vec::new();
@@ -1462,9 +1740,11 @@ pub async fn update_post(
         updates.push(format!("title = ${}", param_count));
     }
 
-    if let Some(ref content) = payload.content {
+    if post_content.is_some() {
         param_count += 1;
         updates.push(format!("content = ${}", param_count));
+        param_count += 1;
+        updates.push(format!("content_source = ${}", param_count));
     }
 
     if let Some(ref is_notice) = payload.is_notice {
@@ -1478,9 +1758,18 @@ pub async fn update_post(
 
     updates.push("updated_at = NOW()".to_string());
 
+    // 수정 전 내용 보관과 수정을 한 트랜잭션에서 처리
+    let mut tx = state.pool.begin().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    RevisionService::snapshot_post(&mut tx, post_id, Some(claims.sub), payload.title.as_deref(), post_content.as_ref().map(|c| c.content.as_str()))
+        .await
+        .map_err(|e| {
+            error!("게시글 수정 이력 저장 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
     // 첨부파일에서 썸네일 URL 생성
     let thumbnail_urls = if let Some(ref attached_files) = payload.attached_files {
-        generate_thumbnail_urls(&Some(attached_files.clone())).await
+        generate_thumbnail_urls(&state, &Some(attached_files.clone())).await
     } else {
         None
     };
@@ -1492,7 +1781,7 @@ pub async fn update_post(
             serde_json::to_value(thumbnails).unwrap(),
             post_id
         )
-        .execute(&state.pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| {
             error!("썸네일 URL 저장 실패: {:?}", e);
@@ -1502,8 +1791,9 @@ pub async fn update_post(
 
     param_count += 1;
     let sql = format!(
-        "UPDATE posts SET {} WHERE id = ${} RETURNING id, board_id, category_id, user_id, title, content, views, likes, dislikes, is_notice, status, created_at, updated_at,
-         (SELECT name FROM users WHERE id = user_id) as user_name,
+        "UPDATE posts SET {} WHERE id = ${} RETURNING id, board_id, category_id, user_id, parent_id, title, content, views, likes, dislikes, is_notice, status, created_at, updated_at, depth, reply_count,
+         COALESCE((SELECT name FROM users WHERE id = user_id), guest_name) as user_name,
+         (SELECT email FROM users WHERE id = user_id) as user_email,
          (SELECT name FROM boards WHERE id = board_id) as board_name,
          (SELECT slug FROM boards WHERE id = board_id) as board_slug,
          (SELECT name FROM categories WHERE id = category_id) as category_name,
@@ -1524,8 +1814,8 @@ pub async fn update_post(
     if let Some(title) = payload.title {
         query_builder = query_builder.bind(title);
     }
-    if let Some(content) = payload.content {
-        query_builder = query_builder.bind(content);
+    if let Some(post_content) = post_content {
+        query_builder = query_builder.bind(post_content.content).bind(post_content.content_source);
     }
     if let Some(is_notice) = payload.is_notice {
         query_builder = query_builder.bind(is_notice);
@@ -1534,12 +1824,13 @@ pub async fn update_post(
     query_builder = query_builder.bind(post_id);
 
     let mut updated_post = query_builder
-        .fetch_one(&state.pool)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| {
             error!("Failed to update post: {}", e);
             StatusCode::INTERNAL_SERVER_ERROR
         })?;
+    tx.commit().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     
     // 압축된 ID 추가
 
@@ -1585,7 +1876,20 @@ pub async fn update_post(
             match file_exists {
                 Ok(Some(file_info)) => {
                     let status = file_info.status.as_deref().unwrap_or("unknown");
-                    if status == "published" {
+                    if status == "draft" {
+                        // 수정 중 새로 올린 파일은 published로 변경 (고아 파일 정리 대상에서 제외)
+                        sqlx::query!(
+                            "UPDATE files SET status = 'published' WHERE id = $1",
+                            file_info.id
+                        )
+                        .execute(&state.pool)
+                        .await
+                        .map_err(|e| {
+                            error!("파일 상태 변경 실패: {:?}", e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?;
+                    }
+                    if status == "published" || status == "draft" {
                         // file_entities 테이블에 연결 정보 저장
                         sqlx::query!(
                             "INSERT INTO file_entities (file_id, entity_type, entity_id, file_purpose, display_order)
@@ -1667,10 +1971,12 @@ pub async fn delete_post(
     .ok_or(StatusCode::NOT_FOUND)?;
 
     // 게시글 작성자만 삭제 가능
-    if post.user_id != claims.sub {
-        eprintln!("삭제 권한 없음: post_user_id={}, current_user_id={}", post.user_id, claims.sub);
+    if post.user_id != Some(claims.sub) {
+        eprintln!("삭제 권한 없음: post_user_id={:?}, current_user_id={}", post.user_id, claims.sub);
         return Err(StatusCode::FORBIDDEN);
     }
+    let board = find_post_board(&state, post_id).await?;
+    ensure_change_limit(&state, &board, Some(&claims), board.delete_comment_limit, REVISION_ENTITY_POST, post_id, post.created_at).await?;
 
     // 소프트 삭제
     sqlx::query("UPDATE posts SET status = 'deleted' WHERE id = $1")
@@ -1793,28 +2099,28 @@ pub async fn get_comments(
             -- 최상위 댓글들 (parent_id가 NULL인 것들)
             SELECT c.id, c.post_id, c.user_id, c.parent_id, c.content, c.likes, 
                    c.status::text as status, c.created_at, c.updated_at, c.depth, c.is_deleted,
-                   u.name as user_name,
+                   u.name as user_name, c.ip_address,
                    c.created_at::text as sort_path,
                    0 as level
             FROM comments c
             JOIN users u ON c.user_id = u.id
-            WHERE c.post_id = $1 AND c.parent_id IS NULL AND c.is_deleted = false
+            WHERE c.post_id = $1 AND c.parent_id IS NULL AND c.is_deleted = false AND c.status <> 'hidden'
             
             UNION ALL
             
             -- 하위 댓글들 (재귀적으로)
             SELECT c.id, c.post_id, c.user_id, c.parent_id, c.content, c.likes,
                    c.status::text as status, c.created_at, c.updated_at, c.depth, c.is_deleted,
-                   u.name as user_name,
+                   u.name as user_name, c.ip_address,
                    ct.sort_path || ',' || c.created_at::text as sort_path,
                    ct.level + 1 as level
             FROM comments c
             JOIN users u ON c.user_id = u.id
             JOIN comment_tree ct ON c.parent_id = ct.id
-            WHERE c.post_id = $1 AND c.is_deleted = false
+            WHERE c.post_id = $1 AND c.is_deleted = false AND c.status <> 'hidden'
         )
         SELECT id, post_id, user_id, parent_id, content, likes, status, 
-               created_at, updated_at, depth, is_deleted, user_name
+               created_at, updated_at, depth, is_deleted, user_name, ip_address
         FROM comment_tree
         ORDER BY sort_path
         "#,
@@ -1854,6 +2160,7 @@ pub async fn get_comments(
             is_deleted: comment_raw.is_deleted,
             user_name: comment_raw.user_name.expect("User name should not be null"),
             is_liked: Some(is_liked),
+            ip_address: author_ip(&board, claims.as_ref(), comment_raw.ip_address.as_deref()),
         };
         comments.push(comment);
     }
@@ -1870,6 +2177,7 @@ pub async fn get_comments(
 pub async fn create_comment(
     State(state): State<AppState>,
     Extension(claims): Extension<Option<crate::utils::auth::Claims>>,
+    ClientIp(client_ip): ClientIp,
     Json(payload): Json<CreateCommentRequest>,
 ) -> Result<Json<ApiResponse<CommentDetail>>, StatusCode> {
     // 인증 확인
@@ -1928,6 +2236,8 @@ pub async fn create_comment(
     if !can_create_comment(&board, Some(&claims.role)) {
         return Err(StatusCode::FORBIDDEN);
     }
+    ensure_email_verified(&state, &board, &claims).await?;
+    ensure_captcha(&state, &board, Some(&claims), &payload.captcha).await?;
 
     // 대댓글 깊이 계산
     let depth = if let Some(parent_id) = payload.parent_id {
@@ -1951,9 +2261,14 @@ pub async fn create_comment(
         0 // 최상위 댓글
     };
 
+    let mut tx = state.pool.begin().await.map_err(|e| {
+        eprintln!("Transaction begin error: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
     let comment = sqlx::query_as::<_, Comment>(
-        "INSERT INTO comments (post_id, user_id, parent_id, content, depth, is_deleted)
-         VALUES ($1, $2, $3, $4, $5, false)
+        "INSERT INTO comments (post_id, user_id, parent_id, content, depth, is_deleted, ip_address)
+         VALUES ($1, $2, $3, $4, $5, false, $6)
          RETURNING id, post_id, user_id, parent_id, content, likes, status, created_at, updated_at, depth, is_deleted"
     )
     .bind(post_id)
@@ -1961,13 +2276,38 @@ pub async fn create_comment(
     .bind(payload.parent_id)
     .bind(payload.content)
     .bind(depth)
-    .fetch_one(&state.pool)
+    .bind(&client_ip)
+    .fetch_one(&mut *tx)
     .await
     .map_err(|e| {
         eprintln!("Comment insert error: {:?}", e);
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
+    // 댓글 포인트 (차감 시 잔액 부족이면 작성 거절)
+    let applied = PointService::apply(
+        &mut tx,
+        claims.sub,
+        board.comment_point,
+        POINT_TYPE_COMMENT_WRITE,
+        "댓글 작성",
+        Some("comment"),
+        Some(comment.id),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Comment point error: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    if applied.is_none() {
+        return Err(StatusCode::PAYMENT_REQUIRED);
+    }
+
+    tx.commit().await.map_err(|e| {
+        eprintln!("Transaction commit error: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
     // 사용자 정보 조회
     let user = sqlx::query!("SELECT name FROM users WHERE id = $1", claims.sub)
         .fetch_one(&state.pool)
@@ -1991,8 +2331,22 @@ pub async fn create_comment(
         is_deleted: comment.is_deleted,
         user_name: user.name,
         is_liked: Some(false), // 새로 생성된 댓글은 좋아요하지 않은 상태
+        ip_address: author_ip(&board, None, client_ip.as_deref()), // 실시간으로 모두에게 전달되므로 공개용 형식
     };
 
+    // 게시글/부모 댓글 작성자에게 알림
+    if let Err(e) = NotificationService::new(state.pool.clone(), state.realtime.clone())
+        .notify_comment(claims.sub, post_id, comment_detail.parent_id)
+        .await
+    {
+        error!("댓글 알림 생성 실패: {:?}", e);
+    }
+
+    // 게시글을 보고 있는 사용자들에게 새 댓글 전달
+    state.realtime.clone()
+        .publish_to_post(post_id, "comment", &comment_detail)
+        .await;
+
     Ok(Json(ApiResponse {
         success: true,
         message: "댓글이 성공적으로 작성되었습니다.".to_string(),
@@ -2020,10 +2374,22 @@ pub async fn update_comment(
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
     .ok_or(StatusCode::NOT_FOUND)?;
 
-    if comment.user_id != claims.sub {
-        // 관리자 권한 확인 (임시로 모든 인증된 사용자를 관리자로 처리)
-        // 실제로는 데이터베이스에서 사용자 역할을 확인해야 함
+    // 작성자 또는 관리자만 수정 가능
+    if comment.user_id != claims.sub && claims.role != "admin" {
+        error!("댓글 수정 권한 없음: comment_user_id={}, current_user_id={}", comment.user_id, claims.sub);
+        return Err(StatusCode::FORBIDDEN);
     }
+    let board = find_post_board(&state, comment.post_id).await?;
+    ensure_change_limit(&state, &board, Some(&claims), board.edit_comment_limit, REVISION_ENTITY_COMMENT, comment_id, comment.created_at).await?;
+
+    // 수정 전 내용 보관과 수정을 한 트랜잭션에서 처리
+    let mut tx = state.pool.begin().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    RevisionService::snapshot_comment(&mut tx, comment_id, claims.sub, &payload.content)
+        .await
+        .map_err(|e| {
+            error!("댓글 수정 이력 저장 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
 
     // 댓글 수정
     let updated_comment_raw = sqlx::query!(
@@ -2033,9 +2399,10 @@ pub async fn update_comment(
         payload.content,
         comment_id
     )
-    .fetch_one(&state.pool)
+    .fetch_one(&mut *tx)
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    tx.commit().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     // 사용자 정보 조회
     let user = sqlx::query!("SELECT name FROM users WHERE id = $1", updated_comment_raw.user_id)
@@ -2057,6 +2424,7 @@ pub async fn update_comment(
         is_deleted: updated_comment_raw.is_deleted,
         user_name: user.name,
         is_liked: None, // 수정 시에는 좋아요 상태를 확인하지 않음
+        ip_address: None,
     };
 
     Ok(Json(ApiResponse {
@@ -2085,10 +2453,13 @@ pub async fn delete_comment(
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
     .ok_or(StatusCode::NOT_FOUND)?;
 
-    if comment.user_id != claims.sub {
-        // 관리자 권한 확인 (임시로 모든 인증된 사용자를 관리자로 처리)
-        // 실제로는 데이터베이스에서 사용자 역할을 확인해야 함
+    // 작성자 또는 관리자만 삭제 가능
+    if comment.user_id != claims.sub && claims.role != "admin" {
+        error!("댓글 삭제 권한 없음: comment_user_id={}, current_user_id={}", comment.user_id, claims.sub);
+        return Err(StatusCode::FORBIDDEN);
     }
+    let board = find_post_board(&state, comment.post_id).await?;
+    ensure_change_limit(&state, &board, Some(&claims), board.delete_comment_limit, REVISION_ENTITY_COMMENT, comment_id, comment.created_at).await?;
 
     // 소프트 삭제 (is_deleted = true로 설정)
     sqlx::query("UPDATE comments SET is_deleted = true, updated_at = NOW() WHERE id = $1")
@@ -2253,6 +2624,11 @@ pub async fn get_posts_by_slug(
         return Err(StatusCode::FORBIDDEN);
     }
 
+    // 검색을 허용하지 않는 게시판
+    if !board.allow_search && query.search.as_deref().is_some_and(|s| !s.trim().is_empty()) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
     // board_id를 강제로 설정하고 직접 게시글 조회
     let mut query = query;
     query.board_id = Some(board.id);
@@ -2271,7 +2647,7 @@ pub async fn get_posts_by_slug(
     count_conditions.push("p.status IN ('active', 'published')".to_string());
 
     if let Some(ref search) = query.search {
-        count_conditions.push(format!("(p.title ILIKE ${} OR p.content ILIKE ${} OR EXISTS (SELECT 1 FROM users u WHERE u.id = p.user_id AND u.name ILIKE ${}))", 
+        count_conditions.push(format!("(p.title ILIKE ${} OR strip_html(p.content) ILIKE ${} OR COALESCE((SELECT u.name FROM users u WHERE u.id = p.user_id), p.guest_name) ILIKE ${})", 
             count_param_count, count_param_count, count_param_count));
         count_param_count += 1;
     }
@@ -2303,7 +2679,7 @@ pub async fn get_posts_by_slug(
         SELECT 
             p.id, p.title, p.board_id, p.user_id, p.content, p.views, p.likes, p.is_notice, p.created_at,
             p.parent_id, p.depth, p.reply_count, p.thumbnail_urls,
-            u.name as user_name,
+            COALESCE(u.name, p.guest_name) as user_name,
             b.name as board_name,
             b.slug as board_slug,
             c.name as category_name,
@@ -2327,7 +2703,7 @@ pub async fn get_posts_by_slug(
     conditions.push("p.status IN ('active', 'published')".to_string());
 
     if let Some(ref search) = query.search {
-        conditions.push(format!("(p.title ILIKE ${} OR p.content ILIKE ${} OR u.name ILIKE ${})", 
+        conditions.push(format!("(p.title ILIKE ${} OR strip_html(p.content) ILIKE ${} OR COALESCE(u.name, p.guest_name) ILIKE ${})", 
             param_count, param_count, param_count));
         param_count += 1;
     }
@@ -2383,7 +2759,7 @@ pub async fn get_posts_by_slug(
             Some(attached_files)
         };
 
-        let thumbnail_urls = generate_thumbnail_urls(&attached_files_option).await;
+        let thumbnail_urls = generate_thumbnail_urls(&state, &attached_files_option).await;
 
         // URL ID 생성
         let url_id = generate_post_url_id(&state.pool, &post.id).await.ok();
@@ -2480,6 +2856,7 @@ pub async fn get_posts_by_slug(
 pub async fn create_post_by_slug(
     State(state): State<AppState>,
     Extension(claims): Extension<Option<crate::utils::auth::Claims>>,
+    ClientIp(client_ip): ClientIp,
     Path(slug): Path<String>,
     Json(mut payload): Json<CreatePostRequest>,
 ) -> Result<Json<ApiResponse<PostDetail>>, StatusCode> {
@@ -2514,16 +2891,15 @@ pub async fn create_post_by_slug(
         eprintln!("❌ 권한 없음: role={}", claims.role);
         return Err(StatusCode::FORBIDDEN);
     }
+    ensure_email_verified(&state, &board, &claims).await?;
     eprintln!("✅ 권한 확인 완료: role={}", claims.role);
 
-    let sanitized_content = clean(&payload.content);
-    payload.content = sanitized_content;
-
+    // 본문 정리와 임시저장 글 발행 처리는 create_post에서 처리
     payload.board_id = Some(board.id);
     eprintln!("📝 create_post 호출 시작: board_id={}", board.id);
     
     // 기존 create_post 로직 재사용
-    let result = create_post(State(state), Extension(Some(claims)), Json(payload)).await;
+    let result = create_post(State(state.clone()), Extension(Some(claims)), ClientIp(client_ip), Json(payload)).await;
     match &result {
         Ok(_) => eprintln!("✅ create_post 성공"),
         Err(e) => eprintln!("❌ create_post 실패: {:?}", e),
@@ -2535,6 +2911,7 @@ pub async fn create_post_by_slug(
 pub async fn create_reply_by_slug(
     State(state): State<AppState>,
     Extension(claims): Extension<Option<crate::utils::auth::Claims>>,
+    ClientIp(client_ip): ClientIp,
     Path(slug): Path<String>,
     Json(payload): Json<CreateReplyRequest>,
 ) -> Result<Json<ApiResponse<PostDetail>>, StatusCode> {
@@ -2547,7 +2924,7 @@ pub async fn create_reply_by_slug(
             p.id, p.board_id, p.category_id, p.user_id, p.parent_id, p.title, p.content,
             p.views, p.likes, p.dislikes, p.is_notice, p.status::text, p.created_at, p.updated_at,
             p.depth, p.reply_count, p.attached_files, p.thumbnail_urls,
-            u.name as user_name, u.email as user_email,
+            COALESCE(u.name, p.guest_name) as user_name, u.email as user_email,
             b.name as board_name, b.slug as board_slug,
             c.name as category_name,
             (SELECT COUNT(*) FROM comments WHERE post_id = p.id AND is_deleted = false) as comment_count
@@ -2590,8 +2967,10 @@ pub async fn create_reply_by_slug(
         error!("답글 생성 권한 없음: role={}", claims.role);
         return Err(StatusCode::FORBIDDEN);
     }
+    ensure_email_verified(&state, &board, &claims).await?;
+    ensure_captcha(&state, &board, Some(&claims), &payload.captcha).await?;
 
-    let sanitized_content = clean(&payload.content);
+    let post_content = prepare_post_content(&board, &payload.content);
     let parent_depth = parent_post.depth.unwrap_or(0);
     let reply_depth = parent_depth + 1;
 
@@ -2601,11 +2980,16 @@ pub async fn create_reply_by_slug(
         return Err(StatusCode::BAD_REQUEST);
     }
 
+    let mut tx = state.pool.begin().await.map_err(|e| {
+        error!("답글 트랜잭션 시작 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
     // 답글 생성
     let post_result = sqlx::query!(
         r#"
-        INSERT INTO posts (board_id, category_id, user_id, parent_id, title, content, status, depth)
-        VALUES ($1, $2, $3, $4, $5, $6, 'published', $7)
+        INSERT INTO posts (board_id, category_id, user_id, parent_id, title, content, content_source, status, depth, ip_address)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, 'published', $8, $9)
         RETURNING id
         "#,
         parent_post.board_id,
@@ -2613,10 +2997,12 @@ pub async fn create_reply_by_slug(
         claims.sub,
         payload.parent_id,
         payload.title,
-        sanitized_content,
-        reply_depth
+        post_content.content,
+        post_content.content_source,
+        reply_depth,
+        client_ip
     )
-    .fetch_one(&state.pool)
+    .fetch_one(&mut *tx)
     .await
     .map_err(|e| {
         error!("답글 생성 실패: {:?}", e);
@@ -2625,6 +3011,30 @@ pub async fn create_reply_by_slug(
 
     let reply_id = post_result.id;
 
+    // 작성 포인트 (차감 시 잔액 부족이면 작성 거절)
+    let applied = PointService::apply(
+        &mut tx,
+        claims.sub,
+        board.write_point,
+        POINT_TYPE_POST_WRITE,
+        "답글 작성",
+        Some("post"),
+        Some(reply_id),
+    )
+    .await
+    .map_err(|e| {
+        error!("답글 포인트 처리 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    if applied.is_none() {
+        return Err(StatusCode::PAYMENT_REQUIRED);
+    }
+
+    tx.commit().await.map_err(|e| {
+        error!("답글 트랜잭션 커밋 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
     // 첨부파일 처리 (필요한 경우)
     if let Some(attached_files) = &payload.attached_files {
         for file_path in attached_files {
@@ -2654,7 +3064,7 @@ pub async fn create_reply_by_slug(
 
     // 첨부파일에서 썸네일 URL 생성
     let thumbnail_urls = if let Some(ref attached_files) = payload.attached_files {
-        generate_thumbnail_urls(&Some(attached_files.clone())).await
+        generate_thumbnail_urls(&state, &Some(attached_files.clone())).await
     } else {
         None
     };
@@ -2686,6 +3096,14 @@ pub async fn create_reply_by_slug(
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
+    // 부모 게시글 작성자에게 알림
+    if let Err(e) = NotificationService::new(state.pool.clone(), state.realtime.clone())
+        .notify_reply(claims.sub, payload.parent_id, reply_id)
+        .await
+    {
+        error!("답글 알림 생성 실패: {:?}", e);
+    }
+
     // 생성된 답글 조회
     let reply = sqlx::query_as::<_, PostDetailRaw>(
         r#"
@@ -2693,7 +3111,7 @@ pub async fn create_reply_by_slug(
             p.id, p.board_id, p.category_id, p.user_id, p.parent_id, p.title, p.content,
             p.views, p.likes, p.dislikes, p.is_notice, p.status::text, p.created_at, p.updated_at,
             p.depth, p.reply_count, p.attached_files, p.thumbnail_urls,
-            u.name as user_name, u.email as user_email,
+            COALESCE(u.name, p.guest_name) as user_name, u.email as user_email,
             b.name as board_name, b.slug as board_slug,
             c.name as category_name,
             (SELECT COUNT(*) FROM comments WHERE post_id = p.id AND is_deleted = false) as comment_count
@@ -2744,8 +3162,17 @@ pub async fn create_reply_by_slug(
         attached_files: None, // 필요시 별도 로드
         thumbnail_urls: reply.thumbnail_urls.and_then(|v| serde_json::from_value(v).ok()),
         is_liked: None,
+        ip_address: author_ip(&board, None, client_ip.as_deref()), // 부모 글 작성자에게도 전달되므로 공개용 형식
+        content_source: None,
     };
 
+    // 부모 게시글 작성자에게 새 답글 전달 (비회원 글 제외)
+    if let Some(parent_author) = parent_post.user_id.filter(|author| *author != claims.sub) {
+        state.realtime.clone()
+            .publish_to_user(parent_author, "reply", &reply_detail)
+            .await;
+    }
+
     Ok(Json(ApiResponse {
         success: true,
         message: "답글이 성공적으로 작성되었습니다.".to_string(),
@@ -2755,31 +3182,78 @@ pub async fn create_reply_by_slug(
 }
 
 // 썸네일 URL 생성 함수 (누락된 썸네일 자동 생성 포함)
-async fn generate_thumbnail_urls(attached_files: &Option<Vec<String>>) -> Option<ThumbnailUrls> {
+async fn generate_thumbnail_urls(state: &AppState, attached_files: &Option<Vec<String>>) -> Option<ThumbnailUrls> {
+    let storage = &state.storage;
     if let Some(files) = attached_files {
         for file_path in files {
+            // 동영상은 후처리 작업에서 추출한 포스터 프레임의 썸네일 사용 (포스터가 없으면 다음 파일)
+            if is_video_key(file_path) {
+                let Some(original_key) = key_from_url(file_path) else {
+                    continue;
+                };
+                let poster_key = video_poster_key(original_key);
+                if !matches!(storage.head(&poster_key).await, Ok(Some(_))) {
+                    continue;
+                }
+                let thumbnail_service = ThumbnailService::new(storage.clone());
+                let (thumb_result, card_result, large_result) = tokio::join!(
+                    ensure_thumbnail_exists(state, &thumbnail_service, &poster_key, "thumb"),
+                    ensure_thumbnail_exists(state, &thumbnail_service, &poster_key, "card"),
+                    ensure_thumbnail_exists(state, &thumbnail_service, &poster_key, "large")
+                );
+                // 포스터 썸네일은 동영상 파일의 image_sizes에 기록됨
+                let srcsets = image_srcsets(&state.pool, storage.as_ref(), original_key)
+                    .await
+                    .unwrap_or_else(|e| {
+                        error!("srcset 조회 실패: {}: {:?}", original_key, e);
+                        ImageSrcsets::default()
+                    });
+
+                let poster_url = upload_url(&poster_key);
+                return Some(ThumbnailUrls {
+                    thumb: Some(thumb_result.unwrap_or_else(|| poster_url.clone())),
+                    card: Some(card_result.unwrap_or_else(|| poster_url.clone())),
+                    large: Some(large_result.unwrap_or_else(|| poster_url.clone())),
+                    srcsets,
+                    poster: Some(poster_url),
+                });
+            }
+
             // 이미지 파일인지 확인
             if is_image_file_path(file_path) {
-                let thumbnail_service = ThumbnailService::new();
-                
-                // 원본 파일 경로를 static/ 형태로 변환
-                let original_path = if file_path.starts_with("/uploads") {
-                    format!("static{}", file_path)
-                } else {
-                    file_path.clone()
+                // 업로드 파일이 아니면 원본 URL 그대로 사용
+                let Some(original_key) = key_from_url(file_path) else {
+                    return Some(ThumbnailUrls {
+                        thumb: Some(file_path.clone()),
+                        card: Some(file_path.clone()),
+                        large: Some(file_path.clone()),
+                        srcsets: ImageSrcsets::default(),
+                        poster: None,
+                    });
                 };
+                let thumbnail_service = ThumbnailService::new(storage.clone());
 
                 // 병렬로 썸네일 생성/확인 처리
                 let (thumb_result, card_result, large_result) = tokio::join!(
-                    ensure_thumbnail_exists(&thumbnail_service, &original_path, "thumb"),
-                    ensure_thumbnail_exists(&thumbnail_service, &original_path, "card"),
-                    ensure_thumbnail_exists(&thumbnail_service, &original_path, "large")
+                    ensure_thumbnail_exists(state, &thumbnail_service, original_key, "thumb"),
+                    ensure_thumbnail_exists(state, &thumbnail_service, original_key, "card"),
+                    ensure_thumbnail_exists(state, &thumbnail_service, original_key, "large")
                 );
                 
+                // 후처리 작업 전이면 srcset 없이 반환 (작업 완료 시 게시글에 저장된 정보 갱신)
+                let srcsets = image_srcsets(&state.pool, storage.as_ref(), original_key)
+                    .await
+                    .unwrap_or_else(|e| {
+                        error!("srcset 조회 실패: {}: {:?}", original_key, e);
+                        ImageSrcsets::default()
+                    });
+
                 return Some(ThumbnailUrls {
                     thumb: Some(thumb_result.unwrap_or_else(|| file_path.clone())),
                     card: Some(card_result.unwrap_or_else(|| file_path.clone())),
                     large: Some(large_result.unwrap_or_else(|| file_path.clone())),
+                    srcsets,
+                    poster: None,
                 });
             }
         }
@@ -2789,47 +3263,37 @@ async fn generate_thumbnail_urls(attached_files: &Option<Vec<String>>) -> Option
 
 // 개별 썸네일 존재 확인 및 생성 함수
 async fn ensure_thumbnail_exists(
+    state: &AppState,
     thumbnail_service: &ThumbnailService,
-    original_path: &str,
+    original_key: &str,
     size_suffix: &str
 ) -> Option<String> {
-    let thumbnail_url = thumbnail_service.get_thumbnail_url(original_path, size_suffix);
+    let thumbnail_url = thumbnail_service.get_thumbnail_url(original_key, size_suffix);
     
     // 썸네일이 이미 존재하는지 확인
-    if thumbnail_service.thumbnail_exists(&thumbnail_url) {
-        return Some(thumbnail_url.replace("static", ""));
+    if thumbnail_service.thumbnail_exists(&thumbnail_key(original_key, size_suffix)).await {
+        return Some(thumbnail_url);
     }
     
     // 썸네일이 없으면 생성 시도 (타임아웃 설정)
-    let create_task = thumbnail_service.create_missing_thumbnail(original_path, size_suffix);
+    let create_task = thumbnail_service.create_missing_thumbnail(original_key, size_suffix);
     
     // 2초 타임아웃 설정 (응답 속도 우선)
     match tokio::time::timeout(std::time::Duration::from_secs(2), create_task).await {
         Ok(Ok(Some(_))) => {
             // 생성 성공
-            let new_url = thumbnail_service.get_thumbnail_url(original_path, size_suffix);
-            Some(new_url.replace("static", ""))
+            Some(thumbnail_url)
         },
         _ => {
-            // 생성 실패 또는 타임아웃 - 백그라운드에서 생성 스케줄링
-            schedule_background_thumbnail_creation(original_path.to_string(), size_suffix.to_string());
+            // 생성 실패 또는 타임아웃 - 작업 큐에서 누락된 썸네일 생성
+            if let Err(e) = enqueue_media_job(&state.pool, JOB_THUMBNAIL, None, original_key, state.config.media_job.max_attempts).await {
+                error!("썸네일 생성 작업 등록 실패: {}: {:?}", original_key, e);
+            }
             None // 원본 이미지를 사용하도록 None 반환
         }
     }
 }
 
-// 백그라운드 썸네일 생성 스케줄링 (실제 구현에서는 큐 시스템 사용)
-fn schedule_background_thumbnail_creation(original_path: String, size_suffix: String) {
-    tokio::spawn(async move {
-        let thumbnail_service = ThumbnailService::new();
-        if let Err(e) = thumbnail_service.create_missing_thumbnail(&original_path, &size_suffix).await {
-            eprintln!("Background thumbnail creation failed for {}: {:?}", original_path, e);
-        } else {
-            println!("Background thumbnail created: {} ({})", original_path, size_suffix);
-        }
-    });
-}
-
 // 파일 경로가 이미지인지 확인
 fn is_image_file_path(file_path: &str) -> bool {
     let extension = std::path::Path::new(file_path)
@@ -2860,7 +3324,7 @@ pub async fn toggle_post_like(
         .ok_or(StatusCode::NOT_FOUND)?;
 
     // 자신이 작성한 게시글에는 좋아요를 할 수 없음
-    if post.user_id == claims.sub {
+    if post.user_id == Some(claims.sub) {
         return Err(StatusCode::FORBIDDEN);
     }
 
@@ -2954,6 +3418,14 @@ pub async fn toggle_post_like(
 
         tx.commit().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+        // 게시글 작성자에게 알림
+        if let Err(e) = NotificationService::new(state.pool.clone(), state.realtime.clone())
+            .notify_post_like(claims.sub, post_id)
+            .await
+        {
+            error!("좋아요 알림 생성 실패: {:?}", e);
+        }
+
         Ok(Json(ApiResponse {
             success: true,
             message: "좋아요가 추가되었습니다.".to_string(),
@@ -2992,7 +3464,9 @@ pub async fn toggle_comment_like(
     // 게시판 정보 조회 (좋아요 허용 여부 확인)
     let board_raw = sqlx::query_as::<_, BoardRaw>(
         r#"
-        SELECT * FROM boards WHERE id = $1
+        SELECT b.* FROM boards b
+        JOIN posts p ON p.board_id = b.id
+        WHERE p.id = $1
         "#
     )
     .bind(comment.post_id)
@@ -3079,6 +3553,14 @@ pub async fn toggle_comment_like(
 
         tx.commit().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+        // 댓글 작성자에게 알림
+        if let Err(e) = NotificationService::new(state.pool.clone(), state.realtime.clone())
+            .notify_comment_like(claims.sub, comment_id)
+            .await
+        {
+            error!("좋아요 알림 생성 실패: {:?}", e);
+        }
+
         Ok(Json(ApiResponse {
             success: true,
             message: "좋아요가 추가되었습니다.".to_string(),
@@ -3191,7 +3673,7 @@ pub async fn get_recent_posts(
             p.updated_at,
             p.attached_files,
             p.thumbnail_urls,
-            u.name as user_name,
+            COALESCE(u.name, p.guest_name) as user_name,
             u.email as user_email,
             b.name as board_name,
             b.slug as board_slug,
@@ -3224,7 +3706,7 @@ pub async fn get_recent_posts(
             serde_json::from_value(v).ok()
         } else {
             // 썸네일 URL이 없으면 첨부파일에서 생성
-            generate_thumbnail_urls(&post_raw.attached_files).await
+            generate_thumbnail_urls(&state, &post_raw.attached_files).await
         };
         
         // URL ID 생성
@@ -3258,6 +3740,8 @@ pub async fn get_recent_posts(
             category_name: post_raw.category_name,
             comment_count: post_raw.comment_count,
             is_liked: None, // 나중에 별도로 로드
+            ip_address: None,
+            content_source: None,
         };
         
         posts_with_thumbnails.push(post_detail);
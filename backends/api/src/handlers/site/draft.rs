@@ -0,0 +1,333 @@
+use axum::{
+    extract::{Path, State, Extension},
+    http::StatusCode,
+    response::Json,
+};
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+use tracing::error;
+use crate::{
+    handlers::site::community::{BoardRaw, convert_board_raw_to_board, can_write_post, find_board},
+    models::admin::board::Board,
+    models::site::community::AttachedFile,
+    models::site::draft::{Draft, DraftDetail, SaveDraftRequest},
+    models::response::ApiResponse,
+    models::FilePurpose,
+    services::public_url,
+    utils::auth::Claims,
+    utils::html_sanitize::{prepare_post_content, PostContent},
+    AppState,
+};
+
+// 빈 값은 비우고, 제목/본문은 게시글과 같이 게시판 설정으로 정리
+fn draft_title(title: Option<String>) -> Option<String> {
+    title.filter(|title| !title.trim().is_empty())
+}
+
+fn draft_content(board: &Board, content: Option<&str>) -> Option<PostContent> {
+    content
+        .filter(|content| !content.trim().is_empty())
+        .map(|content| prepare_post_content(board, content))
+}
+
+// 임시저장 생성 (게시판 slug 기준)
+pub async fn create_draft(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Path(slug): Path<String>,
+    Json(payload): Json<SaveDraftRequest>,
+) -> Result<Json<ApiResponse<Draft>>, StatusCode> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let board_raw = sqlx::query_as::<_, BoardRaw>("SELECT * FROM boards WHERE slug = $1")
+        .bind(&slug)
+        .fetch_optional(&state.pool)
+        .await
+        .map_err(|e| {
+            error!("임시저장 게시판 조회 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    let board = convert_board_raw_to_board(board_raw);
+
+    // 글쓰기 권한이 있는 게시판에만 임시저장 가능
+    if !can_write_post(&board, Some(&claims.role)) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let content = draft_content(&board, payload.content.flatten().as_deref());
+    let draft = sqlx::query_as::<_, Draft>(
+        r#"
+        INSERT INTO drafts (id, user_id, board_id, category_id, title, content, content_source, auto_save_count, expires_at)
+        VALUES (gen_random_uuid(), $1, $2, $3, $4, $5, $6, 0, NOW() + INTERVAL '7 days')
+        RETURNING id, user_id, board_id, category_id, title, content, content_source, auto_save_count, expires_at, created_at, updated_at
+        "#
+    )
+    .bind(claims.sub)
+    .bind(board.id)
+    .bind(payload.category_id.flatten())
+    .bind(draft_title(payload.title.flatten()))
+    .bind(content.as_ref().map(|c| c.content.as_str()))
+    .bind(content.as_ref().and_then(|c| c.content_source.as_deref()))
+    .fetch_one(&state.pool)
+    .await
+    .map_err(|e| {
+        error!("임시저장 생성 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(ApiResponse::success(draft, "임시저장되었습니다.")))
+}
+
+// 내 임시저장 목록 (게시판 slug 기준, 만료되지 않은 것만)
+pub async fn get_my_drafts(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Path(slug): Path<String>,
+) -> Result<Json<ApiResponse<Vec<Draft>>>, StatusCode> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let drafts = sqlx::query_as::<_, Draft>(
+        r#"
+        SELECT d.id, d.user_id, d.board_id, d.category_id, d.title, d.content, d.content_source,
+               d.auto_save_count, d.expires_at, d.created_at, d.updated_at
+        FROM drafts d
+        JOIN boards b ON d.board_id = b.id
+        WHERE b.slug = $1 AND d.user_id = $2 AND d.expires_at > NOW()
+        ORDER BY d.updated_at DESC
+        "#
+    )
+    .bind(&slug)
+    .bind(claims.sub)
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|e| {
+        error!("임시저장 목록 조회 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(ApiResponse::success(drafts, "임시저장 목록을 조회했습니다.")))
+}
+
+// 임시저장 글 불러오기 (첨부파일 포함)
+pub async fn get_draft(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Path(draft_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<DraftDetail>>, StatusCode> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let draft = find_my_draft(&state, draft_id, claims.sub).await?;
+
+    let attached_files = sqlx::query!(
+        r#"
+        SELECT f.id, f.original_name, f.file_path, f.file_size, f.mime_type, fe.display_order, f.download_count,
+               f.duration_seconds, f.width, f.height, f.poster_path
+        FROM file_entities fe
+        JOIN files f ON fe.file_id = f.id
+        WHERE fe.entity_type = 'draft' AND fe.entity_id = $1
+        ORDER BY fe.display_order
+        "#,
+        draft.id
+    )
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|e| {
+        error!("임시저장 첨부파일 조회 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .into_iter()
+    .map(|file| AttachedFile {
+        id: file.id,
+        original_name: file.original_name,
+        file_path: public_url(state.storage.as_ref(), &file.file_path),
+        file_size: file.file_size,
+        mime_type: file.mime_type,
+        file_purpose: Some(FilePurpose::Attachment),
+        display_order: Some(file.display_order.unwrap_or(0)),
+        download_count: file.download_count,
+        duration_seconds: file.duration_seconds,
+        width: file.width,
+        height: file.height,
+        poster_url: file.poster_path.map(|poster_path| public_url(state.storage.as_ref(), &poster_path)),
+    })
+    .collect::<Vec<AttachedFile>>();
+
+    let detail = DraftDetail {
+        id: draft.id,
+        user_id: draft.user_id,
+        board_id: draft.board_id,
+        category_id: draft.category_id,
+        title: draft.title,
+        content: draft.content,
+        content_source: draft.content_source,
+        auto_save_count: draft.auto_save_count,
+        expires_at: draft.expires_at,
+        created_at: draft.created_at,
+        updated_at: draft.updated_at,
+        attached_files,
+    };
+
+    Ok(Json(ApiResponse::success(detail, "임시저장 글을 불러왔습니다.")))
+}
+
+// 자동저장 (보내지 않은 항목은 유지, null/빈 값은 비우기, 저장 횟수 증가 및 만료 시간 연장)
+pub async fn update_draft(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Path(draft_id): Path<Uuid>,
+    Json(payload): Json<SaveDraftRequest>,
+) -> Result<Json<ApiResponse<Draft>>, StatusCode> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let draft = find_my_draft(&state, draft_id, claims.sub).await?;
+    let board = find_board(&state, draft.board_id).await?;
+    let content = payload
+        .content
+        .as_ref()
+        .map(|content| draft_content(&board, content.as_deref()));
+
+    let draft = sqlx::query_as::<_, Draft>(
+        r#"
+        UPDATE drafts
+        SET category_id = CASE WHEN $1 THEN $2 ELSE category_id END,
+            title = CASE WHEN $3 THEN $4 ELSE title END,
+            content = CASE WHEN $5 THEN $6 ELSE content END,
+            content_source = CASE WHEN $5 THEN $7 ELSE content_source END,
+            auto_save_count = COALESCE(auto_save_count, 0) + 1,
+            expires_at = NOW() + INTERVAL '7 days',
+            updated_at = NOW()
+        WHERE id = $8 AND user_id = $9 AND expires_at > NOW()
+        RETURNING id, user_id, board_id, category_id, title, content, content_source, auto_save_count, expires_at, created_at, updated_at
+        "#
+    )
+    .bind(payload.category_id.is_some())
+    .bind(payload.category_id.flatten())
+    .bind(payload.title.is_some())
+    .bind(draft_title(payload.title.flatten()))
+    .bind(content.is_some())
+    .bind(content.as_ref().and_then(|c| c.as_ref()).map(|c| c.content.as_str()))
+    .bind(content.as_ref().and_then(|c| c.as_ref()).and_then(|c| c.content_source.as_deref()))
+    .bind(draft.id)
+    .bind(claims.sub)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|e| {
+        error!("임시저장 업데이트 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    Ok(Json(ApiResponse::success(draft, "자동저장되었습니다.")))
+}
+
+// 임시저장 삭제 (연결된 파일은 고아 상태로 전환)
+pub async fn delete_draft(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Path(draft_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, StatusCode> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let draft = find_my_draft(&state, draft_id, claims.sub).await?;
+
+    let mut tx = state.pool.begin().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    sqlx::query(
+        r#"
+        UPDATE files SET status = 'orphaned', orphaned_at = NOW()
+        WHERE id IN (
+            SELECT file_id FROM file_entities WHERE entity_type = 'draft' AND entity_id = $1
+        )
+        "#
+    )
+    .bind(draft.id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        error!("임시저장 파일 정리 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    sqlx::query("DELETE FROM file_entities WHERE entity_type = 'draft' AND entity_id = $1")
+        .bind(draft.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    sqlx::query("DELETE FROM drafts WHERE id = $1")
+        .bind(draft.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    tx.commit().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(ApiResponse::success((), "임시저장 글이 삭제되었습니다.")))
+}
+
+/// 본인 소유의 만료되지 않은 임시저장 글 조회
+pub(crate) async fn find_my_draft(state: &AppState, draft_id: Uuid, user_id: Uuid) -> Result<Draft, StatusCode> {
+    sqlx::query_as::<_, Draft>(
+        r#"
+        SELECT id, user_id, board_id, category_id, title, content, content_source,
+               auto_save_count, expires_at, created_at, updated_at
+        FROM drafts
+        WHERE id = $1 AND user_id = $2 AND expires_at > NOW()
+        "#
+    )
+    .bind(draft_id)
+    .bind(user_id)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|e| {
+        error!("임시저장 조회 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)
+}
+
+/// 임시저장 글에 연결된 파일을 게시글로 옮기고 임시저장 글 삭제 (게시글 작성 트랜잭션 안에서 호출)
+pub(crate) async fn promote_draft_files(tx: &mut Transaction<'_, Postgres>, draft_id: Uuid, post_id: Uuid) -> Result<(), sqlx::Error> {
+
+    // 이미 게시글에 연결된 파일은 제외하고 연결 대상을 게시글로 변경
+    sqlx::query(
+        r#"
+        UPDATE file_entities fe
+        SET entity_type = 'post', entity_id = $2
+        WHERE fe.entity_type = 'draft' AND fe.entity_id = $1
+        AND NOT EXISTS (
+            SELECT 1 FROM file_entities pe
+            WHERE pe.file_id = fe.file_id AND pe.entity_type = 'post' AND pe.entity_id = $2
+        )
+        "#
+    )
+    .bind(draft_id)
+    .bind(post_id)
+    .execute(&mut **tx)
+    .await?;
+
+    sqlx::query("DELETE FROM file_entities WHERE entity_type = 'draft' AND entity_id = $1")
+        .bind(draft_id)
+        .execute(&mut **tx)
+        .await?;
+
+    sqlx::query(
+        r#"
+        UPDATE files SET status = 'published'
+        WHERE id IN (
+            SELECT file_id FROM file_entities WHERE entity_type = 'post' AND entity_id = $1
+        )
+        "#
+    )
+    .bind(post_id)
+    .execute(&mut **tx)
+    .await?;
+
+    sqlx::query("DELETE FROM drafts WHERE id = $1")
+        .bind(draft_id)
+        .execute(&mut **tx)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,281 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use tracing::error;
+use crate::{
+    handlers::site::community::{
+        author_ip, can_write_post, convert_board_raw_to_board, ensure_board_category, ensure_captcha, ensure_change_limit,
+        find_post_board, BoardRaw,
+    },
+    models::site::community::{CreateGuestPostRequest, GuestPasswordRequest, PostDetail, UpdateGuestPostRequest},
+    models::admin::board::Board,
+    models::response::ApiResponse,
+    models::REVISION_ENTITY_POST,
+    services::{check_rate_limit, RevisionService},
+    utils::auth::{hash_password, verify_password},
+    utils::client_ip::ClientIp,
+    utils::html_sanitize::prepare_post_content,
+    utils::uuid_compression::decompress_base62_to_uuid,
+    AppState,
+};
+
+const GUEST_NAME_MAX_CHARS: usize = 20;
+const GUEST_PASSWORD_MIN_CHARS: usize = 4;
+const GUEST_PASSWORD_MAX_CHARS: usize = 64;
+const RATE_LIMIT_WINDOW_SECONDS: u64 = 3600;
+
+// 작성/수정 후 응답용 컬럼 (update_post와 동일)
+const GUEST_POST_RETURNING: &str = "id, board_id, category_id, user_id, parent_id, title, content, content_source, views, likes, dislikes, is_notice, status, created_at, updated_at, depth, reply_count, ip_address,
+     guest_name as user_name,
+     NULL::text as user_email,
+     (SELECT name FROM boards WHERE id = board_id) as board_name,
+     (SELECT slug FROM boards WHERE id = board_id) as board_slug,
+     (SELECT name FROM categories WHERE id = category_id) as category_name,
+     (SELECT COUNT(*)::bigint FROM comments WHERE post_id = posts.id AND status IN ('active', 'published')) as comment_count";
+
+/// 비회원 닉네임/비밀번호 형식 확인 (닉네임은 앞뒤 공백 제외 1~20자, 비밀번호는 4~64자)
+pub fn is_valid_guest_credentials(guest_name: &str, guest_password: &str) -> bool {
+    let name_len = guest_name.trim().chars().count();
+    let password_len = guest_password.chars().count();
+    (1..=GUEST_NAME_MAX_CHARS).contains(&name_len)
+        && (GUEST_PASSWORD_MIN_CHARS..=GUEST_PASSWORD_MAX_CHARS).contains(&password_len)
+}
+
+// 게시글 ID (UUID 또는 압축된 ID)
+fn parse_post_id(post_id: &str) -> Result<Uuid, StatusCode> {
+    if post_id.len() == 22 && post_id.chars().all(|c| c.is_alphanumeric()) {
+        return decompress_base62_to_uuid(post_id).map_err(|_| StatusCode::BAD_REQUEST);
+    }
+    Uuid::parse_str(post_id).map_err(|_| StatusCode::BAD_REQUEST)
+}
+
+// IP별 요청 수 제한 (초과 시 429)
+// IP를 알 수 없으면 거부(400), Redis를 사용할 수 없으면 제한 없이 허용하지 않고 거부(503)
+async fn ensure_rate_limit(state: &AppState, action: &str, client_ip: Option<&str>, limit: u32) -> Result<(), StatusCode> {
+    let client_ip = client_ip.ok_or_else(|| {
+        error!("비회원 요청 IP 확인 불가: {}", action);
+        StatusCode::BAD_REQUEST
+    })?;
+    let key = format!("{}:{}", action, client_ip);
+    let allowed = check_rate_limit(&state.redis, &key, limit, RATE_LIMIT_WINDOW_SECONDS)
+        .await
+        .map_err(|e| {
+            error!("비회원 요청 수 제한 확인 실패: {}", e);
+            StatusCode::SERVICE_UNAVAILABLE
+        })?;
+    if !allowed {
+        error!("비회원 요청 수 제한 초과: {}", key);
+        return Err(StatusCode::TOO_MANY_REQUESTS);
+    }
+    Ok(())
+}
+
+// 비회원 글 비밀번호 확인 후 게시판 반환 (회원 글이거나 비밀번호가 틀리면 403)
+async fn verify_guest_post(
+    state: &AppState,
+    post_id: Uuid,
+    guest_password: &str,
+    client_ip: Option<&str>,
+) -> Result<(Board, Option<DateTime<Utc>>), StatusCode> {
+    ensure_rate_limit(state, "guest_password", client_ip, state.config.guest_post.password_attempts_per_hour).await?;
+
+    let (password_hash, created_at) = sqlx::query_as::<_, (Option<String>, Option<DateTime<Utc>>)>(
+        "SELECT guest_password_hash, created_at FROM posts WHERE id = $1 AND status = 'published'"
+    )
+    .bind(post_id)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|e| {
+        error!("비회원 게시글 조회 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    let password_hash = password_hash.ok_or(StatusCode::FORBIDDEN)?;
+    if !verify_password(guest_password, &password_hash) {
+        error!("비회원 게시글 비밀번호 불일치: post_id={}", post_id);
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let board = find_post_board(state, post_id).await?;
+    Ok((board, created_at))
+}
+
+// 비회원 게시글 작성 (allow_anonymous 게시판)
+pub async fn create_guest_post(
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(slug): Path<String>,
+    Json(payload): Json<CreateGuestPostRequest>,
+) -> Result<Json<ApiResponse<PostDetail>>, StatusCode> {
+    let board_raw = sqlx::query_as::<_, BoardRaw>("SELECT * FROM boards WHERE slug = $1")
+        .bind(&slug)
+        .fetch_optional(&state.pool)
+        .await
+        .map_err(|e| {
+            error!("비회원 글쓰기 게시판 조회 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    let board = convert_board_raw_to_board(board_raw);
+
+    if !board.allow_anonymous || !can_write_post(&board, None) {
+        error!("비회원 글쓰기 불가 게시판: {}", board.slug);
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if !is_valid_guest_credentials(&payload.guest_name, &payload.guest_password) {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    ensure_board_category(&state, &board, payload.category_id).await?;
+    ensure_rate_limit(&state, "guest_post", client_ip.as_deref(), state.config.guest_post.posts_per_hour).await?;
+    ensure_captcha(&state, &board, None, &payload.captcha).await?;
+
+    let sql = format!(
+        "INSERT INTO posts (board_id, category_id, title, content, content_source, guest_name, guest_password_hash, status, ip_address)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, 'published', $8)
+         RETURNING {}",
+        GUEST_POST_RETURNING
+    );
+    let post_content = prepare_post_content(&board, &payload.content);
+    let mut post = sqlx::query_as::<_, PostDetail>(&sql)
+        .bind(board.id)
+        .bind(payload.category_id)
+        .bind(&payload.title)
+        .bind(post_content.content)
+        .bind(post_content.content_source)
+        .bind(payload.guest_name.trim())
+        .bind(hash_password(&payload.guest_password))
+        .bind(&client_ip)
+        .fetch_one(&state.pool)
+        .await
+        .map_err(|e| {
+            error!("비회원 게시글 작성 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    post.ip_address = author_ip(&board, None, post.ip_address.as_deref());
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: "게시글이 성공적으로 작성되었습니다.".to_string(),
+        data: Some(post),
+        pagination: None,
+    }))
+}
+
+// 비회원 게시글 비밀번호 확인 (수정 화면 진입 전)
+pub async fn verify_guest_post_password(
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(post_id): Path<String>,
+    Json(payload): Json<GuestPasswordRequest>,
+) -> Result<Json<ApiResponse<()>>, StatusCode> {
+    let post_id = parse_post_id(&post_id)?;
+    verify_guest_post(&state, post_id, &payload.guest_password, client_ip.as_deref()).await?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: "비밀번호가 확인되었습니다.".to_string(),
+        data: Some(()),
+        pagination: None,
+    }))
+}
+
+// 비회원 게시글 수정 (비밀번호 확인)
+pub async fn update_guest_post(
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(post_id): Path<String>,
+    Json(payload): Json<UpdateGuestPostRequest>,
+) -> Result<Json<ApiResponse<PostDetail>>, StatusCode> {
+    let post_id = parse_post_id(&post_id)?;
+    let (board, created_at) = verify_guest_post(&state, post_id, &payload.guest_password, client_ip.as_deref()).await?;
+    ensure_change_limit(&state, &board, None, board.edit_comment_limit, REVISION_ENTITY_POST, post_id, created_at).await?;
+    ensure_board_category(&state, &board, payload.category_id).await?;
+
+    let post_content = payload.content.as_deref().map(|content| prepare_post_content(&board, content));
+    let content = post_content.as_ref().map(|c| c.content.as_str());
+    // 수정 전 내용 보관과 수정을 한 트랜잭션에서 처리
+    let mut tx = state.pool.begin().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    RevisionService::snapshot_post(&mut tx, post_id, None, payload.title.as_deref(), content)
+        .await
+        .map_err(|e| {
+            error!("비회원 게시글 수정 이력 저장 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let sql = format!(
+        "UPDATE posts SET category_id = COALESCE($2, category_id), title = COALESCE($3, title), content = COALESCE($4, content),
+         content_source = CASE WHEN $4 IS NULL THEN content_source ELSE $5 END, updated_at = NOW()
+         WHERE id = $1
+         RETURNING {}",
+        GUEST_POST_RETURNING
+    );
+    let mut post = sqlx::query_as::<_, PostDetail>(&sql)
+        .bind(post_id)
+        .bind(payload.category_id)
+        .bind(payload.title.as_deref())
+        .bind(content)
+        .bind(post_content.as_ref().and_then(|c| c.content_source.as_deref()))
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("비회원 게시글 수정 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    tx.commit().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    post.ip_address = author_ip(&board, None, post.ip_address.as_deref());
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: "게시글이 성공적으로 수정되었습니다.".to_string(),
+        data: Some(post),
+        pagination: None,
+    }))
+}
+
+// 비회원 게시글 삭제 (비밀번호 확인, 소프트 삭제)
+pub async fn delete_guest_post(
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(post_id): Path<String>,
+    Json(payload): Json<GuestPasswordRequest>,
+) -> Result<Json<ApiResponse<()>>, StatusCode> {
+    let post_id = parse_post_id(&post_id)?;
+    let (board, created_at) = verify_guest_post(&state, post_id, &payload.guest_password, client_ip.as_deref()).await?;
+    ensure_change_limit(&state, &board, None, board.delete_comment_limit, REVISION_ENTITY_POST, post_id, created_at).await?;
+
+    sqlx::query("UPDATE posts SET status = 'deleted' WHERE id = $1")
+        .bind(post_id)
+        .execute(&state.pool)
+        .await
+        .map_err(|e| {
+            error!("비회원 게시글 삭제 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: "게시글이 성공적으로 삭제되었습니다.".to_string(),
+        data: Some(()),
+        pagination: None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_guest_credentials() {
+        assert!(is_valid_guest_credentials("손님", "1234"));
+        assert!(is_valid_guest_credentials("  손님  ", "1234"));
+        assert!(!is_valid_guest_credentials("   ", "1234"));
+        assert!(!is_valid_guest_credentials(&"가".repeat(21), "1234"));
+        assert!(!is_valid_guest_credentials("손님", "123"));
+        assert!(!is_valid_guest_credentials("손님", &"a".repeat(65)));
+    }
+}
@@ -0,0 +1,122 @@
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::Response,
+};
+use tracing::warn;
+use uuid::Uuid;
+use crate::{
+    handlers::site::community::{can_download_file, can_read_post},
+    handlers::site::upload::find_file_board,
+    models::file::{ImageProxyQuery, ProcessingStatus},
+    services::{content_type_for_key, render_image, verify_image_signature, ImageCache, ImageParams},
+    utils::file_response::etag_matches,
+    utils::file_sniff::SVG_MIME_TYPE,
+    AppState,
+};
+
+// 공유 캐시 최대 유지 시간 (서명 만료 시각을 넘지 않음)
+const IMAGE_MAX_AGE_SECONDS: i64 = 86400;
+
+// 이미지 크기 변환 (/api/img/:file_id?w=&h=&fit=&fmt=&exp=&sig=)
+// 서명이 맞고 만료되지 않은 파라미터만 변환하고 결과는 디스크 캐시에 저장
+// <img>로 요청되어 로그인 정보가 없으므로 비회원도 볼 수 있는 파일만 제공
+pub async fn get_resized_image(
+    State(state): State<AppState>,
+    Path(file_id): Path<Uuid>,
+    Query(query): Query<ImageProxyQuery>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    let secret = &state.config.image.proxy_secret;
+    if secret.is_empty() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let params = ImageParams::from_query(&query, state.config.image.proxy_max_dimension)
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+    if !verify_image_signature(secret, &params.signature_payload(file_id, query.exp), &query.sig) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let remaining = query.exp - chrono::Utc::now().timestamp();
+    if remaining <= 0 {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let cache_control = format!("public, max-age={}", remaining.min(IMAGE_MAX_AGE_SECONDS));
+
+    let (file_path, stored_name, mime_type, processing_status) = sqlx::query_as::<_, (String, String, String, Option<ProcessingStatus>)>(
+        "SELECT file_path, stored_name, mime_type, processing_status FROM files WHERE id = $1"
+    )
+    .bind(file_id)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    // 바이러스 검사가 끝나지 않은 파일은 변환하지 않음
+    if matches!(processing_status, Some(ProcessingStatus::Pending)) {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    // 게시글/댓글 첨부파일은 공개 중이고 비회원이 열람·다운로드할 수 있는 게시판만, 그 외에는 프로필 이미지만 제공
+    let is_public = match find_file_board(&state, file_id).await? {
+        Some(file_board) => {
+            file_board.visible
+                && can_read_post(&file_board.board, None)
+                && can_download_file(&file_board.board, None)
+                && file_board.board.download_point >= 0
+        }
+        None => sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM users WHERE strpos(profile_image, $1) > 0)")
+            .bind(&stored_name)
+            .fetch_one(&state.pool)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
+    };
+    if !is_public {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    if !mime_type.starts_with("image/") || mime_type == SVG_MIME_TYPE {
+        return Err(StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+    let file_key = state.storage.key_from_location(&file_path).ok_or(StatusCode::NOT_FOUND)?;
+
+    let format = params.output_format(file_key);
+    let cache_key = ImageCache::key(&params.canonical(file_id));
+    let etag = format!("\"{}\"", &cache_key[..32]);
+    if headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|if_none_match| etag_matches(if_none_match, &etag))
+    {
+        return Response::builder()
+            .status(StatusCode::NOT_MODIFIED)
+            .header(header::ETAG, &etag)
+            .header(header::CACHE_CONTROL, &cache_control)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    let (data, cache_status) = match state.image_cache.get(&cache_key, format).await {
+        Some(data) => (data, "HIT"),
+        None => {
+            let original = state.storage.get(file_key).await.map_err(|_| StatusCode::NOT_FOUND)?;
+            let rendered = render_image(original, params, format, state.config.image.webp_quality)
+                .await
+                .map_err(|e| {
+                    warn!("이미지 변환 실패: file_id={}, {}", file_id, e);
+                    StatusCode::UNPROCESSABLE_ENTITY
+                })?;
+            state.image_cache.put(&cache_key, format, &rendered).await;
+            (rendered, "MISS")
+        }
+    };
+
+    Response::builder()
+        .header(header::CONTENT_TYPE, content_type_for_key(&format!("image.{}", format)))
+        .header(header::CONTENT_LENGTH, data.len().to_string())
+        .header(header::ETAG, &etag)
+        .header(header::CACHE_CONTROL, &cache_control)
+        .header(header::X_CONTENT_TYPE_OPTIONS, "nosniff")
+        .header("X-Cache", cache_status)
+        .body(Body::from(data))
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
@@ -1,8 +1,21 @@
+pub mod account;
 pub mod auth;
+pub mod captcha;
 pub mod community;
+pub mod draft;
+pub mod guest_post;
+pub mod notification;
+pub mod oauth;
+pub mod point;
+pub mod realtime;
+pub mod report;
+pub mod revision;
+pub mod search;
 pub mod menu;
 pub mod page;
 pub mod upload;
+pub mod upload_session;
+pub mod image_proxy;
 pub mod calendar;
 pub mod site_info;
 
@@ -0,0 +1,111 @@
+use axum::{
+    extract::{Path, Query, State, Extension},
+    http::StatusCode,
+    response::Json,
+};
+use uuid::Uuid;
+use tracing::error;
+use crate::{
+    models::{Notification, NotificationQuery, UnreadCountResponse},
+    models::response::{ApiResponse, PaginationInfo},
+    services::NotificationService,
+    utils::auth::Claims,
+    AppState,
+};
+
+// 내 알림 목록
+pub async fn get_notifications(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Query(query): Query<NotificationQuery>,
+) -> Result<Json<ApiResponse<Vec<Notification>>>, StatusCode> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let page = query.page.unwrap_or(1).max(1);
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+    let offset = (page - 1) * limit;
+
+    let (notifications, total) = NotificationService::new(state.pool.clone(), state.realtime.clone())
+        .list(claims.sub, query.unread_only.unwrap_or(false), limit as i64, offset as i64)
+        .await
+        .map_err(|e| {
+            error!("알림 목록 조회 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let total_pages = ((total as f64) / (limit as f64)).ceil() as u32;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: "알림 목록을 조회했습니다.".to_string(),
+        data: Some(notifications),
+        pagination: Some(PaginationInfo {
+            page,
+            limit,
+            total: total as u64,
+            total_pages,
+        }),
+    }))
+}
+
+// 읽지 않은 알림 수
+pub async fn get_unread_notification_count(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+) -> Result<Json<ApiResponse<UnreadCountResponse>>, StatusCode> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let count = NotificationService::new(state.pool.clone(), state.realtime.clone())
+        .unread_count(claims.sub)
+        .await
+        .map_err(|e| {
+            error!("읽지 않은 알림 수 조회 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(Json(ApiResponse::success(UnreadCountResponse { count }, "읽지 않은 알림 수를 조회했습니다.")))
+}
+
+// 알림 읽음 처리
+pub async fn mark_notification_read(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Path(notification_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, StatusCode> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let updated = NotificationService::new(state.pool.clone(), state.realtime.clone())
+        .mark_read(claims.sub, notification_id)
+        .await
+        .map_err(|e| {
+            error!("알림 읽음 처리 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    if !updated {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    Ok(Json(ApiResponse::success((), "알림을 읽음 처리했습니다.")))
+}
+
+// 모든 알림 읽음 처리
+pub async fn mark_all_notifications_read(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+) -> Result<Json<ApiResponse<serde_json::Value>>, StatusCode> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let updated = NotificationService::new(state.pool.clone(), state.realtime.clone())
+        .mark_all_read(claims.sub)
+        .await
+        .map_err(|e| {
+            error!("전체 알림 읽음 처리 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        serde_json::json!({ "updated_count": updated }),
+        "모든 알림을 읽음 처리했습니다.",
+    )))
+}
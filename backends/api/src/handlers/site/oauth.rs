@@ -0,0 +1,111 @@
+use axum::{
+    extract::{Path, State, Extension},
+    Json,
+};
+use axum_extra::extract::cookie::CookieJar;
+use crate::{
+    errors::ApiError,
+    handlers::site::auth::issue_auth_response,
+    models::{ApiResponse, AuthResponse},
+    models::{OAuthAuthorizeResponse, OAuthCallbackRequest, SocialAccount},
+    services::{clear_oauth_state_cookie, oauth_state_cookie, OAuthService, OAUTH_STATE_COOKIE},
+    utils::auth::Claims,
+    utils::client_ip::ClientIp,
+    AppState,
+};
+
+fn oauth_service(state: &AppState) -> OAuthService {
+    OAuthService::new(state.pool.clone(), state.redis.clone(), state.config.clone())
+}
+
+// 콜백 요청의 state 쿠키 값
+fn state_cookie_value(jar: &CookieJar) -> Option<&str> {
+    jar.get(OAUTH_STATE_COOKIE).map(|cookie| cookie.value())
+}
+
+// 소셜 로그인 인증 페이지 주소 (state 쿠키 발급)
+pub async fn get_oauth_authorize_url(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    Path(provider): Path<String>,
+) -> Result<(CookieJar, Json<ApiResponse<OAuthAuthorizeResponse>>), ApiError> {
+    let response = oauth_service(&state).authorize_url(&provider, None).await?;
+    let jar = jar.add(oauth_state_cookie(&response.state, state.config.is_production()));
+
+    Ok((jar, Json(ApiResponse::success(response, "인증 페이지 주소를 생성했습니다."))))
+}
+
+// 소셜 로그인 콜백 (인증 코드로 로그인 또는 가입 후 토큰 발급)
+pub async fn oauth_login(
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    jar: CookieJar,
+    Path(provider): Path<String>,
+    Json(payload): Json<OAuthCallbackRequest>,
+) -> Result<(CookieJar, Json<ApiResponse<AuthResponse>>), ApiError> {
+    let user = oauth_service(&state)
+        .login(&provider, &payload.code, &payload.state, state_cookie_value(&jar))
+        .await?;
+
+    let service_type = payload.service_type.unwrap_or_else(|| "site".to_string());
+    let auth_response = issue_auth_response(&state, user, &service_type, client_ip.as_deref()).await?;
+
+    Ok((jar.remove(clear_oauth_state_cookie()), Json(ApiResponse::success(auth_response, "로그인 성공"))))
+}
+
+// 내 소셜 계정 목록
+pub async fn get_social_accounts(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+) -> Result<Json<ApiResponse<Vec<SocialAccount>>>, ApiError> {
+    let claims = claims.ok_or_else(|| ApiError::Authentication("로그인이 필요합니다.".to_string()))?;
+
+    let accounts = oauth_service(&state).list_accounts(claims.sub).await?;
+
+    Ok(Json(ApiResponse::success(accounts, "연결된 소셜 계정을 조회했습니다.")))
+}
+
+// 소셜 계정 연결용 인증 페이지 주소 (state 쿠키 발급)
+pub async fn get_social_link_authorize_url(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    jar: CookieJar,
+    Path(provider): Path<String>,
+) -> Result<(CookieJar, Json<ApiResponse<OAuthAuthorizeResponse>>), ApiError> {
+    let claims = claims.ok_or_else(|| ApiError::Authentication("로그인이 필요합니다.".to_string()))?;
+
+    let response = oauth_service(&state).authorize_url(&provider, Some(claims.sub)).await?;
+    let jar = jar.add(oauth_state_cookie(&response.state, state.config.is_production()));
+
+    Ok((jar, Json(ApiResponse::success(response, "인증 페이지 주소를 생성했습니다."))))
+}
+
+// 소셜 계정 연결 콜백
+pub async fn link_social_account(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    jar: CookieJar,
+    Path(provider): Path<String>,
+    Json(payload): Json<OAuthCallbackRequest>,
+) -> Result<(CookieJar, Json<ApiResponse<SocialAccount>>), ApiError> {
+    let claims = claims.ok_or_else(|| ApiError::Authentication("로그인이 필요합니다.".to_string()))?;
+
+    let account = oauth_service(&state)
+        .link(claims.sub, &provider, &payload.code, &payload.state, state_cookie_value(&jar))
+        .await?;
+
+    Ok((jar.remove(clear_oauth_state_cookie()), Json(ApiResponse::success(account, "소셜 계정이 연결되었습니다."))))
+}
+
+// 소셜 계정 연결 해제
+pub async fn unlink_social_account(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Path(provider): Path<String>,
+) -> Result<Json<ApiResponse<()>>, ApiError> {
+    let claims = claims.ok_or_else(|| ApiError::Authentication("로그인이 필요합니다.".to_string()))?;
+
+    oauth_service(&state).unlink(claims.sub, &provider).await?;
+
+    Ok(Json(ApiResponse::success((), "소셜 계정 연결이 해제되었습니다.")))
+}
@@ -0,0 +1,53 @@
+use axum::{
+    extract::{Query, State, Extension},
+    http::StatusCode,
+    response::Json,
+};
+use tracing::error;
+use crate::{
+    models::{PointHistoryQuery, PointHistoryResponse},
+    models::response::{ApiResponse, PaginationInfo},
+    services::PointService,
+    utils::auth::Claims,
+    AppState,
+};
+
+// 내 포인트 잔액 및 거래 내역
+pub async fn get_my_point_history(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Query(query): Query<PointHistoryQuery>,
+) -> Result<Json<ApiResponse<PointHistoryResponse>>, StatusCode> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let page = query.page.unwrap_or(1).max(1);
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+    let offset = (page - 1) * limit;
+
+    let service = PointService::new(state.pool.clone());
+    let balance = service.balance(claims.sub).await.map_err(|e| {
+        error!("포인트 잔액 조회 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    let (transactions, total) = service
+        .history(claims.sub, limit as i64, offset as i64)
+        .await
+        .map_err(|e| {
+            error!("포인트 내역 조회 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let total_pages = ((total as f64) / (limit as f64)).ceil() as u32;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: "포인트 내역을 조회했습니다.".to_string(),
+        data: Some(PointHistoryResponse { balance, transactions }),
+        pagination: Some(PaginationInfo {
+            page,
+            limit,
+            total: total as u64,
+            total_pages,
+        }),
+    }))
+}
@@ -0,0 +1,121 @@
+use std::time::Duration;
+use axum::{
+    extract::{Query, State, Extension},
+    http::{HeaderMap, StatusCode},
+    response::{sse::{Event, KeepAlive, Sse}, IntoResponse},
+};
+use futures_util::StreamExt;
+use serde::Deserialize;
+use tracing::{error, warn};
+use uuid::Uuid;
+use crate::{
+    handlers::site::community::{can_read_post, find_visible_post_board},
+    middleware::is_token_revoked,
+    services::{RealtimeEvent, RealtimeService, BROADCAST_CHANNEL},
+    utils::auth::{verify_token, Claims},
+    AppState,
+};
+
+// 연결 중인 스트림의 토큰 폐기/만료 재확인 주기
+const TOKEN_RECHECK_SECONDS: u64 = 60;
+
+// 이벤트 스트림 구독 옵션
+#[derive(Debug, Deserialize)]
+pub struct EventStreamQuery {
+    pub post_id: Option<Uuid>, // 보고 있는 게시글의 새 댓글 구독
+    pub token: Option<String>, // EventSource는 헤더를 보낼 수 없으므로 쿼리로도 토큰 허용
+}
+
+// 실시간 이벤트 스트림 (SSE)
+// 내 알림/답글, 공지, 보고 있는 게시글의 새 댓글을 Redis pub/sub으로 받아 전달
+pub async fn stream_events(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    headers: HeaderMap,
+    Query(query): Query<EventStreamQuery>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let (token, claims) = match claims {
+        Some(claims) => {
+            let token = headers
+                .get("Authorization")
+                .and_then(|header| header.to_str().ok())
+                .and_then(|header| header.strip_prefix("Bearer "))
+                .ok_or(StatusCode::UNAUTHORIZED)?
+                .to_string();
+            (token, claims)
+        }
+        None => {
+            let token = query.token.clone().ok_or(StatusCode::UNAUTHORIZED)?;
+            let claims = verify_token(&token, &state.config).map_err(|_| StatusCode::UNAUTHORIZED)?;
+            if is_token_revoked(&state, &token, &claims).await? {
+                return Err(StatusCode::UNAUTHORIZED);
+            }
+            (token, claims)
+        }
+    };
+
+    // 게시글 새 댓글 구독은 공개 중인 게시글의 열람 권한이 있을 때만 허용
+    if let Some(post_id) = query.post_id {
+        let board = find_visible_post_board(&state, post_id).await?;
+        if !can_read_post(&board, Some(&claims.role)) {
+            return Err(StatusCode::FORBIDDEN);
+        }
+    }
+
+    let redis_conn = state.redis.get_async_connection().await.map_err(|e| {
+        error!("실시간 이벤트 Redis 연결 실패: {}", e);
+        StatusCode::SERVICE_UNAVAILABLE
+    })?;
+    let mut pubsub = redis_conn.into_pubsub();
+
+    let mut channels = vec![RealtimeService::user_channel(claims.sub), BROADCAST_CHANNEL.to_string()];
+    if let Some(post_id) = query.post_id {
+        channels.push(RealtimeService::post_channel(post_id));
+    }
+    for channel in channels {
+        pubsub.subscribe(&channel).await.map_err(|e| {
+            error!("실시간 이벤트 채널 구독 실패: channel={}, error={}", channel, e);
+            StatusCode::SERVICE_UNAVAILABLE
+        })?;
+    }
+
+    let stream = pubsub
+        .into_on_message()
+        .filter_map(|msg| async move {
+            let payload: String = msg.get_payload().ok()?;
+            let event: RealtimeEvent = serde_json::from_str(&payload).ok()?;
+            Some(Ok::<_, std::convert::Infallible>(
+                Event::default().event(event.event).data(event.data.to_string()),
+            ))
+        })
+        .take_until(wait_until_token_invalid(state, token, claims));
+
+    // nginx 프록시 버퍼링 비활성화
+    Ok((
+        [("X-Accel-Buffering", "no")],
+        Sse::new(stream).keep_alive(KeepAlive::default()),
+    ))
+}
+
+// 토큰이 만료되거나 폐기(로그아웃/비밀번호 변경/정지)되면 완료되어 스트림을 닫음
+async fn wait_until_token_invalid(state: AppState, token: String, claims: Claims) {
+    loop {
+        let remaining = claims.exp - chrono::Utc::now().timestamp();
+        if remaining <= 0 {
+            return;
+        }
+        tokio::time::sleep(Duration::from_secs((remaining as u64).min(TOKEN_RECHECK_SECONDS))).await;
+
+        if claims.exp <= chrono::Utc::now().timestamp() {
+            return;
+        }
+        match is_token_revoked(&state, &token, &claims).await {
+            Ok(false) => {}
+            Ok(true) => return,
+            Err(_) => {
+                warn!("실시간 이벤트 토큰 재확인 실패, 연결 종료: user_id={}", claims.sub);
+                return;
+            }
+        }
+    }
+}
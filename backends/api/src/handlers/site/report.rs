@@ -0,0 +1,27 @@
+use axum::{
+    extract::{State, Extension},
+    Json,
+};
+use crate::{
+    errors::ApiError,
+    models::ApiResponse,
+    models::{CreateReportRequest, Report},
+    services::ReportService,
+    utils::auth::Claims,
+    AppState,
+};
+
+// 게시글/댓글 신고
+pub async fn create_report(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Json(payload): Json<CreateReportRequest>,
+) -> Result<Json<ApiResponse<Report>>, ApiError> {
+    let claims = claims.ok_or_else(|| ApiError::Authentication("로그인이 필요합니다.".to_string()))?;
+
+    let report = ReportService::new(state.pool.clone())
+        .create_report(claims.sub, payload, state.config.report_auto_hide_threshold)
+        .await?;
+
+    Ok(Json(ApiResponse::success(report, "신고가 접수되었습니다.")))
+}
@@ -0,0 +1,102 @@
+use axum::{
+    extract::{Extension, Path, State},
+    Json,
+};
+use uuid::Uuid;
+use crate::{
+    errors::ApiError,
+    models::{ApiResponse, ContentRevision, RevisionDiff, REVISION_ENTITY_COMMENT, REVISION_ENTITY_POST},
+    services::RevisionService,
+    utils::auth::Claims,
+    utils::url_id::resolve_post_uuid,
+    utils::uuid_compression::decompress_base62_to_uuid,
+    AppState,
+};
+
+// 게시글 ID (UUID, 압축된 ID, URL ID 모두 허용)
+async fn resolve_post_id(state: &AppState, post_id: &str) -> Result<Uuid, ApiError> {
+    if let Ok(uuid) = Uuid::parse_str(post_id) {
+        return Ok(uuid);
+    }
+    if post_id.len() == 22 && post_id.chars().all(|c| c.is_alphanumeric()) {
+        return decompress_base62_to_uuid(post_id)
+            .map_err(|_| ApiError::BadRequest("잘못된 게시글 ID입니다.".to_string()));
+    }
+    resolve_post_uuid(&state.pool, post_id).await
+}
+
+// 작성자 또는 관리자만 수정 이력 열람 가능
+async fn authorize_revision_access(
+    service: &RevisionService,
+    claims: Option<Claims>,
+    entity_type: &str,
+    entity_id: Uuid,
+) -> Result<(), ApiError> {
+    let claims = claims.ok_or_else(|| ApiError::Authentication("로그인이 필요합니다.".to_string()))?;
+    let author_id = service
+        .entity_author(entity_type, entity_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("수정 이력 대상을 찾을 수 없습니다.".to_string()))?;
+    if author_id != Some(claims.sub) && claims.role != "admin" {
+        return Err(ApiError::Forbidden("작성자만 수정 이력을 볼 수 있습니다.".to_string()));
+    }
+    Ok(())
+}
+
+// 게시글 수정 이력 목록
+pub async fn get_post_revisions(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Path(post_id): Path<String>,
+) -> Result<Json<ApiResponse<Vec<ContentRevision>>>, ApiError> {
+    let post_id = resolve_post_id(&state, &post_id).await?;
+    let service = RevisionService::new(state.pool.clone());
+    authorize_revision_access(&service, claims, REVISION_ENTITY_POST, post_id).await?;
+
+    let revisions = service.list_revisions(REVISION_ENTITY_POST, post_id).await?;
+
+    Ok(Json(ApiResponse::success(revisions, "게시글 수정 이력을 조회했습니다.")))
+}
+
+// 게시글 수정 이력 상세 (다음 버전과의 차이)
+pub async fn get_post_revision(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Path((post_id, revision_id)): Path<(String, Uuid)>,
+) -> Result<Json<ApiResponse<RevisionDiff>>, ApiError> {
+    let post_id = resolve_post_id(&state, &post_id).await?;
+    let service = RevisionService::new(state.pool.clone());
+    authorize_revision_access(&service, claims, REVISION_ENTITY_POST, post_id).await?;
+
+    let diff = service.revision_diff(REVISION_ENTITY_POST, post_id, revision_id).await?;
+
+    Ok(Json(ApiResponse::success(diff, "게시글 수정 이력을 조회했습니다.")))
+}
+
+// 댓글 수정 이력 목록
+pub async fn get_comment_revisions(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Path(comment_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ContentRevision>>>, ApiError> {
+    let service = RevisionService::new(state.pool.clone());
+    authorize_revision_access(&service, claims, REVISION_ENTITY_COMMENT, comment_id).await?;
+
+    let revisions = service.list_revisions(REVISION_ENTITY_COMMENT, comment_id).await?;
+
+    Ok(Json(ApiResponse::success(revisions, "댓글 수정 이력을 조회했습니다.")))
+}
+
+// 댓글 수정 이력 상세 (다음 버전과의 차이)
+pub async fn get_comment_revision(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Path((comment_id, revision_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<RevisionDiff>>, ApiError> {
+    let service = RevisionService::new(state.pool.clone());
+    authorize_revision_access(&service, claims, REVISION_ENTITY_COMMENT, comment_id).await?;
+
+    let diff = service.revision_diff(REVISION_ENTITY_COMMENT, comment_id, revision_id).await?;
+
+    Ok(Json(ApiResponse::success(diff, "댓글 수정 이력을 조회했습니다.")))
+}
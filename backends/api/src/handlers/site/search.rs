@@ -0,0 +1,43 @@
+use axum::{
+    extract::{Query, State, Extension},
+    Json,
+};
+use crate::{
+    errors::ApiError,
+    models::{ApiResponse, PaginationInfo},
+    models::{SearchQuery, SearchResult},
+    services::SearchService,
+    utils::auth::Claims,
+    AppState,
+};
+
+// 게시글 통합 검색 (제목/본문/작성자/댓글)
+pub async fn search_posts(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Query(query): Query<SearchQuery>,
+) -> Result<Json<ApiResponse<Vec<SearchResult>>>, ApiError> {
+    let user_role = claims.as_ref().map(|c| c.role.as_str());
+
+    let page = query.page.unwrap_or(1).max(1);
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+    let offset = (page - 1) * limit;
+
+    let (results, total) = SearchService::new(state.pool.clone())
+        .search_posts(&query, user_role, limit as i64, offset as i64)
+        .await?;
+
+    let total_pages = ((total as f64) / (limit as f64)).ceil() as u32;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: "검색 결과를 조회했습니다.".to_string(),
+        data: Some(results),
+        pagination: Some(PaginationInfo {
+            page,
+            limit,
+            total: total as u64,
+            total_pages,
+        }),
+    }))
+}
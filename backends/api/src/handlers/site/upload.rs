@@ -1,6 +1,6 @@
 use axum::{
     extract::{Multipart, State, Extension, Path as AxumPath},
-    http::StatusCode,
+    http::{header, HeaderMap, StatusCode},
     response::Json,
 };
 use std::path::Path;
@@ -8,12 +8,30 @@ use uuid::Uuid;
 use chrono::Utc;
 use crate::{
     AppState,
+    errors::ApiError,
+    handlers::site::community::{can_download_file, can_read_post, find_board},
+    models::admin::board::Board,
     models::response::ApiResponse,
-    models::file::{File, FileType, FileStatus, ProcessingStatus, FileEntity, EntityType, FilePurpose, FileInfo},
+    models::file::{File, FileType, FileStatus, ProcessingStatus, FileEntity, EntityType, FilePurpose, FileInfo, StorageUsage},
+    models::POINT_TYPE_FILE_DOWNLOAD,
     utils::auth::Claims,
-    services::thumbnail::ThumbnailService,
+    services::thumbnail::{thumbnail_key, ThumbnailService},
+    services::{
+        check_storage_quota, count_upload, create_virus_scanner, enqueue_media_job, latest_media_job, quarantine_key, reserve_upload,
+        storage_usage, upload_url, validate_key, verify_storage_quota,
+        PointService, ScanResult, JOB_PROCESS_FILE,
+    },
+    utils::file_response::{etag_matches, file_etag, http_date, not_modified_since, parse_range, ByteRange},
+    utils::file_sniff::{content_mime_type, has_svg_extension, is_active_content},
+    utils::image_meta::strip_metadata,
 };
 
+// 권한 확인이 필요한 파일이므로 공유 캐시에 저장하지 않고 매번 재검증
+const DOWNLOAD_CACHE_CONTROL: &str = "private, no-cache";
+
+// /uploads/* 공개 파일 (파일명에 UUID/타임스탬프가 들어가므로 내용이 바뀌지 않음)
+const UPLOAD_CACHE_CONTROL: &str = "public, max-age=86400";
+
 // 파일 업로드 응답
 #[derive(Debug, serde::Serialize)]
 pub struct UploadResponse {
@@ -30,13 +48,13 @@ pub async fn upload_post_file(
     State(state): State<AppState>,
     Extension(claims): Extension<Option<Claims>>,
     mut multipart: Multipart,
-) -> Result<Json<ApiResponse<UploadResponse>>, StatusCode> {
+) -> Result<Json<ApiResponse<UploadResponse>>, ApiError> {
     let mut filename = String::new();
-    let mut file_path = String::new();
+    let mut file_key = String::new();
     let mut size = 0u64;
     let mut mime_type = String::new();
     let mut original_name = String::new();
-    let mut thumbnail_url = None;
+    let mut draft_id: Option<Uuid> = None;
 
     // 인증 확인
     let user_id = claims
@@ -68,7 +86,7 @@ pub async fn upload_post_file(
             // 파일 타입 검증
             if !is_allowed_file_type(&extension) {
                 eprintln!("❌ 허용되지 않는 파일 타입: extension={}", extension);
-                return Err(StatusCode::BAD_REQUEST);
+                return Err(StatusCode::BAD_REQUEST.into());
             }
             
             eprintln!("✅ 파일 타입 검증 통과: extension={}", extension);
@@ -102,7 +120,7 @@ pub async fn upload_post_file(
                         // 누적 크기 확인
                         if file_data.len() + chunk.len() > MAX_SIZE as usize {
                             eprintln!("❌ 파일 크기 초과: {} bytes > {} bytes", file_data.len() + chunk.len(), MAX_SIZE);
-                            return Err(StatusCode::PAYLOAD_TOO_LARGE);
+                            return Err(StatusCode::PAYLOAD_TOO_LARGE.into());
                         }
                         
                         // 청크 데이터를 매우 작은 단위로 나누어 처리
@@ -145,7 +163,7 @@ pub async fn upload_post_file(
                             eprintln!("⚠️ 스트림 오류 발생했지만 {} bytes 데이터 수신됨, 계속 진행", file_data.len());
                             break;
                         } else {
-                            return Err(StatusCode::BAD_REQUEST);
+                            return Err(StatusCode::BAD_REQUEST.into());
                         }
                     }
                 }
@@ -153,6 +171,18 @@ pub async fn upload_post_file(
             
             eprintln!("✅ 파일 크기 검증 통과: {} bytes", file_data.len());
 
+            // 확장자와 실제 내용(매직 바이트)이 일치하는지 확인하고 MIME 타입은 내용 기준으로 결정
+            mime_type = match content_mime_type(&extension, &file_data) {
+                Some(mime) => mime.to_string(),
+                None => {
+                    eprintln!("❌ 파일 내용이 확장자와 일치하지 않음: extension={}", extension);
+                    return Err(StatusCode::BAD_REQUEST.into());
+                }
+            };
+
+            // 저장 공간 / 일일 업로드 한도 확인
+            reserve_upload(&state.pool, &state.config.upload_limit, user_id, file_data.len() as i64).await?;
+
             // 파일명 생성 - UUID_timestamp_originalname.ext 형태
             let timestamp = Utc::now().timestamp();
             let uuid_part = Uuid::new_v4().to_string();
@@ -161,145 +191,44 @@ pub async fn upload_post_file(
             
             // 저장 경로 결정
             let subfolder = if is_image_file(&extension) { "images" } else { "documents" };
-            file_path = format!("static/uploads/posts/{}/{}", subfolder, filename);
+            file_key = format!("posts/{}/{}", subfolder, filename);
+            let file_path = state.storage.location(&file_key);
             eprintln!("📁 저장 경로: {}", file_path);
-            
-            // 디렉토리 생성
-            eprintln!("📁 디렉토리 생성 시작: static/uploads/posts/{}", subfolder);
-            std::fs::create_dir_all(format!("static/uploads/posts/{}", subfolder))
-                .map_err(|e| {
-                    eprintln!("❌ 디렉토리 생성 실패: {:?}", e);
-                    StatusCode::INTERNAL_SERVER_ERROR
-                })?;
-            eprintln!("✅ 디렉토리 생성 완료");
 
-            // 파일 저장
+            // 위치 정보 등 EXIF는 작업 큐를 기다리지 않고 저장 전에 제거
+            let file_data = strip_upload_metadata(&state, &mime_type, file_data);
+            size = file_data.len() as u64;
+
+            // 파일 저장 (검사가 끝날 때까지 격리 위치에 보관)
             eprintln!("📁 파일 저장 시작...");
-            std::fs::write(&file_path, &file_data)
+            state.storage.put(&quarantine_key(&file_key), file_data, &mime_type)
+                .await
                 .map_err(|e| {
                     eprintln!("❌ 파일 저장 실패: {:?}", e);
                     StatusCode::INTERNAL_SERVER_ERROR
                 })?;
             eprintln!("✅ 파일 저장 완료: {}", file_path);
-
-            size = file_data.len() as u64;
-            mime_type = get_mime_type(&extension);
-
-            // 이미지 파일인 경우 썸네일은 백그라운드에서 생성되므로 즉시 반환하지 않음
-            if is_image_file(&extension) {
-                thumbnail_url = None;
-            }
+        } else if field_name == "draft_id" {
+            // 임시저장 글에 첨부하는 경우
+            let value = field.text().await.map_err(|_| StatusCode::BAD_REQUEST)?;
+            draft_id = Some(Uuid::parse_str(value.trim()).map_err(|_| StatusCode::BAD_REQUEST)?);
         }
     }
 
     if filename.is_empty() {
-        return Err(StatusCode::BAD_REQUEST);
+        return Err(StatusCode::BAD_REQUEST.into());
     }
 
-    let url = format!("/uploads/posts/{}/{}", 
-        if is_image_file(&Path::new(&filename).extension().and_then(|ext| ext.to_str()).unwrap_or("")) { "images" } else { "documents" },
-        filename
-    );
-
-    // 파일 타입 결정
-    let file_type = determine_file_type(&mime_type);
-    
-    // files 테이블에 저장 (이미지인 경우 처리 중 상태로 저장)
-    let file_id = Uuid::new_v4();
-    let processing_status = if is_image_file(&Path::new(&filename).extension().and_then(|ext| ext.to_str()).unwrap_or("")) {
-        ProcessingStatus::Processing // 이미지인 경우 처리 중 상태로 저장
-    } else {
-        ProcessingStatus::Completed // 이미지가 아닌 경우 완료 상태로 저장
-    };
-    
-    eprintln!("📁 DB 저장 시작: file_id={}, user_id={}", file_id, user_id);
-    let file_record = sqlx::query!(
-        r#"
-        INSERT INTO files (id, user_id, original_name, stored_name, file_path, file_size, mime_type, file_type, processing_status)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-        RETURNING id
-        "#,
-        file_id,
-        user_id,
-        original_name,
-        filename,
-        file_path,
-        size as i64,
-        mime_type,
-        file_type as FileType,
-        processing_status as ProcessingStatus
-    )
-    .fetch_one(&state.pool)
-    .await
-    .map_err(|e| {
-        eprintln!("❌ DB 저장 실패: {:?}", e);
-        StatusCode::INTERNAL_SERVER_ERROR
-    })?;
-    eprintln!("✅ DB 저장 완료: file_id={}", file_record.id);
-    
-    // 이미지 파일인 경우 백그라운드에서 썸네일 생성
-    if is_image_file(&Path::new(&filename).extension().and_then(|ext| ext.to_str()).unwrap_or("")) {
-        let state_clone = state.clone();
-        let file_path_clone = file_path.clone();
-        let file_id_clone = file_record.id;
-        
-        // 백그라운드 태스크로 썸네일 생성
-        tokio::spawn(async move {
-            eprintln!("🔄 백그라운드 썸네일 생성 시작: {}", file_path_clone);
-            
-            let thumbnail_service = ThumbnailService::new();
-            match thumbnail_service.create_thumbnails(&file_path_clone).await {
-                Ok(_) => {
-                    eprintln!("✅ 백그라운드 썸네일 생성 완료: {}", file_path_clone);
-                    
-                    // DB 상태를 완료로 업데이트
-                    let _ = sqlx::query!(
-                        r#"
-                        UPDATE files 
-                        SET processing_status = $1 
-                        WHERE id = $2
-                        "#,
-                        ProcessingStatus::Completed as ProcessingStatus,
-                        file_id_clone
-                    )
-                    .execute(&state_clone.pool)
-                    .await;
-                    
-                    eprintln!("✅ 파일 처리 상태 업데이트 완료: {}", file_id_clone);
-                }
-                Err(e) => {
-                    eprintln!("❌ 백그라운드 썸네일 생성 실패: {:?}", e);
-                    
-                    // 실패해도 파일은 유지하되 상태 업데이트
-                    let _ = sqlx::query!(
-                        r#"
-                        UPDATE files 
-                        SET processing_status = $1 
-                        WHERE id = $2
-                        "#,
-                        ProcessingStatus::Failed as ProcessingStatus,
-                        file_id_clone
-                    )
-                    .execute(&state_clone.pool)
-                    .await;
-                }
-            }
-        });
-        
-        thumbnail_url = None; // 썸네일은 백그라운드에서 생성되므로 즉시 반환하지 않음
+    // 임시저장 글 소유자 확인
+    if let Some(draft_id) = draft_id {
+        if let Err(status) = crate::handlers::site::draft::find_my_draft(&state, draft_id, user_id).await {
+            let _ = state.storage.delete(&quarantine_key(&file_key)).await;
+            return Err(status.into());
+        }
     }
 
-    // 썸네일 URL은 위에서 이미 생성했으므로 그대로 사용
-
-    let file_info = FileInfo {
-        id: file_record.id,
-        original_name: original_name.clone(),
-        file_path: url.clone(),
-        file_size: size as i64,
-        mime_type: mime_type.clone(),
-        file_type,
-        url: url.clone(),
-    };
+    let file_info = register_post_file(&state, user_id, &original_name, &filename, &file_key, size, &mime_type, draft_id, None).await?;
+    let url = file_info.url.clone();
 
     Ok(Json(ApiResponse {
         success: true,
@@ -310,18 +239,19 @@ pub async fn upload_post_file(
             size,
             mime_type: mime_type.clone(),
             file_info,
-            thumbnail_url,
+            thumbnail_url: None, // 썸네일은 백그라운드에서 생성되므로 즉시 반환하지 않음
         }),
         pagination: None,
     }))
 }
 
 // 청크 업로드 엔드포인트 (실시간 합치기 방식)
+// 기존 클라이언트 호환용 (순서대로만 전송 가능, 새 클라이언트는 업로드 세션 API 사용)
 pub async fn upload_post_file_chunk(
     State(state): State<AppState>,
     Extension(claims): Extension<Option<Claims>>,
     mut multipart: Multipart,
-) -> Result<Json<ApiResponse<UploadResponse>>, StatusCode> {
+) -> Result<Json<ApiResponse<UploadResponse>>, ApiError> {
     let mut chunk_index = 0;
     let mut total_chunks = 0;
     let mut temp_file_id = String::new();
@@ -371,8 +301,19 @@ pub async fn upload_post_file_chunk(
     eprintln!("📁 원본 파일명: {}", original_name);
     eprintln!("📁 원본 크기: {} bytes", original_size);
 
-    // 첫 번째 청크인 경우 파일 정보 초기화
+    // 임시 파일 ID는 디렉터리 이름으로 쓰이므로 안전한 문자만 허용
+    if temp_file_id.is_empty() || !temp_file_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(StatusCode::BAD_REQUEST.into());
+    }
+    // 청크는 각 서버의 로컬 임시 디렉터리에서 합친 뒤 저장소로 옮김
+    let temp_dir = format!("{}/{}", state.config.storage.temp_dir, temp_file_id);
+    let temp_info_path = format!("{}/file_info.json", temp_dir);
+    let temp_data_path = format!("{}/data", temp_dir);
+
+    // 첫 번째 청크인 경우 한도 확인 후 파일 정보 초기화
     if chunk_index == 0 {
+        reserve_upload(&state.pool, &state.config.upload_limit, user_id, original_size as i64).await?;
+
         // 파일명 생성
         let timestamp = Utc::now().timestamp();
         let uuid_part = Uuid::new_v4().to_string();
@@ -389,22 +330,17 @@ pub async fn upload_post_file_chunk(
         
         // 저장 경로 결정
         let subfolder = if is_image_file(&extension) { "images" } else { "documents" };
-        let file_path = format!("static/uploads/posts/{}/{}", subfolder, filename);
-        
-        // 디렉토리 생성
-        std::fs::create_dir_all(format!("static/uploads/posts/{}", subfolder))
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        let file_key = format!("posts/{}/{}", subfolder, filename);
         
         // 파일 정보를 임시 디렉토리에 저장
-        let temp_info_path = format!("static/uploads/temp/{}/file_info.json", temp_file_id);
-        std::fs::create_dir_all(format!("static/uploads/temp/{}", temp_file_id))
+        std::fs::create_dir_all(&temp_dir)
             .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
         
         let file_info = serde_json::json!({
             "filename": filename,
             "extension": extension,
             "subfolder": subfolder,
-            "file_path": file_path,
+            "file_key": file_key,
             "original_name": original_name,
             "original_size": original_size,
             "user_id": user_id.to_string()
@@ -417,17 +353,15 @@ pub async fn upload_post_file_chunk(
     }
 
     // 파일 정보 읽기
-    let temp_info_path = format!("static/uploads/temp/{}/file_info.json", temp_file_id);
     let file_info_content = std::fs::read_to_string(&temp_info_path)
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     let file_info: serde_json::Value = serde_json::from_str(&file_info_content)
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     
     let filename = file_info["filename"].as_str().unwrap().to_string();
-    let file_path = file_info["file_path"].as_str().unwrap().to_string();
+    let file_key = file_info["file_key"].as_str().ok_or(StatusCode::BAD_REQUEST)?.to_string();
     let original_name = file_info["original_name"].as_str().unwrap().to_string();
     let extension = file_info["extension"].as_str().unwrap().to_string();
-    let subfolder = file_info["subfolder"].as_str().unwrap().to_string();
     let user_id = Uuid::parse_str(file_info["user_id"].as_str().unwrap())
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
@@ -435,7 +369,7 @@ pub async fn upload_post_file_chunk(
     let file = std::fs::OpenOptions::new()
         .create(true)
         .append(true)
-        .open(&file_path)
+        .open(&temp_data_path)
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     
     use std::io::Write;
@@ -449,106 +383,42 @@ pub async fn upload_post_file_chunk(
     if chunk_index + 1 == total_chunks {
         eprintln!("📁 모든 청크 수신 완료, 최종 처리 시작");
         
-        // 임시 파일 정보 삭제
-        let _ = std::fs::remove_file(&temp_info_path);
-        let _ = std::fs::remove_dir(format!("static/uploads/temp/{}", temp_file_id));
-        
-        let url = format!("/uploads/posts/{}/{}", subfolder, filename);
-        let mime_type = get_mime_type(&extension);
-        let file_type = determine_file_type(&mime_type);
-        
-        // 파일 크기 확인
-        let file_size = std::fs::metadata(&file_path)
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-            .len();
-        
-        // DB에 저장 (썸네일 없이 먼저 저장)
-        let file_id = Uuid::new_v4();
-        let processing_status = if is_image_file(&extension) {
-            ProcessingStatus::Processing // 이미지인 경우 처리 중 상태로 저장
-        } else {
-            ProcessingStatus::Completed // 이미지가 아닌 경우 완료 상태로 저장
-        };
-        
-        let file_record = sqlx::query!(
-            r#"
-            INSERT INTO files (id, user_id, original_name, stored_name, file_path, file_size, mime_type, file_type, processing_status)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-            RETURNING id
-            "#,
-            file_id,
-            user_id,
-            original_name,
-            filename,
-            file_path,
-            file_size as i64,
-            mime_type,
-            file_type as FileType,
-            processing_status as ProcessingStatus
-        )
-        .fetch_one(&state.pool)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-        
-        let file_info = FileInfo {
-            id: file_record.id,
-            original_name: original_name.to_string(),
-            file_path: url.clone(),
-            file_size: file_size as i64,
-            mime_type: mime_type.clone(),
-            file_type,
-            url: url.clone(),
+        let url = upload_url(&file_key);
+
+        // 합친 파일의 앞부분으로 내용 확인 (확장자와 다르면 임시 파일 삭제 후 거부)
+        let mime_type = match read_file_head(&temp_data_path).map(|head| content_mime_type(&extension, &head)) {
+            Ok(Some(mime)) => mime.to_string(),
+            Ok(None) => {
+                eprintln!("❌ 파일 내용이 확장자와 일치하지 않음: extension={}", extension);
+                let _ = std::fs::remove_dir_all(&temp_dir);
+                return Err(StatusCode::BAD_REQUEST.into());
+            }
+            Err(_) => return Err(StatusCode::INTERNAL_SERVER_ERROR.into()),
         };
-        
-        // 이미지인 경우 백그라운드에서 썸네일 생성 (URL은 즉시 반환하지 않음)
-        if is_image_file(&extension) {
-            let state_clone = state.clone();
-            let file_path_clone = file_path.clone();
-            let file_id_clone = file_record.id;
-            
-            // 백그라운드 태스크로 썸네일 생성
-            tokio::spawn(async move {
-                eprintln!("🔄 백그라운드 썸네일 생성 시작: {}", file_path_clone);
-                
-                let thumbnail_service = ThumbnailService::new();
-                match thumbnail_service.create_thumbnails(&file_path_clone).await {
-                    Ok(_) => {
-                        eprintln!("✅ 백그라운드 썸네일 생성 완료: {}", file_path_clone);
-                        
-                        // DB 상태를 완료로 업데이트
-                        let _ = sqlx::query!(
-                            r#"
-                            UPDATE files 
-                            SET processing_status = $1 
-                            WHERE id = $2
-                            "#,
-                            ProcessingStatus::Completed as ProcessingStatus,
-                            file_id_clone
-                        )
-                        .execute(&state_clone.pool)
-                        .await;
-                        
-                        eprintln!("✅ 파일 처리 상태 업데이트 완료: {}", file_id_clone);
-                    }
-                    Err(e) => {
-                        eprintln!("❌ 백그라운드 썸네일 생성 실패: {:?}", e);
-                        
-                        // 실패해도 파일은 유지하되 상태 업데이트
-                        let _ = sqlx::query!(
-                            r#"
-                            UPDATE files 
-                            SET processing_status = $1 
-                            WHERE id = $2
-                            "#,
-                            ProcessingStatus::Failed as ProcessingStatus,
-                            file_id_clone
-                        )
-                        .execute(&state_clone.pool)
-                        .await;
-                    }
-                }
-            });
+
+        // 위치 정보 등 EXIF는 저장 전에 제거
+        let file_size = strip_upload_file_metadata(&state, &mime_type, Path::new(&temp_data_path))
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+        // 첫 청크에서는 클라이언트가 보낸 크기로만 확인했으므로 실제로 합친 크기로 다시 확인
+        if let Err(e) = check_storage_quota(&state.pool, &state.config.upload_limit, user_id, file_size as i64).await {
+            eprintln!("❌ 합친 파일이 저장 공간 한도 초과: {} bytes", file_size);
+            let _ = std::fs::remove_dir_all(&temp_dir);
+            return Err(e);
         }
+
+        // 합친 파일을 저장소 격리 위치로 옮기고 임시 파일 정보 삭제
+        state.storage.put_file(&quarantine_key(&file_key), Path::new(&temp_data_path), &mime_type)
+            .await
+            .map_err(|e| {
+                eprintln!("❌ 파일 저장 실패: {:?}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+        let _ = std::fs::remove_file(&temp_info_path);
+        let _ = std::fs::remove_dir(&temp_dir);
+        
+        let file_info = register_post_file(&state, user_id, &original_name, &filename, &file_key, file_size, &mime_type, None, None).await?;
         
         eprintln!("📁 최종 파일 업로드 완료: {} ({} bytes)", url, file_size);
         Ok(Json(ApiResponse {
@@ -596,12 +466,12 @@ pub async fn upload_profile_file(
     State(state): State<AppState>,
     Extension(claims): Extension<Option<Claims>>,
     mut multipart: Multipart,
-) -> Result<Json<ApiResponse<UploadResponse>>, StatusCode> {
+) -> Result<Json<ApiResponse<UploadResponse>>, ApiError> {
     let mut filename = String::new();
-    let mut file_path = String::new();
     let mut size = 0u64;
     let mut mime_type = String::new();
     let mut original_name = String::new();
+    let mut file_id = Uuid::nil();
 
     // 인증 확인
     let user_id = claims
@@ -633,7 +503,7 @@ pub async fn upload_profile_file(
             // 프로필 이미지만 허용
             if !is_image_file(&extension) {
                 eprintln!("❌ 허용되지 않는 파일 타입: extension={}", extension);
-                return Err(StatusCode::BAD_REQUEST);
+                return Err(StatusCode::BAD_REQUEST.into());
             }
             
             eprintln!("✅ 프로필 파일 타입 검증 통과: extension={}", extension);
@@ -655,7 +525,7 @@ pub async fn upload_profile_file(
                         // 누적 크기 확인
                         if file_data.len() + chunk.len() > MAX_SIZE as usize {
                             eprintln!("❌ 프로필 파일 크기 초과: {} bytes > {} bytes", file_data.len() + chunk.len(), MAX_SIZE);
-                            return Err(StatusCode::PAYLOAD_TOO_LARGE);
+                            return Err(StatusCode::PAYLOAD_TOO_LARGE.into());
                         }
                         
                         file_data.extend_from_slice(&chunk);
@@ -671,41 +541,49 @@ pub async fn upload_profile_file(
                             eprintln!("⚠️ 프로필 스트림 오류 발생했지만 {} bytes 데이터 수신됨, 계속 진행", file_data.len());
                             break;
                         } else {
-                            return Err(StatusCode::BAD_REQUEST);
+                            return Err(StatusCode::BAD_REQUEST.into());
                         }
                     }
                 }
             }
             
             if file_data.len() as u64 > MAX_SIZE {
-                return Err(StatusCode::PAYLOAD_TOO_LARGE);
+                return Err(StatusCode::PAYLOAD_TOO_LARGE.into());
             }
 
+            // 내용 기준 MIME 타입 확인 후 저장 전에 바이러스 검사
+            mime_type = content_mime_type(&extension, &file_data)
+                .ok_or(StatusCode::BAD_REQUEST)?
+                .to_string();
+            scan_upload(&state, &file_data).await?;
+            reserve_upload(&state.pool, &state.config.upload_limit, user_id, file_data.len() as i64).await?;
+
             // 파일명 생성 - UUID_timestamp_originalname.ext 형태
             let timestamp = Utc::now().timestamp();
             let uuid_part = Uuid::new_v4().to_string();
             let safe_original = sanitize_filename(&original_name);
             filename = format!("{}_{}_{}", uuid_part, timestamp, safe_original);
-            file_path = format!("static/uploads/profiles/avatars/{}", filename);
-            
-            // 디렉토리 생성
-            std::fs::create_dir_all("static/uploads/profiles/avatars")
-                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            let file_key = format!("profiles/avatars/{}", filename);
+
+            // 저장 전에 위치 정보 등 EXIF 제거
+            let file_data = strip_upload_metadata(&state, &mime_type, file_data);
+            size = file_data.len() as u64;
 
             // 파일 저장
-            std::fs::write(&file_path, &file_data)
+            state.storage.put(&file_key, file_data, &mime_type)
+                .await
                 .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
-            size = file_data.len() as u64;
-            mime_type = get_mime_type(&extension);
+            // 저장 공간 사용량에 포함되도록 files에 기록 (현재 프로필 이미지는 users.profile_image 참조로 GC에서 보존)
+            file_id = register_profile_file(&state, user_id, &original_name, &filename, &file_key, size, &mime_type).await?;
         }
     }
 
     if filename.is_empty() {
-        return Err(StatusCode::BAD_REQUEST);
+        return Err(StatusCode::BAD_REQUEST.into());
     }
 
-    let url = format!("/uploads/profiles/avatars/{}", filename);
+    let url = upload_url(&format!("profiles/avatars/{}", filename));
 
     Ok(Json(ApiResponse {
         success: true,
@@ -716,7 +594,7 @@ pub async fn upload_profile_file(
             size,
             mime_type: mime_type.clone(),
             file_info: FileInfo {
-                id: Uuid::new_v4(),
+                id: file_id,
                 original_name: original_name.clone(),
                 file_path: url.clone(),
                 file_size: size as i64,
@@ -735,9 +613,8 @@ pub async fn upload_site_file(
     State(state): State<AppState>,
     Extension(claims): Extension<Option<Claims>>,
     mut multipart: Multipart,
-) -> Result<Json<ApiResponse<UploadResponse>>, StatusCode> {
+) -> Result<Json<ApiResponse<UploadResponse>>, ApiError> {
     let mut filename = String::new();
-    let mut file_path = String::new();
     let mut size = 0u64;
     let mut mime_type = String::new();
     let mut file_type = String::new();
@@ -773,7 +650,7 @@ pub async fn upload_site_file(
             // 이미지 파일만 허용
             if !is_image_file(&extension) {
                 eprintln!("❌ 허용되지 않는 파일 타입: extension={}", extension);
-                return Err(StatusCode::BAD_REQUEST);
+                return Err(StatusCode::BAD_REQUEST.into());
             }
             
             eprintln!("✅ 사이트 파일 타입 검증 통과: extension={}", extension);
@@ -795,7 +672,7 @@ pub async fn upload_site_file(
                         // 누적 크기 확인
                         if file_data.len() + chunk.len() > MAX_SIZE as usize {
                             eprintln!("❌ 사이트 파일 크기 초과: {} bytes > {} bytes", file_data.len() + chunk.len(), MAX_SIZE);
-                            return Err(StatusCode::PAYLOAD_TOO_LARGE);
+                            return Err(StatusCode::PAYLOAD_TOO_LARGE.into());
                         }
                         
                         file_data.extend_from_slice(&chunk);
@@ -811,42 +688,49 @@ pub async fn upload_site_file(
                             eprintln!("⚠️ 사이트 스트림 오류 발생했지만 {} bytes 데이터 수신됨, 계속 진행", file_data.len());
                             break;
                         } else {
-                            return Err(StatusCode::BAD_REQUEST);
+                            return Err(StatusCode::BAD_REQUEST.into());
                         }
                     }
                 }
             }
             
             if file_data.len() as u64 > MAX_SIZE {
-                return Err(StatusCode::PAYLOAD_TOO_LARGE);
+                return Err(StatusCode::PAYLOAD_TOO_LARGE.into());
             }
 
+            // 내용 기준 MIME 타입 확인 후 저장 전에 바이러스 검사
+            mime_type = content_mime_type(&extension, &file_data)
+                .ok_or(StatusCode::BAD_REQUEST)?
+                .to_string();
+            scan_upload(&state, &file_data).await?;
+            // 사이트 파일은 사이트 설정에서 참조하는 공용 자산이라 files에 기록하지 않고(GC 대상 제외)
+            // 개인 저장 공간 한도에서도 제외, 일일 업로드 횟수만 셈
+            count_upload(&state.pool, &state.config.upload_limit, user_id).await?;
+
             // 파일 타입 결정 (hero, background, logo, banner)
             file_type = "hero".to_string(); // 기본값, 실제로는 요청에서 받아야 함
 
             // 파일명 생성
             let timestamp = Utc::now().timestamp();
             filename = format!("{}_{}.{}", file_type, timestamp, extension);
-            file_path = format!("static/uploads/site/{}/{}", file_type, filename);
-            
-            // 디렉토리 생성
-            std::fs::create_dir_all(format!("static/uploads/site/{}", file_type))
-                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            let file_key = format!("site/{}/{}", file_type, filename);
+
+            // 저장 전에 위치 정보 등 EXIF 제거
+            let file_data = strip_upload_metadata(&state, &mime_type, file_data);
+            size = file_data.len() as u64;
 
             // 파일 저장
-            std::fs::write(&file_path, &file_data)
+            state.storage.put(&file_key, file_data, &mime_type)
+                .await
                 .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-            size = file_data.len() as u64;
-            mime_type = get_mime_type(&extension);
         }
     }
 
     if filename.is_empty() {
-        return Err(StatusCode::BAD_REQUEST);
+        return Err(StatusCode::BAD_REQUEST.into());
     }
 
-    let url = format!("/uploads/site/{}/{}", file_type, filename);
+    let url = upload_url(&format!("site/{}/{}", file_type, filename));
 
     Ok(Json(ApiResponse {
         success: true,
@@ -871,7 +755,18 @@ pub async fn upload_site_file(
     }))
 }
 
-// 썸네일 상태 확인 엔드포인트
+// 내 저장 공간 / 업로드 한도 사용량
+pub async fn get_storage_usage(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+) -> Result<Json<ApiResponse<StorageUsage>>, ApiError> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+    let usage = storage_usage(&state.pool, &state.config.upload_limit, claims.sub).await?;
+
+    Ok(Json(ApiResponse::success(usage, "저장 공간 사용량을 조회했습니다.")))
+}
+
+// 썸네일 상태 확인 엔드포인트 (후처리 작업 큐 상태 포함)
 pub async fn check_thumbnail_status(
     State(state): State<AppState>,
     Extension(claims): Extension<Option<Claims>>,
@@ -880,7 +775,9 @@ pub async fn check_thumbnail_status(
     // 파일 정보 조회
     let file_record = sqlx::query!(
         r#"
-        SELECT original_name, stored_name, file_path, mime_type
+        SELECT original_name, stored_name, file_path, mime_type,
+               processing_status as "processing_status: ProcessingStatus",
+               duration_seconds, width, height, poster_path, media_info
         FROM files 
         WHERE id = $1
         "#,
@@ -890,6 +787,52 @@ pub async fn check_thumbnail_status(
     .await
     .map_err(|_| StatusCode::NOT_FOUND)?;
 
+    // 가장 최근 후처리 작업 (재시도 횟수, 다음 시도 시각, 마지막 오류)
+    let job = latest_media_job(&state.pool, file_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map(|job| serde_json::json!({
+            "id": job.id,
+            "job_type": job.job_type,
+            "status": job.status,
+            "attempts": job.attempts,
+            "max_attempts": job.max_attempts,
+            "run_at": job.run_at,
+            "last_error": job.last_error,
+        }));
+
+    // 동영상은 포스터 프레임의 썸네일과 동영상 정보
+    if file_record.mime_type.starts_with("video/") {
+        let poster_key = file_record
+            .poster_path
+            .as_deref()
+            .and_then(|poster_path| state.storage.key_from_location(poster_path));
+        let thumbnail_url = match poster_key {
+            Some(poster_key) if ThumbnailService::new(state.storage.clone()).thumbnail_exists(&thumbnail_key(poster_key, "large")).await => {
+                Some(upload_url(&thumbnail_key(poster_key, "large")))
+            }
+            _ => None,
+        };
+        return Ok(Json(ApiResponse {
+            success: true,
+            message: "썸네일 상태 확인 완료".to_string(),
+            data: Some(serde_json::json!({
+                "has_thumbnail": thumbnail_url.is_some(),
+                "thumbnail_url": thumbnail_url,
+                "processing_status": file_record.processing_status,
+                "video": {
+                    "duration_seconds": file_record.duration_seconds,
+                    "width": file_record.width,
+                    "height": file_record.height,
+                    "poster_url": poster_key.map(upload_url),
+                    "media_info": file_record.media_info,
+                },
+                "job": job
+            })),
+            pagination: None,
+        }));
+    }
+
     // 이미지가 아니면 썸네일 없음 (MIME 타입으로 확인)
     if !file_record.mime_type.starts_with("image/") {
         return Ok(Json(ApiResponse {
@@ -897,17 +840,19 @@ pub async fn check_thumbnail_status(
             message: "이미지 파일이 아닙니다.".to_string(),
             data: Some(serde_json::json!({
                 "has_thumbnail": false,
-                "thumbnail_url": null
+                "thumbnail_url": null,
+                "processing_status": file_record.processing_status,
+                "job": job
             })),
             pagination: None,
         }));
     }
 
     // 썸네일이 생성되었는지 확인
-    let thumbnail_service = ThumbnailService::new();
-    let thumbnail_url = thumbnail_service.get_thumbnail_url(&file_record.file_path, "large");
-    let thumbnail_path = thumbnail_service.build_thumbnail_path(&file_record.file_path, "large");
-    let has_thumbnail = thumbnail_service.thumbnail_exists(&thumbnail_path);
+    let file_key = state.storage.key_from_location(&file_record.file_path).ok_or(StatusCode::NOT_FOUND)?;
+    let thumbnail_service = ThumbnailService::new(state.storage.clone());
+    let thumbnail_url = thumbnail_service.get_thumbnail_url(file_key, "large");
+    let has_thumbnail = thumbnail_service.thumbnail_exists(&thumbnail_key(file_key, "large")).await;
 
     let thumbnail_url_value = if has_thumbnail { 
         serde_json::Value::String(thumbnail_url) 
@@ -920,22 +865,62 @@ pub async fn check_thumbnail_status(
         message: "썸네일 상태 확인 완료".to_string(),
         data: Some(serde_json::json!({
             "has_thumbnail": has_thumbnail,
-            "thumbnail_url": thumbnail_url_value
+            "thumbnail_url": thumbnail_url_value,
+            "processing_status": file_record.processing_status,
+            "job": job
         })),
         pagination: None,
     }))
 }
 
-// 원본 파일 다운로드 엔드포인트
+/// 파일이 첨부된 게시글의 게시판 (댓글 첨부파일은 댓글의 게시글 기준)
+/// 게시글/댓글이 숨김·삭제되었으면 visible이 false
+pub(crate) struct FileBoard {
+    pub board: Board,
+    pub visible: bool,
+}
+
+pub(crate) async fn find_file_board(state: &AppState, file_id: Uuid) -> Result<Option<FileBoard>, StatusCode> {
+    let Some((board_id, visible)) = sqlx::query_as::<_, (Uuid, bool)>(
+        r#"
+        SELECT p.board_id,
+               p.status IN ('active', 'published') AND COALESCE(p.is_deleted, false) = false
+               AND (c.id IS NULL OR (c.status IN ('active', 'published') AND COALESCE(c.is_deleted, false) = false)) AS visible
+        FROM file_entities fe
+        LEFT JOIN comments c ON fe.entity_type = 'comment' AND c.id = fe.entity_id
+        JOIN posts p ON p.id = CASE WHEN fe.entity_type = 'post' THEN fe.entity_id ELSE c.post_id END
+        WHERE fe.file_id = $1 AND fe.entity_type IN ('post', 'comment')
+        ORDER BY visible DESC
+        LIMIT 1
+        "#
+    )
+    .bind(file_id)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|e| {
+        eprintln!("❌ 첨부파일 게시판 조회 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    else {
+        return Ok(None);
+    };
+
+    let board = find_board(state, board_id).await?;
+    Ok(Some(FileBoard { board, visible }))
+}
+
+// 원본 파일 다운로드 엔드포인트 (디스크에서 스트리밍, Range/조건부 요청 지원)
 pub async fn download_original_file(
     State(state): State<AppState>,
     Extension(claims): Extension<Option<Claims>>,
     AxumPath(file_id): AxumPath<Uuid>,
+    headers: HeaderMap,
 ) -> Result<axum::response::Response, StatusCode> {
     // 파일 정보 조회 (인증 없이도 접근 가능)
     let file_record = sqlx::query!(
         r#"
-        SELECT original_name, stored_name, file_path, mime_type, user_id
+        SELECT original_name, stored_name, file_path, mime_type, user_id,
+               processing_status as "processing_status: ProcessingStatus"
         FROM files 
         WHERE id = $1
         "#,
@@ -945,14 +930,92 @@ pub async fn download_original_file(
     .await
     .map_err(|_| StatusCode::NOT_FOUND)?;
 
-    // 파일이 존재하는지 확인
-    if !std::path::Path::new(&file_record.file_path).exists() {
+    // 바이러스 검사가 끝나지 않은 파일은 내려주지 않음 (악성 파일은 삭제되어 저장소 조회에서 404)
+    if matches!(file_record.processing_status, Some(ProcessingStatus::Pending)) {
         return Err(StatusCode::NOT_FOUND);
     }
 
-    // 파일 내용 읽기
-    let file_content = std::fs::read(&file_record.file_path)
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    // 게시글/댓글 첨부파일이면 게시글이 공개 중인지, 게시판 열람·다운로드 권한이 있는지 확인
+    let file_board = find_file_board(&state, file_id).await?;
+    if file_board.as_ref().is_some_and(|file_board| !file_board.visible) {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    let board = file_board.map(|file_board| file_board.board);
+
+    let user_id = claims.as_ref().map(|c| c.sub);
+    let user_role = claims.as_ref().map(|c| c.role.as_str());
+    let is_uploader = user_id == Some(file_record.user_id);
+    if let Some(board) = &board {
+        let allowed = is_uploader || (can_read_post(board, user_role) && can_download_file(board, user_role));
+        if !allowed {
+            return Err(if claims.is_none() { StatusCode::UNAUTHORIZED } else { StatusCode::FORBIDDEN });
+        }
+    }
+
+    let file_key = state.storage.key_from_location(&file_record.file_path).ok_or(StatusCode::NOT_FOUND)?;
+    let meta = state.storage
+        .head(file_key)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    let size = meta.size;
+    let etag = file_etag(size, meta.modified);
+    let last_modified = http_date(meta.modified);
+
+    // 조건부 요청: 변경이 없으면 본문 없이 304
+    if is_not_modified(&headers, &etag, meta.modified) {
+        return not_modified_response(&etag, &last_modified, DOWNLOAD_CACHE_CONTROL);
+    }
+
+    let range = requested_range(&headers, size, &etag, &last_modified);
+    if range == ByteRange::Unsatisfiable {
+        return range_not_satisfiable_response(size);
+    }
+    let (start, end) = match range {
+        ByteRange::Partial { start, end } => (start, end),
+        _ => (0, size.saturating_sub(1)),
+    };
+    // 이어받기/탐색 요청은 다운로드 1회로 세지 않음
+    let is_new_download = start == 0;
+
+    // 게시글 첨부파일이면 게시판 다운로드 포인트 적용 (업로더 본인 제외, 파일당 최초 1회)
+    let download_point = board.as_ref().map_or(0, |b| b.download_point);
+    if download_point != 0 {
+        match user_id {
+            Some(user_id) if !is_uploader => {
+                let mut tx = state.pool.begin().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                let applied = PointService::apply_once(
+                    &mut tx,
+                    user_id,
+                    download_point,
+                    POINT_TYPE_FILE_DOWNLOAD,
+                    "첨부파일 다운로드",
+                    "file",
+                    file_id,
+                )
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                if !applied {
+                    return Err(StatusCode::PAYMENT_REQUIRED);
+                }
+                tx.commit().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            }
+            Some(_) => {}
+            // 포인트가 차감되는 게시판의 첨부파일은 로그인 필요
+            None if download_point < 0 => return Err(StatusCode::UNAUTHORIZED),
+            None => {}
+        }
+    }
+
+    if is_new_download {
+        if let Err(e) = sqlx::query("UPDATE files SET download_count = download_count + 1 WHERE id = $1")
+            .bind(file_id)
+            .execute(&state.pool)
+            .await
+        {
+            eprintln!("Failed to increment download count: {:?}", e);
+        }
+    }
 
     // 원본 파일명으로 다운로드되도록 헤더 설정
     let content_disposition = format!(
@@ -960,15 +1023,170 @@ pub async fn download_original_file(
         urlencoding::encode(&file_record.original_name)
     );
 
-    let response = axum::response::Response::builder()
-        .status(200)
-        .header("Content-Type", file_record.mime_type)
-        .header("Content-Disposition", content_disposition)
-        .header("Content-Length", file_content.len().to_string())
-        .body(axum::body::Body::from(file_content))
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    // 서명 URL 모드면 권한/포인트 확인 후 저장소로 직접 연결
+    if state.config.storage.download_mode == "presigned" {
+        if let Some(url) = state.storage.presigned_url(
+            file_key,
+            state.config.storage.presign_expiry_seconds,
+            Some(&file_record.original_name),
+        ) {
+            return redirect_response(&url);
+        }
+    }
 
-    Ok(response)
+    stream_object_response(
+        &state,
+        file_key,
+        range,
+        (start, end, size),
+        &file_record.mime_type,
+        Some(content_disposition),
+        (&etag, &last_modified, DOWNLOAD_CACHE_CONTROL),
+    )
+    .await
+}
+
+// 업로드 파일 공개 경로 (로컬 디스크가 아닌 저장소에서 /uploads/* 제공)
+pub async fn serve_upload(
+    State(state): State<AppState>,
+    AxumPath(key): AxumPath<String>,
+    headers: HeaderMap,
+) -> Result<axum::response::Response, StatusCode> {
+    validate_key(&key).map_err(|_| StatusCode::NOT_FOUND)?;
+
+    if state.config.storage.download_mode == "presigned" {
+        // SVG는 저장소 응답에서도 다운로드로 처리되도록 파일명 지정
+        let download_name = has_svg_extension(&key).then(|| key.rsplit('/').next().unwrap_or(&key));
+        if let Some(url) = state.storage.presigned_url(&key, state.config.storage.presign_expiry_seconds, download_name) {
+            return redirect_response(&url);
+        }
+    }
+
+    let meta = state.storage
+        .head(&key)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    let etag = file_etag(meta.size, meta.modified);
+    let last_modified = http_date(meta.modified);
+
+    if is_not_modified(&headers, &etag, meta.modified) {
+        return not_modified_response(&etag, &last_modified, UPLOAD_CACHE_CONTROL);
+    }
+
+    let range = requested_range(&headers, meta.size, &etag, &last_modified);
+    let (start, end) = match range {
+        ByteRange::Unsatisfiable => return range_not_satisfiable_response(meta.size),
+        ByteRange::Partial { start, end } => (start, end),
+        ByteRange::Full => (0, meta.size.saturating_sub(1)),
+    };
+
+    stream_object_response(
+        &state,
+        &key,
+        range,
+        (start, end, meta.size),
+        crate::services::content_type_for_key(&key),
+        None,
+        (&etag, &last_modified, UPLOAD_CACHE_CONTROL),
+    )
+    .await
+}
+
+// If-None-Match / If-Modified-Since 확인
+fn is_not_modified(headers: &HeaderMap, etag: &str, modified: chrono::DateTime<Utc>) -> bool {
+    let header_str = |name: header::HeaderName| headers.get(name).and_then(|v| v.to_str().ok());
+    match header_str(header::IF_NONE_MATCH) {
+        Some(if_none_match) => etag_matches(if_none_match, etag),
+        None => header_str(header::IF_MODIFIED_SINCE).is_some_and(|since| not_modified_since(since, modified)),
+    }
+}
+
+// If-Range가 현재 파일과 다르면 Range를 무시하고 전체 전송
+fn requested_range(headers: &HeaderMap, size: u64, etag: &str, last_modified: &str) -> ByteRange {
+    let header_str = |name: header::HeaderName| headers.get(name).and_then(|v| v.to_str().ok());
+    let if_range_matches = header_str(header::IF_RANGE)
+        .is_none_or(|if_range| if_range.trim() == etag || if_range.trim() == last_modified);
+    if if_range_matches {
+        parse_range(header_str(header::RANGE), size)
+    } else {
+        ByteRange::Full
+    }
+}
+
+fn not_modified_response(etag: &str, last_modified: &str, cache_control: &str) -> Result<axum::response::Response, StatusCode> {
+    axum::response::Response::builder()
+        .status(StatusCode::NOT_MODIFIED)
+        .header(header::ETAG, etag)
+        .header(header::LAST_MODIFIED, last_modified)
+        .header(header::CACHE_CONTROL, cache_control)
+        .body(axum::body::Body::empty())
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+fn range_not_satisfiable_response(size: u64) -> Result<axum::response::Response, StatusCode> {
+    axum::response::Response::builder()
+        .status(StatusCode::RANGE_NOT_SATISFIABLE)
+        .header(header::CONTENT_RANGE, format!("bytes */{}", size))
+        .body(axum::body::Body::empty())
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+// 서명 URL은 만료되므로 리다이렉트 응답은 캐시하지 않음
+fn redirect_response(url: &str) -> Result<axum::response::Response, StatusCode> {
+    axum::response::Response::builder()
+        .status(StatusCode::TEMPORARY_REDIRECT)
+        .header(header::LOCATION, url)
+        .header(header::CACHE_CONTROL, "no-store")
+        .body(axum::body::Body::empty())
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+// 요청 범위만 저장소에서 스트리밍
+async fn stream_object_response(
+    state: &AppState,
+    key: &str,
+    range: ByteRange,
+    (start, end, size): (u64, u64, u64),
+    content_type: &str,
+    content_disposition: Option<String>,
+    (etag, last_modified, cache_control): (&str, &str, &str),
+) -> Result<axum::response::Response, StatusCode> {
+    let length = if size == 0 { 0 } else { end - start + 1 };
+    let stream = state.storage
+        .get_range(key, start, length)
+        .await
+        .map_err(|_| StatusCode::NOT_FOUND)?;
+
+    let mut response = axum::response::Response::builder()
+        .header(header::CONTENT_TYPE, content_type)
+        .header(header::CONTENT_LENGTH, length.to_string())
+        .header(header::ACCEPT_RANGES, "bytes")
+        .header(header::ETAG, etag)
+        .header(header::LAST_MODIFIED, last_modified)
+        .header(header::CACHE_CONTROL, cache_control)
+        .header(header::X_CONTENT_TYPE_OPTIONS, "nosniff");
+    // SVG 등 스크립트를 포함할 수 있는 파일은 항상 다운로드로 처리
+    let content_disposition = match content_disposition {
+        None if is_active_content(content_type) => Some("attachment".to_string()),
+        other => other,
+    };
+    if is_active_content(content_type) {
+        response = response.header(header::CONTENT_SECURITY_POLICY, "sandbox");
+    }
+    if let Some(content_disposition) = content_disposition {
+        response = response.header(header::CONTENT_DISPOSITION, content_disposition);
+    }
+    response = match range {
+        ByteRange::Partial { .. } => response
+            .status(StatusCode::PARTIAL_CONTENT)
+            .header(header::CONTENT_RANGE, format!("bytes {}-{}/{}", start, end, size)),
+        _ => response.status(StatusCode::OK),
+    };
+
+    response
+        .body(axum::body::Body::from_stream(stream))
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
 }
 
 // 파일 삭제
@@ -1012,17 +1230,21 @@ pub async fn delete_file(
         return Err(StatusCode::BAD_REQUEST);
     }
 
-    // 파일 시스템에서 실제 파일 삭제
-    if let Err(e) = std::fs::remove_file(&file.file_path) {
-        eprintln!("Failed to delete file from filesystem: {:?}", e);
-        // 파일 시스템 삭제 실패는 무시하고 DB에서만 삭제
-    }
+    // 저장소에서 실제 파일 삭제 (다른 저장소에 남아 있는 파일은 저장소 이전 후 정리)
+    if let Some(file_key) = state.storage.key_from_location(&file.file_path) {
+        if let Err(e) = state.storage.delete(file_key).await {
+            eprintln!("Failed to delete file from storage: {:?}", e);
+            // 저장소 삭제 실패는 무시하고 DB에서만 삭제
+        }
+        // 검사 전 파일은 격리 위치에 남아 있음
+        let _ = state.storage.delete(&quarantine_key(file_key)).await;
 
-    // 썸네일 파일들도 삭제 (이미지인 경우)
-            if file.mime_type.starts_with("image/") {
-        let thumbnail_service = ThumbnailService::new();
-        if let Err(e) = thumbnail_service.delete_thumbnails(&file.file_path).await {
-            eprintln!("Failed to delete thumbnails: {:?}", e);
+        // 썸네일 파일들도 삭제 (이미지, 동영상 포스터)
+        if file.mime_type.starts_with("image/") || file.mime_type.starts_with("video/") {
+            let thumbnail_service = ThumbnailService::new(state.storage.clone());
+            if let Err(e) = thumbnail_service.delete_thumbnails(file_key).await {
+                eprintln!("Failed to delete thumbnails: {:?}", e);
+            }
         }
     }
 
@@ -1062,7 +1284,7 @@ pub async fn delete_post_attachment(
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
     .ok_or(StatusCode::NOT_FOUND)?;
 
-    if post.user_id != user_id {
+    if post.user_id != Some(user_id) {
         return Err(StatusCode::FORBIDDEN);
     }
 
@@ -1096,16 +1318,20 @@ pub async fn delete_post_attachment(
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
         .ok_or(StatusCode::NOT_FOUND)?;
 
-        // 파일 시스템에서 실제 파일 삭제
-        if let Err(e) = std::fs::remove_file(&file.file_path) {
-            eprintln!("Failed to delete file from filesystem: {:?}", e);
-        }
-
-        // 썸네일 파일들도 삭제 (이미지인 경우)
-        if file.mime_type.starts_with("image/") {
-            let thumbnail_service = ThumbnailService::new();
-            if let Err(e) = thumbnail_service.delete_thumbnails(&file.file_path).await {
-                eprintln!("Failed to delete thumbnails: {:?}", e);
+        // 저장소에서 실제 파일 삭제
+        if let Some(file_key) = state.storage.key_from_location(&file.file_path) {
+            if let Err(e) = state.storage.delete(file_key).await {
+                eprintln!("Failed to delete file from storage: {:?}", e);
+            }
+            // 검사 전 파일은 격리 위치에 남아 있음
+            let _ = state.storage.delete(&quarantine_key(file_key)).await;
+
+            // 썸네일 파일들도 삭제 (이미지, 동영상 포스터)
+            if file.mime_type.starts_with("image/") || file.mime_type.starts_with("video/") {
+                let thumbnail_service = ThumbnailService::new(state.storage.clone());
+                if let Err(e) = thumbnail_service.delete_thumbnails(file_key).await {
+                    eprintln!("Failed to delete thumbnails: {:?}", e);
+                }
             }
         }
 
@@ -1124,8 +1350,218 @@ pub async fn delete_post_attachment(
     }))
 }
 
+/// 저장소 격리 위치(quarantine_key)에 올라간 게시글 첨부파일을 files 테이블에 기록하고 후처리 시작
+/// file_path는 공개 위치로 기록하고, 작업 큐에서 바이러스 검사를 통과해야 공개 위치로 옮김
+/// 검사/썸네일 생성이 끝날 때까지 processing_status는 pending
+/// 기록한 파일을 포함해 저장 공간 한도를 넘으면 기록을 취소하고 저장된 파일을 삭제 (413)
+/// 업로드 세션으로 올라온 파일은 같은 트랜잭션에서 세션을 완료 처리 (세션 크기가 사용량에 중복 집계되지 않도록)
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn register_post_file(
+    state: &AppState,
+    user_id: Uuid,
+    original_name: &str,
+    filename: &str,
+    file_key: &str,
+    size: u64,
+    mime_type: &str,
+    draft_id: Option<Uuid>,
+    upload_session_id: Option<Uuid>,
+) -> Result<FileInfo, StatusCode> {
+    let url = upload_url(file_key);
+    let file_path = state.storage.location(file_key);
+    let file_type = determine_file_type(mime_type);
+    let file_id = Uuid::new_v4();
+
+    let mut tx = state.pool.begin().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    eprintln!("📁 DB 저장 시작: file_id={}, user_id={}", file_id, user_id);
+    let file_record = sqlx::query!(
+        r#"
+        INSERT INTO files (id, user_id, original_name, stored_name, file_path, file_size, mime_type, file_type, processing_status)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING id
+        "#,
+        file_id,
+        user_id,
+        original_name,
+        filename,
+        file_path,
+        size as i64,
+        mime_type,
+        file_type as FileType,
+        ProcessingStatus::Pending as ProcessingStatus
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        eprintln!("❌ DB 저장 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    eprintln!("✅ DB 저장 완료: file_id={}", file_record.id);
+
+    // 임시저장 글에 파일 연결
+    if let Some(draft_id) = draft_id {
+        sqlx::query!(
+            r#"
+            INSERT INTO file_entities (id, file_id, entity_type, entity_id, file_purpose, display_order)
+            SELECT uuid_generate_v4(), $1, $2, $3, $4, COUNT(*)::int
+            FROM file_entities WHERE entity_type = $2 AND entity_id = $3
+            "#,
+            file_record.id,
+            EntityType::Draft as EntityType,
+            draft_id,
+            FilePurpose::Attachment as FilePurpose
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            eprintln!("❌ 임시저장 파일 연결 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    }
+
+    if let Some(upload_session_id) = upload_session_id {
+        sqlx::query("UPDATE upload_sessions SET status = 'completed', file_id = $2, updated_at = NOW() WHERE id = $1")
+            .bind(upload_session_id)
+            .bind(file_record.id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    }
+
+    // 동시 업로드로 한도를 넘었으면 기록을 취소하고 저장된 파일 삭제
+    if let Err(e) = verify_storage_quota(&mut tx, &state.config.upload_limit, user_id).await {
+        eprintln!("❌ 저장 공간 한도 초과로 업로드 취소: {:?}", e);
+        drop(tx);
+        let _ = state.storage.delete(&quarantine_key(file_key)).await;
+        return Err(match e {
+            ApiError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        });
+    }
+
+    tx.commit().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    // 작업 큐에서 바이러스 검사 및 썸네일 생성
+    enqueue_media_job(&state.pool, JOB_PROCESS_FILE, Some(file_record.id), file_key, state.config.media_job.max_attempts)
+        .await
+        .map_err(|e| {
+            eprintln!("❌ 파일 후처리 작업 등록 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(FileInfo {
+        id: file_record.id,
+        original_name: original_name.to_string(),
+        file_path: url.clone(),
+        file_size: size as i64,
+        mime_type: mime_type.to_string(),
+        file_type,
+        url,
+    })
+}
+
+/// 프로필 이미지를 files 테이블에 기록 (저장 공간 사용량 집계용)
+/// 공개 위치에 바로 저장되므로 후처리 없이 completed로 기록
+/// 현재 프로필 이미지는 users.profile_image 참조로 GC에서 보존되고, 교체된 이전 이미지는 GC가 정리
+/// 저장 공간 한도를 넘으면 기록을 취소하고 저장된 파일을 삭제
+pub(crate) async fn register_profile_file(
+    state: &AppState,
+    user_id: Uuid,
+    original_name: &str,
+    filename: &str,
+    file_key: &str,
+    size: u64,
+    mime_type: &str,
+) -> Result<Uuid, ApiError> {
+    let file_id = Uuid::new_v4();
+    let mut tx = state.pool.begin().await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO files (id, user_id, original_name, stored_name, file_path, file_size, mime_type, file_type, status, processing_status)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        "#,
+        file_id,
+        user_id,
+        original_name,
+        filename,
+        state.storage.location(file_key),
+        size as i64,
+        mime_type,
+        determine_file_type(mime_type) as FileType,
+        FileStatus::Published as FileStatus,
+        ProcessingStatus::Completed as ProcessingStatus
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    if let Err(e) = verify_storage_quota(&mut tx, &state.config.upload_limit, user_id).await {
+        drop(tx);
+        let _ = state.storage.delete(file_key).await;
+        return Err(e);
+    }
+
+    tx.commit().await?;
+    Ok(file_id)
+}
+
+// 원본 EXIF를 제거하는 이미지 형식 (IMAGE_STRIP_METADATA)
+fn should_strip_metadata(state: &AppState, mime_type: &str) -> bool {
+    state.config.image.strip_metadata && matches!(mime_type, "image/jpeg" | "image/png")
+}
+
+/// 이미지 원본의 위치/기기 정보 등 EXIF를 저장소에 올리기 전에 제거
+pub(crate) fn strip_upload_metadata(state: &AppState, mime_type: &str, data: Vec<u8>) -> Vec<u8> {
+    if should_strip_metadata(state, mime_type) {
+        if let Some(stripped) = strip_metadata(&data) {
+            return stripped;
+        }
+    }
+    data
+}
+
+/// 청크/조각 업로드를 합친 로컬 임시 파일의 EXIF 제거 후 최종 크기 반환
+pub(crate) async fn strip_upload_file_metadata(state: &AppState, mime_type: &str, path: &Path) -> std::io::Result<u64> {
+    if should_strip_metadata(state, mime_type) {
+        let data = tokio::fs::read(path).await?;
+        if let Some(stripped) = strip_metadata(&data) {
+            tokio::fs::write(path, &stripped).await?;
+            return Ok(stripped.len() as u64);
+        }
+    }
+    Ok(tokio::fs::metadata(path).await?.len())
+}
+
+/// 바이러스 검사기가 설정된 경우 저장 전에 검사 (files 테이블에 기록되지 않는 업로드용)
+/// 악성 파일은 422, 검사기 오류는 503
+pub(crate) async fn scan_upload(state: &AppState, data: &[u8]) -> Result<(), StatusCode> {
+    let Some(scanner) = create_virus_scanner(&state.config.virus_scan) else {
+        return Ok(());
+    };
+    match scanner.scan(data).await {
+        Ok(ScanResult::Clean) => Ok(()),
+        Ok(ScanResult::Infected(signature)) => {
+            eprintln!("🦠 악성 파일 업로드 차단: signature={}", signature);
+            Err(StatusCode::UNPROCESSABLE_ENTITY)
+        }
+        Err(e) => {
+            eprintln!("❌ 바이러스 검사 실패: {:?}", e);
+            Err(StatusCode::SERVICE_UNAVAILABLE)
+        }
+    }
+}
+
+// 내용 판별용으로 파일 앞부분만 읽기
+pub(crate) fn read_file_head(path: &str) -> std::io::Result<Vec<u8>> {
+    use std::io::Read;
+    let mut head = Vec::new();
+    std::fs::File::open(path)?.take(8 * 1024).read_to_end(&mut head)?;
+    Ok(head)
+}
+
 // 유틸리티 함수들
-fn is_allowed_file_type(extension: &str) -> bool {
+pub(crate) fn is_allowed_file_type(extension: &str) -> bool {
     let allowed_extensions = [
         // 이미지
         "jpg", "jpeg", "png", "gif", "webp", "svg",
@@ -1138,34 +1574,11 @@ fn is_allowed_file_type(extension: &str) -> bool {
     allowed_extensions.contains(&extension.to_lowercase().as_str())
 }
 
-fn is_image_file(extension: &str) -> bool {
+pub(crate) fn is_image_file(extension: &str) -> bool {
     let image_extensions = ["jpg", "jpeg", "png", "gif", "webp", "svg"];
     image_extensions.contains(&extension.to_lowercase().as_str())
 }
 
-fn get_mime_type(extension: &str) -> String {
-    match extension.to_lowercase().as_str() {
-        "jpg" | "jpeg" => "image/jpeg".to_string(),
-        "png" => "image/png".to_string(),
-        "gif" => "image/gif".to_string(),
-        "webp" => "image/webp".to_string(),
-        "svg" => "image/svg+xml".to_string(),
-        "pdf" => "application/pdf".to_string(),
-        "doc" => "application/msword".to_string(),
-        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document".to_string(),
-        "xls" => "application/vnd.ms-excel".to_string(),
-        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet".to_string(),
-        "ppt" => "application/vnd.ms-powerpoint".to_string(),
-        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation".to_string(),
-        "txt" => "text/plain".to_string(),
-        "mp4" => "video/mp4".to_string(),
-        "avi" => "video/x-msvideo".to_string(),
-        "mov" => "video/quicktime".to_string(),
-        "wmv" => "video/x-ms-wmv".to_string(),
-        _ => "application/octet-stream".to_string(),
-    }
-}
-
 fn determine_file_type(mime_type: &str) -> FileType {
     match mime_type {
         mime if mime.starts_with("image/") => FileType::Image,
@@ -1180,7 +1593,7 @@ fn determine_file_type(mime_type: &str) -> FileType {
     }
 }
 
-fn sanitize_filename(filename: &str) -> String {
+pub(crate) fn sanitize_filename(filename: &str) -> String {
     // 파일명에서 안전하지 않은 문자들을 제거하거나 대체
     let mut safe_name = filename
         .chars()
@@ -0,0 +1,397 @@
+use axum::{
+    body::Bytes,
+    extract::{Path, State, Extension},
+    http::{HeaderMap, StatusCode},
+    response::Json,
+};
+use chrono::Utc;
+use uuid::Uuid;
+use tracing::error;
+use crate::{
+    errors::ApiError,
+    handlers::site::community::{can_write_post, find_board},
+    handlers::site::draft::find_my_draft,
+    handlers::site::upload::{
+        is_allowed_file_type, is_image_file, read_file_head, register_post_file, sanitize_filename, strip_upload_file_metadata,
+        UploadResponse,
+    },
+    models::file::{CreateUploadSessionRequest, UploadSession, UploadSessionStatus},
+    models::response::ApiResponse,
+    services::{
+        assemble_parts, expected_part_size, is_allowed_by_board, is_sha256_hex, missing_parts, part_count, quarantine_key,
+        remove_session_dir, reserve_upload_in, sha256_hex, write_part, DEFAULT_MAX_UPLOAD_SIZE, MAX_UPLOAD_PARTS,
+        MIN_CHUNK_SIZE,
+    },
+    utils::auth::Claims,
+    utils::file_sniff::content_mime_type,
+    AppState,
+};
+
+// 조각 무결성 확인 헤더 (조각 본문의 SHA-256 hex)
+const CHUNK_SHA256_HEADER: &str = "x-chunk-sha256";
+
+const SESSION_COLUMNS: &str = "id, user_id, board_id, draft_id, original_name, total_size, chunk_size, total_parts, \
+    sha256, status, file_id, expires_at, created_at, updated_at";
+
+// 업로드 세션 생성 (조각 크기/수, 만료 시각 반환)
+pub async fn create_upload_session(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Json(payload): Json<CreateUploadSessionRequest>,
+) -> Result<Json<ApiResponse<UploadSessionStatus>>, ApiError> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let extension = file_extension(&payload.original_name);
+    if !is_allowed_file_type(&extension) {
+        return Err(StatusCode::BAD_REQUEST.into());
+    }
+    let sha256 = payload.sha256.trim().to_lowercase();
+    if !is_sha256_hex(&sha256) || payload.total_size <= 0 {
+        return Err(StatusCode::BAD_REQUEST.into());
+    }
+
+    // 조각이 하나뿐이면 최소 크기 제한 없음
+    let max_chunk_size = state.config.storage.max_chunk_size as i64;
+    let chunk_size = payload.chunk_size;
+    if chunk_size <= 0 || chunk_size > max_chunk_size || (chunk_size < MIN_CHUNK_SIZE && chunk_size < payload.total_size) {
+        return Err(StatusCode::BAD_REQUEST.into());
+    }
+    let total_parts = part_count(payload.total_size, chunk_size);
+    if total_parts > MAX_UPLOAD_PARTS {
+        return Err(StatusCode::BAD_REQUEST.into());
+    }
+
+    // 임시저장 글에 첨부하면 해당 게시판 기준으로 제한 적용
+    let board_id = match payload.draft_id {
+        Some(draft_id) => Some(find_my_draft(&state, draft_id, claims.sub).await?.board_id),
+        None => payload.board_id,
+    };
+    let board = match board_id {
+        Some(board_id) => Some(find_board(&state, board_id).await?),
+        None => None,
+    };
+    if let Some(board) = &board {
+        if !board.allow_file_upload || !can_write_post(board, Some(&claims.role)) {
+            return Err(StatusCode::FORBIDDEN.into());
+        }
+    }
+    let max_file_size = board.as_ref().map_or(DEFAULT_MAX_UPLOAD_SIZE, |b| b.max_file_size);
+    if payload.total_size > max_file_size {
+        return Err(StatusCode::PAYLOAD_TOO_LARGE.into());
+    }
+    // 저장 공간 / 일일 업로드 한도 확인 (세션 생성 시 한 번만 셈)
+    // 한도 확인과 세션 기록을 한 트랜잭션에서 처리해 동시 세션 생성으로 한도를 넘지 않도록 함
+    let mut tx = state.pool.begin().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    reserve_upload_in(&mut tx, &state.config.upload_limit, claims.sub, payload.total_size).await?;
+
+    let session = sqlx::query_as::<_, UploadSession>(&format!(
+        r#"
+        INSERT INTO upload_sessions (user_id, board_id, draft_id, original_name, total_size, chunk_size, total_parts, sha256, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, NOW() + make_interval(hours => $9))
+        RETURNING {}
+        "#,
+        SESSION_COLUMNS
+    ))
+    .bind(claims.sub)
+    .bind(board_id)
+    .bind(payload.draft_id)
+    .bind(&payload.original_name)
+    .bind(payload.total_size)
+    .bind(chunk_size as i32)
+    .bind(total_parts as i32)
+    .bind(&sha256)
+    .bind(state.config.storage.upload_session_ttl_hours as i32)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        error!("업로드 세션 생성 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    tx.commit().await.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let status = session_status(&state, session).await?;
+    Ok(Json(ApiResponse::success(status, "업로드 세션이 생성되었습니다.")))
+}
+
+// 업로드 세션 상태 (받은 조각/빠진 조각) 조회
+pub async fn get_upload_session(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Path(session_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<UploadSessionStatus>>, StatusCode> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let session = find_my_session(&state, session_id, claims.sub).await?;
+    let status = session_status(&state, session).await?;
+
+    Ok(Json(ApiResponse::success(status, "업로드 세션을 조회했습니다.")))
+}
+
+// 조각 업로드 (순서 무관, 같은 조각 재전송 시 덮어씀)
+pub async fn upload_session_part(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Path((session_id, part_index)): Path<(Uuid, i32)>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<ApiResponse<UploadSessionStatus>>, StatusCode> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let session = find_my_session(&state, session_id, claims.sub).await?;
+    if session.status != "uploading" {
+        return Err(StatusCode::CONFLICT);
+    }
+
+    let expected_size = expected_part_size(session.total_size, session.chunk_size as i64, part_index as i64)
+        .ok_or(StatusCode::BAD_REQUEST)?;
+    if body.len() as i64 != expected_size {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    // 전송 중 손상 확인
+    let expected_sha256 = headers
+        .get(CHUNK_SHA256_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().to_lowercase())
+        .ok_or(StatusCode::BAD_REQUEST)?;
+    let sha256 = sha256_hex(&body);
+    if sha256 != expected_sha256 {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    write_part(&state.config.storage.temp_dir, session.id, part_index, &body)
+        .await
+        .map_err(|e| {
+            error!("업로드 조각 저장 실패: session_id={}, part={}, {:?}", session.id, part_index, e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO upload_session_parts (session_id, part_index, size, sha256)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (session_id, part_index) DO UPDATE SET size = EXCLUDED.size, sha256 = EXCLUDED.sha256, created_at = NOW()
+        "#
+    )
+    .bind(session.id)
+    .bind(part_index)
+    .bind(body.len() as i32)
+    .bind(&sha256)
+    .execute(&state.pool)
+    .await
+    .map_err(|e| {
+        error!("업로드 조각 기록 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    // 업로드가 계속되는 동안은 세션 만료 연장
+    let session = sqlx::query_as::<_, UploadSession>(&format!(
+        "UPDATE upload_sessions SET expires_at = NOW() + make_interval(hours => $2), updated_at = NOW() WHERE id = $1 RETURNING {}",
+        SESSION_COLUMNS
+    ))
+    .bind(session.id)
+    .bind(state.config.storage.upload_session_ttl_hours as i32)
+    .fetch_one(&state.pool)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let message = format!("조각 {}/{} 업로드 완료", part_index + 1, session.total_parts);
+    let status = session_status(&state, session).await?;
+    Ok(Json(ApiResponse::success(status, message)))
+}
+
+// 업로드 완료 (조각 합치기 → 전체 SHA-256 확인 → 저장소 저장 → files 기록)
+pub async fn complete_upload_session(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Path(session_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<UploadResponse>>, StatusCode> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let session = find_my_session(&state, session_id, claims.sub).await?;
+    let received = received_parts(&state, session.id).await?;
+    if !missing_parts(session.total_parts, &received).is_empty() {
+        return Err(StatusCode::CONFLICT);
+    }
+
+    // 동시에 들어온 완료 요청은 하나만 처리
+    let claimed = sqlx::query(
+        "UPDATE upload_sessions SET status = 'completing', updated_at = NOW() WHERE id = $1 AND status = 'uploading'"
+    )
+    .bind(session.id)
+    .execute(&state.pool)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .rows_affected();
+    if claimed == 0 {
+        return Err(StatusCode::CONFLICT);
+    }
+
+    match finish_session(&state, &session).await {
+        Ok(response) => {
+            remove_session_dir(&state.config.storage.temp_dir, session.id).await;
+            Ok(Json(ApiResponse::success(response, "파일이 성공적으로 업로드되었습니다.")))
+        }
+        Err(status) => {
+            // 조각은 남겨 두고 다시 완료 요청할 수 있도록 되돌림
+            let _ = sqlx::query("UPDATE upload_sessions SET status = 'uploading', updated_at = NOW() WHERE id = $1")
+                .bind(session.id)
+                .execute(&state.pool)
+                .await;
+            Err(status)
+        }
+    }
+}
+
+// 업로드 취소 (세션과 받은 조각 삭제)
+pub async fn abort_upload_session(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Option<Claims>>,
+    Path(session_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, StatusCode> {
+    let claims = claims.ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let deleted = sqlx::query("DELETE FROM upload_sessions WHERE id = $1 AND user_id = $2 AND status = 'uploading'")
+        .bind(session_id)
+        .bind(claims.sub)
+        .execute(&state.pool)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .rows_affected();
+    if deleted == 0 {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    remove_session_dir(&state.config.storage.temp_dir, session_id).await;
+
+    Ok(Json(ApiResponse::success((), "업로드가 취소되었습니다.")))
+}
+
+async fn finish_session(state: &AppState, session: &UploadSession) -> Result<UploadResponse, StatusCode> {
+    let temp_dir = &state.config.storage.temp_dir;
+    let (data_path, size, sha256) = assemble_parts(temp_dir, session.id, session.total_parts)
+        .await
+        .map_err(|e| {
+            error!("업로드 조각 합치기 실패: session_id={}, {:?}", session.id, e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    if size as i64 != session.total_size || sha256 != session.sha256 {
+        let _ = tokio::fs::remove_file(&data_path).await;
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    // 확장자와 내용 확인, 게시판 허용 형식 확인
+    let extension = file_extension(&session.original_name);
+    let head = read_file_head(&data_path.to_string_lossy()).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let Some(mime_type) = content_mime_type(&extension, &head) else {
+        let _ = tokio::fs::remove_file(&data_path).await;
+        return Err(StatusCode::BAD_REQUEST);
+    };
+    if let Some(board_id) = session.board_id {
+        let board = find_board(state, board_id).await?;
+        let allowed = board.allowed_file_types.unwrap_or_default();
+        if !is_allowed_by_board(&allowed, mime_type, &extension) {
+            let _ = tokio::fs::remove_file(&data_path).await;
+            return Err(StatusCode::BAD_REQUEST);
+        }
+    }
+
+    let filename = format!(
+        "{}_{}_{}",
+        Uuid::new_v4(),
+        Utc::now().timestamp(),
+        sanitize_filename(&session.original_name)
+    );
+    let subfolder = if is_image_file(&extension) { "images" } else { "documents" };
+    let file_key = format!("posts/{}/{}", subfolder, filename);
+
+    // 위치 정보 등 EXIF는 저장 전에 제거
+    let size = strip_upload_file_metadata(state, mime_type, &data_path)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    // 검사가 끝날 때까지 격리 위치에 보관
+    state.storage
+        .put_file(&quarantine_key(&file_key), &data_path, mime_type)
+        .await
+        .map_err(|e| {
+            error!("업로드 파일 저장 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let file_info = register_post_file(
+        state,
+        session.user_id,
+        &session.original_name,
+        &filename,
+        &file_key,
+        size,
+        mime_type,
+        session.draft_id,
+        Some(session.id),
+    )
+    .await?;
+
+    Ok(UploadResponse {
+        filename,
+        url: file_info.url.clone(),
+        size,
+        mime_type: mime_type.to_string(),
+        file_info,
+        thumbnail_url: None, // 썸네일은 백그라운드에서 생성되므로 즉시 반환하지 않음
+    })
+}
+
+/// 본인 소유의 만료되지 않은 업로드 세션 조회
+async fn find_my_session(state: &AppState, session_id: Uuid, user_id: Uuid) -> Result<UploadSession, StatusCode> {
+    let session = sqlx::query_as::<_, UploadSession>(&format!(
+        "SELECT {} FROM upload_sessions WHERE id = $1 AND user_id = $2",
+        SESSION_COLUMNS
+    ))
+    .bind(session_id)
+    .bind(user_id)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|e| {
+        error!("업로드 세션 조회 실패: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    if session.status != "completed" && session.expires_at < Utc::now() {
+        return Err(StatusCode::GONE);
+    }
+    Ok(session)
+}
+
+async fn received_parts(state: &AppState, session_id: Uuid) -> Result<Vec<i32>, StatusCode> {
+    sqlx::query_scalar::<_, i32>(
+        "SELECT part_index FROM upload_session_parts WHERE session_id = $1 ORDER BY part_index"
+    )
+    .bind(session_id)
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+async fn session_status(state: &AppState, session: UploadSession) -> Result<UploadSessionStatus, StatusCode> {
+    let received = received_parts(state, session.id).await?;
+    Ok(UploadSessionStatus {
+        id: session.id,
+        missing_parts: missing_parts(session.total_parts, &received),
+        received_parts: received,
+        status: session.status,
+        original_name: session.original_name,
+        total_size: session.total_size,
+        chunk_size: session.chunk_size,
+        total_parts: session.total_parts,
+        file_id: session.file_id,
+        expires_at: session.expires_at,
+    })
+}
+
+fn file_extension(original_name: &str) -> String {
+    match original_name.rsplit_once('.') {
+        Some((_, ext)) if !ext.is_empty() => ext.to_lowercase(),
+        _ => "bin".to_string(),
+    }
+}
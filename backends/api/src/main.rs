@@ -10,18 +10,21 @@ mod utils;
 
 use axum::{
     extract::Request,
-    http::{Method, HeaderValue},
-    response::Response,
+    http::{Method, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
     routing::get,
     Router,
     middleware::Next,
 };
+use std::net::SocketAddr;
+use std::sync::Arc;
 use tower_http::services::fs::ServeDir;
 use tracing::{info, error, warn};
 use crate::config::Config;
 use redis::Client as RedisClient;
 use sqlx::PgPool;
 use crate::routes::{site_routes, admin_routes};
+use crate::services::{ImageCache, RealtimeService, Storage, TokenBlacklistService};
 
 // 애플리케이션 상태 구조체
 #[derive(Clone)]
@@ -29,6 +32,36 @@ pub struct AppState {
     pub pool: PgPool,
     pub config: Config,
     pub redis: RedisClient,
+    pub realtime: RealtimeService,
+    pub token_blacklist: TokenBlacklistService,
+    pub storage: Arc<dyn Storage>,
+    pub image_cache: Arc<ImageCache>,
+}
+
+// 업로드 파일 보안 헤더 미들웨어
+// 브라우저의 MIME 추측을 막고, 스크립트를 포함할 수 있는 SVG는 인라인으로 열리지 않도록 다운로드 처리
+async fn upload_security_headers(request: Request, next: Next) -> Response {
+    let is_svg = utils::file_sniff::has_svg_extension(request.uri().path());
+
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+
+    headers.insert("X-Content-Type-Options", HeaderValue::from_static("nosniff"));
+    if is_svg {
+        headers.insert("Content-Disposition", HeaderValue::from_static("attachment"));
+        headers.insert("Content-Security-Policy", HeaderValue::from_static("sandbox"));
+    }
+
+    response
+}
+
+// 바이러스 검사 전 격리된 업로드는 서빙하지 않음 (검사 중/악성 파일은 공개 위치에 없어 404)
+async fn block_quarantined_uploads(request: Request, next: Next) -> Response {
+    let key = request.uri().path().trim_start_matches("/uploads");
+    if services::is_quarantine_key(key) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    next.run(request).await
 }
 
 // CORS 미들웨어
@@ -50,18 +83,24 @@ async fn cors_middleware(request: Request, next: Next) -> Response {
         .filter(|s| !s.is_empty())
         .collect();
 
+    // 허용된 Origin에만 쿠키 전송 허용 (소셜 로그인 state 쿠키)
+    let is_allowed_origin = allowed_origins.contains(&origin);
+
     // OPTIONS preflight 처리
     if method == Method::OPTIONS {
         let mut response = Response::new(axum::body::Body::empty());
         let headers = response.headers_mut();
         
-        let allowed_origin = if allowed_origins.contains(&origin) {
+        let allowed_origin = if is_allowed_origin {
             origin
         } else {
             "*".to_string()
         };
         
         headers.insert("Access-Control-Allow-Origin", HeaderValue::from_str(&allowed_origin).unwrap_or_else(|_| HeaderValue::from_static("*")));
+        if is_allowed_origin {
+            headers.insert("Access-Control-Allow-Credentials", HeaderValue::from_static("true"));
+        }
         headers.insert("Access-Control-Allow-Methods", HeaderValue::from_static("GET, POST, PUT, DELETE, OPTIONS"));
         headers.insert("Access-Control-Allow-Headers", HeaderValue::from_static("authorization, content-type, x-requested-with"));
         headers.insert("Access-Control-Max-Age", HeaderValue::from_static("86400"));
@@ -72,7 +111,7 @@ async fn cors_middleware(request: Request, next: Next) -> Response {
     // 일반 요청 처리
     let mut response = next.run(request).await;
     
-    let allowed_origin = if allowed_origins.contains(&origin) {
+    let allowed_origin = if is_allowed_origin {
         origin
     } else {
         "*".to_string()
@@ -82,6 +121,9 @@ async fn cors_middleware(request: Request, next: Next) -> Response {
         "Access-Control-Allow-Origin",
         HeaderValue::from_str(&allowed_origin).unwrap_or_else(|_| HeaderValue::from_static("*"))
     );
+    if is_allowed_origin {
+        response.headers_mut().insert("Access-Control-Allow-Credentials", HeaderValue::from_static("true"));
+    }
 
     response
 }
@@ -103,19 +145,78 @@ async fn main() {
     
     // 데이터베이스 연결
     let pool = crate::database::get_database().await.expect("Failed to connect to database");
-    
 
+    // 저장소 이전 명령: mincenter-api storage-migrate --from local --to s3 [--dry-run]
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("storage-migrate") {
+        let code = services::run_storage_migrate_command(&pool, &config.storage, &args[1..]).await;
+        std::process::exit(code);
+    }
+    // 이미지 변환 서명 URL 생성: mincenter-api image-url <file_id> --w 320 --fmt webp
+    if args.first().map(String::as_str) == Some("image-url") {
+        std::process::exit(services::run_image_url_command(&config.image, &args[1..]));
+    }
+
+    // 업로드 파일 저장소
+    let storage = services::create_storage(&config.storage, &config.storage.backend)
+        .expect("Failed to initialize storage");
+    info!("Upload storage: {}", storage.backend());
     
     // Redis 연결
     let redis_url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://localhost:6379".to_string());
     let redis = RedisClient::open(redis_url).expect("Failed to connect to Redis");
+
+    // 캡차 제공자 설정 확인 (알 수 없는 CAPTCHA_PROVIDER면 시작하지 않음)
+    services::CaptchaService::new(redis.clone(), &config.captcha).expect("Invalid CAPTCHA_PROVIDER");
+
+    // 이미지 크기 변환 결과 캐시
+    let image_cache = Arc::new(ImageCache::open(&config.image.cache_dir, config.image.cache_max_mb).await);
+    if config.image.proxy_secret.is_empty() {
+        warn!("IMAGE_PROXY_SECRET이 설정되지 않아 이미지 크기 변환(/api/img)을 사용하지 않습니다.");
+    }
+    if config.image.avif_variants && !cfg!(feature = "avif") {
+        warn!("IMAGE_AVIF_VARIANTS가 설정되었지만 avif 기능 없이 빌드되어 AVIF 파일을 만들지 않습니다.");
+    }
     
     let state = AppState {
+        token_blacklist: TokenBlacklistService::new(pool.clone(), redis.clone()),
         pool,
         config,
+        realtime: RealtimeService::new(redis.clone()),
         redis,
+        storage,
+        image_cache,
     };
 
+    // 만료된 토큰 폐기 항목 정리 작업
+    services::spawn_token_blacklist_purger(state.token_blacklist.clone());
+
+    // 만료된 업로드 세션/임시 파일, 지난 일별 업로드 횟수 정리 작업
+    services::spawn_upload_session_sweeper(
+        state.pool.clone(),
+        state.config.storage.temp_dir.clone(),
+        state.config.storage.upload_session_ttl_hours,
+    );
+
+    // 썸네일/미디어 후처리 작업 큐
+    services::spawn_media_job_workers(
+        state.pool.clone(),
+        state.storage.clone(),
+        state.config.media_job.clone(),
+        state.config.virus_scan.clone(),
+        state.config.image.clone(),
+        state.config.video.clone(),
+        state.config.storage.temp_dir.clone(),
+    );
+
+    // 참조 없는 업로드 파일(고아 파일) 정리 작업
+    services::spawn_file_gc(
+        state.pool.clone(),
+        state.storage.clone(),
+        state.config.storage.orphan_grace_hours,
+        state.config.storage.file_gc_interval_minutes,
+    );
+
     // 라우터 모듈 사용
     let site_router = site_routes(state.clone());
     let admin_router = admin_routes(state.clone());
@@ -125,13 +226,21 @@ async fn main() {
         .route("/health", get(handlers::health_check));
     
 
+    // 업로드 파일 서빙 (로컬 디스크는 정적 파일, 그 외 저장소는 프록시/서명 URL)
+    let uploads_router = if state.storage.backend() == "local" {
+        Router::new().nest_service("/uploads", ServeDir::new(&state.config.storage.local_root))
+    } else {
+        Router::new().route("/uploads/*key", get(handlers::upload::serve_upload))
+    }
+    .layer(axum::middleware::from_fn(upload_security_headers))
+    .layer(axum::middleware::from_fn(block_quarantined_uploads));
+
     // 라우터 결합
     let app = Router::new()
         .merge(health_routes)
         .merge(site_router)
         .merge(admin_router)
-        // 정적 파일 서빙
-        .nest_service("/uploads", ServeDir::new("static/uploads"))
+        .merge(uploads_router)
         .with_state(state)
         .layer(axum::middleware::from_fn(cors_middleware));
 
@@ -150,7 +259,7 @@ async fn main() {
 
     info!("Server is running and ready to accept connections");
     
-    if let Err(e) = axum::serve(listener, app).await {
+    if let Err(e) = axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await {
         error!("Server error: {}", e);
         std::process::exit(1);
     }
@@ -18,6 +18,17 @@ pub mod cors;
 pub use rbac::*;
 pub use cors::*;
 
+// 로그아웃/비밀번호 변경/정지로 폐기된 토큰인지 확인
+pub(crate) async fn is_token_revoked(state: &AppState, token: &str, claims: &Claims) -> Result<bool, StatusCode> {
+    state.token_blacklist
+        .is_revoked(token, claims)
+        .await
+        .map_err(|e| {
+            error!("토큰 폐기 여부 확인 실패: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+}
+
 pub async fn optional_auth_middleware(
     State(state): State<AppState>,
     request: Request,
@@ -33,7 +44,11 @@ pub async fn optional_auth_middleware(
     let claims: Option<Claims> = if let Some(token) = auth_header {
         // 토큰이 있으면 검증
         match verify_token(token, &state.config) {
-            Ok(claims) => Some(claims),
+            // 폐기된 토큰은 비로그인으로 처리
+            Ok(claims) => match is_token_revoked(&state, token, &claims).await? {
+                false => Some(claims),
+                true => None,
+            },
             Err(_) => None, // 토큰이 유효하지 않으면 None
         }
     } else {
@@ -75,6 +90,11 @@ pub async fn auth_middleware(
             StatusCode::UNAUTHORIZED
         })?;
 
+    if is_token_revoked(&state, token, &claims).await? {
+        eprintln!("❌ 폐기된 토큰, 사용자 ID: {}", claims.sub);
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
     eprintln!("✅ 토큰 검증 성공, 사용자 ID: {}", claims.sub);
 
     // 요청에 사용자 정보 추가
@@ -102,6 +122,10 @@ pub async fn admin_middleware(
     let claims = verify_token(token, &state.config)
         .map_err(|_| StatusCode::UNAUTHORIZED)?;
 
+    if is_token_revoked(&state, token, &claims).await? {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
     // 요청에 사용자 정보 추가
     let mut request = request;
     request.extensions_mut().insert(claims);
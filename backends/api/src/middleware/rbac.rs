@@ -4,43 +4,35 @@ use axum::{
     middleware::Next,
     response::Response,
 };
-use crate::{AppState, utils::auth::Claims, models::User, errors::ApiError};
+use tracing::warn;
+use crate::{AppState, utils::auth::Claims, models::User, errors::ApiError, services::RbacService};
 
-// 권한 체크 미들웨어
+// 권한 체크 미들웨어 (admin_middleware 이후에 실행되어야 함)
 pub async fn check_permission_middleware(
     State(state): State<AppState>,
-    claims: Claims,
     request: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    let path = request.uri().path();
-    let method = request.method().as_str();
+    let claims = request
+        .extensions()
+        .get::<Claims>()
+        .cloned()
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let path = request.uri().path().to_string();
+    let method = request.method().as_str().to_string();
     
     // 리소스와 액션 매핑
-    let (resource, action) = map_path_to_permission(path, method);
+    let (resource, action) = map_path_to_permission(&path, &method);
     
-    // 권한 체크
-    let has_permission = sqlx::query_scalar::<_, bool>(
-        r#"
-        SELECT EXISTS(
-            SELECT 1 FROM permissions p
-            INNER JOIN role_permissions rp ON p.id = rp.permission_id
-            INNER JOIN user_roles ur ON rp.role_id = ur.role_id
-            WHERE ur.user_id = $1 
-            AND p.resource = $2 
-            AND p.action = $3 
-            AND p.is_active = true
-        )
-        "#
-    )
-    .bind(claims.sub)
-    .bind(resource)
-    .bind(action)
-    .fetch_one(&state.pool)
-    .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    // 권한 체크 (사용자별 권한 캐시 사용)
+    let has_permission = RbacService::new(state.pool.clone(), state.redis.clone())
+        .has_permission(claims.sub, resource, action)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     if !has_permission {
+        warn!("권한 없음: user_id={}, {} {} -> {}.{}", claims.sub, method, path, resource, action);
         return Err(StatusCode::FORBIDDEN);
     }
 
@@ -50,6 +42,13 @@ pub async fn check_permission_middleware(
 // 경로와 HTTP 메서드를 권한 리소스/액션으로 매핑
 fn map_path_to_permission(path: &str, method: &str) -> (&'static str, &'static str) {
     match (path, method) {
+        // 대시보드
+        (path, "GET") if path.starts_with("/api/admin/dashboard") => ("dashboard", "read"),
+
+        // 사용자 역할 관리
+        (path, _) if path.starts_with("/api/admin/users/") && path.ends_with("/roles") => ("users", "roles"),
+        (path, "POST") if path.starts_with("/api/admin/users/") && path.ends_with("/points") => ("users", "update"),
+
         // 사용자 관리
         (path, "GET") if path.starts_with("/api/admin/users") => ("users", "read"),
         (path, "POST") if path.starts_with("/api/admin/users") => ("users", "create"),
@@ -62,12 +61,23 @@ fn map_path_to_permission(path: &str, method: &str) -> (&'static str, &'static s
         (path, "PUT") if path.starts_with("/api/admin/boards") => ("boards", "update"),
         (path, "DELETE") if path.starts_with("/api/admin/boards") => ("boards", "delete"),
         
+        // 게시글 이동/숨김 (모더레이션)
+        (path, "POST" | "PUT") if path.starts_with("/api/admin/posts")
+            && (path.ends_with("/move") || path.ends_with("/hide") || path.ends_with("/unhide") || path.ends_with("/hide-status")) => ("posts", "moderate"),
+        (path, "GET") if path.starts_with("/api/admin/statistics") => ("posts", "read"),
+        (path, "POST") if path.starts_with("/api/admin/posts") && path.ends_with("/restore") => ("posts", "update"),
+
         // 게시글 관리
         (path, "GET") if path.starts_with("/api/admin/posts") => ("posts", "read"),
         (path, "POST") if path.starts_with("/api/admin/posts") => ("posts", "create"),
         (path, "PUT") if path.starts_with("/api/admin/posts") => ("posts", "update"),
         (path, "DELETE") if path.starts_with("/api/admin/posts") => ("posts", "delete"),
         
+        // 댓글 숨김 (모더레이션)
+        (path, "POST") if path.starts_with("/api/admin/comments")
+            && (path.ends_with("/hide") || path.ends_with("/unhide")) => ("comments", "moderate"),
+        (path, "POST") if path.starts_with("/api/admin/comments") && path.ends_with("/restore") => ("comments", "update"),
+
         // 댓글 관리
         (path, "GET") if path.starts_with("/api/admin/comments") => ("comments", "read"),
         (path, "POST") if path.starts_with("/api/admin/comments") => ("comments", "create"),
@@ -77,6 +87,11 @@ fn map_path_to_permission(path: &str, method: &str) -> (&'static str, &'static s
         // 사이트 설정
         (path, "GET") if path.starts_with("/api/admin/site/settings") => ("settings", "read"),
         (path, "PUT") if path.starts_with("/api/admin/site/settings") => ("settings", "update"),
+        (path, "POST") if path.starts_with("/api/admin/upload/site") => ("settings", "update"),
+        (path, "GET") if path.starts_with("/api/admin/files/gc") => ("settings", "read"),
+        (path, "POST") if path.starts_with("/api/admin/files/gc") => ("settings", "update"),
+        (path, "GET") if path.starts_with("/api/admin/media-jobs") => ("settings", "read"),
+        (path, "POST") if path.starts_with("/api/admin/media-jobs") => ("settings", "update"),
         
         // 메뉴 관리
         (path, "GET") if path.starts_with("/api/admin/menus") => ("menus", "read"),
@@ -96,6 +111,13 @@ fn map_path_to_permission(path: &str, method: &str) -> (&'static str, &'static s
         (path, "PUT") if path.starts_with("/api/admin/calendar") => ("calendar", "update"),
         (path, "DELETE") if path.starts_with("/api/admin/calendar") => ("calendar", "delete"),
         
+        // 신고 관리
+        (path, "GET") if path.starts_with("/api/admin/reports") => ("reports", "read"),
+        (path, "POST") if path.starts_with("/api/admin/reports") => ("reports", "moderate"),
+
+        // 알림 관리
+        (path, "POST") if path.starts_with("/api/admin/notifications") => ("notifications", "create"),
+
         // 역할 관리
         (path, "GET") if path.starts_with("/api/admin/roles") => ("roles", "read"),
         (path, "POST") if path.starts_with("/api/admin/roles") => ("roles", "create"),
@@ -104,9 +126,10 @@ fn map_path_to_permission(path: &str, method: &str) -> (&'static str, &'static s
         
         // 권한 관리
         (path, "GET") if path.starts_with("/api/admin/permissions") => ("permissions", "read"),
+        (path, "POST" | "PUT" | "DELETE") if path.starts_with("/api/admin/permissions") => ("permissions", "assign"),
         (path, "POST") if path.starts_with("/api/admin/check-permission") => ("permissions", "assign"),
         
-        // 기본값: 읽기 권한
+        // 기본값: 읽기 권한 (해당 권한이 부여되지 않은 경우 접근 거부)
         _ => ("general", "read"),
     }
 }
@@ -119,25 +142,10 @@ pub fn require_permission(resource: &'static str, action: &'static str) -> impl
         let action_clone = action;
         
         Box::pin(async move {
-            let has_permission = sqlx::query_scalar::<_, bool>(
-                r#"
-                SELECT EXISTS(
-                    SELECT 1 FROM permissions p
-                    INNER JOIN role_permissions rp ON p.id = rp.permission_id
-                    INNER JOIN user_roles ur ON rp.role_id = ur.role_id
-                    WHERE ur.user_id = $1 
-                    AND p.resource = $2 
-                    AND p.action = $3 
-                    AND p.is_active = true
-                )
-                "#
-            )
-            .bind(claims.sub)
-            .bind(resource_clone)
-            .bind(action_clone)
-            .fetch_one(&state_clone.pool)
-            .await
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            let has_permission = RbacService::new(state_clone.pool.clone(), state_clone.redis.clone())
+                .has_permission(claims.sub, resource_clone, action_clone)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
             if !has_permission {
                 return Err(StatusCode::FORBIDDEN);
@@ -154,4 +162,27 @@ pub fn require_role(user: &User, required_role: &str) -> Result<(), ApiError> {
         Some(role) if role.to_string().to_lowercase() == required_role.to_lowercase() => Ok(()),
         _ => Err(ApiError::Forbidden("관리자 권한이 필요합니다.".to_string()))
     }
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_path_to_permission() {
+        assert_eq!(map_path_to_permission("/api/admin/users", "GET"), ("users", "read"));
+        assert_eq!(map_path_to_permission("/api/admin/users/123/roles", "PUT"), ("users", "roles"));
+        assert_eq!(map_path_to_permission("/api/admin/users/123/points", "POST"), ("users", "update"));
+        assert_eq!(map_path_to_permission("/api/admin/users/123/points", "GET"), ("users", "read"));
+        assert_eq!(map_path_to_permission("/api/admin/posts/123/hide", "POST"), ("posts", "moderate"));
+        assert_eq!(map_path_to_permission("/api/admin/posts/123", "DELETE"), ("posts", "delete"));
+        assert_eq!(map_path_to_permission("/api/admin/permissions/123", "PUT"), ("permissions", "assign"));
+        assert_eq!(map_path_to_permission("/api/admin/notifications/broadcast", "POST"), ("notifications", "create"));
+        assert_eq!(map_path_to_permission("/api/admin/comments/123/hide", "POST"), ("comments", "moderate"));
+        assert_eq!(map_path_to_permission("/api/admin/posts/123/revisions/456/restore", "POST"), ("posts", "update"));
+        assert_eq!(map_path_to_permission("/api/admin/comments/123/revisions/456/restore", "POST"), ("comments", "update"));
+        assert_eq!(map_path_to_permission("/api/admin/reports", "GET"), ("reports", "read"));
+        assert_eq!(map_path_to_permission("/api/admin/reports/123/resolve", "POST"), ("reports", "moderate"));
+        assert_eq!(map_path_to_permission("/api/admin/unknown", "GET"), ("general", "read"));
+    }
+}
@@ -3,6 +3,15 @@ use sqlx::FromRow;
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 
+// 수정/삭제 제한 기준 (edit_comment_limit, delete_comment_limit 값의 단위)
+pub const COMMENT_LIMIT_UNIT_COMMENTS: &str = "comments";
+pub const COMMENT_LIMIT_UNIT_MINUTES: &str = "minutes";
+
+// 에디터 종류 (markdown은 서버에서 HTML로 변환 후 저장)
+pub const EDITOR_TYPE_RICH: &str = "rich";
+pub const EDITOR_TYPE_SIMPLE: &str = "simple";
+pub const EDITOR_TYPE_MARKDOWN: &str = "markdown";
+
 #[derive(Debug, Serialize, Deserialize, FromRow)]
 pub struct Board {
     pub id: Uuid,
@@ -45,6 +54,7 @@ pub struct Board {
     // 수정/삭제 제한
     pub edit_comment_limit: i32,
     pub delete_comment_limit: i32,
+    pub comment_limit_unit: String, // comments: 댓글 수 기준, minutes: 작성 후 경과 시간(분) 기준
     // 추가 기능
     pub use_sns: bool,
     pub use_captcha: bool,
@@ -58,6 +68,8 @@ pub struct Board {
     pub download_point: i32,
     // iframe 도메인 설정
     pub allowed_iframe_domains: Option<Vec<String>>,
+    // 이메일 인증 회원만 글/댓글 작성
+    pub require_email_verified: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -94,6 +106,7 @@ pub struct CreateBoardRequest {
     pub show_ip: Option<bool>,
     pub edit_comment_limit: Option<i32>,
     pub delete_comment_limit: Option<i32>,
+    pub comment_limit_unit: Option<String>,
     pub use_sns: Option<bool>,
     pub use_captcha: Option<bool>,
     pub title_length: Option<i32>,
@@ -103,6 +116,7 @@ pub struct CreateBoardRequest {
     pub comment_point: Option<i32>,
     pub download_point: Option<i32>,
     pub allowed_iframe_domains: Option<Vec<String>>,
+    pub require_email_verified: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -137,6 +151,7 @@ pub struct UpdateBoardRequest {
     pub show_ip: Option<bool>,
     pub edit_comment_limit: Option<i32>,
     pub delete_comment_limit: Option<i32>,
+    pub comment_limit_unit: Option<String>,
     pub use_sns: Option<bool>,
     pub use_captcha: Option<bool>,
     pub title_length: Option<i32>,
@@ -146,6 +161,7 @@ pub struct UpdateBoardRequest {
     pub comment_point: Option<i32>,
     pub download_point: Option<i32>,
     pub allowed_iframe_domains: Option<Vec<String>>,
+    pub require_email_verified: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, FromRow)]
@@ -211,6 +227,7 @@ pub struct BoardResponse {
     pub show_ip: bool,
     pub edit_comment_limit: i32,
     pub delete_comment_limit: i32,
+    pub comment_limit_unit: String,
     pub use_sns: bool,
     pub use_captcha: bool,
     pub title_length: i32,
@@ -220,6 +237,7 @@ pub struct BoardResponse {
     pub comment_point: i32,
     pub download_point: i32,
     pub allowed_iframe_domains: Option<Vec<String>>,
+    pub require_email_verified: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
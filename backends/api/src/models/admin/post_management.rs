@@ -25,7 +25,7 @@ pub struct PostHideHistory {
     pub hide_reason: Option<String>,
     pub hide_category: String,
     pub hide_tags: Option<Vec<String>>,
-    pub hidden_by: Uuid,
+    pub hidden_by: Option<Uuid>, // 신고 누적 자동 숨김은 NULL
     pub hidden_at: DateTime<Utc>,
     pub hide_location: String,
     pub is_hidden: bool,
@@ -49,7 +49,7 @@ pub struct CreatePostHideHistory {
     pub hide_reason: Option<String>,
     pub hide_category: String,
     pub hide_tags: Option<Vec<String>>,
-    pub hidden_by: Uuid,
+    pub hidden_by: Option<Uuid>,
     pub hide_location: String,
 }
 
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+// 캡차 응답 (캡차 사용 게시판의 글/답글/댓글 작성 요청에 함께 전달)
+// 외부 제공자는 위젯 응답 토큰, 수식 캡차는 문제 ID와 답을 보냄
+// /api/captcha/verify에서 받은 검증 완료 토큰도 captcha_token으로 사용 가능
+#[derive(Debug, Default, Deserialize)]
+pub struct CaptchaSubmission {
+    pub captcha_token: Option<String>,
+    pub captcha_id: Option<String>,
+    pub captcha_answer: Option<String>,
+}
+
+// 캡차 문제 발급 응답 (외부 제공자는 site_key로 프론트엔드 위젯 표시)
+#[derive(Debug, Serialize)]
+pub struct CaptchaChallenge {
+    pub provider: String,
+    pub site_key: Option<String>,
+    pub captcha_id: Option<String>,
+    pub image: Option<String>, // data:image/png;base64,...
+    pub expires_in: u64,
+}
+
+// 캡차 확인 응답 (1회용 검증 완료 토큰)
+#[derive(Debug, Serialize)]
+pub struct CaptchaVerifyResponse {
+    pub captcha_token: String,
+    pub expires_in: u64,
+}
@@ -19,6 +19,8 @@ pub struct File {
     pub compression_ratio: Option<f64>,
     pub has_thumbnails: bool,
     pub processing_status: ProcessingStatus,
+    #[sqlx(default)]
+    pub download_count: i32,
     pub created_at: DateTime<Utc>,
 }
 
@@ -115,4 +117,146 @@ pub struct FileInfo {
     pub mime_type: String, // NOT NULL로 변경됨
     pub file_type: FileType,
     pub url: String,
-} 
\ No newline at end of file
+} 
+// 청크 업로드 세션
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct UploadSession {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub board_id: Option<Uuid>,
+    pub draft_id: Option<Uuid>,
+    pub original_name: String,
+    pub total_size: i64,
+    pub chunk_size: i32,
+    pub total_parts: i32,
+    pub sha256: String,
+    pub status: String, // uploading, completing, completed
+    pub file_id: Option<Uuid>,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+// 업로드 세션 생성 요청
+#[derive(Debug, Deserialize)]
+pub struct CreateUploadSessionRequest {
+    pub original_name: String,
+    pub total_size: i64,
+    pub chunk_size: i64,
+    pub sha256: String, // 전체 파일 SHA-256 (hex)
+    pub board_id: Option<Uuid>, // 게시판 파일 크기/형식 제한 적용
+    pub draft_id: Option<Uuid>, // 임시저장 글에 첨부하는 경우
+}
+
+// 업로드 세션 상태 응답
+#[derive(Debug, Serialize)]
+pub struct UploadSessionStatus {
+    pub id: Uuid,
+    pub status: String,
+    pub original_name: String,
+    pub total_size: i64,
+    pub chunk_size: i32,
+    pub total_parts: i32,
+    pub received_parts: Vec<i32>,
+    pub missing_parts: Vec<i32>,
+    pub file_id: Option<Uuid>,
+    pub expires_at: DateTime<Utc>,
+}
+
+// 고아 파일 정리 대상
+#[derive(Debug, Serialize, FromRow)]
+pub struct FileGcEntry {
+    pub id: Uuid,
+    pub original_name: String,
+    pub file_path: String,
+    pub file_size: i64,
+    pub created_at: DateTime<Utc>,
+    pub orphaned_at: Option<DateTime<Utc>>,
+}
+
+// 고아 파일 정리 결과 (dry_run이면 실제 변경 없이 대상만 집계)
+#[derive(Debug, Serialize)]
+pub struct FileGcReport {
+    pub dry_run: bool,
+    pub grace_hours: i64,
+    pub restored: u64,            // 다시 참조되어 상태를 되돌린 파일 수
+    pub marked: Vec<FileGcEntry>, // 이번에 고아로 전환된 파일
+    pub deleted: Vec<FileGcEntry>, // 삭제된 파일 (원본 + 썸네일)
+    pub failed: u64,
+    pub reclaimed_bytes: u64,
+}
+
+// 고아 파일 정리 쿼리 파라미터
+#[derive(Debug, Deserialize)]
+pub struct FileGcQuery {
+    pub grace_hours: Option<i64>,
+}
+
+// 썸네일/미디어 후처리 작업
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct MediaJob {
+    pub id: Uuid,
+    pub job_type: String,
+    pub file_id: Option<Uuid>,
+    pub file_key: String,
+    pub status: String,
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub run_at: DateTime<Utc>,
+    pub locked_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+// 작업 목록 쿼리 (기본값: 실패한 작업)
+#[derive(Debug, Deserialize)]
+pub struct MediaJobQuery {
+    pub status: Option<String>,
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+}
+
+// 이미지 크기 변환 요청 (/api/img/:file_id, sig는 서명된 파라미터의 HMAC)
+#[derive(Debug, Deserialize)]
+pub struct ImageProxyQuery {
+    pub w: Option<u32>,
+    pub h: Option<u32>,
+    pub fit: Option<String>, // contain(기본값), cover
+    pub fmt: Option<String>, // jpeg, png, webp, avif (없으면 원본 형식)
+    pub exp: i64,            // 서명 만료 시각 (unix 초)
+    pub sig: String,
+}
+
+// 썸네일 형식별 srcset ("URL 250w, URL 800w", image_sizes 기준)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImageSrcsets {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub srcset: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webp_srcset: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avif_srcset: Option<String>,
+}
+
+// 동영상 정보 (ffprobe 결과, files.media_info에 저장)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VideoMetadata {
+    pub duration_seconds: Option<f64>,
+    pub width: Option<i32>, // 회전 정보를 반영한 표시 크기
+    pub height: Option<i32>,
+    pub video_codec: Option<String>,
+    pub audio_codec: Option<String>,
+    pub frame_rate: Option<f64>,
+    pub bit_rate: Option<i64>,
+}
+
+// 사용자 저장 공간 / 업로드 한도 사용량 (한도가 None이면 무제한)
+#[derive(Debug, Clone, Serialize)]
+pub struct StorageUsage {
+    pub used_bytes: i64,
+    pub file_count: i64,
+    pub quota_bytes: Option<i64>,
+    pub uploads_today: i32,
+    pub daily_upload_limit: Option<i32>,
+}
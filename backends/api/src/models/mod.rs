@@ -1,15 +1,27 @@
 pub mod admin;
 pub mod calendar;
+pub mod captcha;
 pub mod file;
+pub mod notification;
+pub mod point;
+pub mod report;
 pub mod response;
+pub mod revision;
 pub mod rbac;
 pub mod site;
+pub mod social_account;
 pub mod user;
 
 pub use admin::*;
 pub use calendar::*;
+pub use captcha::*;
 pub use file::*;
+pub use notification::*;
+pub use point::*;
+pub use report::*;
 pub use response::*;
+pub use revision::*;
 pub use rbac::*;
 pub use site::*;
+pub use social_account::*;
 pub use user::*;
\ No newline at end of file
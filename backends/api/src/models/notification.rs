@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+// 알림 종류
+#[derive(Debug, Serialize, Deserialize, sqlx::Type, Clone, Copy, PartialEq)]
+#[sqlx(type_name = "notification_type", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationType {
+    Comment,
+    Like,
+    System,
+    Announcement,
+}
+
+// 알림 모델
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct Notification {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    #[sqlx(rename = "type")]
+    #[serde(rename = "type")]
+    pub notification_type: NotificationType,
+    pub title: String,
+    pub message: String,
+    pub entity_type: Option<String>,
+    pub entity_id: Option<Uuid>,
+    pub is_read: Option<bool>,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+// 알림 목록 조회 쿼리
+#[derive(Debug, Deserialize)]
+pub struct NotificationQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    pub unread_only: Option<bool>,
+}
+
+// 읽지 않은 알림 수
+#[derive(Debug, Serialize)]
+pub struct UnreadCountResponse {
+    pub count: i64,
+}
+
+// 공지 알림 발송 요청 (role이 없으면 전체 사용자)
+#[derive(Debug, Deserialize)]
+pub struct BroadcastAnnouncementRequest {
+    pub title: String,
+    pub message: String,
+    pub role: Option<String>,
+}
+
+// 공지 알림 발송 결과
+#[derive(Debug, Serialize)]
+pub struct BroadcastAnnouncementResponse {
+    pub sent_count: u64,
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+// 포인트 거래 종류
+pub const POINT_TYPE_POST_WRITE: &str = "post_write";
+pub const POINT_TYPE_COMMENT_WRITE: &str = "comment_write";
+pub const POINT_TYPE_POST_READ: &str = "post_read";
+pub const POINT_TYPE_FILE_DOWNLOAD: &str = "file_download";
+pub const POINT_TYPE_ADMIN_GRANT: &str = "admin_grant";
+pub const POINT_TYPE_ADMIN_REVOKE: &str = "admin_revoke";
+
+// 포인트 거래 내역
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct PointTransaction {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    #[sqlx(rename = "type")]
+    #[serde(rename = "type")]
+    pub transaction_type: String,
+    pub amount: i32,
+    pub reason: String,
+    pub reference_type: Option<String>,
+    pub reference_id: Option<Uuid>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+// 포인트 내역 조회 응답 (현재 잔액 포함)
+#[derive(Debug, Serialize)]
+pub struct PointHistoryResponse {
+    pub balance: i32,
+    pub transactions: Vec<PointTransaction>,
+}
+
+// 포인트 내역 조회 쿼리
+#[derive(Debug, Deserialize)]
+pub struct PointHistoryQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+}
+
+// 관리자 포인트 지급/차감 요청 (amount가 음수면 차감)
+#[derive(Debug, Deserialize)]
+pub struct AdjustPointsRequest {
+    pub amount: i32,
+    pub reason: String,
+}
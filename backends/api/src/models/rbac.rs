@@ -62,10 +62,9 @@ pub struct UpdateRoleRequest {
     pub permissions: Option<Vec<Uuid>>, // 권한 ID 목록
 }
 
-// 사용자 역할 할당 요청
+// 사용자 역할 할당 요청 (사용자 ID는 경로에서 전달)
 #[derive(Debug, Deserialize)]
 pub struct AssignUserRoleRequest {
-    pub user_id: Uuid,
     pub role_ids: Vec<Uuid>, // 역할 ID 목록
 }
 
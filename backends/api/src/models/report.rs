@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+// 신고 처리 상태
+pub const REPORT_STATUS_PENDING: &str = "pending";
+pub const REPORT_STATUS_RESOLVED: &str = "resolved";
+pub const REPORT_STATUS_DISMISSED: &str = "dismissed";
+
+// 신고 모델
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct Report {
+    pub id: Uuid,
+    pub reporter_id: Uuid,
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    pub reason: String,
+    pub status: Option<String>,
+    pub admin_note: Option<String>,
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+// 관리자 신고 목록 항목 (신고자/대상 정보 포함)
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct ReportListItem {
+    pub id: Uuid,
+    pub reporter_id: Uuid,
+    pub reporter_name: Option<String>,
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    pub entity_preview: Option<String>, // 게시글 제목 또는 댓글 내용 일부
+    pub reason: String,
+    pub status: Option<String>,
+    pub admin_note: Option<String>,
+    pub resolved_by: Option<Uuid>,
+    pub resolver_name: Option<String>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub report_count: Option<i64>, // 같은 대상에 대한 신고자 수
+}
+
+// 신고 접수 요청
+#[derive(Debug, Deserialize)]
+pub struct CreateReportRequest {
+    pub entity_type: String, // "post" | "comment"
+    pub entity_id: Uuid,
+    pub reason: String,
+}
+
+// 신고 목록 조회 쿼리
+#[derive(Debug, Deserialize)]
+pub struct ReportQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    pub status: Option<String>,
+    pub entity_type: Option<String>,
+}
+
+// 신고 처리 요청
+#[derive(Debug, Deserialize)]
+pub struct ResolveReportRequest {
+    pub action: String, // "hide" (숨김 처리) | "dismiss" (기각) | "restore" (기각 후 숨김 해제)
+    pub hide_category: Option<String>,
+    pub admin_note: Option<String>,
+}
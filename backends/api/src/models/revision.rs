@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+// 수정 이력 대상
+pub const REVISION_ENTITY_POST: &str = "post";
+pub const REVISION_ENTITY_COMMENT: &str = "comment";
+
+// 게시글/댓글 수정 이력 (수정 직전의 내용)
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct ContentRevision {
+    pub id: Uuid,
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    pub title: Option<String>, // 댓글은 None
+    pub content: String,
+    #[sqlx(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_source: Option<String>, // markdown 게시판 글의 Markdown 원문
+    pub edited_by: Option<Uuid>, // 이 내용을 바꾼 사용자
+    pub editor_name: Option<String>,
+    pub created_at: DateTime<Utc>, // 바뀐 시각
+}
+
+// 단어 단위 변경 내역 (op: equal | insert | delete)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DiffChange {
+    pub op: String,
+    pub text: String,
+}
+
+// 수정 이력 상세 (이 버전과 바로 다음 버전의 차이)
+#[derive(Debug, Serialize)]
+pub struct RevisionDiff {
+    #[serde(flatten)]
+    pub revision: ContentRevision,
+    pub next_title: Option<String>,
+    pub changes: Vec<DiffChange>,
+}
@@ -3,7 +3,8 @@ use sqlx::FromRow;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 use std::str::FromStr;
-use crate::models::file::FilePurpose;
+use crate::models::captcha::CaptchaSubmission;
+use crate::models::file::{FilePurpose, ImageSrcsets};
 
 // 게시글 상태 enum
 #[derive(Debug, Serialize, Deserialize, sqlx::Type)]
@@ -47,7 +48,7 @@ pub struct Post {
     pub id: Uuid,
     pub board_id: Uuid,
     pub category_id: Option<Uuid>,
-    pub user_id: Uuid,
+    pub user_id: Option<Uuid>, // 비회원 글은 None
     pub parent_id: Option<Uuid>, // 답글의 경우 부모 게시글 ID
     pub title: String,
     pub content: String, // NOT NULL로 변경됨
@@ -71,6 +72,16 @@ pub struct AttachedFile {
     pub mime_type: String, // NOT NULL로 변경됨
     pub file_purpose: Option<FilePurpose>,
     pub display_order: Option<i32>,
+    pub download_count: i32,
+    // 동영상 정보 (후처리 완료 후)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_seconds: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poster_url: Option<String>,
 }
 
 // 게시글 상세 정보 (사용자 정보 포함)
@@ -79,7 +90,7 @@ pub struct PostDetail {
     pub id: Uuid,
     pub board_id: Uuid,
     pub category_id: Option<Uuid>,
-    pub user_id: Uuid,
+    pub user_id: Option<Uuid>, // 비회원 글은 None
     pub parent_id: Option<Uuid>, // 답글의 경우 부모 게시글 ID
     pub title: String,
     pub content: String, // NOT NULL로 변경됨
@@ -104,6 +115,12 @@ pub struct PostDetail {
     pub thumbnail_urls: Option<ThumbnailUrls>,
     #[sqlx(skip)]
     pub is_liked: Option<bool>,
+    #[sqlx(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>, // 작성자 IP (관리자는 전체, show_ip 게시판은 일부 가림)
+    #[sqlx(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_source: Option<String>, // markdown 게시판 글의 Markdown 원문 (수정 화면에서 사용)
 }
 
 // 댓글 모델
@@ -139,6 +156,9 @@ pub struct CommentDetail {
     pub user_name: String,
     #[sqlx(skip)]
     pub is_liked: Option<bool>,
+    #[sqlx(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>, // 작성자 IP (관리자는 전체, show_ip 게시판은 일부 가림)
 }
 
 // 게시글 생성 요청
@@ -150,6 +170,9 @@ pub struct CreatePostRequest {
     pub content: String,
     pub is_notice: Option<bool>,
     pub attached_files: Option<Vec<String>>,
+    pub draft_id: Option<Uuid>, // 임시저장 글에서 발행하는 경우
+    #[serde(flatten)]
+    pub captcha: CaptchaSubmission,
 }
 
 // 답글 생성 요청
@@ -159,6 +182,8 @@ pub struct CreateReplyRequest {
     pub title: String,
     pub content: String,
     pub attached_files: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub captcha: CaptchaSubmission,
 }
 
 // 게시글 수정 요청
@@ -172,12 +197,41 @@ pub struct UpdatePostRequest {
     pub attached_files: Option<Vec<String>>,
 }
 
+// 비회원 게시글 작성 요청 (allow_anonymous 게시판)
+#[derive(Debug, Deserialize)]
+pub struct CreateGuestPostRequest {
+    pub category_id: Option<Uuid>,
+    pub title: String,
+    pub content: String,
+    pub guest_name: String,
+    pub guest_password: String,
+    #[serde(flatten)]
+    pub captcha: CaptchaSubmission,
+}
+
+// 비회원 게시글 수정 요청 (작성 시 입력한 비밀번호로 확인)
+#[derive(Debug, Deserialize)]
+pub struct UpdateGuestPostRequest {
+    pub guest_password: String,
+    pub category_id: Option<Uuid>,
+    pub title: Option<String>,
+    pub content: Option<String>,
+}
+
+// 비회원 게시글 비밀번호 확인 / 삭제 요청
+#[derive(Debug, Deserialize)]
+pub struct GuestPasswordRequest {
+    pub guest_password: String,
+}
+
 // 댓글 생성 요청
 #[derive(Debug, Deserialize)]
 pub struct CreateCommentRequest {
     pub post_id: String, // 압축된 ID 지원을 위해 String으로 변경
     pub parent_id: Option<Uuid>,
     pub content: String,
+    #[serde(flatten)]
+    pub captcha: CaptchaSubmission,
 }
 
 // 댓글 수정 요청
@@ -294,6 +348,10 @@ pub struct ThumbnailUrls {
     pub thumb: Option<String>,   // 목록용 (150x150)
     pub card: Option<String>,    // 카드용 (300x200)  
     pub large: Option<String>,   // 본문용 (800x600)
+    #[serde(flatten)]
+    pub srcsets: ImageSrcsets,   // 반응형 이미지용 (<img srcset>, <source type="image/webp">)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poster: Option<String>,  // 동영상 포스터 원본 (<video poster>)
 }
 
 // API 응답용 구조체들 (short_id 포함)
@@ -303,7 +361,7 @@ pub struct PostDetailResponse {
     pub short_id: String, // Base62 압축된 ID
     pub board_id: Uuid,
     pub category_id: Option<Uuid>,
-    pub user_id: Uuid,
+    pub user_id: Option<Uuid>, // 비회원 글은 None
     pub parent_id: Option<Uuid>,
     pub title: String,
     pub content: String,
@@ -325,6 +383,8 @@ pub struct PostDetailResponse {
     pub attached_files: Option<Vec<AttachedFile>>,
     pub thumbnail_urls: Option<ThumbnailUrls>,
     pub is_liked: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -417,6 +477,7 @@ impl PostDetail {
             attached_files: self.attached_files,
             thumbnail_urls: self.thumbnail_urls,
             is_liked: self.is_liked,
+            ip_address: self.ip_address,
         }
     }
 }
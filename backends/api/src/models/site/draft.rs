@@ -0,0 +1,59 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::models::site::community::AttachedFile;
+
+// 임시저장 글 모델
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct Draft {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub board_id: Uuid,
+    pub category_id: Option<Uuid>,
+    pub title: Option<String>,
+    pub content: Option<String>,
+    pub content_source: Option<String>, // markdown 게시판의 Markdown 원문
+    pub auto_save_count: Option<i32>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+// 임시저장 글 상세 (첨부파일 포함)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DraftDetail {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub board_id: Uuid,
+    pub category_id: Option<Uuid>,
+    pub title: Option<String>,
+    pub content: Option<String>,
+    pub content_source: Option<String>, // markdown 게시판의 Markdown 원문
+    pub auto_save_count: Option<i32>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub attached_files: Vec<AttachedFile>,
+}
+
+// 임시저장 생성/자동저장 요청
+// 보내지 않은 항목은 None(유지), null은 Some(None)(비우기)
+#[derive(Debug, Deserialize)]
+pub struct SaveDraftRequest {
+    #[serde(default, deserialize_with = "double_option")]
+    pub category_id: Option<Option<Uuid>>,
+    #[serde(default, deserialize_with = "double_option")]
+    pub title: Option<Option<String>>,
+    #[serde(default, deserialize_with = "double_option")]
+    pub content: Option<Option<String>>,
+}
+
+// 값이 있으면 null이어도 Some으로 감싸 생략된 항목과 구분
+fn double_option<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
@@ -1,9 +1,12 @@
 pub mod community;
+pub mod draft;
 pub mod menu;
 pub mod page;
+pub mod search;
 pub mod settings;
 
 pub use community::*;
 pub use menu::*;
 pub use page::*;
+pub use search::*;
 pub use settings::*; 
\ No newline at end of file
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, NaiveDate, Utc};
+
+// 검색 대상 필드
+pub const SEARCH_FIELDS: [&str; 4] = ["title", "content", "author", "comment"];
+
+// 게시글 검색 쿼리
+#[derive(Debug, Deserialize)]
+pub struct SearchQuery {
+    pub q: String,
+    pub fields: Option<String>, // 쉼표 구분 (title,content,author,comment), 없으면 전체
+    pub board: Option<String>,  // 게시판 slug
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub sort: Option<String>, // "relevance" (기본) | "latest"
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+}
+
+// 게시글 검색 결과 (하이라이트는 <mark> 태그로 표시)
+#[derive(Debug, Serialize, FromRow)]
+pub struct SearchResult {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub board_slug: String,
+    pub board_name: String,
+    pub user_name: Option<String>,
+    pub title: String,
+    pub title_highlight: Option<String>,
+    pub snippet: Option<String>,
+    pub matched_comment: Option<String>,
+    pub views: Option<i32>,
+    pub likes: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub rank: f32,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+// 연결된 소셜 계정 (제공자 토큰은 응답에 포함하지 않음)
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct SocialAccount {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub provider: String,
+    pub provider_id: String,
+    pub provider_email: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+// 제공자 인증 페이지 주소 응답
+#[derive(Debug, Serialize)]
+pub struct OAuthAuthorizeResponse {
+    pub authorize_url: String,
+    pub state: String,
+}
+
+// 제공자 인증 후 콜백 요청 (프론트엔드가 code/state를 전달)
+#[derive(Debug, Deserialize)]
+pub struct OAuthCallbackRequest {
+    pub code: String,
+    pub state: String,
+    pub service_type: Option<String>,
+}
@@ -12,24 +12,46 @@ pub fn admin_routes(state: AppState) -> Router<AppState> {
         .route("/api/admin/login", post(handlers::admin::admin_login))
         .route("/api/admin/refresh", post(handlers::admin::admin_refresh));
 
-    // 관리자 보호 라우터 (미들웨어 적용)
-    let admin_protected_routes = Router::new()
+    // 관리자 세션 라우터 (인증만 필요, 권한 체크 안함)
+    let admin_session_routes = Router::new()
         // 관리자 로그아웃
         .route("/api/admin/logout", post(handlers::admin::admin_logout))
         // 관리자 프로필
         .route("/api/admin/me", get(handlers::admin::admin_me))
+        .layer(axum::middleware::from_fn_with_state(state.clone(), middleware::admin_middleware));
+
+    // 관리자 보호 라우터 (인증 + 역할 기반 권한 체크 적용)
+    let admin_protected_routes = Router::new()
         // 대시보드
         .route("/api/admin/dashboard/stats", get(handlers::admin::get_dashboard_stats))
         // 사용자 관리
         .route("/api/admin/users", get(handlers::admin::get_users))
         .route("/api/admin/users/:id", get(handlers::admin::get_user))
         .route("/api/admin/users/:id", put(handlers::admin::update_user))
+        .route("/api/admin/users/:id/roles", get(handlers::admin::get_user_permissions))
+        .route("/api/admin/users/:id/roles", put(handlers::admin::assign_user_roles))
+        .route("/api/admin/users/:id/points", get(handlers::admin::get_user_points))
+        .route("/api/admin/users/:id/points", post(handlers::admin::adjust_user_points))
+        // 역할 관리
+        .route("/api/admin/roles", get(handlers::admin::get_roles))
+        .route("/api/admin/roles", post(handlers::admin::create_role))
+        .route("/api/admin/roles/:id", get(handlers::admin::get_role))
+        .route("/api/admin/roles/:id", put(handlers::admin::update_role))
+        .route("/api/admin/roles/:id", delete(handlers::admin::delete_role))
+        // 권한 관리
+        .route("/api/admin/permissions", get(handlers::admin::get_permissions))
+        .route("/api/admin/permissions", post(handlers::admin::create_permission))
+        .route("/api/admin/permissions/:id", put(handlers::admin::update_permission))
+        .route("/api/admin/permissions/:id", delete(handlers::admin::delete_permission))
+        .route("/api/admin/check-permission", post(handlers::admin::check_permission))
         // 게시글 관리
         .route("/api/admin/posts", get(handlers::admin::get_posts))
         .route("/api/admin/posts", post(handlers::admin::create_post))
         .route("/api/admin/posts/:id", get(handlers::admin::get_post))
         .route("/api/admin/posts/:id", put(handlers::admin::update_post))
         .route("/api/admin/posts/:id", delete(handlers::admin::delete_post))
+        .route("/api/admin/posts/:id/revisions", get(handlers::admin::get_post_revisions))
+        .route("/api/admin/posts/:id/revisions/:revision_id/restore", post(handlers::admin::restore_post_revision))
         // 게시판 관리
         .route("/api/admin/boards", get(handlers::board::list_boards))
         .route("/api/admin/boards", post(handlers::board::create_board))
@@ -42,6 +64,13 @@ pub fn admin_routes(state: AppState) -> Router<AppState> {
         .route("/api/admin/boards/:id/categories/:category_id", delete(handlers::board::delete_category))
         // 댓글 관리
         .route("/api/admin/comments", get(handlers::admin::get_comments))
+        .route("/api/admin/comments/:id/hide", post(handlers::admin::hide_comment))
+        .route("/api/admin/comments/:id/unhide", post(handlers::admin::unhide_comment))
+        .route("/api/admin/comments/:id/revisions", get(handlers::admin::get_comment_revisions))
+        .route("/api/admin/comments/:id/revisions/:revision_id/restore", post(handlers::admin::restore_comment_revision))
+        // 신고 관리
+        .route("/api/admin/reports", get(handlers::admin::get_reports))
+        .route("/api/admin/reports/:id/resolve", post(handlers::admin::resolve_report))
         // 메뉴 관리
         .route("/api/admin/menus", get(handlers::admin_menu::get_menus))
         .route("/api/admin/menus", post(handlers::admin_menu::create_menu))
@@ -58,14 +87,26 @@ pub fn admin_routes(state: AppState) -> Router<AppState> {
         .route("/api/admin/calendar/events", post(handlers::calendar::create_event))
         .route("/api/admin/calendar/events/:id", put(handlers::calendar::update_event))
         .route("/api/admin/calendar/events/:id", delete(handlers::calendar::delete_event))
+        // 알림 관리
+        .route("/api/admin/notifications/broadcast", post(handlers::admin::broadcast_announcement))
         // 사이트 설정
         .route("/api/admin/site/settings", get(handlers::admin::settings::get_site_settings))
         .route("/api/admin/site/settings", put(handlers::admin::settings::save_site_settings))
         // 파일 업로드 (관리자용)
         .route("/api/admin/upload/site", post(handlers::admin_upload::upload_site_file))
+        // 고아 파일 정리 (GET: 미리보기, POST: 실행)
+        .route("/api/admin/files/gc", get(handlers::admin::preview_file_gc))
+        .route("/api/admin/files/gc", post(handlers::admin::run_file_gc))
+        // 미디어 후처리 작업 (실패 목록, 재시도)
+        .route("/api/admin/media-jobs", get(handlers::admin::get_media_jobs))
+        .route("/api/admin/media-jobs/:id/retry", post(handlers::admin::retry_failed_media_job))
         // 게시글 관리 (이동, 숨김 등)
         .nest("/api/admin", handlers::admin::post_management::post_management_routes())
+        // 레이어는 나중에 추가된 것이 먼저 실행됨: admin_middleware(인증) -> check_permission_middleware(권한)
+        .layer(axum::middleware::from_fn_with_state(state.clone(), middleware::check_permission_middleware))
         .layer(axum::middleware::from_fn_with_state(state.clone(), middleware::admin_middleware));
 
-    admin_auth_routes.merge(admin_protected_routes)
+    admin_auth_routes
+        .merge(admin_session_routes)
+        .merge(admin_protected_routes)
 }
\ No newline at end of file
@@ -13,6 +13,16 @@ pub fn site_routes(state: AppState) -> Router<AppState> {
         .route("/api/auth/register", post(handlers::auth::register))
         .route("/api/auth/refresh", post(handlers::auth::refresh))
         .route("/api/auth/logout", post(handlers::auth::logout))
+        // 이메일 인증 / 비밀번호 재설정
+        .route("/api/auth/email/verify", post(handlers::account::verify_email))
+        .route("/api/auth/password/forgot", post(handlers::account::forgot_password))
+        .route("/api/auth/password/reset", post(handlers::account::reset_password))
+        // 소셜 로그인
+        .route("/api/auth/oauth/:provider/authorize", get(handlers::oauth::get_oauth_authorize_url))
+        .route("/api/auth/oauth/:provider/callback", post(handlers::oauth::oauth_login))
+        // 캡차
+        .route("/api/captcha/challenge", post(handlers::captcha::issue_captcha_challenge))
+        .route("/api/captcha/verify", post(handlers::captcha::verify_captcha))
         // Community
         .route("/api/community/boards", get(handlers::community::get_boards))
         .route("/api/community/posts", get(handlers::community::get_posts))
@@ -23,6 +33,11 @@ pub fn site_routes(state: AppState) -> Router<AppState> {
         .route("/api/community/boards/:slug/posts", get(handlers::community::get_posts_by_slug))
         .route("/api/community/boards/:slug/categories", get(handlers::community::get_categories_by_slug))
         .route("/api/community/boards-with-categories", get(handlers::community::get_boards_with_categories))
+        .route("/api/community/search", get(handlers::search::search_posts))
+        // 비회원 글쓰기 (allow_anonymous 게시판, 비밀번호로 수정/삭제)
+        .route("/api/community/boards/:slug/guest-posts", post(handlers::guest_post::create_guest_post))
+        .route("/api/community/guest-posts/:id", put(handlers::guest_post::update_guest_post).delete(handlers::guest_post::delete_guest_post))
+        .route("/api/community/guest-posts/:id/verify", post(handlers::guest_post::verify_guest_post_password))
         // Pages (공개)
         .route("/api/pages", get(handlers::page::get_published_pages))
         .route("/api/pages/:slug", get(handlers::page::get_page_by_slug))
@@ -37,15 +52,36 @@ pub fn site_routes(state: AppState) -> Router<AppState> {
         .route("/api/upload/posts/chunk", post(handlers::upload::upload_post_file_chunk))
         .route("/api/upload/profiles", post(handlers::upload::upload_profile_file))
         .route("/api/upload/site", post(handlers::upload::upload_site_file))
+        .route("/api/upload/usage", get(handlers::upload::get_storage_usage))
+        // 이어 올리기 가능한 업로드 세션
+        .route("/api/upload/sessions", post(handlers::upload_session::create_upload_session))
+        .route("/api/upload/sessions/:session_id", get(handlers::upload_session::get_upload_session).delete(handlers::upload_session::abort_upload_session))
+        .route(
+            "/api/upload/sessions/:session_id/parts/:part_index",
+            put(handlers::upload_session::upload_session_part)
+                .layer(axum::extract::DefaultBodyLimit::max(state.config.storage.max_chunk_size)),
+        )
+        .route("/api/upload/sessions/:session_id/complete", post(handlers::upload_session::complete_upload_session))
         // 파일 다운로드
         .route("/api/upload/files/:file_id/download", get(handlers::upload::download_original_file))
         // 썸네일 상태 확인
         .route("/api/upload/files/:file_id/thumbnail-status", get(handlers::upload::check_thumbnail_status))
+        // 이미지 크기 변환 (서명된 파라미터)
+        .route("/api/img/:file_id", get(handlers::image_proxy::get_resized_image))
+        // 실시간 이벤트 (SSE, 쿼리 토큰 인증 허용)
+        .route("/api/events/stream", get(handlers::realtime::stream_events))
         .layer(axum::middleware::from_fn_with_state(state.clone(), middleware::optional_auth_middleware));
 
     let protected_routes = Router::new()
         // 인증된 사용자 API
         .route("/api/auth/me", get(handlers::auth::me))
+        .route("/api/auth/password", put(handlers::auth::change_password))
+        .route("/api/auth/email/verification", post(handlers::account::request_email_verification))
+        // 소셜 계정 연결
+        .route("/api/auth/social-accounts", get(handlers::oauth::get_social_accounts))
+        .route("/api/auth/social-accounts/:provider/authorize", get(handlers::oauth::get_social_link_authorize_url))
+        .route("/api/auth/social-accounts/:provider", post(handlers::oauth::link_social_account))
+        .route("/api/auth/social-accounts/:provider", delete(handlers::oauth::unlink_social_account))
         // Community (인증된 사용자)
         .route("/api/community/posts", post(handlers::community::create_post))
         .route("/api/community/posts/:id", put(handlers::community::update_post))
@@ -55,11 +91,31 @@ pub fn site_routes(state: AppState) -> Router<AppState> {
         .route("/api/community/comments/:id", delete(handlers::community::delete_comment))
         .route("/api/community/boards/:slug/posts", post(handlers::community::create_post_by_slug))
         .route("/api/community/boards/:slug/replies", post(handlers::community::create_reply_by_slug))
+        // 수정 이력 API (작성자/관리자)
+        .route("/api/community/posts/:id/revisions", get(handlers::revision::get_post_revisions))
+        .route("/api/community/posts/:id/revisions/:revision_id", get(handlers::revision::get_post_revision))
+        .route("/api/community/comments/:id/revisions", get(handlers::revision::get_comment_revisions))
+        .route("/api/community/comments/:id/revisions/:revision_id", get(handlers::revision::get_comment_revision))
+        // 임시저장 API
+        .route("/api/community/boards/:slug/drafts", get(handlers::draft::get_my_drafts))
+        .route("/api/community/boards/:slug/drafts", post(handlers::draft::create_draft))
+        .route("/api/community/drafts/:id", get(handlers::draft::get_draft))
+        .route("/api/community/drafts/:id", put(handlers::draft::update_draft))
+        .route("/api/community/drafts/:id", delete(handlers::draft::delete_draft))
         // 좋아요 API
         .route("/api/community/posts/:id/like", post(handlers::community::toggle_post_like))
         .route("/api/community/posts/:id/like/status", get(handlers::community::get_post_like_status))
         .route("/api/community/comments/:id/like", post(handlers::community::toggle_comment_like))
         .route("/api/community/comments/:id/like/status", get(handlers::community::get_comment_like_status))
+        // 신고 API
+        .route("/api/community/reports", post(handlers::report::create_report))
+        // 포인트 API
+        .route("/api/points/history", get(handlers::point::get_my_point_history))
+        // 알림 API
+        .route("/api/notifications", get(handlers::notification::get_notifications))
+        .route("/api/notifications/unread-count", get(handlers::notification::get_unread_notification_count))
+        .route("/api/notifications/read-all", put(handlers::notification::mark_all_notifications_read))
+        .route("/api/notifications/:id/read", put(handlers::notification::mark_notification_read))
         // 파일 삭제 API
         .route("/api/upload/files/:file_id", delete(handlers::upload::delete_file))
         .route("/api/community/posts/:post_id/attachments/:file_id", delete(handlers::upload::delete_post_attachment))
@@ -0,0 +1,306 @@
+use chrono::{Duration, Utc};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use redis::Client as RedisClient;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::errors::ApiError;
+use crate::services::{create_mailer, MailMessage, TokenBlacklistService};
+use crate::utils::auth::hash_password;
+
+// 토큰 용도
+const PURPOSE_VERIFY_EMAIL: &str = "verify_email";
+const PURPOSE_RESET_PASSWORD: &str = "reset_password";
+
+// 토큰 유효 시간
+const VERIFY_EMAIL_TOKEN_HOURS: i64 = 24;
+const RESET_PASSWORD_TOKEN_MINUTES: i64 = 30;
+
+// 같은 사용자에게 같은 메일을 다시 보낼 수 있는 간격
+const RESEND_COOLDOWN_SECONDS: u64 = 60;
+
+const MIN_PASSWORD_LENGTH: usize = 8;
+
+// 메일 링크용 서명 토큰 (액세스 토큰과 다른 키로 서명)
+#[derive(Debug, Serialize, Deserialize)]
+struct EmailTokenClaims {
+    sub: Uuid,
+    purpose: String,
+    email: String,
+    // 비밀번호 재설정 토큰: 발급 당시 비밀번호 해시의 지문 (재설정 후 같은 토큰 재사용 방지)
+    fingerprint: Option<String>,
+    exp: i64,
+    iat: i64,
+}
+
+/// 이메일 인증 및 비밀번호 재설정 메일 발송/확인
+pub struct AccountEmailService {
+    pool: PgPool,
+    redis: RedisClient,
+    config: Config,
+    token_blacklist: TokenBlacklistService,
+}
+
+impl AccountEmailService {
+    pub fn new(pool: PgPool, redis: RedisClient, config: Config, token_blacklist: TokenBlacklistService) -> Self {
+        Self { pool, redis, config, token_blacklist }
+    }
+
+    /// 이메일 인증 메일 발송
+    pub async fn send_verification(&self, user_id: Uuid) -> Result<(), ApiError> {
+        let (email, name, email_verified) = sqlx::query_as::<_, (String, String, Option<bool>)>(
+            "SELECT email, name, email_verified FROM users WHERE id = $1"
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("사용자를 찾을 수 없습니다.".to_string()))?;
+
+        if email_verified.unwrap_or(false) {
+            return Err(ApiError::BadRequest("이미 인증된 이메일입니다.".to_string()));
+        }
+        if !self.acquire_cooldown(PURPOSE_VERIFY_EMAIL, user_id).await {
+            return Err(ApiError::BadRequest("잠시 후 다시 요청해주세요.".to_string()));
+        }
+
+        let token = create_email_token(
+            &self.config.jwt_secret,
+            user_id,
+            PURPOSE_VERIFY_EMAIL,
+            &email,
+            None,
+            Duration::hours(VERIFY_EMAIL_TOKEN_HOURS),
+        )?;
+        let link = format!("{}/auth/verify-email?token={}", self.config.site_url, token);
+
+        let message = MailMessage {
+            to: email,
+            subject: "[MinCenter] 이메일 인증".to_string(),
+            body: format!(
+                "{}님, 안녕하세요.\n\n아래 링크를 눌러 이메일 인증을 완료해주세요. 링크는 {}시간 동안 유효합니다.\n\n{}\n\n본인이 요청하지 않았다면 이 메일을 무시해주세요.\n",
+                name, VERIFY_EMAIL_TOKEN_HOURS, link
+            ),
+        };
+        create_mailer(&self.config.mail).send(&message).await
+    }
+
+    /// 이메일 인증 확인
+    pub async fn verify_email(&self, token: &str) -> Result<(), ApiError> {
+        let claims = decode_email_token(&self.config.jwt_secret, token, PURPOSE_VERIFY_EMAIL)
+            .ok_or_else(|| ApiError::BadRequest("인증 링크가 올바르지 않거나 만료되었습니다.".to_string()))?;
+
+        // 메일 발송 후 이메일이 변경되었으면 무효
+        let result = sqlx::query(
+            r#"
+            UPDATE users
+            SET email_verified = TRUE, email_verified_at = COALESCE(email_verified_at, NOW()), updated_at = NOW()
+            WHERE id = $1 AND email = $2
+            "#
+        )
+        .bind(claims.sub)
+        .bind(&claims.email)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(ApiError::BadRequest("인증 링크가 올바르지 않거나 만료되었습니다.".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// 비밀번호 재설정 메일 발송
+    /// 가입 여부를 노출하지 않도록 없는 이메일도 성공으로 처리
+    pub async fn send_password_reset(&self, email: &str) -> Result<(), ApiError> {
+        let user = sqlx::query_as::<_, (Uuid, String, String, Option<String>)>(
+            "SELECT id, email, name, password_hash FROM users WHERE LOWER(email) = LOWER($1) AND status = 'active'"
+        )
+        .bind(email.trim())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some((user_id, email, name, password_hash)) = user else {
+            return Ok(());
+        };
+        if !self.acquire_cooldown(PURPOSE_RESET_PASSWORD, user_id).await {
+            return Ok(());
+        }
+
+        let token = create_email_token(
+            &self.config.jwt_secret,
+            user_id,
+            PURPOSE_RESET_PASSWORD,
+            &email,
+            Some(password_fingerprint(password_hash.as_deref())),
+            Duration::minutes(RESET_PASSWORD_TOKEN_MINUTES),
+        )?;
+        let link = format!("{}/auth/reset-password?token={}", self.config.site_url, token);
+
+        let message = MailMessage {
+            to: email,
+            subject: "[MinCenter] 비밀번호 재설정".to_string(),
+            body: format!(
+                "{}님, 안녕하세요.\n\n아래 링크에서 새 비밀번호를 설정해주세요. 링크는 {}분 동안 유효하며 한 번만 사용할 수 있습니다.\n\n{}\n\n본인이 요청하지 않았다면 이 메일을 무시해주세요.\n",
+                name, RESET_PASSWORD_TOKEN_MINUTES, link
+            ),
+        };
+        create_mailer(&self.config.mail).send(&message).await
+    }
+
+    /// 비밀번호 재설정 (재설정 후 모든 기기의 토큰 폐기)
+    pub async fn reset_password(&self, token: &str, new_password: &str) -> Result<(), ApiError> {
+        if new_password.len() < MIN_PASSWORD_LENGTH {
+            return Err(ApiError::Validation(format!("비밀번호는 {}자 이상이어야 합니다.", MIN_PASSWORD_LENGTH)));
+        }
+
+        let invalid = || ApiError::BadRequest("재설정 링크가 올바르지 않거나 만료되었습니다.".to_string());
+        let claims = decode_email_token(&self.config.jwt_secret, token, PURPOSE_RESET_PASSWORD).ok_or_else(invalid)?;
+
+        let mut tx = self.pool.begin().await?;
+
+        let password_hash = sqlx::query_scalar::<_, Option<String>>(
+            "SELECT password_hash FROM users WHERE id = $1 AND email = $2 FOR UPDATE"
+        )
+        .bind(claims.sub)
+        .bind(&claims.email)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(invalid)?;
+
+        // 이미 사용했거나 그 사이 비밀번호가 바뀐 토큰은 거부
+        if claims.fingerprint.as_deref() != Some(password_fingerprint(password_hash.as_deref()).as_str()) {
+            return Err(invalid());
+        }
+
+        // 메일을 받은 것으로 이메일 소유가 확인되므로 인증 처리
+        sqlx::query(
+            r#"
+            UPDATE users
+            SET password_hash = $1, email_verified = TRUE, email_verified_at = COALESCE(email_verified_at, NOW()), updated_at = NOW()
+            WHERE id = $2
+            "#
+        )
+        .bind(hash_password(new_password))
+        .bind(claims.sub)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        self.token_blacklist
+            .revoke_all_for_user(claims.sub, self.config.access_token_expiry)
+            .await
+    }
+
+    /// 재발송 간격 확인 (Redis를 사용할 수 없으면 허용)
+    async fn acquire_cooldown(&self, purpose: &str, user_id: Uuid) -> bool {
+        let mut conn = match self.redis.get_async_connection().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                warn!("메일 재발송 제한 Redis 연결 실패: {}", e);
+                return true;
+            }
+        };
+
+        let result: Result<Option<String>, redis::RedisError> = redis::cmd("SET")
+            .arg(format!("mail:cooldown:{}:{}", purpose, user_id))
+            .arg(1)
+            .arg("NX")
+            .arg("EX")
+            .arg(RESEND_COOLDOWN_SECONDS)
+            .query_async(&mut conn)
+            .await;
+
+        match result {
+            Ok(reply) => reply.is_some(),
+            Err(e) => {
+                warn!("메일 재발송 제한 확인 실패: {}", e);
+                true
+            }
+        }
+    }
+}
+
+/// 이메일 인증 여부 (글/댓글 작성 제한 확인용)
+pub async fn is_email_verified(pool: &PgPool, user_id: Uuid) -> Result<bool, sqlx::Error> {
+    let verified = sqlx::query_scalar::<_, Option<bool>>("SELECT email_verified FROM users WHERE id = $1")
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?
+        .flatten()
+        .unwrap_or(false);
+
+    Ok(verified)
+}
+
+fn email_token_key(jwt_secret: &str) -> String {
+    format!("{}:email", jwt_secret)
+}
+
+fn create_email_token(
+    jwt_secret: &str,
+    user_id: Uuid,
+    purpose: &str,
+    email: &str,
+    fingerprint: Option<String>,
+    ttl: Duration,
+) -> Result<String, ApiError> {
+    let now = Utc::now();
+    let claims = EmailTokenClaims {
+        sub: user_id,
+        purpose: purpose.to_string(),
+        email: email.to_string(),
+        fingerprint,
+        exp: (now + ttl).timestamp(),
+        iat: now.timestamp(),
+    };
+
+    encode(&Header::default(), &claims, &EncodingKey::from_secret(email_token_key(jwt_secret).as_bytes()))
+        .map_err(|e| ApiError::Internal(format!("토큰 생성 실패: {}", e)))
+}
+
+/// 서명/만료/용도가 모두 맞을 때만 claims 반환
+fn decode_email_token(jwt_secret: &str, token: &str, purpose: &str) -> Option<EmailTokenClaims> {
+    let claims = decode::<EmailTokenClaims>(
+        token,
+        &DecodingKey::from_secret(email_token_key(jwt_secret).as_bytes()),
+        &Validation::default(),
+    )
+    .ok()?
+    .claims;
+
+    (claims.purpose == purpose).then_some(claims)
+}
+
+/// 비밀번호 해시 지문 (비밀번호가 없는 소셜 로그인 계정은 빈 문자열 기준)
+fn password_fingerprint(password_hash: Option<&str>) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(password_hash.unwrap_or("").as_bytes());
+    format!("{:x}", hasher.finalize())[..16].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_email_token_purpose_and_secret() {
+        let user_id = Uuid::new_v4();
+        let token = create_email_token("secret", user_id, PURPOSE_RESET_PASSWORD, "a@b.c", Some(password_fingerprint(Some("hash"))), Duration::minutes(5)).unwrap();
+
+        let claims = decode_email_token("secret", &token, PURPOSE_RESET_PASSWORD).unwrap();
+        assert_eq!(claims.sub, user_id);
+        assert_eq!(claims.fingerprint, Some(password_fingerprint(Some("hash"))));
+        assert_ne!(password_fingerprint(Some("hash")), password_fingerprint(Some("other")));
+
+        assert!(decode_email_token("secret", &token, PURPOSE_VERIFY_EMAIL).is_none());
+        assert!(decode_email_token("other", &token, PURPOSE_RESET_PASSWORD).is_none());
+
+        let expired = create_email_token("secret", user_id, PURPOSE_VERIFY_EMAIL, "a@b.c", None, Duration::minutes(-5)).unwrap();
+        assert!(decode_email_token("secret", &expired, PURPOSE_VERIFY_EMAIL).is_none());
+    }
+}
@@ -0,0 +1,441 @@
+use async_trait::async_trait;
+use base64::{engine::general_purpose, Engine as _};
+use image::{ImageOutputFormat, Rgb, RgbImage};
+use rand::{distributions::Alphanumeric, Rng};
+use redis::{AsyncCommands, Client as RedisClient};
+use serde::Deserialize;
+use std::io::Cursor;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tracing::warn;
+
+use crate::config::CaptchaConfig;
+use crate::errors::ApiError;
+use crate::models::{CaptchaChallenge, CaptchaSubmission, CaptchaVerifyResponse};
+
+// 수식 문제 정답 / 검증 완료 토큰 보관 (1회용)
+const CHALLENGE_KEY_PREFIX: &str = "captcha:challenge:";
+const PASS_KEY_PREFIX: &str = "captcha:pass:";
+const CHALLENGE_ID_LENGTH: usize = 32;
+const PASS_TOKEN_LENGTH: usize = 48;
+
+// 비밀 키가 없다는 경고는 한 번만 출력
+static SECRET_MISSING_WARNED: AtomicBool = AtomicBool::new(false);
+
+// 제공자별 기본 siteverify 주소
+const HCAPTCHA_VERIFY_URL: &str = "https://api.hcaptcha.com/siteverify";
+const RECAPTCHA_VERIFY_URL: &str = "https://www.google.com/recaptcha/api/siteverify";
+const TURNSTILE_VERIFY_URL: &str = "https://challenges.cloudflare.com/turnstile/v0/siteverify";
+
+/// 캡차 검증 추상화
+#[async_trait]
+pub trait CaptchaVerifier: Send + Sync {
+    fn provider(&self) -> &str;
+
+    /// 서버에서 만드는 문제 발급 (문제 ID, 이미지 data URL)
+    /// 외부 제공자는 프론트엔드 위젯이 문제를 표시하므로 None
+    async fn issue(&self) -> Result<Option<(String, String)>, ApiError> {
+        Ok(None)
+    }
+
+    async fn verify(&self, submission: &CaptchaSubmission, remote_ip: Option<&str>) -> Result<bool, ApiError>;
+}
+
+/// CAPTCHA_PROVIDER 설정에 맞는 검증기 생성 (none이면 검증하지 않음)
+/// 알 수 없는 제공자는 캡차 없이 통과시키지 않도록 오류
+pub fn create_captcha_verifier(config: &CaptchaConfig, redis: &RedisClient) -> Result<Option<Box<dyn CaptchaVerifier>>, ApiError> {
+    let default_url = match config.provider.as_str() {
+        "none" => return Ok(None),
+        "math" => {
+            return Ok(Some(Box::new(MathCaptcha {
+                redis: redis.clone(),
+                ttl_seconds: config.ttl_seconds,
+            })))
+        }
+        "hcaptcha" => HCAPTCHA_VERIFY_URL,
+        "recaptcha" => RECAPTCHA_VERIFY_URL,
+        "turnstile" => TURNSTILE_VERIFY_URL,
+        other => return Err(ApiError::Internal(format!("지원하지 않는 캡차 제공자입니다: {}", other))),
+    };
+
+    if config.secret_key.is_empty() && !SECRET_MISSING_WARNED.swap(true, Ordering::Relaxed) {
+        warn!("CAPTCHA_SECRET_KEY가 없어 {} 캡차를 확인할 수 없습니다.", config.provider);
+    }
+    Ok(Some(Box::new(SiteVerifyCaptcha {
+        provider: config.provider.clone(),
+        secret_key: config.secret_key.clone(),
+        verify_url: if config.verify_url.is_empty() {
+            default_url.to_string()
+        } else {
+            config.verify_url.clone()
+        },
+        min_score: config.min_score,
+        timeout: Duration::from_secs(config.timeout_seconds.max(1)),
+    })))
+}
+
+// siteverify 응답 (hCaptcha, reCAPTCHA, Turnstile 공통 형식)
+#[derive(Debug, Deserialize)]
+struct SiteVerifyResponse {
+    success: bool,
+    score: Option<f64>, // reCAPTCHA v3
+    #[serde(rename = "error-codes", default)]
+    error_codes: Vec<String>,
+}
+
+/// hCaptcha / reCAPTCHA / Turnstile 서버 검증 (위젯 응답 토큰을 siteverify로 확인)
+pub struct SiteVerifyCaptcha {
+    provider: String,
+    secret_key: String,
+    verify_url: String,
+    min_score: f64,
+    timeout: Duration,
+}
+
+#[async_trait]
+impl CaptchaVerifier for SiteVerifyCaptcha {
+    fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    async fn verify(&self, submission: &CaptchaSubmission, remote_ip: Option<&str>) -> Result<bool, ApiError> {
+        let token = match submission.captcha_token.as_deref().map(str::trim) {
+            Some(token) if !token.is_empty() => token,
+            _ => return Ok(false),
+        };
+
+        let mut form = vec![("secret", self.secret_key.as_str()), ("response", token)];
+        if let Some(ip) = remote_ip {
+            form.push(("remoteip", ip));
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .build()
+            .map_err(|e| ApiError::Internal(e.to_string()))?;
+        let response = client
+            .post(&self.verify_url)
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| {
+                warn!("캡차 검증 요청 실패: provider={}, error={}", self.provider, e);
+                ApiError::Internal("캡차 제공자에 연결할 수 없습니다.".to_string())
+            })?
+            .json::<SiteVerifyResponse>()
+            .await
+            .map_err(|e| {
+                warn!("캡차 검증 응답 해석 실패: provider={}, error={}", self.provider, e);
+                ApiError::Internal("캡차 제공자 응답을 해석할 수 없습니다.".to_string())
+            })?;
+
+        if !response.success {
+            warn!("캡차 검증 실패: provider={}, errors={:?}", self.provider, response.error_codes);
+            return Ok(false);
+        }
+        Ok(score_passes(response.score, self.min_score))
+    }
+}
+
+/// 자체 수식 이미지 캡차 (외부 연결 없이 사용, 정답은 Redis에 1회용으로 보관)
+pub struct MathCaptcha {
+    redis: RedisClient,
+    ttl_seconds: u64,
+}
+
+#[async_trait]
+impl CaptchaVerifier for MathCaptcha {
+    fn provider(&self) -> &str {
+        "math"
+    }
+
+    async fn issue(&self) -> Result<Option<(String, String)>, ApiError> {
+        // thread_rng는 await 전에 사용을 끝냄
+        let (captcha_id, answer, png) = {
+            let mut rng = rand::thread_rng();
+            let (question, answer) = math_question(&mut rng);
+            let png = render_captcha_png(&question, &mut rng)?;
+            (random_token(&mut rng, CHALLENGE_ID_LENGTH), answer, png)
+        };
+
+        let mut redis_conn = redis_connection(&self.redis).await?;
+        redis_conn
+            .set_ex::<_, _, ()>(format!("{}{}", CHALLENGE_KEY_PREFIX, captcha_id), answer, self.ttl_seconds)
+            .await
+            .map_err(|e| ApiError::Internal(format!("Redis 저장 실패: {}", e)))?;
+
+        let image = format!("data:image/png;base64,{}", general_purpose::STANDARD.encode(png));
+        Ok(Some((captcha_id, image)))
+    }
+
+    async fn verify(&self, submission: &CaptchaSubmission, _remote_ip: Option<&str>) -> Result<bool, ApiError> {
+        let (captcha_id, answer) = match (submission.captcha_id.as_deref(), submission.captcha_answer.as_deref()) {
+            (Some(id), Some(answer)) if is_token(id, CHALLENGE_ID_LENGTH) => (id, answer),
+            _ => return Ok(false),
+        };
+
+        // 틀려도 문제는 폐기 (같은 문제로 여러 번 시도하지 못하게)
+        let mut redis_conn = redis_connection(&self.redis).await?;
+        let expected: Option<String> = redis::cmd("GETDEL")
+            .arg(format!("{}{}", CHALLENGE_KEY_PREFIX, captcha_id))
+            .query_async(&mut redis_conn)
+            .await
+            .map_err(|e| ApiError::Internal(format!("Redis 조회 실패: {}", e)))?;
+
+        Ok(expected.is_some_and(|expected| check_answer(&expected, answer)))
+    }
+}
+
+/// 캡차 문제 발급, 확인, 검증 완료 토큰 관리
+pub struct CaptchaService {
+    redis: RedisClient,
+    site_key: Option<String>,
+    ttl_seconds: u64,
+    verifier: Option<Box<dyn CaptchaVerifier>>,
+}
+
+impl CaptchaService {
+    pub fn new(redis: RedisClient, config: &CaptchaConfig) -> Result<Self, ApiError> {
+        Ok(Self {
+            verifier: create_captcha_verifier(config, &redis)?,
+            redis,
+            site_key: Some(config.site_key.clone()).filter(|key| !key.is_empty()),
+            ttl_seconds: config.ttl_seconds,
+        })
+    }
+
+    /// 캡차 문제 발급 (외부 제공자는 위젯용 site_key만 반환)
+    pub async fn issue_challenge(&self) -> Result<CaptchaChallenge, ApiError> {
+        let verifier = match &self.verifier {
+            Some(verifier) => verifier,
+            None => {
+                return Ok(CaptchaChallenge {
+                    provider: "none".to_string(),
+                    site_key: None,
+                    captcha_id: None,
+                    image: None,
+                    expires_in: 0,
+                })
+            }
+        };
+
+        let issued = verifier.issue().await?;
+        Ok(CaptchaChallenge {
+            provider: verifier.provider().to_string(),
+            site_key: self.site_key.clone(),
+            expires_in: if issued.is_some() { self.ttl_seconds } else { 0 },
+            captcha_id: issued.as_ref().map(|(id, _)| id.clone()),
+            image: issued.map(|(_, image)| image),
+        })
+    }
+
+    /// 캡차 확인 후 글/댓글 작성에 사용할 1회용 검증 완료 토큰 발급
+    pub async fn issue_pass(&self, submission: &CaptchaSubmission, remote_ip: Option<&str>) -> Result<CaptchaVerifyResponse, ApiError> {
+        let verifier = self
+            .verifier
+            .as_ref()
+            .ok_or_else(|| ApiError::BadRequest("캡차를 사용하지 않도록 설정되어 있습니다.".to_string()))?;
+        if !verifier.verify(submission, remote_ip).await? {
+            return Err(ApiError::BadRequest("캡차 인증에 실패했습니다.".to_string()));
+        }
+
+        let captcha_token = random_token(&mut rand::thread_rng(), PASS_TOKEN_LENGTH);
+        let mut redis_conn = redis_connection(&self.redis).await?;
+        redis_conn
+            .set_ex::<_, _, ()>(format!("{}{}", PASS_KEY_PREFIX, captcha_token), verifier.provider(), self.ttl_seconds)
+            .await
+            .map_err(|e| ApiError::Internal(format!("Redis 저장 실패: {}", e)))?;
+
+        Ok(CaptchaVerifyResponse {
+            captcha_token,
+            expires_in: self.ttl_seconds,
+        })
+    }
+
+    /// 작성 요청의 캡차 확인 (검증 완료 토큰 또는 캡차 응답, 캡차를 사용하지 않으면 통과)
+    pub async fn verify(&self, submission: &CaptchaSubmission, remote_ip: Option<&str>) -> Result<bool, ApiError> {
+        let verifier = match &self.verifier {
+            Some(verifier) => verifier,
+            None => return Ok(true),
+        };
+
+        if let Some(token) = submission.captcha_token.as_deref().filter(|t| is_token(t, PASS_TOKEN_LENGTH)) {
+            let mut redis_conn = redis_connection(&self.redis).await?;
+            let passed: Option<String> = redis::cmd("GETDEL")
+                .arg(format!("{}{}", PASS_KEY_PREFIX, token))
+                .query_async(&mut redis_conn)
+                .await
+                .map_err(|e| ApiError::Internal(format!("Redis 조회 실패: {}", e)))?;
+            if passed.is_some() {
+                return Ok(true);
+            }
+        }
+
+        verifier.verify(submission, remote_ip).await
+    }
+}
+
+async fn redis_connection(redis: &RedisClient) -> Result<redis::aio::Connection, ApiError> {
+    redis
+        .get_async_connection()
+        .await
+        .map_err(|e| ApiError::Internal(format!("Redis 연결 실패: {}", e)))
+}
+
+fn random_token(rng: &mut impl Rng, length: usize) -> String {
+    rng.sample_iter(&Alphanumeric)
+        .take(length)
+        .map(char::from)
+        .collect()
+}
+
+// 서버가 발급한 형식의 토큰인지 확인 (임의 문자열로 Redis 키를 만들지 않음)
+fn is_token(value: &str, length: usize) -> bool {
+    value.len() == length && value.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// reCAPTCHA v3 점수 확인 (점수가 없는 제공자는 통과)
+pub fn score_passes(score: Option<f64>, min_score: f64) -> bool {
+    score.is_none_or(|score| score >= min_score)
+}
+
+/// 수식 문제와 정답 생성 (덧셈 또는 음수가 나오지 않는 뺄셈)
+pub fn math_question(rng: &mut impl Rng) -> (String, String) {
+    if rng.gen_bool(0.5) {
+        let (a, b) = (rng.gen_range(1..=20), rng.gen_range(1..=20));
+        (format!("{} + {} = ?", a, b), (a + b).to_string())
+    } else {
+        let (a, b) = (rng.gen_range(10..=40), rng.gen_range(1..=9));
+        (format!("{} - {} = ?", a, b), (a - b).to_string())
+    }
+}
+
+/// 수식 캡차 정답 확인 (앞뒤 공백 허용)
+pub fn check_answer(expected: &str, answer: &str) -> bool {
+    match (expected.trim().parse::<i64>(), answer.trim().parse::<i64>()) {
+        (Ok(expected), Ok(answer)) => expected == answer,
+        _ => false,
+    }
+}
+
+// 5x7 비트맵 글꼴 (수식에 쓰는 문자만)
+fn glyph(c: char) -> Option<[u8; 7]> {
+    let rows = match c {
+        '0' => [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110],
+        '1' => [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        '2' => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111],
+        '3' => [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110],
+        '4' => [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010],
+        '5' => [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110],
+        '6' => [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110],
+        '7' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000],
+        '8' => [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110],
+        '9' => [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100],
+        '+' => [0b00000, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0b00000],
+        '-' => [0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000],
+        '=' => [0b00000, 0b00000, 0b11111, 0b00000, 0b11111, 0b00000, 0b00000],
+        '?' => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b00000, 0b00100],
+        _ => return None,
+    };
+    Some(rows)
+}
+
+fn put_pixel(img: &mut RgbImage, x: i32, y: i32, color: Rgb<u8>) {
+    if x >= 0 && y >= 0 && (x as u32) < img.width() && (y as u32) < img.height() {
+        img.put_pixel(x as u32, y as u32, color);
+    }
+}
+
+const GLYPH_SCALE: i32 = 4;
+const GLYPH_ADVANCE: i32 = 5 * GLYPH_SCALE + 6;
+const CAPTCHA_PADDING: i32 = 12;
+const CAPTCHA_HEIGHT: u32 = 56;
+
+/// 수식 문제를 흔들린 글자와 잡음이 섞인 PNG로 그림
+pub fn render_captcha_png(text: &str, rng: &mut impl Rng) -> Result<Vec<u8>, ApiError> {
+    let width = (CAPTCHA_PADDING * 2 + text.chars().count() as i32 * GLYPH_ADVANCE) as u32;
+    let mut img = RgbImage::from_pixel(width, CAPTCHA_HEIGHT, Rgb([245, 245, 240]));
+
+    // 배경 잡음
+    for _ in 0..(width * CAPTCHA_HEIGHT / 10) {
+        let shade = rng.gen_range(170..=230);
+        let (x, y) = (rng.gen_range(0..width) as i32, rng.gen_range(0..CAPTCHA_HEIGHT) as i32);
+        put_pixel(&mut img, x, y, Rgb([shade, shade, shade]));
+    }
+
+    // 글자마다 위치, 기울기, 색을 다르게
+    let base_top = (CAPTCHA_HEIGHT as i32 - 7 * GLYPH_SCALE) / 2;
+    let mut x = CAPTCHA_PADDING;
+    for c in text.chars() {
+        if let Some(rows) = glyph(c) {
+            let color = Rgb([rng.gen_range(0..110), rng.gen_range(0..110), rng.gen_range(0..110)]);
+            let left = x + rng.gen_range(-3..=3);
+            let top = base_top + rng.gen_range(-6..=6);
+            let slant = rng.gen_range(-1..=1);
+            for (row, bits) in rows.iter().enumerate() {
+                let shift = slant * (row as i32 - 3);
+                for col in 0..5 {
+                    if bits >> (4 - col) & 1 == 0 {
+                        continue;
+                    }
+                    for dy in 0..GLYPH_SCALE {
+                        for dx in 0..GLYPH_SCALE {
+                            put_pixel(&mut img, left + col * GLYPH_SCALE + dx + shift, top + row as i32 * GLYPH_SCALE + dy, color);
+                        }
+                    }
+                }
+            }
+        }
+        x += if c == ' ' { GLYPH_ADVANCE / 2 } else { GLYPH_ADVANCE };
+    }
+
+    // 글자를 가로지르는 선
+    for _ in 0..4 {
+        let shade = rng.gen_range(80..=160);
+        let (x0, y0) = (rng.gen_range(0..width) as f32, rng.gen_range(0..CAPTCHA_HEIGHT) as f32);
+        let (x1, y1) = (rng.gen_range(0..width) as f32, rng.gen_range(0..CAPTCHA_HEIGHT) as f32);
+        let steps = (x1 - x0).abs().max((y1 - y0).abs()).max(1.0) as i32;
+        for step in 0..=steps {
+            let t = step as f32 / steps as f32;
+            put_pixel(&mut img, (x0 + (x1 - x0) * t) as i32, (y0 + (y1 - y0) * t) as i32, Rgb([shade, shade, shade]));
+        }
+    }
+
+    let mut output = Cursor::new(Vec::new());
+    img.write_to(&mut output, ImageOutputFormat::Png)
+        .map_err(|e| ApiError::Internal(format!("캡차 이미지 생성 실패: {}", e)))?;
+    Ok(output.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_math_question() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let (question, answer) = math_question(&mut rng);
+            let parts: Vec<&str> = question.split_whitespace().collect();
+            let (a, b): (i64, i64) = (parts[0].parse().unwrap(), parts[2].parse().unwrap());
+            let expected = if parts[1] == "+" { a + b } else { a - b };
+            assert!(expected >= 0);
+            assert!(check_answer(&answer, &format!(" {} ", expected)));
+            assert!(!check_answer(&answer, &(expected + 1).to_string()));
+            assert!(question.chars().all(|c| c == ' ' || glyph(c).is_some()));
+        }
+        assert!(!check_answer("12", "twelve"));
+        assert!(score_passes(None, 0.5));
+        assert!(!score_passes(Some(0.3), 0.5));
+    }
+
+    #[test]
+    fn test_render_captcha_png() {
+        let png = render_captcha_png("12 + 7 = ?", &mut rand::thread_rng()).unwrap();
+        let img = image::load_from_memory(&png).unwrap();
+        assert_eq!(img.height(), CAPTCHA_HEIGHT);
+        assert!(img.width() > 200);
+        assert!(is_token(&random_token(&mut rand::thread_rng(), PASS_TOKEN_LENGTH), PASS_TOKEN_LENGTH));
+        assert!(!is_token("../../etc", CHALLENGE_ID_LENGTH));
+    }
+}
@@ -0,0 +1,248 @@
+use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::errors::ApiError;
+use crate::models::file::{FileGcEntry, FileGcReport};
+use crate::services::{quarantine_key, thumbnail_variant_keys, Storage, ThumbnailService};
+
+// 한 번 실행에서 고아 전환/삭제하는 최대 파일 수
+const GC_BATCH_SIZE: i64 = 500;
+
+// 게시글/댓글/갤러리/프로필에 연결된 파일 (삭제된 게시글/댓글에 남은 연결은 제외)
+const PUBLISHED_LINK: &str = r#"
+    EXISTS (
+        SELECT 1 FROM file_entities fe
+        WHERE fe.file_id = f.id AND (
+            (fe.entity_type = 'post' AND EXISTS (SELECT 1 FROM posts p WHERE p.id = fe.entity_id))
+            OR (fe.entity_type = 'comment' AND EXISTS (SELECT 1 FROM comments c WHERE c.id = fe.entity_id))
+            OR fe.entity_type IN ('gallery', 'user_profile')
+        )
+    )
+"#;
+
+// 만료되지 않은 임시저장 글에 연결된 파일
+const DRAFT_LINK: &str = r#"
+    EXISTS (
+        SELECT 1 FROM file_entities fe
+        JOIN drafts d ON d.id = fe.entity_id
+        WHERE fe.file_id = f.id AND fe.entity_type = 'draft' AND d.expires_at > NOW()
+    )
+"#;
+
+// 게시글/댓글/임시저장 본문(에디터 삽입 이미지)이나 프로필 이미지에서 저장 파일명으로 참조하는 파일
+const CONTENT_REFERENCE: &str = r#"
+    EXISTS (SELECT 1 FROM posts p WHERE strpos(p.content, f.stored_name) > 0)
+    OR EXISTS (SELECT 1 FROM comments c WHERE strpos(c.content, f.stored_name) > 0)
+    OR EXISTS (SELECT 1 FROM drafts d WHERE d.expires_at > NOW() AND strpos(d.content, f.stored_name) > 0)
+    OR EXISTS (SELECT 1 FROM users u WHERE strpos(u.profile_image, f.stored_name) > 0)
+"#;
+
+/// 어디에서도 참조하지 않는 파일 조건 (files 별칭 f)
+fn unreferenced() -> String {
+    format!("NOT ({}) AND NOT ({}) AND NOT ({})", PUBLISHED_LINK, DRAFT_LINK, CONTENT_REFERENCE)
+}
+
+/// 참조 없는 업로드 파일 정리
+/// 1) 다시 참조된 고아 파일과 게시글에 연결된 draft 파일의 상태 복구
+/// 2) 유예 시간이 지난 미참조 파일을 고아로 전환
+/// 3) 고아 상태로 유예 시간이 지난 파일의 원본/썸네일/레코드 삭제
+pub struct FileGcService {
+    pool: PgPool,
+    storage: Arc<dyn Storage>,
+}
+
+impl FileGcService {
+    pub fn new(pool: PgPool, storage: Arc<dyn Storage>) -> Self {
+        Self { pool, storage }
+    }
+
+    pub async fn run(&self, grace_hours: i64, dry_run: bool) -> Result<FileGcReport, ApiError> {
+        let grace_hours = grace_hours.max(0);
+        let mut report = FileGcReport {
+            dry_run,
+            grace_hours,
+            restored: 0,
+            marked: Vec::new(),
+            deleted: Vec::new(),
+            failed: 0,
+            reclaimed_bytes: 0,
+        };
+
+        report.restored = self.restore_referenced(dry_run).await?;
+        report.marked = self.mark_orphaned(grace_hours, dry_run).await?;
+
+        // 고아로 전환된 뒤 유예 시간이 지난 파일만 삭제 (이번에 전환된 파일은 다음 실행 대상)
+        let marked_ids: Vec<Uuid> = report.marked.iter().map(|entry| entry.id).collect();
+        let candidates = sqlx::query_as::<_, FileGcEntry>(&format!(
+            r#"
+            SELECT f.id, f.original_name, f.file_path, f.file_size, f.created_at, f.orphaned_at
+            FROM files f
+            WHERE f.status = 'orphaned'
+            AND COALESCE(f.orphaned_at, f.created_at) < NOW() - make_interval(hours => $1)
+            AND f.id <> ALL($3)
+            AND {}
+            ORDER BY f.orphaned_at
+            LIMIT $2
+            "#,
+            unreferenced()
+        ))
+        .bind(grace_hours as i32)
+        .bind(GC_BATCH_SIZE)
+        .bind(&marked_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        for entry in candidates {
+            let Some(key) = self.storage.key_from_location(&entry.file_path).map(str::to_string) else {
+                // 다른 저장소에 기록된 파일은 저장소 이전 후 정리
+                warn!("현재 저장소의 파일이 아니어서 정리 건너뜀: {} ({})", entry.id, entry.file_path);
+                report.failed += 1;
+                continue;
+            };
+
+            let size = self.stored_size(&key, entry.file_size).await;
+            if !dry_run {
+                if let Err(e) = self.delete_file(&entry, &key).await {
+                    warn!("고아 파일 삭제 실패: {} ({}): {:?}", entry.id, key, e);
+                    report.failed += 1;
+                    continue;
+                }
+            }
+            report.reclaimed_bytes += size;
+            report.deleted.push(entry);
+        }
+
+        Ok(report)
+    }
+
+    // 다시 참조된 고아 파일은 연결에 맞는 상태로, 게시글 등에 연결된 draft 파일은 published로 전환
+    async fn restore_referenced(&self, dry_run: bool) -> Result<u64, ApiError> {
+        let condition = format!(
+            "(f.status = 'orphaned' AND NOT ({})) OR (f.status = 'draft' AND {})",
+            unreferenced(),
+            PUBLISHED_LINK
+        );
+
+        if dry_run {
+            let count = sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) FROM files f WHERE {}", condition))
+                .fetch_one(&self.pool)
+                .await?;
+            return Ok(count as u64);
+        }
+
+        let result = sqlx::query(&format!(
+            r#"
+            UPDATE files f
+            SET status = CASE
+                    WHEN {} THEN 'published'::file_status
+                    WHEN {} THEN 'draft'::file_status
+                    ELSE 'published'::file_status
+                END,
+                orphaned_at = NULL
+            WHERE {}
+            "#,
+            PUBLISHED_LINK, DRAFT_LINK, condition
+        ))
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    // 업로드 후 유예 시간이 지나도록 참조되지 않은 파일을 고아로 전환
+    async fn mark_orphaned(&self, grace_hours: i64, dry_run: bool) -> Result<Vec<FileGcEntry>, ApiError> {
+        let candidates = format!(
+            r#"
+            SELECT f.id FROM files f
+            WHERE f.status <> 'orphaned'
+            AND f.created_at < NOW() - make_interval(hours => $1)
+            AND {}
+            ORDER BY f.created_at
+            LIMIT $2
+            "#,
+            unreferenced()
+        );
+
+        let sql = if dry_run {
+            format!(
+                "SELECT id, original_name, file_path, file_size, created_at, orphaned_at FROM files WHERE id IN ({})",
+                candidates
+            )
+        } else {
+            format!(
+                r#"
+                UPDATE files SET status = 'orphaned', orphaned_at = NOW()
+                WHERE id IN ({})
+                RETURNING id, original_name, file_path, file_size, created_at, orphaned_at
+                "#,
+                candidates
+            )
+        };
+
+        let marked = sqlx::query_as::<_, FileGcEntry>(&sql)
+            .bind(grace_hours as i32)
+            .bind(GC_BATCH_SIZE)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(marked)
+    }
+
+    // 원본과 썸네일(WebP/AVIF 포함)의 실제 저장 크기 (조회 실패 시 원본은 files.file_size 사용)
+    async fn stored_size(&self, key: &str, recorded_size: i64) -> u64 {
+        let mut size = match self.storage.head(key).await {
+            Ok(Some(meta)) => meta.size,
+            Ok(None) => 0,
+            Err(_) => recorded_size.max(0) as u64,
+        };
+        for thumbnail_key in thumbnail_variant_keys(key) {
+            if let Ok(Some(meta)) = self.storage.head(&thumbnail_key).await {
+                size += meta.size;
+            }
+        }
+        size
+    }
+
+    // 저장소 객체를 먼저 지우고 레코드 삭제 (레코드 삭제 실패 시 다음 실행에서 재시도)
+    async fn delete_file(&self, entry: &FileGcEntry, key: &str) -> Result<(), ApiError> {
+        self.storage.delete(key).await?;
+        self.storage.delete(&quarantine_key(key)).await?;
+        ThumbnailService::new(self.storage.clone())
+            .delete_thumbnails(key)
+            .await
+            .map_err(|e| ApiError::Internal(format!("썸네일 삭제 실패: {}", e)))?;
+
+        // file_entities에 남은 연결(삭제된 게시글, 만료된 임시저장)은 FK CASCADE로 함께 삭제
+        sqlx::query("DELETE FROM files WHERE id = $1 AND status = 'orphaned'")
+            .bind(entry.id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+/// 고아 파일 주기적 정리 작업 (interval_minutes가 0이면 실행하지 않음)
+pub fn spawn_file_gc(pool: PgPool, storage: Arc<dyn Storage>, grace_hours: i64, interval_minutes: u64) {
+    if interval_minutes == 0 {
+        return;
+    }
+    tokio::spawn(async move {
+        let service = FileGcService::new(pool, storage);
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_minutes * 60));
+        loop {
+            interval.tick().await;
+            match service.run(grace_hours, false).await {
+                Ok(report) if report.marked.is_empty() && report.deleted.is_empty() && report.failed == 0 => {}
+                Ok(report) => info!(
+                    "고아 파일 정리: 전환 {}건, 삭제 {}건 ({} bytes), 실패 {}건",
+                    report.marked.len(),
+                    report.deleted.len(),
+                    report.reclaimed_bytes,
+                    report.failed
+                ),
+                Err(e) => warn!("고아 파일 정리 실패: {:?}", e),
+            }
+        }
+    });
+}
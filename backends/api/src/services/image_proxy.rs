@@ -0,0 +1,426 @@
+use base64::{engine::general_purpose, Engine as _};
+use hmac::{Hmac, Mac};
+use image::{imageops::FilterType, DynamicImage, GenericImageView};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
+use tokio::sync::{Mutex, Semaphore};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::config::ImageConfig;
+use crate::models::file::ImageProxyQuery;
+use crate::services::{encode_image, image_format_name, load_oriented_image};
+
+// 동시에 변환하는 최대 이미지 수 (디코딩/리사이즈는 CPU와 메모리를 많이 사용)
+const MAX_CONCURRENT_RENDERS: usize = 4;
+// 캐시 용량을 넘으면 이 비율까지 줄임 (매 요청마다 정리하지 않도록)
+const CACHE_LOW_WATERMARK_PERCENT: u64 = 90;
+// image-url 명령의 기본 서명 유효 시간
+const DEFAULT_SIGNED_URL_TTL_SECONDS: i64 = 86400;
+
+static RENDER_PERMITS: Semaphore = Semaphore::const_new(MAX_CONCURRENT_RENDERS);
+
+// 크기 맞춤 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFit {
+    Contain, // 비율 유지, 지정 크기 안에 맞춤 (기본값)
+    Cover,   // 지정 크기를 꽉 채우고 넘치는 부분은 가운데 기준으로 잘라냄
+}
+
+impl ImageFit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageFit::Contain => "contain",
+            ImageFit::Cover => "cover",
+        }
+    }
+}
+
+/// 이미지 변환 파라미터 (검증 완료)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageParams {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub fit: ImageFit,
+    pub format: Option<&'static str>, // None이면 원본 형식
+}
+
+impl ImageParams {
+    /// 쿼리 검증 (w/h 중 하나 이상, cover는 둘 다 필요)
+    pub fn from_query(query: &ImageProxyQuery, max_dimension: u32) -> Result<Self, String> {
+        for (name, value) in [("w", query.w), ("h", query.h)] {
+            if value.is_some_and(|v| v == 0 || v > max_dimension) {
+                return Err(format!("{}는 1~{} 사이여야 합니다.", name, max_dimension));
+            }
+        }
+        if query.w.is_none() && query.h.is_none() {
+            return Err("w 또는 h가 필요합니다.".to_string());
+        }
+
+        let fit = match query.fit.as_deref() {
+            None | Some("contain") => ImageFit::Contain,
+            Some("cover") => ImageFit::Cover,
+            Some(other) => return Err(format!("지원하지 않는 fit입니다: {}", other)),
+        };
+        if fit == ImageFit::Cover && (query.w.is_none() || query.h.is_none()) {
+            return Err("cover는 w와 h가 모두 필요합니다.".to_string());
+        }
+
+        let format = match query.fmt.as_deref() {
+            None | Some("auto") => None,
+            Some("jpeg") | Some("jpg") => Some("jpeg"),
+            Some("png") => Some("png"),
+            Some("webp") => Some("webp"),
+            Some("avif") if cfg!(feature = "avif") => Some("avif"),
+            Some(other) => return Err(format!("지원하지 않는 형식입니다: {}", other)),
+        };
+
+        Ok(Self { width: query.w, height: query.h, fit, format })
+    }
+
+    /// 변환 결과 식별 문자열: "{file_id}:{w}:{h}:{fit}:{fmt}" (없는 값은 0, auto, 캐시 키로 사용)
+    pub fn canonical(&self, file_id: Uuid) -> String {
+        format!(
+            "{}:{}:{}:{}:{}",
+            file_id,
+            self.width.unwrap_or(0),
+            self.height.unwrap_or(0),
+            self.fit.as_str(),
+            self.format.unwrap_or("auto")
+        )
+    }
+
+    /// 서명 대상 문자열: "{file_id}:{w}:{h}:{fit}:{fmt}:{exp}"
+    pub fn signature_payload(&self, file_id: Uuid, exp: i64) -> String {
+        format!("{}:{}", self.canonical(file_id), exp)
+    }
+
+    /// 출력 형식 (원본 형식 유지 시 GIF/BMP 등은 PNG로 변환)
+    pub fn output_format(&self, original_key: &str) -> &'static str {
+        if let Some(format) = self.format {
+            return format;
+        }
+        let extension = Path::new(original_key).extension().and_then(|s| s.to_str()).unwrap_or("");
+        match image_format_name(extension) {
+            format @ ("jpeg" | "png" | "webp") => format,
+            _ => "png",
+        }
+    }
+}
+
+/// 변환 파라미터 서명 (HMAC-SHA256, base64url 패딩 없음)
+/// 프론트엔드 서버는 같은 키로 signature_payload 문자열에 서명해 ?exp=&sig= 로 전달
+pub fn sign_image_params(secret: &str, canonical: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC은 모든 키 길이를 허용");
+    mac.update(canonical.as_bytes());
+    general_purpose::URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes())
+}
+
+/// 서명 확인 (상수 시간 비교)
+pub fn verify_image_signature(secret: &str, canonical: &str, signature: &str) -> bool {
+    let Ok(signature) = general_purpose::URL_SAFE_NO_PAD.decode(signature) else {
+        return false;
+    };
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC은 모든 키 길이를 허용");
+    mac.update(canonical.as_bytes());
+    mac.verify_slice(&signature).is_ok()
+}
+
+/// 서명된 변환 URL (/api/img/{file_id}?w=&h=&fit=&fmt=&exp=&sig=)
+pub fn signed_image_url(secret: &str, file_id: Uuid, params: &ImageParams, exp: i64) -> String {
+    let mut query = Vec::new();
+    if let Some(width) = params.width {
+        query.push(format!("w={}", width));
+    }
+    if let Some(height) = params.height {
+        query.push(format!("h={}", height));
+    }
+    query.push(format!("fit={}", params.fit.as_str()));
+    if let Some(format) = params.format {
+        query.push(format!("fmt={}", format));
+    }
+    query.push(format!("exp={}", exp));
+    query.push(format!("sig={}", sign_image_params(secret, &params.signature_payload(file_id, exp))));
+    format!("/api/img/{}?{}", file_id, query.join("&"))
+}
+
+/// 서명 URL 생성 명령: mincenter-api image-url <file_id> [--w N] [--h N] [--fit contain|cover] [--fmt webp] [--ttl 초]
+pub fn run_image_url_command(config: &ImageConfig, args: &[String]) -> i32 {
+    let value_of = |flag: &str| {
+        args.iter()
+            .position(|arg| arg == flag)
+            .and_then(|index| args.get(index + 1))
+            .cloned()
+    };
+    let usage = "사용법: mincenter-api image-url <file_id> [--w N] [--h N] [--fit contain|cover] [--fmt jpeg|png|webp|avif] [--ttl 초]";
+
+    if config.proxy_secret.is_empty() {
+        eprintln!("❌ IMAGE_PROXY_SECRET이 설정되지 않았습니다.");
+        return 1;
+    }
+    let Some(file_id) = args.first().and_then(|arg| Uuid::parse_str(arg).ok()) else {
+        eprintln!("{}", usage);
+        return 1;
+    };
+    let dimension = |flag: &str| value_of(flag).map(|v| v.parse::<u32>().unwrap_or(0));
+    let query = ImageProxyQuery {
+        w: dimension("--w"),
+        h: dimension("--h"),
+        fit: value_of("--fit"),
+        fmt: value_of("--fmt"),
+        exp: 0,
+        sig: String::new(),
+    };
+    let ttl = value_of("--ttl").and_then(|v| v.parse::<i64>().ok()).unwrap_or(DEFAULT_SIGNED_URL_TTL_SECONDS);
+    let exp = chrono::Utc::now().timestamp() + ttl;
+
+    match ImageParams::from_query(&query, config.proxy_max_dimension) {
+        Ok(params) => {
+            println!("{}", signed_image_url(&config.proxy_secret, file_id, &params, exp));
+            0
+        }
+        Err(e) => {
+            eprintln!("❌ {}\n{}", e, usage);
+            1
+        }
+    }
+}
+
+/// 원본을 파라미터에 맞게 변환 (원본보다 크게 확대하지 않음)
+pub async fn render_image(
+    data: Vec<u8>,
+    params: ImageParams,
+    format: &'static str,
+    webp_quality: f32,
+) -> Result<Vec<u8>, String> {
+    let _permit = RENDER_PERMITS.acquire().await.map_err(|e| e.to_string())?;
+    tokio::task::spawn_blocking(move || {
+        let img = load_oriented_image(&data).map_err(|e| format!("이미지를 읽을 수 없습니다: {}", e))?;
+        let resized = resize_for_params(&img, &params);
+        encode_image(&resized, format, webp_quality).map_err(|e| format!("이미지 인코딩 실패: {}", e))
+    })
+    .await
+    .map_err(|e| format!("이미지 변환 작업 실패: {}", e))?
+}
+
+fn resize_for_params(img: &DynamicImage, params: &ImageParams) -> DynamicImage {
+    let (orig_width, orig_height) = img.dimensions();
+    match params.fit {
+        ImageFit::Cover => {
+            let width = params.width.unwrap_or(orig_width);
+            let height = params.height.unwrap_or(orig_height);
+            // 원본이 더 작으면 같은 비율로 줄인 크기로 자름
+            let scale = (orig_width as f64 / width as f64).min(orig_height as f64 / height as f64).min(1.0);
+            let width = ((width as f64 * scale).round() as u32).max(1);
+            let height = ((height as f64 * scale).round() as u32).max(1);
+            img.resize_to_fill(width, height, FilterType::Lanczos3)
+        }
+        ImageFit::Contain => {
+            let width = params.width.unwrap_or(orig_width).min(orig_width);
+            let height = params.height.unwrap_or(orig_height).min(orig_height);
+            if width == orig_width && height == orig_height {
+                img.clone()
+            } else {
+                img.resize(width, height, FilterType::Lanczos3)
+            }
+        }
+    }
+}
+
+/// 변환 결과 디스크 캐시 (용량을 넘으면 마지막 사용 시각이 오래된 파일부터 삭제)
+/// 캐시 적중 시 파일 수정 시각을 갱신해 사용 시각으로 사용
+pub struct ImageCache {
+    dir: PathBuf,
+    max_bytes: u64,
+    used_bytes: AtomicU64,
+    evicting: Mutex<()>,
+}
+
+impl ImageCache {
+    /// 캐시 디렉터리 준비 및 현재 사용량 집계
+    pub async fn open(dir: &str, max_mb: u64) -> Self {
+        let cache = Self {
+            dir: PathBuf::from(dir),
+            max_bytes: max_mb.saturating_mul(1024 * 1024),
+            used_bytes: AtomicU64::new(0),
+            evicting: Mutex::new(()),
+        };
+        if let Err(e) = tokio::fs::create_dir_all(&cache.dir).await {
+            warn!("이미지 캐시 디렉터리 생성 실패: {}: {:?}", dir, e);
+        }
+        let used: u64 = cache.entries().await.iter().map(|(_, size, _)| size).sum();
+        cache.used_bytes.store(used, Ordering::Relaxed);
+        info!("이미지 캐시: {} ({} / {} bytes)", dir, used, cache.max_bytes);
+        cache
+    }
+
+    /// 캐시 키 (파일 ID + 변환 파라미터의 해시)
+    pub fn key(canonical: &str) -> String {
+        format!("{:x}", Sha256::digest(canonical.as_bytes()))
+    }
+
+    fn path(&self, key: &str, format: &str) -> PathBuf {
+        self.dir.join(&key[..2]).join(format!("{}.{}", key, format))
+    }
+
+    pub async fn get(&self, key: &str, format: &str) -> Option<Vec<u8>> {
+        let path = self.path(key, format);
+        let data = tokio::fs::read(&path).await.ok()?;
+        // 마지막 사용 시각 갱신 (실패해도 응답에는 영향 없음)
+        let _ = tokio::task::spawn_blocking(move || {
+            std::fs::File::options().write(true).open(&path)?.set_modified(SystemTime::now())
+        })
+        .await;
+        Some(data)
+    }
+
+    pub async fn put(&self, key: &str, format: &str, data: &[u8]) {
+        // 캐시보다 큰 결과는 저장하지 않음
+        if data.len() as u64 > self.max_bytes {
+            return;
+        }
+        let path = self.path(key, format);
+        let temp_path = path.with_extension(format!("{}.tmp", Uuid::new_v4()));
+        let written = async {
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            tokio::fs::write(&temp_path, data).await?;
+            // 다른 요청이 같은 결과를 동시에 쓰더라도 완성된 파일만 보이도록 이름 변경
+            tokio::fs::rename(&temp_path, &path).await
+        }
+        .await;
+        if let Err(e) = written {
+            warn!("이미지 캐시 저장 실패: {:?}: {:?}", path, e);
+            let _ = tokio::fs::remove_file(&temp_path).await;
+            return;
+        }
+
+        let used = self.used_bytes.fetch_add(data.len() as u64, Ordering::Relaxed) + data.len() as u64;
+        if used > self.max_bytes {
+            self.evict().await;
+        }
+    }
+
+    // 오래 사용하지 않은 파일부터 삭제 (다른 요청이 정리 중이면 건너뜀)
+    async fn evict(&self) {
+        let Ok(_guard) = self.evicting.try_lock() else {
+            return;
+        };
+        let mut entries = self.entries().await;
+        let mut used: u64 = entries.iter().map(|(_, size, _)| size).sum();
+        let target = self.max_bytes / 100 * CACHE_LOW_WATERMARK_PERCENT;
+
+        entries.sort_by_key(|(_, _, modified)| *modified);
+        let mut removed = 0;
+        for (path, size, _) in entries {
+            if used <= target {
+                break;
+            }
+            if tokio::fs::remove_file(&path).await.is_ok() {
+                used = used.saturating_sub(size);
+                removed += 1;
+            }
+        }
+        self.used_bytes.store(used, Ordering::Relaxed);
+        info!("이미지 캐시 정리: {}개 삭제, 사용량 {} bytes", removed, used);
+    }
+
+    // 캐시 파일 목록 (경로, 크기, 마지막 사용 시각)
+    async fn entries(&self) -> Vec<(PathBuf, u64, SystemTime)> {
+        let mut entries = Vec::new();
+        let Ok(mut prefixes) = tokio::fs::read_dir(&self.dir).await else {
+            return entries;
+        };
+        while let Ok(Some(prefix)) = prefixes.next_entry().await {
+            let Ok(mut files) = tokio::fs::read_dir(prefix.path()).await else {
+                continue;
+            };
+            while let Ok(Some(file)) = files.next_entry().await {
+                if let Ok(meta) = file.metadata().await {
+                    if meta.is_file() {
+                        entries.push((file.path(), meta.len(), meta.modified().unwrap_or(SystemTime::UNIX_EPOCH)));
+                    }
+                }
+            }
+        }
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::RgbImage;
+
+    fn query(w: Option<u32>, h: Option<u32>, fit: Option<&str>, fmt: Option<&str>) -> ImageProxyQuery {
+        ImageProxyQuery {
+            w,
+            h,
+            fit: fit.map(str::to_string),
+            fmt: fmt.map(str::to_string),
+            exp: 0,
+            sig: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_image_params_validation() {
+        let params = ImageParams::from_query(&query(Some(320), None, None, Some("jpg")), 2048).unwrap();
+        assert_eq!(params.fit, ImageFit::Contain);
+        assert_eq!(params.format, Some("jpeg"));
+
+        assert!(ImageParams::from_query(&query(None, None, None, None), 2048).is_err());
+        assert!(ImageParams::from_query(&query(Some(0), None, None, None), 2048).is_err());
+        assert!(ImageParams::from_query(&query(Some(4096), None, None, None), 2048).is_err());
+        assert!(ImageParams::from_query(&query(Some(100), None, Some("cover"), None), 2048).is_err());
+        assert!(ImageParams::from_query(&query(Some(100), None, Some("stretch"), None), 2048).is_err());
+        assert!(ImageParams::from_query(&query(Some(100), None, None, Some("tiff")), 2048).is_err());
+    }
+
+    #[test]
+    fn test_image_signature() {
+        let file_id = Uuid::nil();
+        let params = ImageParams::from_query(&query(Some(300), Some(200), Some("cover"), Some("webp")), 2048).unwrap();
+        let payload = params.signature_payload(file_id, 1_700_000_000);
+        assert_eq!(payload, "00000000-0000-0000-0000-000000000000:300:200:cover:webp:1700000000");
+
+        let signature = sign_image_params("secret", &payload);
+        assert!(verify_image_signature("secret", &payload, &signature));
+        assert!(!verify_image_signature("other", &payload, &signature));
+        assert!(!verify_image_signature("secret", &params.signature_payload(Uuid::max(), 1_700_000_000), &signature));
+        // 만료 시각을 바꾸면 서명이 맞지 않음
+        assert!(!verify_image_signature("secret", &params.signature_payload(file_id, 1_900_000_000), &signature));
+        assert!(!verify_image_signature("secret", &payload, "not-base64!"));
+
+        let url = signed_image_url("secret", file_id, &params, 1_700_000_000);
+        assert!(url.starts_with("/api/img/00000000-0000-0000-0000-000000000000?w=300&h=200&fit=cover&fmt=webp&exp=1700000000&sig="));
+    }
+
+    #[test]
+    fn test_resize_for_params() {
+        let img = DynamicImage::ImageRgb8(RgbImage::new(400, 200));
+        let contain = ImageParams { width: Some(100), height: None, fit: ImageFit::Contain, format: None };
+        assert_eq!(resize_for_params(&img, &contain).dimensions(), (100, 50));
+
+        // 원본보다 크게 확대하지 않음
+        let larger = ImageParams { width: Some(800), height: Some(800), fit: ImageFit::Contain, format: None };
+        assert_eq!(resize_for_params(&img, &larger).dimensions(), (400, 200));
+
+        let cover = ImageParams { width: Some(100), height: Some(100), fit: ImageFit::Cover, format: None };
+        assert_eq!(resize_for_params(&img, &cover).dimensions(), (100, 100));
+        let cover_larger = ImageParams { width: Some(600), height: Some(300), fit: ImageFit::Cover, format: None };
+        assert_eq!(resize_for_params(&img, &cover_larger).dimensions(), (400, 200));
+    }
+
+    #[test]
+    fn test_output_format() {
+        let params = ImageParams { width: Some(1), height: None, fit: ImageFit::Contain, format: None };
+        assert_eq!(params.output_format("a.JPG"), "jpeg");
+        assert_eq!(params.output_format("a.gif"), "png");
+        let webp = ImageParams { format: Some("webp"), ..params };
+        assert_eq!(webp.output_format("a.png"), "webp");
+    }
+}
@@ -0,0 +1,129 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::config::MailConfig;
+use crate::errors::ApiError;
+
+// 발송할 메일 (본문은 일반 텍스트)
+#[derive(Debug, Clone)]
+pub struct MailMessage {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// 메일 발송 방식 추상화 (SMTP, 파일 저장, 로그 출력)
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, message: &MailMessage) -> Result<(), ApiError>;
+}
+
+/// MAIL_TRANSPORT 설정에 맞는 발송기 생성
+pub fn create_mailer(config: &MailConfig) -> Box<dyn Mailer> {
+    match config.transport.as_str() {
+        "smtp" => Box::new(SmtpMailer { config: config.clone() }),
+        "file" => Box::new(FileMailer { from: config.from.clone(), dir: config.file_dir.clone() }),
+        _ => Box::new(LogMailer { log_body: config.log_body }),
+    }
+}
+
+fn build_message(from: &str, message: &MailMessage) -> Result<Message, ApiError> {
+    let from: Mailbox = from
+        .parse()
+        .map_err(|e| ApiError::Internal(format!("발신 주소가 올바르지 않습니다: {}", e)))?;
+    let to: Mailbox = message
+        .to
+        .parse()
+        .map_err(|_| ApiError::Validation("이메일 주소가 올바르지 않습니다.".to_string()))?;
+
+    Message::builder()
+        .from(from)
+        .to(to)
+        .subject(&message.subject)
+        .header(ContentType::TEXT_PLAIN)
+        .body(message.body.clone())
+        .map_err(|e| ApiError::Internal(format!("메일 생성 실패: {}", e)))
+}
+
+/// SMTP 서버로 발송
+pub struct SmtpMailer {
+    config: MailConfig,
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, message: &MailMessage) -> Result<(), ApiError> {
+        let email = build_message(&self.config.from, message)?;
+        let host = self.config.smtp_host.as_str();
+
+        let builder = match self.config.smtp_security.as_str() {
+            "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(host),
+            "none" => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)),
+            _ => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host),
+        }
+        .map_err(|e| ApiError::Internal(format!("SMTP 설정 오류: {}", e)))?;
+
+        let mut builder = builder.port(self.config.smtp_port);
+        if let (Some(username), Some(password)) = (&self.config.smtp_username, &self.config.smtp_password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        builder
+            .build()
+            .send(email)
+            .await
+            .map_err(|e| ApiError::Internal(format!("메일 발송 실패: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+/// 디렉터리에 .eml 파일로 저장 (로컬 테스트용)
+pub struct FileMailer {
+    from: String,
+    dir: String,
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, message: &MailMessage) -> Result<(), ApiError> {
+        let email = build_message(&self.from, message)?;
+
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| ApiError::Internal(format!("메일 저장 디렉터리 생성 실패: {}", e)))?;
+
+        let path = format!("{}/{}_{}.eml", self.dir, Utc::now().format("%Y%m%d%H%M%S"), Uuid::new_v4());
+        tokio::fs::write(&path, email.formatted())
+            .await
+            .map_err(|e| ApiError::Internal(format!("메일 저장 실패: {}", e)))?;
+
+        info!("메일 파일 저장: to={}, path={}", message.to, path);
+        Ok(())
+    }
+}
+
+/// 로그로만 출력 (개발 환경 기본값)
+/// 본문에는 인증/재설정 토큰이 들어 있으므로 log_body일 때만 출력
+pub struct LogMailer {
+    log_body: bool,
+}
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, message: &MailMessage) -> Result<(), ApiError> {
+        if self.log_body {
+            info!("메일 발송(로그): to={}, subject={}\n{}", message.to, message.subject, message.body);
+        } else {
+            info!("메일 발송(로그): to={}, subject={}", message.to, message.subject);
+        }
+        Ok(())
+    }
+}
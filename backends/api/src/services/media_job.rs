@@ -0,0 +1,655 @@
+use futures_util::StreamExt;
+use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::{Notify, Semaphore};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::config::{ImageConfig, MediaJobConfig, VideoConfig, VirusScanConfig};
+use crate::errors::ApiError;
+use crate::models::file::{ImageSrcsets, MediaJob, ProcessingStatus, VideoMetadata};
+use crate::models::site::community::ThumbnailUrls;
+use crate::services::{
+    build_srcset, content_type_for_key, create_virus_scanner, image_format_name, public_url, quarantine_key, thumbnail_key, upload_url,
+    video_poster_key, ScanResult, Storage, ThumbnailInfo, ThumbnailService, VideoProcessor, VideoToolError,
+};
+use crate::utils::file_sniff::SVG_MIME_TYPE;
+
+// 작업 종류
+pub const JOB_PROCESS_FILE: &str = "process_file"; // 바이러스 검사 + 썸네일 + processing_status 갱신
+pub const JOB_THUMBNAIL: &str = "thumbnail"; // 누락된 썸네일만 생성
+
+pub const JOB_STATUSES: [&str; 4] = ["queued", "running", "completed", "failed"];
+
+// 실행 중으로 남은 작업을 다시 대기열로 돌리는 기준 (서버가 작업 중 종료된 경우)
+const LOCK_TIMEOUT_MINUTES: i32 = 15;
+const MAX_RETRY_DELAY_SECONDS: i64 = 3600;
+const COMPLETED_RETENTION_DAYS: i32 = 7;
+const MAINTENANCE_INTERVAL: Duration = Duration::from_secs(300);
+
+// 작업 추가/완료 시 작업자를 바로 깨움 (다른 서버의 작업자는 폴링으로 가져감)
+static WAKEUP: Notify = Notify::const_new();
+
+/// 재시도 대기 시간: base * 2^(시도 횟수 - 1), 최대 1시간
+pub fn retry_delay_seconds(base: i64, attempts: i32) -> i64 {
+    let exponent = attempts.saturating_sub(1).clamp(0, 20) as u32;
+    base.max(1).saturating_mul(1i64 << exponent).min(MAX_RETRY_DELAY_SECONDS)
+}
+
+// 작업 실패 정보
+struct JobFailure {
+    message: String,
+    retry: bool,
+    // 재시도를 포기할 때 파일에 기록할 처리 상태 (None이면 그대로 유지)
+    give_up_status: Option<ProcessingStatus>,
+}
+
+impl JobFailure {
+    fn retry(message: String, give_up_status: Option<ProcessingStatus>) -> Self {
+        Self { message, retry: true, give_up_status }
+    }
+
+    fn fatal(message: String, give_up_status: Option<ProcessingStatus>) -> Self {
+        Self { message, retry: false, give_up_status }
+    }
+}
+
+/// 작업 추가 (같은 파일에 대기/실행 중인 같은 종류의 작업이 있으면 추가하지 않음)
+pub async fn enqueue_media_job(
+    pool: &PgPool,
+    job_type: &str,
+    file_id: Option<Uuid>,
+    file_key: &str,
+    max_attempts: i32,
+) -> Result<(), ApiError> {
+    sqlx::query(
+        r#"
+        INSERT INTO media_jobs (job_type, file_id, file_key, max_attempts)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (job_type, file_key) WHERE status IN ('queued', 'running') DO NOTHING
+        "#
+    )
+    .bind(job_type)
+    .bind(file_id)
+    .bind(file_key)
+    .bind(max_attempts.max(1))
+    .execute(pool)
+    .await?;
+
+    WAKEUP.notify_one();
+    Ok(())
+}
+
+/// 파일 처리 상태 갱신
+pub async fn set_processing_status(pool: &PgPool, file_id: Uuid, status: ProcessingStatus) {
+    if let Err(e) = sqlx::query("UPDATE files SET processing_status = $1 WHERE id = $2")
+        .bind(status)
+        .bind(file_id)
+        .execute(pool)
+        .await
+    {
+        warn!("파일 처리 상태 업데이트 실패: file_id={}, {:?}", file_id, e);
+    }
+}
+
+/// 파일의 가장 최근 작업 (썸네일 상태 응답용)
+pub async fn latest_media_job(pool: &PgPool, file_id: Uuid) -> Result<Option<MediaJob>, ApiError> {
+    let job = sqlx::query_as::<_, MediaJob>(
+        "SELECT * FROM media_jobs WHERE file_id = $1 ORDER BY created_at DESC LIMIT 1"
+    )
+    .bind(file_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(job)
+}
+
+/// 작업 목록 (관리자용)
+pub async fn list_media_jobs(pool: &PgPool, status: &str, limit: i64, offset: i64) -> Result<(Vec<MediaJob>, i64), ApiError> {
+    let jobs = sqlx::query_as::<_, MediaJob>(
+        "SELECT * FROM media_jobs WHERE status = $1 ORDER BY updated_at DESC LIMIT $2 OFFSET $3"
+    )
+    .bind(status)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+
+    let total = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM media_jobs WHERE status = $1")
+        .bind(status)
+        .fetch_one(pool)
+        .await?;
+
+    Ok((jobs, total))
+}
+
+/// 실패한 작업을 처음부터 다시 시도
+pub async fn retry_media_job(pool: &PgPool, job_id: Uuid) -> Result<MediaJob, ApiError> {
+    let job = sqlx::query_as::<_, MediaJob>(
+        r#"
+        UPDATE media_jobs
+        SET status = 'queued', attempts = 0, run_at = NOW(), locked_at = NULL, updated_at = NOW()
+        WHERE id = $1 AND status = 'failed'
+        AND NOT EXISTS (
+            SELECT 1 FROM media_jobs a
+            WHERE a.job_type = media_jobs.job_type AND a.file_key = media_jobs.file_key
+            AND a.status IN ('queued', 'running')
+        )
+        RETURNING *
+        "#
+    )
+    .bind(job_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| ApiError::NotFound("재시도할 수 있는 실패 작업이 없습니다.".to_string()))?;
+
+    WAKEUP.notify_one();
+    Ok(job)
+}
+
+/// 원본 파일의 썸네일 형식별 srcset (image_sizes에 기록된 변형 기준)
+pub async fn image_srcsets(pool: &PgPool, storage: &dyn Storage, original_key: &str) -> Result<ImageSrcsets, ApiError> {
+    let rows = sqlx::query_as::<_, (String, Option<i32>, String)>(
+        r#"
+        SELECT s.file_path, s.width, s.format
+        FROM image_sizes s
+        JOIN files f ON f.id = s.file_id
+        WHERE f.file_path = $1
+        "#
+    )
+    .bind(storage.location(original_key))
+    .fetch_all(pool)
+    .await?;
+
+    let original_format = std::path::Path::new(original_key)
+        .extension()
+        .and_then(|s| s.to_str())
+        .map(image_format_name)
+        .unwrap_or("jpeg");
+    let srcset_of = |format: &str| {
+        let candidates: Vec<(String, i32)> = rows
+            .iter()
+            .filter(|(_, _, row_format)| row_format == format)
+            .map(|(file_path, width, _)| (public_url(storage, file_path), width.unwrap_or(0)))
+            .collect();
+        build_srcset(&candidates)
+    };
+
+    Ok(ImageSrcsets {
+        srcset: srcset_of(original_format),
+        webp_srcset: srcset_of("webp"),
+        avif_srcset: srcset_of("avif"),
+    })
+}
+
+/// 작업 큐 작업자: 대기 작업을 가져와 동시 처리 수 안에서 실행
+struct MediaJobWorker {
+    pool: PgPool,
+    storage: Arc<dyn Storage>,
+    config: MediaJobConfig,
+    virus_scan: VirusScanConfig,
+    image: ImageConfig,
+    video: VideoConfig,
+    temp_dir: String, // 동영상 처리용 로컬 임시 디렉터리
+}
+
+impl MediaJobWorker {
+    // 실행 가능한 대기 작업을 limit개까지 실행 중으로 전환 (여러 서버가 같은 작업을 가져가지 않도록 SKIP LOCKED)
+    async fn claim(&self, limit: i64) -> Result<Vec<MediaJob>, ApiError> {
+        let jobs = sqlx::query_as::<_, MediaJob>(
+            r#"
+            UPDATE media_jobs
+            SET status = 'running', attempts = attempts + 1, locked_at = NOW(), updated_at = NOW()
+            WHERE id IN (
+                SELECT id FROM media_jobs
+                WHERE status = 'queued' AND run_at <= NOW()
+                ORDER BY run_at
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING *
+            "#
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(jobs)
+    }
+
+    async fn execute(&self, job: MediaJob) {
+        let result = match job.job_type.as_str() {
+            JOB_PROCESS_FILE => self.process_file(&job).await,
+            JOB_THUMBNAIL => self.create_missing_thumbnails(&job).await,
+            other => Err(JobFailure::fatal(format!("알 수 없는 작업 종류: {}", other), None)),
+        };
+
+        let saved = match result {
+            Ok(()) => {
+                sqlx::query(
+                    "UPDATE media_jobs SET status = 'completed', locked_at = NULL, last_error = NULL, updated_at = NOW() WHERE id = $1"
+                )
+                .bind(job.id)
+                .execute(&self.pool)
+                .await
+            }
+            Err(failure) if failure.retry && job.attempts < job.max_attempts => {
+                let delay = retry_delay_seconds(self.config.retry_base_seconds, job.attempts);
+                warn!("미디어 작업 실패, {}초 후 재시도 ({}/{}): {} {}: {}",
+                    delay, job.attempts, job.max_attempts, job.job_type, job.file_key, failure.message);
+                sqlx::query(
+                    r#"
+                    UPDATE media_jobs
+                    SET status = 'queued', run_at = NOW() + make_interval(secs => $2), locked_at = NULL,
+                        last_error = $3, updated_at = NOW()
+                    WHERE id = $1
+                    "#
+                )
+                .bind(job.id)
+                .bind(delay as f64)
+                .bind(&failure.message)
+                .execute(&self.pool)
+                .await
+            }
+            Err(failure) => {
+                warn!("미디어 작업 최종 실패: {} {}: {}", job.job_type, job.file_key, failure.message);
+                if let (Some(file_id), Some(status)) = (job.file_id, failure.give_up_status) {
+                    set_processing_status(&self.pool, file_id, status).await;
+                }
+                sqlx::query(
+                    "UPDATE media_jobs SET status = 'failed', locked_at = NULL, last_error = $2, updated_at = NOW() WHERE id = $1"
+                )
+                .bind(job.id)
+                .bind(&failure.message)
+                .execute(&self.pool)
+                .await
+            }
+        };
+        if let Err(e) = saved {
+            warn!("미디어 작업 상태 저장 실패: job_id={}, {:?}", job.id, e);
+        }
+    }
+
+    // 업로드 후처리: 바이러스 검사 → 공개 위치로 게시 → (이미지) 썸네일 생성 / (동영상) 정보, 포스터 추출 → 처리 완료
+    // 원본 EXIF는 업로드 시 저장 전에 제거되므로 작업 큐는 파생 파일만 만듦
+    // 업로드는 격리 위치(quarantine_key)에 저장되므로 검사를 통과하기 전까지 /uploads로 내려가지 않음
+    async fn process_file(&self, job: &MediaJob) -> Result<(), JobFailure> {
+        let Some(file_id) = job.file_id else {
+            return Err(JobFailure::fatal("파일 ID가 없는 작업입니다.".to_string(), None));
+        };
+        let mime_type = sqlx::query_scalar::<_, String>("SELECT mime_type FROM files WHERE id = $1")
+            .bind(file_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| JobFailure::retry(format!("파일 조회 실패: {}", e), None))?
+            .ok_or_else(|| JobFailure::fatal("파일 레코드가 없습니다.".to_string(), None))?;
+
+        // 격리 위치에 없으면 이미 게시된 뒤 재시도된 작업이거나 격리 도입 전 업로드
+        let quarantined = quarantine_key(&job.file_key);
+        let (source_key, meta) = match self.storage.head(&quarantined).await {
+            Ok(Some(meta)) => (quarantined.as_str(), meta),
+            Ok(None) => match self.storage.head(&job.file_key).await {
+                Ok(Some(meta)) => (job.file_key.as_str(), meta),
+                Ok(None) => {
+                    return Err(JobFailure::fatal("저장소에 파일이 없습니다.".to_string(), Some(ProcessingStatus::Failed)));
+                }
+                Err(e) => return Err(JobFailure::retry(format!("저장소 조회 실패: {:?}", e), None)),
+            },
+            Err(e) => return Err(JobFailure::retry(format!("저장소 조회 실패: {:?}", e), None)),
+        };
+
+        if let Some(scanner) = create_virus_scanner(&self.virus_scan) {
+            let scanned = match self.storage.get_range(source_key, 0, meta.size).await {
+                Ok(stream) => scanner.scan_stream(stream).await,
+                Err(e) => Err(e),
+            };
+            match scanned {
+                Ok(ScanResult::Clean) => {}
+                Ok(ScanResult::Infected(signature)) => {
+                    warn!("악성 파일 탐지: file_id={}, signature={}", file_id, signature);
+                    if let Err(e) = self.storage.delete(source_key).await {
+                        warn!("악성 파일 삭제 실패: {:?}", e);
+                    }
+                    set_processing_status(&self.pool, file_id, ProcessingStatus::Failed).await;
+                    return Ok(());
+                }
+                // 끝내 검사하지 못한 파일은 격리 위치에 pending으로 남겨 두고 관리자가 확인
+                Err(e) => return Err(JobFailure::retry(format!("바이러스 검사 실패: {:?}", e), None)),
+            }
+        }
+
+        if source_key != job.file_key {
+            self.storage
+                .rename(source_key, &job.file_key)
+                .await
+                .map_err(|e| JobFailure::retry(format!("파일 게시 실패: {:?}", e), None))?;
+        }
+
+        if mime_type.starts_with("video/") {
+            set_processing_status(&self.pool, file_id, ProcessingStatus::Processing).await;
+            self.process_video(job, file_id).await?;
+            set_processing_status(&self.pool, file_id, ProcessingStatus::Completed).await;
+            return Ok(());
+        }
+
+        // SVG는 썸네일을 만들지 않음
+        if !mime_type.starts_with("image/") || mime_type == SVG_MIME_TYPE {
+            set_processing_status(&self.pool, file_id, ProcessingStatus::Completed).await;
+            return Ok(());
+        }
+
+        set_processing_status(&self.pool, file_id, ProcessingStatus::Processing).await;
+        let info = ThumbnailService::with_config(self.storage.clone(), &self.image)
+            .create_thumbnails(&job.file_key)
+            .await
+            .map_err(|e| JobFailure::retry(format!("썸네일 생성 실패: {}", e), Some(ProcessingStatus::Failed)))?;
+        self.record_image_sizes(file_id, &info)
+            .await
+            .map_err(|e| JobFailure::retry(format!("썸네일 정보 저장 실패: {:?}", e), Some(ProcessingStatus::Failed)))?;
+        set_processing_status(&self.pool, file_id, ProcessingStatus::Completed).await;
+        Ok(())
+    }
+
+    async fn create_missing_thumbnails(&self, job: &MediaJob) -> Result<(), JobFailure> {
+        let info = ThumbnailService::with_config(self.storage.clone(), &self.image)
+            .ensure_thumbnails_exist(&job.file_key)
+            .await
+            .map_err(|e| JobFailure::retry(format!("썸네일 생성 실패: {}", e), None))?;
+
+        // 게시글 조회 중 등록된 작업은 파일 ID 없이 키만 가짐 (동영상 포스터는 포스터 키)
+        let file_id = match job.file_id {
+            Some(file_id) => Some(file_id),
+            None => sqlx::query_scalar::<_, Uuid>("SELECT id FROM files WHERE file_path = $1 OR poster_path = $1 LIMIT 1")
+                .bind(self.storage.location(&job.file_key))
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| JobFailure::retry(format!("파일 조회 실패: {}", e), None))?,
+        };
+        if let Some(file_id) = file_id {
+            self.record_image_sizes(file_id, &info)
+                .await
+                .map_err(|e| JobFailure::retry(format!("썸네일 정보 저장 실패: {:?}", e), None))?;
+        }
+        Ok(())
+    }
+
+    // 동영상 정보 조회 및 포스터 프레임 추출 (ffprobe/ffmpeg는 로컬 파일만 읽으므로 임시 파일로 복사)
+    // ffprobe/ffmpeg가 없으면 정보 없이 처리 완료
+    async fn process_video(&self, job: &MediaJob, file_id: Uuid) -> Result<(), JobFailure> {
+        let work_dir = std::path::Path::new(&self.temp_dir).join(format!("video-{}", job.id));
+        let result = self.process_video_in(job, file_id, &work_dir).await;
+        if let Err(e) = tokio::fs::remove_dir_all(&work_dir).await {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                warn!("동영상 임시 파일 삭제 실패: {}: {}", work_dir.display(), e);
+            }
+        }
+        result
+    }
+
+    async fn process_video_in(&self, job: &MediaJob, file_id: Uuid, work_dir: &std::path::Path) -> Result<(), JobFailure> {
+        let extension = std::path::Path::new(&job.file_key)
+            .extension()
+            .and_then(|s| s.to_str())
+            .unwrap_or("bin");
+        let input = work_dir.join(format!("input.{}", extension));
+        self.download_to(&job.file_key, work_dir, &input).await?;
+
+        let processor = VideoProcessor::new(&self.video);
+        let metadata = match processor.probe(&input).await {
+            Ok(Some(metadata)) => metadata,
+            Ok(None) => {
+                warn!("영상 스트림이 없는 동영상 파일: file_id={}", file_id);
+                return Ok(());
+            }
+            Err(VideoToolError::Unavailable(_)) => return Ok(()),
+            // 손상되었거나 지원하지 않는 파일은 재시도하지 않고 정보 없이 공개
+            Err(e) => return Err(JobFailure::fatal(format!("동영상 정보 조회 실패: {}", e), Some(ProcessingStatus::Completed))),
+        };
+
+        // 포스터 추출에 실패해도 동영상 정보는 저장
+        let poster = work_dir.join("poster.jpg");
+        let poster_key = match processor.extract_poster(&input, &poster, metadata.duration_seconds).await {
+            Ok(()) => {
+                let poster_key = video_poster_key(&job.file_key);
+                self.storage.put_file(&poster_key, &poster, content_type_for_key(&poster_key)).await
+                    .map_err(|e| JobFailure::retry(format!("포스터 저장 실패: {:?}", e), Some(ProcessingStatus::Completed)))?;
+                Some(poster_key)
+            }
+            Err(e) => {
+                warn!("포스터 프레임 추출 실패: file_id={}, {}", file_id, e);
+                None
+            }
+        };
+
+        self.save_video_metadata(file_id, &metadata, poster_key.as_deref())
+            .await
+            .map_err(|e| JobFailure::retry(format!("동영상 정보 저장 실패: {:?}", e), Some(ProcessingStatus::Completed)))?;
+
+        if let Some(poster_key) = poster_key {
+            let info = ThumbnailService::with_config(self.storage.clone(), &self.image)
+                .create_thumbnails(&poster_key)
+                .await
+                .map_err(|e| JobFailure::retry(format!("포스터 썸네일 생성 실패: {}", e), Some(ProcessingStatus::Completed)))?;
+            self.record_image_sizes(file_id, &info)
+                .await
+                .map_err(|e| JobFailure::retry(format!("썸네일 정보 저장 실패: {:?}", e), Some(ProcessingStatus::Completed)))?;
+            self.fill_video_post_thumbnails(file_id, &job.file_key, &poster_key)
+                .await
+                .map_err(|e| JobFailure::retry(format!("게시글 썸네일 갱신 실패: {:?}", e), Some(ProcessingStatus::Completed)))?;
+        }
+        Ok(())
+    }
+
+    // 저장소 객체를 로컬 파일로 복사
+    async fn download_to(&self, key: &str, work_dir: &std::path::Path, path: &std::path::Path) -> Result<(), JobFailure> {
+        let meta = match self.storage.head(key).await {
+            Ok(Some(meta)) => meta,
+            Ok(None) => return Err(JobFailure::fatal("저장소에 파일이 없습니다.".to_string(), Some(ProcessingStatus::Failed))),
+            Err(e) => return Err(JobFailure::retry(format!("저장소 조회 실패: {:?}", e), None)),
+        };
+        let mut stream = self.storage.get_range(key, 0, meta.size).await
+            .map_err(|e| JobFailure::retry(format!("원본 조회 실패: {:?}", e), None))?;
+
+        let io_failure = |e: std::io::Error| JobFailure::retry(format!("임시 파일 쓰기 실패: {}", e), None);
+        tokio::fs::create_dir_all(work_dir).await.map_err(io_failure)?;
+        let mut file = tokio::fs::File::create(path).await.map_err(io_failure)?;
+        while let Some(chunk) = stream.next().await {
+            file.write_all(&chunk.map_err(io_failure)?).await.map_err(io_failure)?;
+        }
+        file.flush().await.map_err(io_failure)?;
+        Ok(())
+    }
+
+    async fn save_video_metadata(&self, file_id: Uuid, metadata: &VideoMetadata, poster_key: Option<&str>) -> Result<(), ApiError> {
+        sqlx::query(
+            r#"
+            UPDATE files
+            SET duration_seconds = $2, width = $3, height = $4, poster_path = $5, media_info = $6
+            WHERE id = $1
+            "#
+        )
+        .bind(file_id)
+        .bind(metadata.duration_seconds)
+        .bind(metadata.width)
+        .bind(metadata.height)
+        .bind(poster_key.map(|key| self.storage.location(key)))
+        .bind(serde_json::to_value(metadata).unwrap_or_default())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    // 게시글 작성 시점에는 포스터가 없어 썸네일 정보가 비어 있으므로 완료 후 채움
+    async fn fill_video_post_thumbnails(&self, file_id: Uuid, file_key: &str, poster_key: &str) -> Result<(), ApiError> {
+        let thumbnail_urls = ThumbnailUrls {
+            thumb: Some(upload_url(&thumbnail_key(poster_key, "thumb"))),
+            card: Some(upload_url(&thumbnail_key(poster_key, "card"))),
+            large: Some(upload_url(&thumbnail_key(poster_key, "large"))),
+            srcsets: image_srcsets(&self.pool, self.storage.as_ref(), file_key).await?,
+            poster: Some(upload_url(poster_key)),
+        };
+        sqlx::query(
+            r#"
+            UPDATE posts p SET thumbnail_urls = $2
+            FROM file_entities fe
+            WHERE fe.file_id = $1 AND fe.entity_type = 'post' AND fe.entity_id = p.id AND p.thumbnail_urls IS NULL
+            "#
+        )
+        .bind(file_id)
+        .bind(serde_json::to_value(&thumbnail_urls).unwrap_or_default())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    // 원본의 EXIF(GPS, 기기 정보 등) 제거 후 같은 키에 다시 저장 (다시 인코딩하지 않으므로 화질 변화 없음)
+    // 생성된 썸네일 변형을 image_sizes에 기록하고 게시글에 저장된 썸네일 정보의 srcset 갱신
+    async fn record_image_sizes(&self, file_id: Uuid, info: &ThumbnailInfo) -> Result<(), ApiError> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM image_sizes WHERE file_id = $1")
+            .bind(file_id)
+            .execute(&mut *tx)
+            .await?;
+        for variant in &info.thumbnails {
+            sqlx::query(
+                r#"
+                INSERT INTO image_sizes (file_id, size_name, width, height, file_path, file_size, format)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                "#
+            )
+            .bind(file_id)
+            .bind(&variant.size_suffix)
+            .bind(variant.width as i32)
+            .bind(variant.height as i32)
+            .bind(self.storage.location(&variant.key))
+            .bind(variant.file_size as i64)
+            .bind(&variant.format)
+            .execute(&mut *tx)
+            .await?;
+        }
+        sqlx::query("UPDATE files SET has_thumbnails = $2 WHERE id = $1")
+            .bind(file_id)
+            .bind(!info.thumbnails.is_empty())
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+
+        // 게시글 작성 시점에는 작업 전이라 srcset이 비어 있으므로 완료 후 채움
+        // (동영상은 포스터 썸네일이지만 image_sizes는 동영상 파일 기준)
+        let file_path = sqlx::query_scalar::<_, String>("SELECT file_path FROM files WHERE id = $1")
+            .bind(file_id)
+            .fetch_one(&self.pool)
+            .await?;
+        let source_key = self.storage.key_from_location(&file_path).unwrap_or(&info.original_key);
+        let srcsets = image_srcsets(&self.pool, self.storage.as_ref(), source_key).await?;
+        sqlx::query(
+            r#"
+            UPDATE posts SET thumbnail_urls = thumbnail_urls || $1
+            WHERE thumbnail_urls->>'thumb' IN ($2, $3)
+            "#
+        )
+        .bind(serde_json::to_value(&srcsets).unwrap_or_default())
+        .bind(upload_url(&thumbnail_key(&info.original_key, "thumb")))
+        .bind(upload_url(&info.original_key))
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    // 중단된 작업 복구 및 오래된 완료 작업 정리
+    async fn maintenance(&self) -> Result<(), ApiError> {
+        let recovered = sqlx::query(
+            r#"
+            UPDATE media_jobs
+            SET status = CASE WHEN attempts >= max_attempts THEN 'failed' ELSE 'queued' END,
+                last_error = COALESCE(last_error, '작업 시간 초과 (서버 중단)'),
+                locked_at = NULL, run_at = NOW(), updated_at = NOW()
+            WHERE status = 'running' AND locked_at < NOW() - make_interval(mins => $1)
+            "#
+        )
+        .bind(LOCK_TIMEOUT_MINUTES)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+        if recovered > 0 {
+            info!("중단된 미디어 작업 복구: {}건", recovered);
+        }
+
+        sqlx::query("DELETE FROM media_jobs WHERE status = 'completed' AND updated_at < NOW() - make_interval(days => $1)")
+            .bind(COMPLETED_RETENTION_DAYS)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+/// 미디어 작업 큐 작업자 실행 (concurrency가 0이면 실행하지 않음)
+pub fn spawn_media_job_workers(
+    pool: PgPool,
+    storage: Arc<dyn Storage>,
+    config: MediaJobConfig,
+    virus_scan: VirusScanConfig,
+    image: ImageConfig,
+    video: VideoConfig,
+    temp_dir: String,
+) {
+    if config.concurrency == 0 {
+        return;
+    }
+    let permits = Arc::new(Semaphore::new(config.concurrency));
+    let poll_interval = Duration::from_secs(config.poll_interval_seconds.max(1));
+    let worker = Arc::new(MediaJobWorker { pool, storage, config, virus_scan, image, video, temp_dir });
+
+    tokio::spawn(async move {
+        let mut last_maintenance: Option<Instant> = None;
+        loop {
+            if last_maintenance.is_none_or(|at| at.elapsed() >= MAINTENANCE_INTERVAL) {
+                if let Err(e) = worker.maintenance().await {
+                    warn!("미디어 작업 정리 실패: {:?}", e);
+                }
+                last_maintenance = Some(Instant::now());
+            }
+
+            let available = permits.available_permits();
+            if available > 0 {
+                match worker.claim(available as i64).await {
+                    Ok(jobs) => {
+                        for job in jobs {
+                            let Ok(permit) = permits.clone().acquire_owned().await else {
+                                return;
+                            };
+                            let worker = worker.clone();
+                            tokio::spawn(async move {
+                                worker.execute(job).await;
+                                drop(permit);
+                                // 빈 자리가 생기면 바로 다음 작업을 가져감
+                                WAKEUP.notify_one();
+                            });
+                        }
+                    }
+                    Err(e) => warn!("미디어 작업 가져오기 실패: {:?}", e),
+                }
+            }
+
+            tokio::select! {
+                _ = WAKEUP.notified() => {}
+                _ = tokio::time::sleep(poll_interval) => {}
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay_seconds() {
+        assert_eq!(retry_delay_seconds(30, 1), 30);
+        assert_eq!(retry_delay_seconds(30, 2), 60);
+        assert_eq!(retry_delay_seconds(30, 4), 240);
+        assert_eq!(retry_delay_seconds(30, 20), MAX_RETRY_DELAY_SECONDS);
+        assert_eq!(retry_delay_seconds(0, 0), 1);
+        assert_eq!(retry_delay_seconds(i64::MAX, 3), MAX_RETRY_DELAY_SECONDS);
+    }
+}
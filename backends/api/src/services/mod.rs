@@ -1,5 +1,51 @@
 pub mod thumbnail;
 pub mod post_management;
+pub mod rbac;
+pub mod notification;
+pub mod realtime;
+pub mod report;
+pub mod point;
+pub mod search;
+pub mod oauth;
+pub mod token_blacklist;
+pub mod mail;
+pub mod account_email;
+pub mod storage;
+pub mod s3_storage;
+pub mod storage_migration;
+pub mod virus_scan;
+pub mod upload_session;
+pub mod file_gc;
+pub mod media_job;
+pub mod image_proxy;
+pub mod video;
+pub mod upload_limit;
+pub mod captcha;
+pub mod revision;
+pub mod rate_limit;
 
 pub use thumbnail::*;
-pub use post_management::*;
\ No newline at end of file
+pub use post_management::*;
+pub use rbac::*;
+pub use notification::*;
+pub use realtime::*;
+pub use report::*;
+pub use point::*;
+pub use search::*;
+pub use oauth::*;
+pub use token_blacklist::*;
+pub use mail::*;
+pub use account_email::*;
+pub use storage::*;
+pub use s3_storage::*;
+pub use storage_migration::*;
+pub use virus_scan::*;
+pub use upload_session::*;
+pub use file_gc::*;
+pub use media_job::*;
+pub use image_proxy::*;
+pub use video::*;
+pub use upload_limit::*;
+pub use captcha::*;
+pub use revision::*;
+pub use rate_limit::*;
@@ -0,0 +1,303 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::errors::ApiError;
+use crate::models::notification::{Notification, NotificationType};
+use crate::services::realtime::RealtimeService;
+
+// 활동 알림의 연결 대상 (게시글 상세로 이동)
+const POST_ENTITY: &str = "post";
+
+/// 사용자 알림 생성/조회/읽음 처리 (생성 시 실시간 이벤트 발행)
+pub struct NotificationService {
+    pool: PgPool,
+    realtime: RealtimeService,
+}
+
+impl NotificationService {
+    pub fn new(pool: PgPool, realtime: RealtimeService) -> Self {
+        Self { pool, realtime }
+    }
+
+    /// 알림 생성
+    pub async fn create(
+        &self,
+        user_id: Uuid,
+        notification_type: NotificationType,
+        title: &str,
+        message: &str,
+        entity_type: Option<&str>,
+        entity_id: Option<Uuid>,
+    ) -> Result<Notification, ApiError> {
+        let notification = sqlx::query_as::<_, Notification>(
+            r#"
+            INSERT INTO notifications (id, user_id, type, title, message, entity_type, entity_id)
+            VALUES (gen_random_uuid(), $1, $2, $3, $4, $5, $6)
+            RETURNING id, user_id, type, title, message, entity_type, entity_id, is_read, read_at, created_at
+            "#
+        )
+        .bind(user_id)
+        .bind(notification_type)
+        .bind(title)
+        .bind(message)
+        .bind(entity_type)
+        .bind(entity_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        self.realtime.publish_to_user(user_id, "notification", &notification).await;
+
+        Ok(notification)
+    }
+
+    /// 활동 알림 생성 (본인 활동은 알리지 않음)
+    async fn notify_activity(
+        &self,
+        actor_id: Uuid,
+        recipient_id: Uuid,
+        notification_type: NotificationType,
+        title: &str,
+        message: String,
+        post_id: Uuid,
+    ) -> Result<(), ApiError> {
+        if actor_id == recipient_id {
+            return Ok(());
+        }
+
+        self.create(recipient_id, notification_type, title, &message, Some(POST_ENTITY), Some(post_id))
+            .await?;
+        Ok(())
+    }
+
+    async fn actor_name(&self, actor_id: Uuid) -> Result<String, ApiError> {
+        let name = sqlx::query_scalar::<_, String>("SELECT name FROM users WHERE id = $1")
+            .bind(actor_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(name.unwrap_or_else(|| "알 수 없는 사용자".to_string()))
+    }
+
+    /// 댓글 작성 알림 (게시글 작성자, 대댓글이면 부모 댓글 작성자)
+    pub async fn notify_comment(
+        &self,
+        actor_id: Uuid,
+        post_id: Uuid,
+        parent_comment_id: Option<Uuid>,
+    ) -> Result<(), ApiError> {
+        let post = sqlx::query_as::<_, (Uuid, String)>("SELECT user_id, title FROM posts WHERE id = $1 AND user_id IS NOT NULL")
+            .bind(post_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        let Some((post_author, post_title)) = post else {
+            return Ok(());
+        };
+        let actor_name = self.actor_name(actor_id).await?;
+
+        self.notify_activity(
+            actor_id,
+            post_author,
+            NotificationType::Comment,
+            "새 댓글",
+            format!("{}님이 '{}' 게시글에 댓글을 남겼습니다.", actor_name, post_title),
+            post_id,
+        )
+        .await?;
+
+        if let Some(parent_comment_id) = parent_comment_id {
+            let parent_author = sqlx::query_scalar::<_, Uuid>("SELECT user_id FROM comments WHERE id = $1")
+                .bind(parent_comment_id)
+                .fetch_optional(&self.pool)
+                .await?;
+            // 게시글 작성자와 같으면 이미 알림을 받았으므로 제외
+            if let Some(parent_author) = parent_author.filter(|id| *id != post_author) {
+                self.notify_activity(
+                    actor_id,
+                    parent_author,
+                    NotificationType::Comment,
+                    "새 답글",
+                    format!("{}님이 회원님의 댓글에 답글을 남겼습니다.", actor_name),
+                    post_id,
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 답글(게시글) 작성 알림 (부모 게시글 작성자)
+    pub async fn notify_reply(&self, actor_id: Uuid, parent_post_id: Uuid, reply_id: Uuid) -> Result<(), ApiError> {
+        let parent = sqlx::query_as::<_, (Uuid, String)>("SELECT user_id, title FROM posts WHERE id = $1 AND user_id IS NOT NULL")
+            .bind(parent_post_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        let Some((parent_author, parent_title)) = parent else {
+            return Ok(());
+        };
+        let actor_name = self.actor_name(actor_id).await?;
+
+        self.notify_activity(
+            actor_id,
+            parent_author,
+            NotificationType::Comment,
+            "새 답글",
+            format!("{}님이 '{}' 게시글에 답글을 작성했습니다.", actor_name, parent_title),
+            reply_id,
+        )
+        .await
+    }
+
+    /// 게시글 좋아요 알림
+    pub async fn notify_post_like(&self, actor_id: Uuid, post_id: Uuid) -> Result<(), ApiError> {
+        let post = sqlx::query_as::<_, (Uuid, String)>("SELECT user_id, title FROM posts WHERE id = $1 AND user_id IS NOT NULL")
+            .bind(post_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        let Some((post_author, post_title)) = post else {
+            return Ok(());
+        };
+        let actor_name = self.actor_name(actor_id).await?;
+
+        self.notify_activity(
+            actor_id,
+            post_author,
+            NotificationType::Like,
+            "좋아요",
+            format!("{}님이 '{}' 게시글을 좋아합니다.", actor_name, post_title),
+            post_id,
+        )
+        .await
+    }
+
+    /// 댓글 좋아요 알림
+    pub async fn notify_comment_like(&self, actor_id: Uuid, comment_id: Uuid) -> Result<(), ApiError> {
+        let comment = sqlx::query_as::<_, (Uuid, Uuid)>("SELECT user_id, post_id FROM comments WHERE id = $1")
+            .bind(comment_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        let Some((comment_author, post_id)) = comment else {
+            return Ok(());
+        };
+        let actor_name = self.actor_name(actor_id).await?;
+
+        self.notify_activity(
+            actor_id,
+            comment_author,
+            NotificationType::Like,
+            "좋아요",
+            format!("{}님이 회원님의 댓글을 좋아합니다.", actor_name),
+            post_id,
+        )
+        .await
+    }
+
+    /// 공지 알림 일괄 발송 (role이 없으면 활성 사용자 전체)
+    /// role은 사용자 기본 역할(user/admin) 또는 RBAC 역할 이름과 비교
+    pub async fn broadcast_announcement(&self, title: &str, message: &str, role: Option<&str>) -> Result<u64, ApiError> {
+        let user_ids = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            INSERT INTO notifications (id, user_id, type, title, message)
+            SELECT gen_random_uuid(), u.id, $1, $2, $3
+            FROM users u
+            WHERE u.status = 'active'
+            AND (
+                $4::text IS NULL
+                OR u.role::text = $4
+                OR EXISTS (
+                    SELECT 1 FROM user_roles ur
+                    JOIN roles r ON ur.role_id = r.id
+                    WHERE ur.user_id = u.id AND r.name = $4 AND r.is_active = true
+                )
+            )
+            RETURNING user_id
+            "#
+        )
+        .bind(NotificationType::Announcement)
+        .bind(title)
+        .bind(message)
+        .bind(role)
+        .fetch_all(&self.pool)
+        .await?;
+
+        // 전체 발송은 공용 채널 하나로, 역할 대상 발송은 사용자별 채널로 전달
+        let event = serde_json::json!({ "title": title, "message": message });
+        if role.is_none() {
+            self.realtime.publish_to_all("announcement", &event).await;
+        } else {
+            for user_id in &user_ids {
+                self.realtime.publish_to_user(*user_id, "announcement", &event).await;
+            }
+        }
+
+        Ok(user_ids.len() as u64)
+    }
+
+    /// 내 알림 목록 (최신순)
+    pub async fn list(&self, user_id: Uuid, unread_only: bool, limit: i64, offset: i64) -> Result<(Vec<Notification>, i64), ApiError> {
+        let notifications = sqlx::query_as::<_, Notification>(
+            r#"
+            SELECT id, user_id, type, title, message, entity_type, entity_id, is_read, read_at, created_at
+            FROM notifications
+            WHERE user_id = $1 AND ($2 = false OR is_read = false)
+            ORDER BY created_at DESC
+            LIMIT $3 OFFSET $4
+            "#
+        )
+        .bind(user_id)
+        .bind(unread_only)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM notifications WHERE user_id = $1 AND ($2 = false OR is_read = false)"
+        )
+        .bind(user_id)
+        .bind(unread_only)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok((notifications, total))
+    }
+
+    /// 읽지 않은 알림 수
+    pub async fn unread_count(&self, user_id: Uuid) -> Result<i64, ApiError> {
+        let count = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM notifications WHERE user_id = $1 AND is_read = false"
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    /// 알림 읽음 처리 (본인 알림만)
+    pub async fn mark_read(&self, user_id: Uuid, notification_id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE notifications SET is_read = true, read_at = COALESCE(read_at, NOW())
+            WHERE id = $1 AND user_id = $2
+            "#
+        )
+        .bind(notification_id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 모든 알림 읽음 처리
+    pub async fn mark_all_read(&self, user_id: Uuid) -> Result<u64, ApiError> {
+        let result = sqlx::query(
+            "UPDATE notifications SET is_read = true, read_at = NOW() WHERE user_id = $1 AND is_read = false"
+        )
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
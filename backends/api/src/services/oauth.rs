@@ -0,0 +1,494 @@
+use axum_extra::extract::cookie::{Cookie, SameSite};
+use rand::{distributions::Alphanumeric, Rng};
+use redis::{AsyncCommands, Client as RedisClient};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::PgPool;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::config::{Config, OAuthProviderConfig};
+use crate::errors::ApiError;
+use crate::models::{OAuthAuthorizeResponse, SocialAccount, User, UserStatus};
+use crate::utils::auth::hash_refresh_token;
+
+// 인증 요청 state 보관 (CSRF 방지, 1회용)
+const STATE_KEY_PREFIX: &str = "oauth:state:";
+const STATE_TTL_SECONDS: u64 = 600;
+
+// 인증을 시작한 브라우저에 state 해시를 남기는 쿠키 (로그인 CSRF 방지)
+pub const OAUTH_STATE_COOKIE: &str = "oauth_state";
+const STATE_COOKIE_PATH: &str = "/api/auth";
+
+// 인증 요청 시 저장하는 정보 (link_user_id가 있으면 계정 연결 요청)
+#[derive(Debug, Serialize, Deserialize)]
+struct OAuthState {
+    provider: String,
+    link_user_id: Option<Uuid>,
+}
+
+// 제공자 토큰 응답
+#[derive(Debug, Deserialize)]
+struct OAuthTokenResponse {
+    access_token: Option<String>,
+    refresh_token: Option<String>,
+    expires_in: Option<i64>,
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+/// state 해시를 담는 쿠키 (HttpOnly, SameSite=Lax, state와 같은 10분 유효)
+pub fn oauth_state_cookie(state: &str, secure: bool) -> Cookie<'static> {
+    Cookie::build((OAUTH_STATE_COOKIE, hash_refresh_token(state)))
+        .path(STATE_COOKIE_PATH)
+        .http_only(true)
+        .same_site(SameSite::Lax)
+        .secure(secure)
+        .max_age(time::Duration::seconds(STATE_TTL_SECONDS as i64))
+        .build()
+}
+
+/// 콜백 처리 후 state 쿠키 삭제
+pub fn clear_oauth_state_cookie() -> Cookie<'static> {
+    Cookie::build(OAUTH_STATE_COOKIE).path(STATE_COOKIE_PATH).build()
+}
+
+/// 제공자 사용자 정보 (제공자별 응답 형식을 공통 형태로 변환)
+#[derive(Debug, PartialEq)]
+pub struct SocialProfile {
+    pub provider_id: String,
+    pub email: Option<String>,
+    pub name: Option<String>,
+    pub profile_image: Option<String>,
+}
+
+/// 소셜 로그인 (OAuth2 authorization code) 및 계정 연결 관리
+pub struct OAuthService {
+    pool: PgPool,
+    redis: RedisClient,
+    config: Config,
+}
+
+impl OAuthService {
+    pub fn new(pool: PgPool, redis: RedisClient, config: Config) -> Self {
+        Self { pool, redis, config }
+    }
+
+    fn provider(&self, provider: &str) -> Result<&OAuthProviderConfig, ApiError> {
+        self.config
+            .oauth_provider(provider)
+            .ok_or_else(|| ApiError::NotFound("지원하지 않는 소셜 로그인입니다.".to_string()))
+    }
+
+    /// 제공자 인증 페이지 주소 생성 (state는 Redis에 10분간 보관)
+    pub async fn authorize_url(&self, provider: &str, link_user_id: Option<Uuid>) -> Result<OAuthAuthorizeResponse, ApiError> {
+        let provider_config = self.provider(provider)?;
+
+        let state: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(32)
+            .map(char::from)
+            .collect();
+        let payload = serde_json::to_string(&OAuthState {
+            provider: provider.to_string(),
+            link_user_id,
+        })
+        .map_err(|e| ApiError::Internal(e.to_string()))?;
+
+        let mut redis_conn = self.redis_connection().await?;
+        redis_conn
+            .set_ex::<_, _, ()>(format!("{}{}", STATE_KEY_PREFIX, state), payload, STATE_TTL_SECONDS)
+            .await
+            .map_err(|e| ApiError::Internal(format!("Redis 저장 실패: {}", e)))?;
+
+        let separator = if provider_config.authorize_url.contains('?') { '&' } else { '?' };
+        let mut authorize_url = format!(
+            "{}{}response_type=code&client_id={}&redirect_uri={}&state={}",
+            provider_config.authorize_url,
+            separator,
+            urlencoding::encode(&provider_config.client_id),
+            urlencoding::encode(&provider_config.redirect_uri),
+            state,
+        );
+        if !provider_config.scope.is_empty() {
+            authorize_url.push_str(&format!("&scope={}", urlencoding::encode(&provider_config.scope)));
+        }
+
+        Ok(OAuthAuthorizeResponse { authorize_url, state })
+    }
+
+    /// 소셜 로그인 (연결된 계정이 없으면 새 회원으로 가입)
+    pub async fn login(&self, provider: &str, code: &str, state: &str, state_cookie: Option<&str>) -> Result<User, ApiError> {
+        let oauth_state = self.take_state(provider, state, state_cookie).await?;
+        if oauth_state.link_user_id.is_some() {
+            return Err(ApiError::BadRequest("계정 연결용 인증 요청입니다.".to_string()));
+        }
+
+        let (tokens, profile) = self.fetch_profile(provider, code, state).await?;
+
+        let linked_user_id = sqlx::query_scalar::<_, Uuid>(
+            "SELECT user_id FROM user_social_accounts WHERE provider = $1 AND provider_id = $2"
+        )
+        .bind(provider)
+        .bind(&profile.provider_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let user = match linked_user_id {
+            Some(user_id) => {
+                self.save_account(user_id, provider, &profile, &tokens).await?;
+                sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = $1")
+                    .bind(user_id)
+                    .fetch_one(&self.pool)
+                    .await?
+            }
+            None => self.create_user(provider, &profile, &tokens).await?,
+        };
+
+        if user.status.as_ref().is_some_and(|status| !matches!(status, UserStatus::Active)) {
+            return Err(ApiError::Forbidden("이용이 제한된 계정입니다.".to_string()));
+        }
+
+        Ok(user)
+    }
+
+    /// 로그인한 사용자에게 소셜 계정 연결
+    pub async fn link(&self, user_id: Uuid, provider: &str, code: &str, state: &str, state_cookie: Option<&str>) -> Result<SocialAccount, ApiError> {
+        let oauth_state = self.take_state(provider, state, state_cookie).await?;
+        if oauth_state.link_user_id != Some(user_id) {
+            return Err(ApiError::BadRequest("유효하지 않은 인증 요청입니다.".to_string()));
+        }
+
+        let (tokens, profile) = self.fetch_profile(provider, code, state).await?;
+
+        let linked_user_id = sqlx::query_scalar::<_, Uuid>(
+            "SELECT user_id FROM user_social_accounts WHERE provider = $1 AND provider_id = $2"
+        )
+        .bind(provider)
+        .bind(&profile.provider_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        if linked_user_id.is_some_and(|linked| linked != user_id) {
+            return Err(ApiError::BadRequest("다른 계정에 이미 연결된 소셜 계정입니다.".to_string()));
+        }
+
+        let already_linked = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM user_social_accounts WHERE user_id = $1 AND provider = $2 AND provider_id <> $3)"
+        )
+        .bind(user_id)
+        .bind(provider)
+        .bind(&profile.provider_id)
+        .fetch_one(&self.pool)
+        .await?;
+        if already_linked {
+            return Err(ApiError::BadRequest("이미 같은 제공자의 다른 계정이 연결되어 있습니다.".to_string()));
+        }
+
+        self.save_account(user_id, provider, &profile, &tokens).await
+    }
+
+    /// 연결된 소셜 계정 목록
+    pub async fn list_accounts(&self, user_id: Uuid) -> Result<Vec<SocialAccount>, ApiError> {
+        let accounts = sqlx::query_as::<_, SocialAccount>(
+            "SELECT * FROM user_social_accounts WHERE user_id = $1 ORDER BY created_at"
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(accounts)
+    }
+
+    /// 소셜 계정 연결 해제 (비밀번호가 없으면 마지막 로그인 수단은 해제할 수 없음)
+    pub async fn unlink(&self, user_id: Uuid, provider: &str) -> Result<(), ApiError> {
+        let has_password = sqlx::query_scalar::<_, bool>(
+            "SELECT password_hash IS NOT NULL FROM users WHERE id = $1"
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("사용자를 찾을 수 없습니다.".to_string()))?;
+
+        let account_count = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM user_social_accounts WHERE user_id = $1"
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        if !has_password && account_count <= 1 {
+            return Err(ApiError::BadRequest("비밀번호를 설정하기 전에는 마지막 소셜 계정을 해제할 수 없습니다.".to_string()));
+        }
+
+        let result = sqlx::query("DELETE FROM user_social_accounts WHERE user_id = $1 AND provider = $2")
+            .bind(user_id)
+            .bind(provider)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(ApiError::NotFound("연결된 소셜 계정이 없습니다.".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn redis_connection(&self) -> Result<redis::aio::Connection, ApiError> {
+        self.redis
+            .get_async_connection()
+            .await
+            .map_err(|e| ApiError::Internal(format!("Redis 연결 실패: {}", e)))
+    }
+
+    /// state 확인 후 삭제 (재사용 방지)
+    /// 인증을 시작한 브라우저의 state 쿠키와 일치해야 함 (다른 사람의 code/state로 로그인시키는 요청 차단)
+    async fn take_state(&self, provider: &str, state: &str, state_cookie: Option<&str>) -> Result<OAuthState, ApiError> {
+        if state_cookie != Some(hash_refresh_token(state).as_str()) {
+            return Err(ApiError::BadRequest("만료되었거나 유효하지 않은 인증 요청입니다.".to_string()));
+        }
+
+        let mut redis_conn = self.redis_connection().await?;
+        let payload: Option<String> = redis::cmd("GETDEL")
+            .arg(format!("{}{}", STATE_KEY_PREFIX, state))
+            .query_async(&mut redis_conn)
+            .await
+            .map_err(|e| ApiError::Internal(format!("Redis 조회 실패: {}", e)))?;
+
+        let oauth_state = payload
+            .and_then(|payload| serde_json::from_str::<OAuthState>(&payload).ok())
+            .filter(|oauth_state| oauth_state.provider == provider)
+            .ok_or_else(|| ApiError::BadRequest("만료되었거나 유효하지 않은 인증 요청입니다.".to_string()))?;
+
+        Ok(oauth_state)
+    }
+
+    /// 인증 코드로 토큰 발급 후 제공자 사용자 정보 조회
+    async fn fetch_profile(&self, provider: &str, code: &str, state: &str) -> Result<(OAuthTokenResponse, SocialProfile), ApiError> {
+        let provider_config = self.provider(provider)?;
+        let client = reqwest::Client::new();
+
+        let tokens = client
+            .post(&provider_config.token_url)
+            .header("Accept", "application/json")
+            .form(&[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("client_id", provider_config.client_id.as_str()),
+                ("client_secret", provider_config.client_secret.as_str()),
+                ("redirect_uri", provider_config.redirect_uri.as_str()),
+                ("state", state),
+            ])
+            .send()
+            .await
+            .map_err(|e| {
+                warn!("소셜 로그인 토큰 요청 실패: provider={}, error={}", provider, e);
+                ApiError::Internal("소셜 로그인 제공자에 연결할 수 없습니다.".to_string())
+            })?
+            .json::<OAuthTokenResponse>()
+            .await
+            .map_err(|e| {
+                warn!("소셜 로그인 토큰 응답 해석 실패: provider={}, error={}", provider, e);
+                ApiError::Authentication("소셜 로그인 인증에 실패했습니다.".to_string())
+            })?;
+
+        let Some(access_token) = tokens.access_token.as_deref() else {
+            warn!(
+                "소셜 로그인 토큰 발급 거절: provider={}, error={:?}, description={:?}",
+                provider, tokens.error, tokens.error_description
+            );
+            return Err(ApiError::Authentication("소셜 로그인 인증에 실패했습니다.".to_string()));
+        };
+
+        let response = client
+            .get(&provider_config.userinfo_url)
+            .bearer_auth(access_token)
+            .send()
+            .await
+            .map_err(|e| {
+                warn!("소셜 로그인 사용자 정보 요청 실패: provider={}, error={}", provider, e);
+                ApiError::Internal("소셜 로그인 제공자에 연결할 수 없습니다.".to_string())
+            })?;
+        if !response.status().is_success() {
+            warn!("소셜 로그인 사용자 정보 조회 거절: provider={}, status={}", provider, response.status());
+            return Err(ApiError::Authentication("소셜 로그인 사용자 정보를 가져올 수 없습니다.".to_string()));
+        }
+
+        let body = response
+            .json::<Value>()
+            .await
+            .map_err(|_| ApiError::Authentication("소셜 로그인 사용자 정보를 가져올 수 없습니다.".to_string()))?;
+        let profile = parse_profile(provider, &body)
+            .ok_or_else(|| ApiError::Authentication("소셜 로그인 사용자 정보를 가져올 수 없습니다.".to_string()))?;
+
+        Ok((tokens, profile))
+    }
+
+    /// 소셜 계정 연결 정보 저장 (이미 있으면 토큰 갱신)
+    async fn save_account(
+        &self,
+        user_id: Uuid,
+        provider: &str,
+        profile: &SocialProfile,
+        tokens: &OAuthTokenResponse,
+    ) -> Result<SocialAccount, ApiError> {
+        let account = sqlx::query_as::<_, SocialAccount>(
+            r#"
+            INSERT INTO user_social_accounts (id, user_id, provider, provider_id, provider_email, access_token, refresh_token, expires_at)
+            VALUES (gen_random_uuid(), $1, $2, $3, $4, $5, $6, NOW() + make_interval(secs => $7))
+            ON CONFLICT (provider, provider_id) DO UPDATE SET
+                provider_email = COALESCE(EXCLUDED.provider_email, user_social_accounts.provider_email),
+                access_token = EXCLUDED.access_token,
+                refresh_token = COALESCE(EXCLUDED.refresh_token, user_social_accounts.refresh_token),
+                expires_at = EXCLUDED.expires_at,
+                updated_at = NOW()
+            RETURNING *
+            "#
+        )
+        .bind(user_id)
+        .bind(provider)
+        .bind(&profile.provider_id)
+        .bind(profile.email.as_deref())
+        .bind(tokens.access_token.as_deref())
+        .bind(tokens.refresh_token.as_deref())
+        .bind(tokens.expires_in.map(|secs| secs as f64))
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(account)
+    }
+
+    /// 소셜 계정으로 새 회원 가입
+    /// 같은 이메일의 기존 회원이 있으면 자동 연결하지 않고 로그인 후 연결하도록 안내
+    async fn create_user(&self, provider: &str, profile: &SocialProfile, tokens: &OAuthTokenResponse) -> Result<User, ApiError> {
+        if let Some(email) = profile.email.as_deref() {
+            let email_taken = sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM users WHERE email = $1)")
+                .bind(email)
+                .fetch_one(&self.pool)
+                .await?;
+            if email_taken {
+                return Err(ApiError::BadRequest(
+                    "이미 가입된 이메일입니다. 기존 계정으로 로그인한 뒤 소셜 계정을 연결해주세요.".to_string(),
+                ));
+            }
+        }
+
+        // 이메일을 제공하지 않는 제공자는 내부용 주소로 가입
+        let email = profile
+            .email
+            .clone()
+            .unwrap_or_else(|| format!("{}_{}@social.local", provider, profile.provider_id));
+        let name = profile.name.clone().unwrap_or_else(|| format!("{} 사용자", provider));
+
+        let mut tx = self.pool.begin().await?;
+
+        let user = sqlx::query_as::<_, User>(
+            "INSERT INTO users (id, email, name, profile_image, password_hash, role, status, created_at, updated_at)
+             VALUES (gen_random_uuid(), $1, $2, $3, NULL, 'user', 'active', NOW(), NOW())
+             RETURNING *"
+        )
+        .bind(&email)
+        .bind(&name)
+        .bind(profile.profile_image.as_deref())
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO user_social_accounts (id, user_id, provider, provider_id, provider_email, access_token, refresh_token, expires_at)
+            VALUES (gen_random_uuid(), $1, $2, $3, $4, $5, $6, NOW() + make_interval(secs => $7))
+            "#
+        )
+        .bind(user.id)
+        .bind(provider)
+        .bind(&profile.provider_id)
+        .bind(profile.email.as_deref())
+        .bind(tokens.access_token.as_deref())
+        .bind(tokens.refresh_token.as_deref())
+        .bind(tokens.expires_in.map(|secs| secs as f64))
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(user)
+    }
+}
+
+/// 제공자별 사용자 정보 응답을 공통 형태로 변환
+/// 제공자가 인증되지 않았다고 표시한 이메일은 사용하지 않음
+fn parse_profile(provider: &str, body: &Value) -> Option<SocialProfile> {
+    let text = |value: &Value| value.as_str().filter(|s| !s.is_empty()).map(str::to_string);
+
+    match provider {
+        "kakao" => {
+            let account = &body["kakao_account"];
+            let provider_id = match &body["id"] {
+                Value::Number(id) => id.to_string(),
+                id => text(id)?,
+            };
+            let email_verified = account["is_email_verified"].as_bool().unwrap_or(true);
+            Some(SocialProfile {
+                provider_id,
+                email: text(&account["email"]).filter(|_| email_verified),
+                name: text(&account["profile"]["nickname"]).or_else(|| text(&body["properties"]["nickname"])),
+                profile_image: text(&account["profile"]["profile_image_url"]),
+            })
+        }
+        "naver" => {
+            let response = &body["response"];
+            Some(SocialProfile {
+                provider_id: text(&response["id"])?,
+                email: text(&response["email"]),
+                name: text(&response["name"]).or_else(|| text(&response["nickname"])),
+                profile_image: text(&response["profile_image"]),
+            })
+        }
+        "google" => {
+            let email_verified = body["email_verified"].as_bool().unwrap_or(true);
+            Some(SocialProfile {
+                provider_id: text(&body["sub"])?,
+                email: text(&body["email"]).filter(|_| email_verified),
+                name: text(&body["name"]),
+                profile_image: text(&body["picture"]),
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_profile() {
+        let kakao = json!({
+            "id": 12345,
+            "kakao_account": {
+                "email": "user@kakao.com",
+                "is_email_verified": false,
+                "profile": { "nickname": "카카오", "profile_image_url": "https://k.kakaocdn.net/p.jpg" }
+            }
+        });
+        assert_eq!(
+            parse_profile("kakao", &kakao),
+            Some(SocialProfile {
+                provider_id: "12345".to_string(),
+                email: None,
+                name: Some("카카오".to_string()),
+                profile_image: Some("https://k.kakaocdn.net/p.jpg".to_string()),
+            })
+        );
+
+        let naver = json!({ "resultcode": "00", "response": { "id": "abc", "email": "user@naver.com", "nickname": "네이버" } });
+        let profile = parse_profile("naver", &naver).unwrap();
+        assert_eq!(profile.provider_id, "abc");
+        assert_eq!(profile.email.as_deref(), Some("user@naver.com"));
+        assert_eq!(profile.name.as_deref(), Some("네이버"));
+
+        let google = json!({ "sub": "109", "email": "user@gmail.com", "email_verified": true, "name": "Google" });
+        assert_eq!(parse_profile("google", &google).unwrap().email.as_deref(), Some("user@gmail.com"));
+
+        assert_eq!(parse_profile("naver", &json!({ "resultcode": "024" })), None);
+        assert_eq!(parse_profile("github", &google), None);
+    }
+}
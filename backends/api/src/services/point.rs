@@ -0,0 +1,154 @@
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::errors::ApiError;
+use crate::models::PointTransaction;
+
+/// 포인트 적립/차감 및 거래 내역 관리
+pub struct PointService {
+    pool: PgPool,
+}
+
+impl PointService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 포인트 변동 기록 (호출 측 트랜잭션 안에서 실행)
+    /// 잔액이 음수가 되는 차감은 거절하고 None 반환, 성공 시 변경 후 잔액 반환
+    pub async fn apply(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+        amount: i32,
+        transaction_type: &str,
+        reason: &str,
+        reference_type: Option<&str>,
+        reference_id: Option<Uuid>,
+    ) -> Result<Option<i32>, sqlx::Error> {
+        if amount == 0 {
+            let balance = sqlx::query_scalar::<_, i32>("SELECT COALESCE(points, 0) FROM users WHERE id = $1")
+                .bind(user_id)
+                .fetch_optional(&mut *conn)
+                .await?;
+            return Ok(balance);
+        }
+
+        let balance = sqlx::query_scalar::<_, i32>(
+            r#"
+            UPDATE users SET points = COALESCE(points, 0) + $2
+            WHERE id = $1 AND COALESCE(points, 0) + $2 >= 0
+            RETURNING points
+            "#
+        )
+        .bind(user_id)
+        .bind(amount)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        if balance.is_none() {
+            return Ok(None);
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO point_transactions (id, user_id, type, amount, reason, reference_type, reference_id)
+            VALUES (gen_random_uuid(), $1, $2, $3, $4, $5, $6)
+            "#
+        )
+        .bind(user_id)
+        .bind(transaction_type)
+        .bind(amount)
+        .bind(reason)
+        .bind(reference_type)
+        .bind(reference_id)
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(balance)
+    }
+
+    /// 대상별 최초 1회만 포인트 변동 기록 (게시글 열람, 파일 다운로드)
+    /// 이미 처리된 대상이면 변동 없이 true, 잔액 부족이면 false 반환
+    pub async fn apply_once(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+        amount: i32,
+        transaction_type: &str,
+        reason: &str,
+        reference_type: &str,
+        reference_id: Uuid,
+    ) -> Result<bool, sqlx::Error> {
+        if amount == 0 {
+            return Ok(true);
+        }
+
+        // 동시 요청으로 중복 처리되지 않도록 사용자 행을 잠근 뒤 확인
+        sqlx::query("SELECT id FROM users WHERE id = $1 FOR UPDATE")
+            .bind(user_id)
+            .execute(&mut *conn)
+            .await?;
+
+        let already = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM point_transactions WHERE user_id = $1 AND type = $2 AND reference_id = $3)"
+        )
+        .bind(user_id)
+        .bind(transaction_type)
+        .bind(reference_id)
+        .fetch_one(&mut *conn)
+        .await?;
+        if already {
+            return Ok(true);
+        }
+
+        let balance = Self::apply(conn, user_id, amount, transaction_type, reason, Some(reference_type), Some(reference_id)).await?;
+        Ok(balance.is_some())
+    }
+
+    /// 현재 포인트 잔액
+    pub async fn balance(&self, user_id: Uuid) -> Result<i32, ApiError> {
+        let balance = sqlx::query_scalar::<_, i32>("SELECT COALESCE(points, 0) FROM users WHERE id = $1")
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("사용자를 찾을 수 없습니다.".to_string()))?;
+
+        Ok(balance)
+    }
+
+    /// 포인트 거래 내역 (최신순)
+    pub async fn history(&self, user_id: Uuid, limit: i64, offset: i64) -> Result<(Vec<PointTransaction>, i64), ApiError> {
+        let transactions = sqlx::query_as::<_, PointTransaction>(
+            r#"
+            SELECT id, user_id, type, amount, reason, reference_type, reference_id, created_at
+            FROM point_transactions
+            WHERE user_id = $1
+            ORDER BY created_at DESC
+            LIMIT $2 OFFSET $3
+            "#
+        )
+        .bind(user_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM point_transactions WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok((transactions, total))
+    }
+
+    /// 관리자 포인트 지급/차감
+    pub async fn adjust(&self, user_id: Uuid, amount: i32, transaction_type: &str, reason: &str, admin_id: Uuid) -> Result<i32, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        let balance = Self::apply(&mut tx, user_id, amount, transaction_type, reason, Some("admin"), Some(admin_id))
+            .await?
+            .ok_or_else(|| ApiError::BadRequest("차감할 포인트가 보유 포인트보다 많거나 사용자가 없습니다.".to_string()))?;
+
+        tx.commit().await?;
+        Ok(balance)
+    }
+}
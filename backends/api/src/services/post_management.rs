@@ -157,7 +157,7 @@ impl PostManagementService {
     }
 
     /// 게시글 숨김
-    pub async fn hide_post(&self, data: PostHideRequest, user_id: Uuid) -> Result<PostHideHistory, ApiError> {
+    pub async fn hide_post(&self, data: PostHideRequest, user_id: Option<Uuid>) -> Result<PostHideHistory, ApiError> {
         // 트랜잭션 시작
         let mut tx = self.pool.begin().await?;
 
@@ -181,6 +181,12 @@ impl PostManagementService {
 
         let history = self.create_hide_history(hide_data).await?;
 
+        // 게시글 상태를 숨김으로 변경 (사이트 목록/상세에서 제외)
+        sqlx::query("UPDATE posts SET status = 'hidden' WHERE id = $1")
+            .bind(data.post_id)
+            .execute(&mut *tx)
+            .await?;
+
         // 트랜잭션 커밋
         tx.commit().await?;
 
@@ -201,6 +207,12 @@ impl PostManagementService {
             .fetch_one(&self.pool)
             .await?;
 
+        // 숨김 상태였던 게시글 복원
+        sqlx::query("UPDATE posts SET status = 'published' WHERE id = $1 AND status = 'hidden'")
+            .bind(data.post_id)
+            .execute(&self.pool)
+            .await?;
+
         Ok(result)
     }
 
@@ -0,0 +1,25 @@
+use redis::{Client as RedisClient, RedisError};
+
+const RATE_LIMIT_KEY_PREFIX: &str = "ratelimit:";
+
+/// 고정 구간 요청 수 제한 (구간 안에서 limit번까지 허용, limit이 0이면 제한 없음)
+/// Redis를 사용할 수 없으면 오류를 반환하므로 호출하는 쪽에서 요청을 거부
+pub async fn check_rate_limit(redis: &RedisClient, key: &str, limit: u32, window_seconds: u64) -> Result<bool, RedisError> {
+    if limit == 0 {
+        return Ok(true);
+    }
+
+    let mut conn = redis.get_async_connection().await?;
+
+    // 만료 시간 설정과 증가를 한 트랜잭션으로 처리 (증가 후 만료 설정 전에 실패해 키가 영구히 남지 않도록)
+    // 구간의 첫 요청에서만 SET NX로 만료 시간이 있는 키 생성
+    let key = format!("{}{}", RATE_LIMIT_KEY_PREFIX, key);
+    let (count,): (u64,) = redis::pipe()
+        .atomic()
+        .cmd("SET").arg(&key).arg(0).arg("NX").arg("EX").arg(window_seconds.max(1)).ignore()
+        .incr(&key, 1)
+        .query_async(&mut conn)
+        .await?;
+
+    Ok(count <= limit as u64)
+}
@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+
+use redis::{AsyncCommands, Client as RedisClient};
+use sqlx::PgPool;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::errors::ApiError;
+
+// 사용자별 권한 캐시 키 접두사 / 만료 시간(초)
+const PERMISSION_CACHE_PREFIX: &str = "rbac:permissions:";
+const PERMISSION_CACHE_TTL: u64 = 600;
+
+/// 사용자 권한 조회 및 Redis 캐시 관리
+pub struct RbacService {
+    pool: PgPool,
+    redis: RedisClient,
+}
+
+impl RbacService {
+    pub fn new(pool: PgPool, redis: RedisClient) -> Self {
+        Self { pool, redis }
+    }
+
+    fn cache_key(user_id: Uuid) -> String {
+        format!("{}{}", PERMISSION_CACHE_PREFIX, user_id)
+    }
+
+    /// 사용자가 가진 권한 목록 ("resource.action" 형태)
+    /// 캐시에 없으면 DB에서 조회한 뒤 캐시에 저장
+    pub async fn get_user_permission_keys(&self, user_id: Uuid) -> Result<HashSet<String>, ApiError> {
+        let key = Self::cache_key(user_id);
+
+        if let Ok(mut redis_conn) = self.redis.get_async_connection().await {
+            let cached: Result<Option<String>, redis::RedisError> = redis_conn.get(&key).await;
+            if let Ok(Some(cached)) = cached {
+                if let Ok(permissions) = serde_json::from_str::<HashSet<String>>(&cached) {
+                    return Ok(permissions);
+                }
+            }
+        }
+
+        let rows = sqlx::query_as::<_, (String, String)>(
+            r#"
+            SELECT DISTINCT p.resource, p.action FROM permissions p
+            INNER JOIN role_permissions rp ON p.id = rp.permission_id
+            INNER JOIN roles r ON rp.role_id = r.id
+            INNER JOIN user_roles ur ON r.id = ur.role_id
+            WHERE ur.user_id = $1
+            AND p.is_active = true
+            AND r.is_active = true
+            "#
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let permissions: HashSet<String> = rows
+            .into_iter()
+            .map(|(resource, action)| format!("{}.{}", resource, action))
+            .collect();
+
+        if let Ok(mut redis_conn) = self.redis.get_async_connection().await {
+            if let Ok(serialized) = serde_json::to_string(&permissions) {
+                let result: Result<(), redis::RedisError> = redis_conn
+                    .set_ex(&key, serialized, PERMISSION_CACHE_TTL)
+                    .await;
+                if let Err(e) = result {
+                    warn!("권한 캐시 저장 실패: {}", e);
+                }
+            }
+        }
+
+        Ok(permissions)
+    }
+
+    /// 사용자가 특정 리소스/액션 권한을 가지고 있는지 확인
+    pub async fn has_permission(&self, user_id: Uuid, resource: &str, action: &str) -> Result<bool, ApiError> {
+        let permissions = self.get_user_permission_keys(user_id).await?;
+        Ok(permissions.contains(&format!("{}.{}", resource, action)))
+    }
+
+    /// 특정 사용자의 권한 캐시 무효화 (역할 할당 변경 시)
+    pub async fn invalidate_user(&self, user_id: Uuid) {
+        if let Ok(mut redis_conn) = self.redis.get_async_connection().await {
+            let _: Result<(), redis::RedisError> = redis_conn.del(Self::cache_key(user_id)).await;
+        }
+    }
+
+    /// 특정 역할을 가진 모든 사용자의 권한 캐시 무효화 (역할 권한 변경 시)
+    pub async fn invalidate_role(&self, role_id: Uuid) -> Result<(), ApiError> {
+        let user_ids = sqlx::query_scalar::<_, Uuid>(
+            "SELECT user_id FROM user_roles WHERE role_id = $1"
+        )
+        .bind(role_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        if user_ids.is_empty() {
+            return Ok(());
+        }
+
+        if let Ok(mut redis_conn) = self.redis.get_async_connection().await {
+            let keys: Vec<String> = user_ids.into_iter().map(Self::cache_key).collect();
+            let _: Result<(), redis::RedisError> = redis_conn.del(keys).await;
+        }
+
+        Ok(())
+    }
+
+    /// 전체 권한 캐시 무효화 (권한 정의 변경 시)
+    pub async fn invalidate_all(&self) {
+        if let Ok(mut redis_conn) = self.redis.get_async_connection().await {
+            let keys: Vec<String> = {
+                let mut keys = Vec::new();
+                if let Ok(mut iter) = redis_conn
+                    .scan_match::<_, String>(format!("{}*", PERMISSION_CACHE_PREFIX))
+                    .await
+                {
+                    while let Some(key) = iter.next_item().await {
+                        keys.push(key);
+                    }
+                }
+                keys
+            };
+
+            if !keys.is_empty() {
+                let _: Result<(), redis::RedisError> = redis_conn.del(keys).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use redis::{aio::ConnectionManager, AsyncCommands, Client as RedisClient};
+use serde::{Deserialize, Serialize};
+use tokio::sync::OnceCell;
+use tracing::warn;
+use uuid::Uuid;
+
+// Redis pub/sub 채널 (여러 API 인스턴스가 같은 채널을 구독)
+const USER_CHANNEL_PREFIX: &str = "events:user:";
+const POST_CHANNEL_PREFIX: &str = "events:post:";
+pub const BROADCAST_CHANNEL: &str = "events:broadcast";
+
+/// 실시간 이벤트 (SSE event 이름과 JSON 데이터)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RealtimeEvent {
+    pub event: String,
+    pub data: serde_json::Value,
+}
+
+/// Redis pub/sub 기반 실시간 이벤트 발행 (AppState에 두고 복제해서 사용, 발행 연결은 공유)
+#[derive(Clone)]
+pub struct RealtimeService {
+    redis: RedisClient,
+    connection: Arc<OnceCell<ConnectionManager>>,
+}
+
+impl RealtimeService {
+    pub fn new(redis: RedisClient) -> Self {
+        Self { redis, connection: Arc::new(OnceCell::new()) }
+    }
+
+    // 첫 발행 때 연결하고 이후에는 같은 연결 재사용 (끊기면 ConnectionManager가 다시 연결)
+    async fn connection(&self) -> Option<ConnectionManager> {
+        match self.connection.get_or_try_init(|| ConnectionManager::new(self.redis.clone())).await {
+            Ok(conn) => Some(conn.clone()),
+            Err(e) => {
+                warn!("실시간 이벤트용 Redis 연결 실패: {}", e);
+                None
+            }
+        }
+    }
+
+    pub fn user_channel(user_id: Uuid) -> String {
+        format!("{}{}", USER_CHANNEL_PREFIX, user_id)
+    }
+
+    pub fn post_channel(post_id: Uuid) -> String {
+        format!("{}{}", POST_CHANNEL_PREFIX, post_id)
+    }
+
+    /// 채널에 이벤트 발행 (실패해도 요청 처리에는 영향 없음)
+    async fn publish<T: Serialize>(&self, channel: String, event: &str, data: &T) {
+        let payload = match serde_json::to_value(data) {
+            Ok(data) => RealtimeEvent { event: event.to_string(), data },
+            Err(e) => {
+                warn!("실시간 이벤트 직렬화 실패: {}", e);
+                return;
+            }
+        };
+        let Ok(payload) = serde_json::to_string(&payload) else {
+            return;
+        };
+
+        let Some(mut redis_conn) = self.connection().await else {
+            return;
+        };
+        let result: Result<(), redis::RedisError> = redis_conn.publish(&channel, payload).await;
+        if let Err(e) = result {
+            warn!("실시간 이벤트 발행 실패: channel={}, error={}", channel, e);
+        }
+    }
+
+    /// 특정 사용자에게 이벤트 발행
+    pub async fn publish_to_user<T: Serialize>(&self, user_id: Uuid, event: &str, data: &T) {
+        self.publish(Self::user_channel(user_id), event, data).await;
+    }
+
+    /// 게시글을 보고 있는 사용자들에게 이벤트 발행
+    pub async fn publish_to_post<T: Serialize>(&self, post_id: Uuid, event: &str, data: &T) {
+        self.publish(Self::post_channel(post_id), event, data).await;
+    }
+
+    /// 접속 중인 모든 사용자에게 이벤트 발행
+    pub async fn publish_to_all<T: Serialize>(&self, event: &str, data: &T) {
+        self.publish(BROADCAST_CHANNEL.to_string(), event, data).await;
+    }
+}
@@ -0,0 +1,338 @@
+use sqlx::PgPool;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::errors::ApiError;
+use crate::handlers::admin::comment_management::{apply_comment_hide, apply_comment_unhide, CommentHideRequest};
+use crate::models::admin::post_management::{PostHideRequest, PostUnhideRequest};
+use crate::models::{
+    CreateReportRequest, Report, ReportListItem, ResolveReportRequest,
+    REPORT_STATUS_DISMISSED, REPORT_STATUS_PENDING, REPORT_STATUS_RESOLVED,
+};
+use crate::services::post_management::PostManagementService;
+
+// 신고 가능한 대상
+const REPORTABLE_ENTITIES: [&str; 2] = ["post", "comment"];
+
+// 자동 숨김 / 기본 관리자 숨김 카테고리 (post/comment_hide_history CHECK 제약 값)
+const AUTO_HIDE_CATEGORY: &str = "other";
+const DEFAULT_HIDE_CATEGORY: &str = "violation";
+
+/// 신고 접수, 누적 신고 자동 숨김, 관리자 신고 처리
+pub struct ReportService {
+    pool: PgPool,
+}
+
+impl ReportService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 신고 접수 (같은 대상을 처리 대기 중에 중복 신고할 수 없음)
+    /// 기각되지 않은 신고자 수가 auto_hide_threshold 이상이면 대상을 자동 숨김 (0이면 비활성)
+    pub async fn create_report(
+        &self,
+        reporter_id: Uuid,
+        data: CreateReportRequest,
+        auto_hide_threshold: i64,
+    ) -> Result<Report, ApiError> {
+        let entity_type = data.entity_type.trim().to_lowercase();
+        if !REPORTABLE_ENTITIES.contains(&entity_type.as_str()) {
+            return Err(ApiError::Validation("신고할 수 없는 대상입니다.".to_string()));
+        }
+
+        let reason = data.reason.trim();
+        if reason.is_empty() || reason.chars().count() > 500 {
+            return Err(ApiError::Validation("신고 사유는 1~500자로 입력해주세요.".to_string()));
+        }
+
+        let author_id = self
+            .entity_author(&entity_type, data.entity_id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("신고 대상을 찾을 수 없습니다.".to_string()))?;
+        if author_id == Some(reporter_id) {
+            return Err(ApiError::BadRequest("본인이 작성한 글은 신고할 수 없습니다.".to_string()));
+        }
+
+        let already_reported = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM reports
+                WHERE reporter_id = $1 AND entity_type = $2 AND entity_id = $3 AND status = $4
+            )
+            "#
+        )
+        .bind(reporter_id)
+        .bind(&entity_type)
+        .bind(data.entity_id)
+        .bind(REPORT_STATUS_PENDING)
+        .fetch_one(&self.pool)
+        .await?;
+        if already_reported {
+            return Err(ApiError::BadRequest("이미 신고한 대상입니다.".to_string()));
+        }
+
+        let report = sqlx::query_as::<_, Report>(
+            r#"
+            INSERT INTO reports (id, reporter_id, entity_type, entity_id, reason, status)
+            VALUES (gen_random_uuid(), $1, $2, $3, $4, $5)
+            RETURNING *
+            "#
+        )
+        .bind(reporter_id)
+        .bind(&entity_type)
+        .bind(data.entity_id)
+        .bind(reason)
+        .bind(REPORT_STATUS_PENDING)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| match &e {
+            // 동시에 들어온 중복 신고는 처리 대기 신고 유니크 인덱스에서 걸러짐
+            sqlx::Error::Database(db) if db.code().as_deref() == Some("23505") => {
+                ApiError::BadRequest("이미 신고한 대상입니다.".to_string())
+            }
+            _ => ApiError::from(e),
+        })?;
+
+        if auto_hide_threshold > 0 {
+            let reporter_count = self.reporter_count(&entity_type, data.entity_id).await?;
+            if reporter_count >= auto_hide_threshold && !self.is_hidden(&entity_type, data.entity_id).await? {
+                info!("신고 누적 자동 숨김: {} {} ({}명)", entity_type, data.entity_id, reporter_count);
+                // 자동 숨김은 처리자 없이(NULL) 기록하고 사유에 기준 도달 내역을 남김
+                let reason = format!("신고 누적 자동 숨김 (신고자 {}명, 기준 {}명)", reporter_count, auto_hide_threshold);
+                if let Err(e) = self
+                    .hide_entity(&entity_type, data.entity_id, None, AUTO_HIDE_CATEGORY, Some(reason), "site")
+                    .await
+                {
+                    warn!("신고 누적 자동 숨김 실패: {:?}", e);
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// 관리자 신고 목록 (상태/대상 종류 필터)
+    pub async fn list_reports(
+        &self,
+        status: Option<&str>,
+        entity_type: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<ReportListItem>, i64), ApiError> {
+        let reports = sqlx::query_as::<_, ReportListItem>(
+            r#"
+            SELECT
+                r.id, r.reporter_id, reporter.name as reporter_name,
+                r.entity_type, r.entity_id,
+                CASE r.entity_type
+                    WHEN 'post' THEN (SELECT p.title FROM posts p WHERE p.id = r.entity_id)
+                    WHEN 'comment' THEN (SELECT LEFT(c.content, 100) FROM comments c WHERE c.id = r.entity_id)
+                END as entity_preview,
+                r.reason, r.status, r.admin_note, r.resolved_by, resolver.name as resolver_name,
+                r.resolved_at, r.created_at,
+                (
+                    SELECT COUNT(DISTINCT r2.reporter_id) FROM reports r2
+                    WHERE r2.entity_type = r.entity_type AND r2.entity_id = r.entity_id
+                    AND r2.status IS DISTINCT FROM 'dismissed'
+                ) as report_count
+            FROM reports r
+            LEFT JOIN users reporter ON r.reporter_id = reporter.id
+            LEFT JOIN users resolver ON r.resolved_by = resolver.id
+            WHERE ($1::text IS NULL OR r.status = $1)
+            AND ($2::text IS NULL OR r.entity_type = $2)
+            ORDER BY r.created_at DESC
+            LIMIT $3 OFFSET $4
+            "#
+        )
+        .bind(status)
+        .bind(entity_type)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(*) FROM reports r
+            WHERE ($1::text IS NULL OR r.status = $1)
+            AND ($2::text IS NULL OR r.entity_type = $2)
+            "#
+        )
+        .bind(status)
+        .bind(entity_type)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok((reports, total))
+    }
+
+    /// 신고 처리
+    /// hide: 대상 숨김 후 같은 대상의 대기 중 신고를 모두 처리 완료
+    /// dismiss: 같은 대상의 대기 중 신고를 모두 기각
+    /// restore: 기각과 함께 (자동) 숨김 해제
+    pub async fn resolve_report(
+        &self,
+        report_id: Uuid,
+        admin_id: Uuid,
+        data: ResolveReportRequest,
+    ) -> Result<Report, ApiError> {
+        let report = sqlx::query_as::<_, Report>("SELECT * FROM reports WHERE id = $1")
+            .bind(report_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("신고를 찾을 수 없습니다.".to_string()))?;
+
+        let new_status = match data.action.as_str() {
+            "hide" => {
+                if !self.is_hidden(&report.entity_type, report.entity_id).await? {
+                    let category = data.hide_category.as_deref().unwrap_or(DEFAULT_HIDE_CATEGORY);
+                    self.hide_entity(
+                        &report.entity_type,
+                        report.entity_id,
+                        Some(admin_id),
+                        category,
+                        Some(report.reason.clone()),
+                        "admin",
+                    )
+                    .await?;
+                }
+                REPORT_STATUS_RESOLVED
+            }
+            "dismiss" => REPORT_STATUS_DISMISSED,
+            "restore" => {
+                if self.is_hidden(&report.entity_type, report.entity_id).await? {
+                    self.unhide_entity(&report.entity_type, report.entity_id, admin_id).await?;
+                }
+                REPORT_STATUS_DISMISSED
+            }
+            _ => return Err(ApiError::Validation("지원하지 않는 처리 방식입니다.".to_string())),
+        };
+
+        // 선택한 신고와 같은 대상의 대기 중 신고를 함께 처리
+        sqlx::query(
+            r#"
+            UPDATE reports
+            SET status = $1, admin_note = $2, resolved_by = $3, resolved_at = NOW()
+            WHERE entity_type = $4 AND entity_id = $5 AND (status = $6 OR id = $7)
+            "#
+        )
+        .bind(new_status)
+        .bind(data.admin_note.as_deref())
+        .bind(admin_id)
+        .bind(&report.entity_type)
+        .bind(report.entity_id)
+        .bind(REPORT_STATUS_PENDING)
+        .bind(report.id)
+        .execute(&self.pool)
+        .await?;
+
+        let report = sqlx::query_as::<_, Report>("SELECT * FROM reports WHERE id = $1")
+            .bind(report_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(report)
+    }
+
+    /// 신고 대상 작성자 (대상이 없으면 None, 비회원 글은 Some(None))
+    async fn entity_author(&self, entity_type: &str, entity_id: Uuid) -> Result<Option<Option<Uuid>>, ApiError> {
+        let query = match entity_type {
+            "post" => "SELECT user_id FROM posts WHERE id = $1 AND is_deleted = false",
+            _ => "SELECT user_id FROM comments WHERE id = $1 AND is_deleted = false",
+        };
+
+        let author = sqlx::query_scalar::<_, Option<Uuid>>(query)
+            .bind(entity_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(author)
+    }
+
+    /// 기각되지 않은 신고의 서로 다른 신고자 수
+    async fn reporter_count(&self, entity_type: &str, entity_id: Uuid) -> Result<i64, ApiError> {
+        let count = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(DISTINCT reporter_id) FROM reports
+            WHERE entity_type = $1 AND entity_id = $2 AND status IS DISTINCT FROM $3
+            "#
+        )
+        .bind(entity_type)
+        .bind(entity_id)
+        .bind(REPORT_STATUS_DISMISSED)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    async fn is_hidden(&self, entity_type: &str, entity_id: Uuid) -> Result<bool, ApiError> {
+        let query = match entity_type {
+            "post" => "SELECT COALESCE(status = 'hidden', false) FROM posts WHERE id = $1",
+            _ => "SELECT COALESCE(status = 'hidden', false) FROM comments WHERE id = $1",
+        };
+
+        let hidden = sqlx::query_scalar::<_, bool>(query)
+            .bind(entity_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(hidden.unwrap_or(false))
+    }
+
+    async fn hide_entity(
+        &self,
+        entity_type: &str,
+        entity_id: Uuid,
+        hidden_by: Option<Uuid>,
+        hide_category: &str,
+        hide_reason: Option<String>,
+        hide_location: &str,
+    ) -> Result<(), ApiError> {
+        match entity_type {
+            "post" => {
+                let request = PostHideRequest {
+                    post_id: entity_id,
+                    hide_reason,
+                    hide_category: hide_category.to_string(),
+                    hide_tags: None,
+                    hide_location: hide_location.to_string(),
+                };
+                PostManagementService::new(self.pool.clone())
+                    .hide_post(request, hidden_by)
+                    .await?;
+            }
+            _ => {
+                let request = CommentHideRequest {
+                    hide_category: hide_category.to_string(),
+                    hide_reason,
+                    hide_tags: None,
+                };
+                apply_comment_hide(&self.pool, entity_id, hidden_by, &request, hide_location).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn unhide_entity(&self, entity_type: &str, entity_id: Uuid, admin_id: Uuid) -> Result<(), ApiError> {
+        match entity_type {
+            "post" => {
+                let request = PostUnhideRequest {
+                    post_id: entity_id,
+                    unhide_reason: Some("신고 기각".to_string()),
+                    unhide_location: "admin".to_string(),
+                };
+                PostManagementService::new(self.pool.clone())
+                    .unhide_post(request, admin_id)
+                    .await?;
+            }
+            _ => {
+                apply_comment_unhide(&self.pool, entity_id, admin_id, "admin").await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,285 @@
+use chrono::{DateTime, Duration, Utc};
+use similar::{ChangeTag, TextDiff};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::errors::ApiError;
+use crate::models::{
+    ContentRevision, DiffChange, RevisionDiff,
+    COMMENT_LIMIT_UNIT_MINUTES, REVISION_ENTITY_COMMENT, REVISION_ENTITY_POST,
+};
+
+/// 게시판 수정/삭제 제한 확인 (limit이 0 이하면 제한 없음)
+/// minutes: 작성 후 limit분이 지나면 불가, comments: 달린 댓글 수가 limit 이상이면 불가
+pub fn within_change_limit(
+    limit: i32,
+    unit: &str,
+    created_at: Option<DateTime<Utc>>,
+    comment_count: i64,
+    now: DateTime<Utc>,
+) -> bool {
+    if limit <= 0 {
+        return true;
+    }
+    if unit == COMMENT_LIMIT_UNIT_MINUTES {
+        created_at.is_none_or(|created_at| now - created_at < Duration::minutes(limit as i64))
+    } else {
+        comment_count < limit as i64
+    }
+}
+
+/// 단어 단위 변경 내역 (같은 종류가 이어지면 하나로 합침)
+pub fn diff_changes(old: &str, new: &str) -> Vec<DiffChange> {
+    let mut changes: Vec<DiffChange> = Vec::new();
+    for change in TextDiff::from_words(old, new).iter_all_changes() {
+        let op = match change.tag() {
+            ChangeTag::Equal => "equal",
+            ChangeTag::Insert => "insert",
+            ChangeTag::Delete => "delete",
+        };
+        match changes.last_mut() {
+            Some(last) if last.op == op => last.text.push_str(change.value()),
+            _ => changes.push(DiffChange { op: op.to_string(), text: change.value().to_string() }),
+        }
+    }
+    changes
+}
+
+/// 수정/삭제 제한용 댓글 수 (게시글은 댓글 수, 댓글은 답글 수, 삭제된 댓글 제외)
+pub async fn count_replies(pool: &PgPool, entity_type: &str, entity_id: Uuid) -> Result<i64, sqlx::Error> {
+    let column = if entity_type == REVISION_ENTITY_POST { "post_id" } else { "parent_id" };
+    sqlx::query_scalar::<_, i64>(&format!(
+        "SELECT COUNT(*) FROM comments WHERE {} = $1 AND COALESCE(is_deleted, false) = false AND status IN ('active', 'published')",
+        column
+    ))
+    .bind(entity_id)
+    .fetch_one(pool)
+    .await
+}
+
+fn validate_entity_type(entity_type: &str) -> Result<(), ApiError> {
+    if entity_type == REVISION_ENTITY_POST || entity_type == REVISION_ENTITY_COMMENT {
+        Ok(())
+    } else {
+        Err(ApiError::BadRequest("수정 이력을 조회할 수 없는 대상입니다.".to_string()))
+    }
+}
+
+/// 게시글/댓글 수정 이력 보관, 조회, 복원
+pub struct RevisionService {
+    pool: PgPool,
+}
+
+impl RevisionService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 게시글 수정 직전 내용 보관 (제목/내용이 그대로면 남기지 않음)
+    /// 수정 UPDATE와 같은 트랜잭션에서 호출 (행을 잠가 동시 수정 시 중간 내용이 이력에서 빠지지 않도록)
+    pub async fn snapshot_post(
+        conn: &mut PgConnection,
+        post_id: Uuid,
+        edited_by: Option<Uuid>,
+        title: Option<&str>,
+        content: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("SELECT 1 FROM posts WHERE id = $1 FOR UPDATE")
+            .bind(post_id)
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query(
+            r#"
+            INSERT INTO content_revisions (entity_type, entity_id, title, content, content_source, edited_by)
+            SELECT $1, id, title, content, content_source, $3 FROM posts
+            WHERE id = $2
+            AND (title IS DISTINCT FROM COALESCE($4, title) OR content IS DISTINCT FROM COALESCE($5, content))
+            "#
+        )
+        .bind(REVISION_ENTITY_POST)
+        .bind(post_id)
+        .bind(edited_by)
+        .bind(title)
+        .bind(content)
+        .execute(&mut *conn)
+        .await?;
+        Ok(())
+    }
+
+    /// 댓글 수정 직전 내용 보관 (내용이 그대로면 남기지 않음)
+    /// 수정 UPDATE와 같은 트랜잭션에서 호출
+    pub async fn snapshot_comment(
+        conn: &mut PgConnection,
+        comment_id: Uuid,
+        edited_by: Uuid,
+        content: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("SELECT 1 FROM comments WHERE id = $1 FOR UPDATE")
+            .bind(comment_id)
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query(
+            r#"
+            INSERT INTO content_revisions (entity_type, entity_id, content, edited_by)
+            SELECT $1, id, content, $3 FROM comments
+            WHERE id = $2 AND content IS DISTINCT FROM $4
+            "#
+        )
+        .bind(REVISION_ENTITY_COMMENT)
+        .bind(comment_id)
+        .bind(edited_by)
+        .bind(content)
+        .execute(&mut *conn)
+        .await?;
+        Ok(())
+    }
+
+    /// 작성자 (수정 이력 열람 권한 확인용, 대상이 없으면 None, 비회원 글은 Some(None))
+    pub async fn entity_author(&self, entity_type: &str, entity_id: Uuid) -> Result<Option<Option<Uuid>>, ApiError> {
+        validate_entity_type(entity_type)?;
+        let table = if entity_type == REVISION_ENTITY_POST { "posts" } else { "comments" };
+        let author = sqlx::query_scalar::<_, Option<Uuid>>(&format!("SELECT user_id FROM {} WHERE id = $1", table))
+            .bind(entity_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(author)
+    }
+
+    /// 수정 이력 목록 (최근 순)
+    pub async fn list_revisions(&self, entity_type: &str, entity_id: Uuid) -> Result<Vec<ContentRevision>, ApiError> {
+        validate_entity_type(entity_type)?;
+        let revisions = sqlx::query_as::<_, ContentRevision>(
+            r#"
+            SELECT r.id, r.entity_type, r.entity_id, r.title, r.content, r.content_source, r.edited_by,
+                   u.name as editor_name, r.created_at
+            FROM content_revisions r
+            LEFT JOIN users u ON r.edited_by = u.id
+            WHERE r.entity_type = $1 AND r.entity_id = $2
+            ORDER BY r.created_at DESC
+            "#
+        )
+        .bind(entity_type)
+        .bind(entity_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(revisions)
+    }
+
+    async fn find_revision(&self, entity_type: &str, entity_id: Uuid, revision_id: Uuid) -> Result<ContentRevision, ApiError> {
+        validate_entity_type(entity_type)?;
+        sqlx::query_as::<_, ContentRevision>(
+            r#"
+            SELECT r.id, r.entity_type, r.entity_id, r.title, r.content, r.content_source, r.edited_by,
+                   u.name as editor_name, r.created_at
+            FROM content_revisions r
+            LEFT JOIN users u ON r.edited_by = u.id
+            WHERE r.id = $1 AND r.entity_type = $2 AND r.entity_id = $3
+            "#
+        )
+        .bind(revision_id)
+        .bind(entity_type)
+        .bind(entity_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("수정 이력을 찾을 수 없습니다.".to_string()))
+    }
+
+    /// 수정 이력 상세 (바로 다음 이력, 없으면 현재 내용과 비교)
+    pub async fn revision_diff(&self, entity_type: &str, entity_id: Uuid, revision_id: Uuid) -> Result<RevisionDiff, ApiError> {
+        let revision = self.find_revision(entity_type, entity_id, revision_id).await?;
+
+        let next = sqlx::query_as::<_, (Option<String>, String)>(
+            r#"
+            SELECT title, content FROM content_revisions
+            WHERE entity_type = $1 AND entity_id = $2 AND created_at > $3
+            ORDER BY created_at ASC
+            LIMIT 1
+            "#
+        )
+        .bind(entity_type)
+        .bind(entity_id)
+        .bind(revision.created_at)
+        .fetch_optional(&self.pool)
+        .await?;
+        let (next_title, next_content) = match next {
+            Some(next) => next,
+            None if entity_type == REVISION_ENTITY_POST => {
+                sqlx::query_as::<_, (Option<String>, String)>("SELECT title, content FROM posts WHERE id = $1")
+                    .bind(entity_id)
+                    .fetch_one(&self.pool)
+                    .await?
+            }
+            None => {
+                sqlx::query_as::<_, (Option<String>, String)>("SELECT NULL::text, content FROM comments WHERE id = $1")
+                    .bind(entity_id)
+                    .fetch_one(&self.pool)
+                    .await?
+            }
+        };
+
+        let changes = diff_changes(&revision.content, &next_content);
+        Ok(RevisionDiff { revision, next_title, changes })
+    }
+
+    /// 이전 버전으로 복원 (현재 내용은 새 이력으로 보관)
+    pub async fn restore_revision(
+        &self,
+        entity_type: &str,
+        entity_id: Uuid,
+        revision_id: Uuid,
+        restored_by: Uuid,
+    ) -> Result<ContentRevision, ApiError> {
+        let revision = self.find_revision(entity_type, entity_id, revision_id).await?;
+
+        let mut tx = self.pool.begin().await?;
+        if entity_type == REVISION_ENTITY_POST {
+            Self::snapshot_post(&mut tx, entity_id, Some(restored_by), revision.title.as_deref(), Some(&revision.content)).await?;
+            sqlx::query("UPDATE posts SET title = COALESCE($2, title), content = $3, content_source = $4, updated_at = NOW() WHERE id = $1")
+                .bind(entity_id)
+                .bind(revision.title.as_deref())
+                .bind(&revision.content)
+                .bind(revision.content_source.as_deref())
+                .execute(&mut *tx)
+                .await?;
+        } else {
+            Self::snapshot_comment(&mut tx, entity_id, restored_by, &revision.content).await?;
+            sqlx::query("UPDATE comments SET content = $2, updated_at = NOW() WHERE id = $1")
+                .bind(entity_id)
+                .bind(&revision.content)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+
+        Ok(revision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_within_change_limit() {
+        let now = Utc::now();
+        let created_at = Some(now - Duration::minutes(30));
+
+        assert!(within_change_limit(0, "comments", created_at, 100, now));
+        assert!(within_change_limit(3, "comments", created_at, 2, now));
+        assert!(!within_change_limit(3, "comments", created_at, 3, now));
+        assert!(within_change_limit(60, "minutes", created_at, 100, now));
+        assert!(!within_change_limit(10, "minutes", created_at, 0, now));
+        assert!(within_change_limit(10, "minutes", None, 0, now));
+    }
+
+    #[test]
+    fn test_diff_changes() {
+        let changes = diff_changes("안녕하세요 반갑습니다 여러분", "안녕하세요 고맙습니다 여러분");
+        let ops: Vec<(&str, &str)> = changes.iter().map(|c| (c.op.as_str(), c.text.as_str())).collect();
+        assert_eq!(
+            ops,
+            vec![("equal", "안녕하세요 "), ("delete", "반갑습니다"), ("insert", "고맙습니다"), ("equal", " 여러분")]
+        );
+        assert!(diff_changes("", "").is_empty());
+    }
+}
@@ -0,0 +1,214 @@
+use sqlx::PgPool;
+
+use crate::errors::ApiError;
+use crate::models::{SearchQuery, SearchResult, SEARCH_FIELDS};
+
+// 검색어 최대 길이 / 최대 검색 단어 수
+const MAX_QUERY_LENGTH: usize = 100;
+const MAX_QUERY_TERMS: usize = 8;
+
+// 하이라이트 옵션 (strip_html 결과는 꺾쇠가 이스케이프되어 있어 <mark>만 HTML로 해석됨)
+const TITLE_HEADLINE_OPTIONS: &str = "StartSel=<mark>, StopSel=</mark>, HighlightAll=true";
+const SNIPPET_HEADLINE_OPTIONS: &str = "StartSel=<mark>, StopSel=</mark>, MaxWords=35, MinWords=15, MaxFragments=2, FragmentDelimiter=\" … \"";
+
+// 공개 게시글 + 검색 허용 게시판 + 읽기 권한 + 필드/기간 조건
+// $1 검색어, $2 접두 일치 tsquery, $3 부분 일치 패턴, $4~$7 제목/본문/작성자/댓글 검색 여부,
+// $8 게시판 slug, $9 시작일, $10 종료일, $11 사용자 역할
+const SEARCH_FROM_WHERE: &str = r#"
+    FROM posts p
+    JOIN boards b ON p.board_id = b.id
+    LEFT JOIN users u ON p.user_id = u.id
+    CROSS JOIN (SELECT COALESCE(to_tsquery('simple', $2), plainto_tsquery('simple', $1)) AS tsq) q
+    WHERE p.status IN ('active', 'published') AND p.is_deleted = false
+    AND COALESCE(b.allow_search, true) = true
+    AND (
+        COALESCE(b.read_permission, 'guest') NOT IN ('member', 'admin')
+        OR (b.read_permission = 'member' AND $11::text IS NOT NULL)
+        OR (b.read_permission = 'admin' AND $11::text = 'admin')
+    )
+    AND ($8::text IS NULL OR b.slug = $8)
+    AND ($9::date IS NULL OR p.created_at >= $9::date)
+    AND ($10::date IS NULL OR p.created_at < $10::date + 1)
+    AND (
+        ($4 AND (to_tsvector('simple', p.title) @@ q.tsq OR p.title ILIKE $3))
+        OR ($5 AND (to_tsvector('simple', strip_html(p.content)) @@ q.tsq OR strip_html(p.content) ILIKE $3))
+        OR ($6 AND COALESCE(u.name, p.guest_name) ILIKE $3)
+        OR ($7 AND EXISTS (
+            SELECT 1 FROM comments c
+            WHERE c.post_id = p.id AND c.is_deleted = false
+            AND (to_tsvector('simple', c.content) @@ q.tsq OR c.content ILIKE $3)
+        ))
+    )
+"#;
+
+/// 게시글/댓글 통합 검색 (pg_trgm 부분 일치 + tsvector 접두 일치, 관련도 정렬)
+pub struct SearchService {
+    pool: PgPool,
+}
+
+impl SearchService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 검색 실행, (결과, 전체 개수) 반환
+    pub async fn search_posts(
+        &self,
+        query: &SearchQuery,
+        user_role: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<SearchResult>, i64), ApiError> {
+        let keyword = query.q.trim();
+        if keyword.is_empty() {
+            return Err(ApiError::Validation("검색어를 입력해주세요.".to_string()));
+        }
+        if keyword.chars().count() > MAX_QUERY_LENGTH {
+            return Err(ApiError::Validation(format!("검색어는 {}자 이하로 입력해주세요.", MAX_QUERY_LENGTH)));
+        }
+        if let (Some(from), Some(to)) = (query.from, query.to) {
+            if from > to {
+                return Err(ApiError::Validation("검색 시작일이 종료일보다 늦습니다.".to_string()));
+            }
+        }
+
+        let [title, content, author, comment] = parse_search_fields(query.fields.as_deref())?;
+        let tsquery = build_prefix_tsquery(keyword);
+        let pattern = like_pattern(keyword);
+        let board = query.board.as_deref().filter(|s| !s.is_empty());
+        let sort_latest = query.sort.as_deref() == Some("latest");
+
+        let sql = format!(
+            r#"
+            SELECT * FROM (
+                SELECT
+                    p.id, p.board_id, b.slug as board_slug, b.name as board_name, COALESCE(u.name, p.guest_name) as user_name,
+                    p.title, p.views, p.likes, p.created_at,
+                    ts_headline('simple', strip_html(p.title), q.tsq, '{title_options}') as title_highlight,
+                    ts_headline('simple', strip_html(p.content), q.tsq, '{snippet_options}') as snippet,
+                    CASE WHEN $7 THEN (
+                        SELECT ts_headline('simple', strip_html(c.content), q.tsq, '{snippet_options}')
+                        FROM comments c
+                        WHERE c.post_id = p.id AND c.is_deleted = false
+                        AND (to_tsvector('simple', c.content) @@ q.tsq OR c.content ILIKE $3)
+                        ORDER BY c.created_at
+                        LIMIT 1
+                    ) END as matched_comment,
+                    (
+                        CASE WHEN $4 THEN COALESCE(ts_rank(to_tsvector('simple', p.title), q.tsq), 0) * 2 + word_similarity($1, p.title) * 2 ELSE 0 END
+                        + CASE WHEN $5 THEN COALESCE(ts_rank(to_tsvector('simple', strip_html(p.content)), q.tsq), 0) + word_similarity($1, strip_html(p.content)) ELSE 0 END
+                        + CASE WHEN $6 AND COALESCE(u.name, p.guest_name) ILIKE $3 THEN 1 ELSE 0 END
+                    )::real as rank
+                {from_where}
+            ) s
+            ORDER BY CASE WHEN $12 THEN 0 ELSE s.rank END DESC, s.created_at DESC
+            LIMIT $13 OFFSET $14
+            "#,
+            title_options = TITLE_HEADLINE_OPTIONS,
+            snippet_options = SNIPPET_HEADLINE_OPTIONS,
+            from_where = SEARCH_FROM_WHERE,
+        );
+
+        let results = sqlx::query_as::<_, SearchResult>(&sql)
+            .bind(keyword)
+            .bind(tsquery.as_deref())
+            .bind(&pattern)
+            .bind(title)
+            .bind(content)
+            .bind(author)
+            .bind(comment)
+            .bind(board)
+            .bind(query.from)
+            .bind(query.to)
+            .bind(user_role)
+            .bind(sort_latest)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let count_sql = format!("SELECT COUNT(*) {}", SEARCH_FROM_WHERE);
+        let total = sqlx::query_scalar::<_, i64>(&count_sql)
+            .bind(keyword)
+            .bind(tsquery.as_deref())
+            .bind(&pattern)
+            .bind(title)
+            .bind(content)
+            .bind(author)
+            .bind(comment)
+            .bind(board)
+            .bind(query.from)
+            .bind(query.to)
+            .bind(user_role)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok((results, total))
+    }
+}
+
+/// 검색 필드 목록 파싱 (title, content, author, comment 순서의 사용 여부)
+/// 지정하지 않으면 전체 필드 검색
+fn parse_search_fields(fields: Option<&str>) -> Result<[bool; 4], ApiError> {
+    let fields = match fields.map(str::trim).filter(|s| !s.is_empty()) {
+        Some(fields) => fields,
+        None => return Ok([true; 4]),
+    };
+
+    let mut selected = [false; 4];
+    for field in fields.split(',').map(|f| f.trim().to_lowercase()) {
+        match SEARCH_FIELDS.iter().position(|f| *f == field) {
+            Some(index) => selected[index] = true,
+            None => return Err(ApiError::Validation(format!("지원하지 않는 검색 필드입니다: {}", field))),
+        }
+    }
+
+    Ok(selected)
+}
+
+/// 검색어를 단어별 접두 일치 tsquery 문자열로 변환 ("검색 기능" -> "'검색':* & '기능':*")
+/// 조사가 붙은 어절("검색을")도 찾을 수 있도록 접두 일치 사용, 연산자 문자는 제거
+fn build_prefix_tsquery(keyword: &str) -> Option<String> {
+    let terms: Vec<String> = keyword
+        .split_whitespace()
+        .map(|term| {
+            term.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|term| !term.is_empty())
+        .take(MAX_QUERY_TERMS)
+        .map(|term| format!("'{}':*", term))
+        .collect();
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" & "))
+    }
+}
+
+/// ILIKE 부분 일치 패턴 (와일드카드 문자 이스케이프)
+fn like_pattern(keyword: &str) -> String {
+    let escaped = keyword
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{}%", escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_prefix_tsquery() {
+        assert_eq!(build_prefix_tsquery("검색 기능"), Some("'검색':* & '기능':*".to_string()));
+        assert_eq!(build_prefix_tsquery("Rust's & (test)"), Some("'rusts':* & 'test':*".to_string()));
+        assert_eq!(build_prefix_tsquery(" !:* "), None);
+        assert_eq!(like_pattern("100%_done"), "%100\\%\\_done%");
+        assert_eq!(parse_search_fields(Some("title, comment")).unwrap(), [true, false, false, true]);
+        assert!(parse_search_fields(Some("email")).is_err());
+    }
+}
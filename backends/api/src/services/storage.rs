@@ -0,0 +1,369 @@
+use async_trait::async_trait;
+use axum::body::Bytes;
+use chrono::{DateTime, Utc};
+use futures_util::stream::BoxStream;
+use futures_util::StreamExt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio_util::io::ReaderStream;
+
+use crate::config::StorageConfig;
+use crate::errors::ApiError;
+use crate::services::S3Storage;
+
+// 공개 URL 접두어 (저장소와 관계없이 본문/응답에는 /uploads/<키> 형태로 노출)
+pub const UPLOAD_URL_PREFIX: &str = "/uploads/";
+
+// 바이러스 검사 전 업로드를 두는 격리 접두어 (/uploads로 서빙하지 않고 검사 후 공개 키로 옮김)
+pub const QUARANTINE_PREFIX: &str = "quarantine/";
+
+// 다운로드 응답 본문 스트림
+pub type ByteStream = BoxStream<'static, std::io::Result<Bytes>>;
+
+// 저장된 객체 정보
+#[derive(Debug, Clone)]
+pub struct ObjectMeta {
+    pub key: String,
+    pub size: u64,
+    pub modified: DateTime<Utc>,
+}
+
+/// 업로드 파일 저장소 추상화 (로컬 디스크, S3 호환 오브젝트 스토리지)
+/// 키는 업로드 루트 기준 상대 경로 (예: posts/images/a.jpg)
+#[async_trait]
+pub trait Storage: Send + Sync {
+    /// 저장소 종류 (local, s3)
+    fn backend(&self) -> &'static str;
+
+    /// files.file_path에 기록하는 위치
+    fn location(&self, key: &str) -> String;
+
+    /// files.file_path에서 키 추출 (다른 저장소의 위치이면 None)
+    fn key_from_location<'a>(&self, location: &'a str) -> Option<&'a str>;
+
+    async fn put(&self, key: &str, data: Vec<u8>, content_type: &str) -> Result<(), ApiError>;
+
+    /// 로컬 임시 파일을 저장소로 옮김 (성공하면 임시 파일은 삭제됨)
+    async fn put_file(&self, key: &str, path: &Path, content_type: &str) -> Result<(), ApiError> {
+        let data = tokio::fs::read(path)
+            .await
+            .map_err(|e| ApiError::Internal(format!("임시 파일 읽기 실패: {}", e)))?;
+        self.put(key, data, content_type).await?;
+        let _ = tokio::fs::remove_file(path).await;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, ApiError>;
+
+    /// 객체 정보 조회 (없으면 None)
+    async fn head(&self, key: &str) -> Result<Option<ObjectMeta>, ApiError>;
+
+    /// start부터 length 바이트만 스트리밍
+    async fn get_range(&self, key: &str, start: u64, length: u64) -> Result<ByteStream, ApiError>;
+
+    /// 객체 삭제 (없는 객체는 성공으로 처리)
+    async fn delete(&self, key: &str) -> Result<(), ApiError>;
+
+    /// 접두어로 시작하는 모든 객체 목록
+    async fn list(&self, prefix: &str) -> Result<Vec<ObjectMeta>, ApiError>;
+
+    /// 객체를 다른 키로 옮김 (격리 위치에서 공개 위치로 게시)
+    async fn rename(&self, from: &str, to: &str) -> Result<(), ApiError> {
+        let data = self.get(from).await?;
+        self.put(to, data, content_type_for_key(to)).await?;
+        self.delete(from).await
+    }
+
+    /// 서명된 다운로드 URL (지원하지 않는 저장소는 None)
+    fn presigned_url(&self, _key: &str, _expires_in_seconds: u64, _download_name: Option<&str>) -> Option<String> {
+        None
+    }
+}
+
+/// 저장소 생성 (backend: local, s3)
+pub fn create_storage(config: &StorageConfig, backend: &str) -> Result<Arc<dyn Storage>, ApiError> {
+    match backend {
+        "local" => Ok(Arc::new(LocalStorage::new(&config.local_root))),
+        "s3" => Ok(Arc::new(S3Storage::new(config)?)),
+        other => Err(ApiError::Internal(format!("지원하지 않는 저장소입니다: {}", other))),
+    }
+}
+
+/// 키의 공개 URL
+pub fn upload_url(key: &str) -> String {
+    format!("{}{}", UPLOAD_URL_PREFIX, key)
+}
+
+/// 공개 URL(/uploads/...)에서 키 추출
+pub fn key_from_url(url: &str) -> Option<&str> {
+    url.strip_prefix(UPLOAD_URL_PREFIX).filter(|key| !key.is_empty())
+}
+
+/// files.file_path를 공개 URL로 변환
+pub fn public_url(storage: &dyn Storage, file_path: &str) -> String {
+    match storage.key_from_location(file_path) {
+        Some(key) => upload_url(key),
+        // 저장소 이전 전의 기존 로컬 경로
+        None => upload_url(file_path.trim_start_matches("static/uploads/")),
+    }
+}
+
+/// 검사 전 격리 위치의 키
+pub fn quarantine_key(key: &str) -> String {
+    format!("{}{}", QUARANTINE_PREFIX, key)
+}
+
+/// 격리 위치를 가리키는 경로인지 확인 (정적 파일 서빙과 같이 퍼센트 인코딩, 빈 조각, "."을 정규화해 비교)
+pub fn is_quarantine_key(path: &str) -> bool {
+    let decoded = urlencoding::decode(path).map(|path| path.into_owned()).unwrap_or_else(|_| path.to_string());
+    decoded
+        .split(['/', '\\'])
+        .find(|part| !part.is_empty() && *part != ".")
+        .is_some_and(|part| part == QUARANTINE_PREFIX.trim_end_matches('/'))
+}
+
+/// 키 검증 (상위 디렉터리 접근, 절대 경로 차단)
+pub fn validate_key(key: &str) -> Result<(), ApiError> {
+    let invalid = key.is_empty()
+        || key.starts_with('/')
+        || key.contains('\\')
+        || key.split('/').any(|part| part.is_empty() || part == "." || part == "..");
+
+    if invalid {
+        return Err(ApiError::BadRequest(format!("잘못된 파일 경로입니다: {}", key)));
+    }
+    Ok(())
+}
+
+/// 확장자로 Content-Type 결정
+pub fn content_type_for_key(key: &str) -> &'static str {
+    let extension = Path::new(key)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+
+    match extension.as_str() {
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "bmp" => "image/bmp",
+        "svg" => "image/svg+xml",
+        "pdf" => "application/pdf",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xls" => "application/vnd.ms-excel",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "ppt" => "application/vnd.ms-powerpoint",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        "txt" => "text/plain",
+        "mp4" => "video/mp4",
+        "avi" => "video/x-msvideo",
+        "mov" => "video/quicktime",
+        "wmv" => "video/x-ms-wmv",
+        _ => "application/octet-stream",
+    }
+}
+
+fn not_found(key: &str) -> ApiError {
+    ApiError::NotFound(format!("파일을 찾을 수 없습니다: {}", key))
+}
+
+fn io_error(action: &str, e: std::io::Error) -> ApiError {
+    ApiError::Internal(format!("파일 {} 실패: {}", action, e))
+}
+
+/// 로컬 디스크 저장소 (단일 서버 또는 공유 볼륨)
+pub struct LocalStorage {
+    root: PathBuf,
+    root_prefix: String,
+}
+
+impl LocalStorage {
+    pub fn new(root: &str) -> Self {
+        let root = root.trim_end_matches('/');
+        Self {
+            root: PathBuf::from(root),
+            root_prefix: format!("{}/", root),
+        }
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf, ApiError> {
+        validate_key(key)?;
+        Ok(self.root.join(key))
+    }
+
+    async fn ensure_parent(path: &Path) -> Result<(), ApiError> {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| io_error("디렉터리 생성", e))?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Storage for LocalStorage {
+    fn backend(&self) -> &'static str {
+        "local"
+    }
+
+    fn location(&self, key: &str) -> String {
+        format!("{}{}", self.root_prefix, key)
+    }
+
+    fn key_from_location<'a>(&self, location: &'a str) -> Option<&'a str> {
+        location.strip_prefix(self.root_prefix.as_str())
+    }
+
+    async fn put(&self, key: &str, data: Vec<u8>, _content_type: &str) -> Result<(), ApiError> {
+        let path = self.path(key)?;
+        Self::ensure_parent(&path).await?;
+        tokio::fs::write(&path, data).await.map_err(|e| io_error("저장", e))
+    }
+
+    async fn put_file(&self, key: &str, source: &Path, _content_type: &str) -> Result<(), ApiError> {
+        let path = self.path(key)?;
+        Self::ensure_parent(&path).await?;
+
+        // 다른 파일시스템이면 rename이 실패하므로 복사 후 삭제
+        if tokio::fs::rename(source, &path).await.is_err() {
+            tokio::fs::copy(source, &path).await.map_err(|e| io_error("저장", e))?;
+            let _ = tokio::fs::remove_file(source).await;
+        }
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, ApiError> {
+        match tokio::fs::read(self.path(key)?).await {
+            Ok(data) => Ok(data),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(not_found(key)),
+            Err(e) => Err(io_error("읽기", e)),
+        }
+    }
+
+    async fn head(&self, key: &str) -> Result<Option<ObjectMeta>, ApiError> {
+        match tokio::fs::metadata(self.path(key)?).await {
+            Ok(metadata) if metadata.is_file() => Ok(Some(ObjectMeta {
+                key: key.to_string(),
+                size: metadata.len(),
+                modified: metadata.modified().map(Into::into).unwrap_or_else(|_| Utc::now()),
+            })),
+            Ok(_) => Ok(None),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(io_error("정보 조회", e)),
+        }
+    }
+
+    async fn get_range(&self, key: &str, start: u64, length: u64) -> Result<ByteStream, ApiError> {
+        let mut file = match tokio::fs::File::open(self.path(key)?).await {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Err(not_found(key)),
+            Err(e) => return Err(io_error("열기", e)),
+        };
+        if start > 0 {
+            file.seek(std::io::SeekFrom::Start(start))
+                .await
+                .map_err(|e| io_error("탐색", e))?;
+        }
+        Ok(ReaderStream::new(file.take(length)).boxed())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), ApiError> {
+        match tokio::fs::remove_file(self.path(key)?).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(io_error("삭제", e)),
+        }
+    }
+
+    async fn rename(&self, from: &str, to: &str) -> Result<(), ApiError> {
+        let source = self.path(from)?;
+        let target = self.path(to)?;
+        Self::ensure_parent(&target).await?;
+        match tokio::fs::rename(&source, &target).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(not_found(from)),
+            Err(e) => Err(io_error("이동", e)),
+        }
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<ObjectMeta>, ApiError> {
+        let mut objects = Vec::new();
+        let mut dirs = vec![self.root.clone()];
+
+        while let Some(dir) = dirs.pop() {
+            let mut entries = match tokio::fs::read_dir(&dir).await {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(io_error("목록 조회", e)),
+            };
+
+            while let Some(entry) = entries.next_entry().await.map_err(|e| io_error("목록 조회", e))? {
+                let path = entry.path();
+                let metadata = entry.metadata().await.map_err(|e| io_error("정보 조회", e))?;
+                if metadata.is_dir() {
+                    dirs.push(path);
+                    continue;
+                }
+
+                let Some(key) = path
+                    .strip_prefix(&self.root)
+                    .ok()
+                    .and_then(|relative| relative.to_str())
+                    .map(|relative| relative.replace('\\', "/"))
+                else {
+                    continue;
+                };
+                if key.starts_with(prefix) {
+                    objects.push(ObjectMeta {
+                        key,
+                        size: metadata.len(),
+                        modified: metadata.modified().map(Into::into).unwrap_or_else(|_| Utc::now()),
+                    });
+                }
+            }
+        }
+
+        objects.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok(objects)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_and_locations() {
+        assert!(validate_key("posts/images/a.jpg").is_ok());
+        assert!(validate_key("../etc/passwd").is_err());
+        assert!(validate_key("posts/../../a").is_err());
+        assert!(validate_key("/posts/a.jpg").is_err());
+        assert!(validate_key("posts//a.jpg").is_err());
+
+        let storage = LocalStorage::new("static/uploads/");
+        assert_eq!(storage.location("posts/a.jpg"), "static/uploads/posts/a.jpg");
+        assert_eq!(storage.key_from_location("static/uploads/posts/a.jpg"), Some("posts/a.jpg"));
+        assert_eq!(storage.key_from_location("s3://bucket/posts/a.jpg"), None);
+
+        assert_eq!(public_url(&storage, "static/uploads/posts/a.jpg"), "/uploads/posts/a.jpg");
+        assert_eq!(key_from_url("/uploads/posts/a.jpg"), Some("posts/a.jpg"));
+        assert_eq!(key_from_url("/static/a.jpg"), None);
+    }
+
+    #[test]
+    fn test_is_quarantine_key() {
+        assert_eq!(quarantine_key("posts/images/a.jpg"), "quarantine/posts/images/a.jpg");
+        assert!(is_quarantine_key("quarantine/posts/images/a.jpg"));
+        assert!(is_quarantine_key("/quarantine/posts/a.jpg"));
+        assert!(is_quarantine_key("//./quarantine/posts/a.jpg"));
+        assert!(is_quarantine_key("/%71uarantine/posts/a.jpg"));
+        assert!(!is_quarantine_key("/posts/quarantine/a.jpg"));
+        assert!(!is_quarantine_key("/quarantined/a.jpg"));
+    }
+}
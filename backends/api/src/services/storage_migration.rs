@@ -0,0 +1,145 @@
+use sqlx::PgPool;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::config::StorageConfig;
+use crate::errors::ApiError;
+use crate::services::{content_type_for_key, create_storage, Storage};
+
+// 청크 업로드 임시 파일은 이전하지 않음
+const SKIPPED_PREFIXES: [&str; 1] = ["temp/"];
+
+// 저장소 이전 결과
+#[derive(Debug, Default)]
+pub struct StorageMigrationReport {
+    pub copied: usize,
+    pub skipped: usize, // 대상에 같은 크기로 이미 존재
+    pub failed: usize,
+    pub rewritten: usize, // files.file_path 갱신 건수
+}
+
+/// 저장소 간 파일 복사 후 files.file_path 갱신
+/// 썸네일, 프로필/사이트 이미지처럼 files 테이블에 없는 파일도 함께 복사
+/// 원본 저장소의 파일은 삭제하지 않음 (확인 후 직접 정리)
+pub async fn migrate_storage(
+    pool: &PgPool,
+    source: &dyn Storage,
+    target: &dyn Storage,
+    dry_run: bool,
+) -> Result<StorageMigrationReport, ApiError> {
+    let mut report = StorageMigrationReport::default();
+
+    let objects = source.list("").await?;
+    info!("저장소 이전 대상: {}개 ({} → {})", objects.len(), source.backend(), target.backend());
+
+    for object in objects {
+        if SKIPPED_PREFIXES.iter().any(|prefix| object.key.starts_with(prefix)) {
+            continue;
+        }
+
+        match target.head(&object.key).await {
+            Ok(Some(existing)) if existing.size == object.size => {
+                report.skipped += 1;
+                continue;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                warn!("대상 저장소 조회 실패: key={}, {:?}", object.key, e);
+                report.failed += 1;
+                continue;
+            }
+        }
+
+        if dry_run {
+            report.copied += 1;
+            continue;
+        }
+
+        let result = match source.get(&object.key).await {
+            Ok(data) => target.put(&object.key, data, content_type_for_key(&object.key)).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(()) => report.copied += 1,
+            Err(e) => {
+                warn!("파일 복사 실패: key={}, {:?}", object.key, e);
+                report.failed += 1;
+            }
+        }
+    }
+
+    // 대상 저장소에 실제로 있는 파일만 경로 갱신
+    let files = sqlx::query_as::<_, (Uuid, String)>("SELECT id, file_path FROM files")
+        .fetch_all(pool)
+        .await?;
+
+    for (file_id, file_path) in files {
+        let Some(key) = source.key_from_location(&file_path) else {
+            continue;
+        };
+        let exists = dry_run || matches!(target.head(key).await, Ok(Some(_)));
+        if !exists {
+            warn!("대상 저장소에 파일이 없어 경로를 유지합니다: file_id={}, key={}", file_id, key);
+            continue;
+        }
+
+        if !dry_run {
+            sqlx::query("UPDATE files SET file_path = $1 WHERE id = $2")
+                .bind(target.location(key))
+                .bind(file_id)
+                .execute(pool)
+                .await?;
+        }
+        report.rewritten += 1;
+    }
+
+    Ok(report)
+}
+
+/// `storage-migrate --from <local|s3> --to <local|s3> [--dry-run]` 명령 실행
+/// 종료 코드 반환 (0: 성공, 1: 실패가 있거나 인자 오류)
+pub async fn run_storage_migrate_command(pool: &PgPool, config: &StorageConfig, args: &[String]) -> i32 {
+    let value_of = |flag: &str| {
+        args.iter()
+            .position(|arg| arg == flag)
+            .and_then(|index| args.get(index + 1))
+            .map(String::as_str)
+    };
+    let dry_run = args.iter().any(|arg| arg == "--dry-run");
+
+    let (Some(from), Some(to)) = (value_of("--from"), value_of("--to")) else {
+        eprintln!("사용법: mincenter-api storage-migrate --from <local|s3> --to <local|s3> [--dry-run]");
+        return 1;
+    };
+    if from == to {
+        eprintln!("❌ 원본과 대상 저장소가 같습니다: {}", from);
+        return 1;
+    }
+
+    let storages = create_storage(config, from).and_then(|source| Ok((source, create_storage(config, to)?)));
+    let (source, target) = match storages {
+        Ok(storages) => storages,
+        Err(e) => {
+            eprintln!("❌ 저장소 설정 오류: {:?}", e);
+            return 1;
+        }
+    };
+
+    match migrate_storage(pool, source.as_ref(), target.as_ref(), dry_run).await {
+        Ok(report) => {
+            println!(
+                "✅ 저장소 이전{}: 복사 {}개, 건너뜀 {}개, 실패 {}개, 경로 갱신 {}건",
+                if dry_run { " (dry-run)" } else { "" },
+                report.copied,
+                report.skipped,
+                report.failed,
+                report.rewritten
+            );
+            if report.failed > 0 { 1 } else { 0 }
+        }
+        Err(e) => {
+            eprintln!("❌ 저장소 이전 실패: {:?}", e);
+            1
+        }
+    }
+}
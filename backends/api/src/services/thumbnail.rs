@@ -1,7 +1,11 @@
 use image::{DynamicImage, ImageFormat, imageops::FilterType, GenericImageView};
+use std::io::Cursor;
 use std::path::Path;
-use std::fs;
-use uuid::Uuid;
+use std::sync::Arc;
+
+use crate::config::ImageConfig;
+use crate::services::{content_type_for_key, is_video_key, upload_url, video_poster_key, Storage};
+use crate::utils::image_meta::{apply_orientation, exif_orientation};
 
 // 썸네일 크기 정의
 #[derive(Debug, Clone)]
@@ -18,146 +22,258 @@ pub const THUMBNAIL_SIZES: [ThumbnailSize; 3] = [
     ThumbnailSize { width: 1200, height: 900, suffix: "large" },    // 본문용
 ];
 
+// 원본 형식 썸네일과 함께 생성할 수 있는 추가 형식 (srcset 용)
+pub const ALTERNATE_FORMATS: [&str; 2] = ["webp", "avif"];
+
 #[derive(Debug)]
 pub struct ThumbnailInfo {
-    pub original_path: String,
+    pub original_key: String,
     pub thumbnails: Vec<ThumbnailVariant>,
 }
 
 #[derive(Debug)]
 pub struct ThumbnailVariant {
     pub size_suffix: String,
-    pub path: String,
-    pub width: u32,
+    pub key: String,
+    pub format: String, // jpeg, png, webp, avif 등
+    pub width: u32,     // 실제 픽셀 크기 (원본 비율 유지, 확대하지 않음)
     pub height: u32,
     pub file_size: u64,
 }
 
-pub struct ThumbnailService;
+/// 원본 키에서 썸네일 키 생성 (같은 디렉터리의 {이름}_{크기}.{확장자})
+pub fn thumbnail_key(original_key: &str, size_suffix: &str) -> String {
+    thumbnail_key_with_format(original_key, size_suffix, None)
+}
+
+/// 다른 형식 썸네일 키 생성 ({이름}_{크기}.{형식}, format이 None이면 원본 확장자)
+pub fn thumbnail_key_with_format(original_key: &str, size_suffix: &str, format: Option<&str>) -> String {
+    let path = Path::new(original_key);
+
+    if let (Some(parent), Some(stem), Some(ext)) = (
+        path.parent(),
+        path.file_stem().and_then(|s| s.to_str()),
+        path.extension().and_then(|s| s.to_str())
+    ) {
+        let thumbnail_filename = format!("{}_{}.{}", stem, size_suffix, format.unwrap_or(ext));
+        parent.join(&thumbnail_filename).to_string_lossy().replace('\\', "/")
+    } else {
+        original_key.to_string()
+    }
+}
+
+/// 원본에 딸린 모든 썸네일 키 (설정과 무관하게 생성될 수 있는 형식 전체, 삭제/용량 계산용)
+/// 동영상은 포스터와 포스터의 썸네일
+pub fn thumbnail_variant_keys(original_key: &str) -> Vec<String> {
+    if is_video_key(original_key) {
+        let poster_key = video_poster_key(original_key);
+        let mut keys = thumbnail_variant_keys(&poster_key);
+        keys.push(poster_key);
+        return keys;
+    }
+
+    let mut keys = Vec::new();
+    for size in &THUMBNAIL_SIZES {
+        let key = thumbnail_key(original_key, size.suffix);
+        for format in ALTERNATE_FORMATS {
+            let alternate = thumbnail_key_with_format(original_key, size.suffix, Some(format));
+            if alternate != key {
+                keys.push(alternate);
+            }
+        }
+        keys.push(key);
+    }
+    keys
+}
+
+/// srcset 문자열 생성 ("URL 250w, URL 800w", 너비 순, 같은 너비는 첫 항목만)
+pub fn build_srcset(candidates: &[(String, i32)]) -> Option<String> {
+    let mut candidates: Vec<&(String, i32)> = candidates.iter().filter(|(_, width)| *width > 0).collect();
+    candidates.sort_by_key(|(_, width)| *width);
+    candidates.dedup_by_key(|(_, width)| *width);
+    if candidates.is_empty() {
+        return None;
+    }
+    Some(
+        candidates
+            .iter()
+            .map(|(url, width)| format!("{} {}w", url, width))
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
+
+/// 확장자별 이미지 형식 이름 (image_sizes.format 값)
+pub fn image_format_name(extension: &str) -> &'static str {
+    match extension.to_lowercase().as_str() {
+        "png" => "png",
+        "webp" => "webp",
+        "gif" => "gif",
+        "bmp" => "bmp",
+        "avif" => "avif",
+        _ => "jpeg",
+    }
+}
+
+/// 이미지 로드 후 EXIF 방향에 맞게 회전 (변환 결과에는 EXIF를 기록하지 않음)
+pub fn load_oriented_image(data: &[u8]) -> Result<DynamicImage, image::ImageError> {
+    let img = image::load_from_memory(data)?;
+    Ok(apply_orientation(img, exif_orientation(data).unwrap_or(1)))
+}
+
+/// 형식에 맞게 인코딩 (webp_quality는 WebP 손실 압축 품질 0~100)
+pub fn encode_image(img: &DynamicImage, format: &str, webp_quality: f32) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+    let format = match format {
+        "png" => ImageFormat::Png,
+        "webp" => {
+            // image 크레이트는 WebP 손실 압축을 지원하지 않아 libwebp 사용
+            let rgba = img.to_rgba8();
+            let encoded = webp::Encoder::from_rgba(&rgba, rgba.width(), rgba.height()).encode(webp_quality);
+            return Ok(encoded.to_vec());
+        }
+        "avif" => ImageFormat::Avif,
+        "gif" => ImageFormat::Gif,
+        "bmp" => ImageFormat::Bmp,
+        // 그 외는 JPEG로 저장 (JPEG는 투명도를 지원하지 않음)
+        _ if img.color().has_alpha() => {
+            let mut output = Cursor::new(Vec::new());
+            DynamicImage::ImageRgb8(img.to_rgb8()).write_to(&mut output, ImageFormat::Jpeg)?;
+            return Ok(output.into_inner());
+        }
+        _ => ImageFormat::Jpeg,
+    };
+
+    let mut output = Cursor::new(Vec::new());
+    img.write_to(&mut output, format)?;
+    Ok(output.into_inner())
+}
+
+// 썸네일은 원본과 같은 저장소에 저장 (키는 업로드 루트 기준 상대 경로)
+pub struct ThumbnailService {
+    storage: Arc<dyn Storage>,
+    webp_quality: Option<f32>, // None이면 WebP 썸네일 생성 안 함
+    avif: bool,
+}
 
 impl ThumbnailService {
-    pub fn new() -> Self {
-        Self
+    /// 원본 형식 썸네일만 생성
+    pub fn new(storage: Arc<dyn Storage>) -> Self {
+        Self { storage, webp_quality: None, avif: false }
+    }
+
+    /// 설정에 따라 WebP/AVIF 썸네일도 함께 생성
+    pub fn with_config(storage: Arc<dyn Storage>, config: &ImageConfig) -> Self {
+        Self {
+            storage,
+            webp_quality: config.webp_variants.then_some(config.webp_quality.clamp(0.0, 100.0)),
+            avif: config.avif_variants && cfg!(feature = "avif"),
+        }
+    }
+
+    // 이 서비스가 생성하는 추가 형식
+    fn alternate_formats(&self, original_key: &str) -> Vec<&'static str> {
+        let extension = Path::new(original_key)
+            .extension()
+            .and_then(|s| s.to_str())
+            .map(image_format_name)
+            .unwrap_or("jpeg");
+        let mut formats = Vec::new();
+        if self.webp_quality.is_some() && extension != "webp" {
+            formats.push("webp");
+        }
+        if self.avif {
+            formats.push("avif");
+        }
+        formats
     }
 
     /// 이미지 파일에 대해 썸네일들을 생성
-    pub async fn create_thumbnails(&self, original_path: &str) -> Result<ThumbnailInfo, Box<dyn std::error::Error + Send + Sync>> {
+    pub async fn create_thumbnails(&self, original_key: &str) -> Result<ThumbnailInfo, Box<dyn std::error::Error + Send + Sync>> {
         // 원본 파일이 이미지인지 확인
-        if !self.is_image_file(original_path) {
+        if !self.is_image_file(original_key) {
             return Err("Not an image file".into());
         }
 
         // 파일이 존재하고 읽을 수 있는지 확인
-        if !Path::new(original_path).exists() {
-            return Err("File does not exist".into());
-        }
+        let data = match self.storage.get(original_key).await {
+            Ok(data) => data,
+            Err(_) => return Err("File does not exist".into()),
+        };
 
         // 파일 크기 확인 (최소 1KB 이상이어야 유효한 이미지)
-        let metadata = fs::metadata(original_path)?;
-        if metadata.len() < 1024 {
+        if data.len() < 1024 {
             return Err("File too small to be a valid image".into());
         }
 
         // 원본 이미지 로드 (파일 손상 여부 확인)
-        let img = match image::open(original_path) {
+        let img = match load_oriented_image(&data) {
             Ok(img) => img,
             Err(e) => {
                 eprintln!("Failed to open image file: {:?}", e);
                 return Err(format!("Invalid or corrupted image file: {}", e).into());
             }
         };
-        let original_dir = Path::new(original_path).parent()
-            .ok_or("Invalid file path")?;
-        
-        let file_stem = Path::new(original_path)
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .ok_or("Invalid filename")?;
-        
-        let extension = Path::new(original_path)
-            .extension()
-            .and_then(|s| s.to_str())
-            .unwrap_or("jpg");
 
         let mut thumbnails = Vec::new();
 
         // 각 크기별 썸네일 생성
         for size in &THUMBNAIL_SIZES {
-            let thumbnail_filename = format!("{}_{}.{}", file_stem, size.suffix, extension);
-            let thumbnail_path = original_dir.join(&thumbnail_filename);
-            
-            // 썸네일 생성 및 저장 (품질 향상)
-            let resized_img = self.resize_image(&img, size.width, size.height);
-            self.save_image_with_quality(&resized_img, &thumbnail_path, extension)?;
+            thumbnails.extend(self.render_size(&img, original_key, size).await?);
+        }
+
+        Ok(ThumbnailInfo {
+            original_key: original_key.to_string(),
+            thumbnails,
+        })
+    }
+
+    /// 한 크기의 원본 형식 + 추가 형식 썸네일 생성 및 저장
+    async fn render_size(&self, img: &DynamicImage, original_key: &str, size: &ThumbnailSize) -> Result<Vec<ThumbnailVariant>, Box<dyn std::error::Error + Send + Sync>> {
+        let resized_img = self.resize_image(img, size.width, size.height);
+        let (width, height) = resized_img.dimensions();
+        let extension = Path::new(original_key)
+            .extension()
+            .and_then(|s| s.to_str())
+            .unwrap_or("jpg");
+
+        let mut formats = vec![(thumbnail_key(original_key, size.suffix), image_format_name(extension))];
+        for format in self.alternate_formats(original_key) {
+            formats.push((thumbnail_key_with_format(original_key, size.suffix, Some(format)), format));
+        }
 
-            // 파일 크기 가져오기
-            let file_size = fs::metadata(&thumbnail_path)?.len();
+        let mut variants = Vec::new();
+        for (key, format) in formats {
+            let encoded = encode_image(&resized_img, format, self.webp_quality.unwrap_or(80.0))?;
+            let file_size = encoded.len() as u64;
+            self.storage.put(&key, encoded, content_type_for_key(&key)).await?;
 
-            thumbnails.push(ThumbnailVariant {
+            variants.push(ThumbnailVariant {
                 size_suffix: size.suffix.to_string(),
-                path: thumbnail_path.to_string_lossy().to_string(),
-                width: size.width,
-                height: size.height,
+                key,
+                format: format.to_string(),
+                width,
+                height,
                 file_size,
             });
         }
-
-        Ok(ThumbnailInfo {
-            original_path: original_path.to_string(),
-            thumbnails,
-        })
+        Ok(variants)
     }
 
-    /// 이미지 리사이즈 (가로세로 비율 유지)
+    /// 이미지 리사이즈 (가로세로 비율 유지, 원본보다 크게 확대하지 않음)
     fn resize_image(&self, img: &DynamicImage, target_width: u32, target_height: u32) -> DynamicImage {
         let (orig_width, orig_height) = img.dimensions();
         
         // 가로세로 비율 계산
         let ratio_w = target_width as f32 / orig_width as f32;
         let ratio_h = target_height as f32 / orig_height as f32;
-        let ratio = ratio_w.min(ratio_h);
+        let ratio = ratio_w.min(ratio_h).min(1.0);
 
-        let new_width = (orig_width as f32 * ratio) as u32;
-        let new_height = (orig_height as f32 * ratio) as u32;
+        let new_width = ((orig_width as f32 * ratio) as u32).max(1);
+        let new_height = ((orig_height as f32 * ratio) as u32).max(1);
 
         img.resize(new_width, new_height, FilterType::Lanczos3)
     }
 
-    /// 이미지를 품질 설정과 함께 저장
-    fn save_image_with_quality(&self, img: &DynamicImage, path: &Path, extension: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let extension_lower = extension.to_lowercase();
-        
-        match extension_lower.as_str() {
-            "jpg" | "jpeg" => {
-                // JPEG는 품질 설정 가능 (90% 품질로 설정 - 카드에서 텍스트가 잘 보이도록)
-                let mut output = std::fs::File::create(path)?;
-                img.write_to(&mut output, ImageFormat::Jpeg)?;
-            },
-            "png" => {
-                // PNG는 무손실이므로 품질 설정 불필요
-                img.save_with_format(path, ImageFormat::Png)?;
-            },
-            "webp" => {
-                // WebP는 품질 설정 가능 (90% 품질로 설정)
-                let mut output = std::fs::File::create(path)?;
-                img.write_to(&mut output, ImageFormat::WebP)?;
-            },
-            "gif" => {
-                img.save_with_format(path, ImageFormat::Gif)?;
-            },
-            "bmp" => {
-                img.save_with_format(path, ImageFormat::Bmp)?;
-            },
-            _ => {
-                // 기본적으로 JPEG로 저장 (90% 품질)
-                let mut output = std::fs::File::create(path)?;
-                img.write_to(&mut output, ImageFormat::Jpeg)?;
-            }
-        }
-        
-        Ok(())
-    }
-
     /// 파일이 이미지인지 확인
     fn is_image_file(&self, path: &str) -> bool {
         let extension = Path::new(path)
@@ -169,26 +285,9 @@ impl ThumbnailService {
         matches!(extension.as_str(), "jpg" | "jpeg" | "png" | "gif" | "webp" | "bmp")
     }
 
-    /// 썸네일 URL 생성
-    pub fn get_thumbnail_url(&self, original_file_path: &str, size_suffix: &str) -> String {
-        let path = Path::new(original_file_path);
-        
-        if let (Some(parent), Some(stem), Some(ext)) = (
-            path.parent(),
-            path.file_stem().and_then(|s| s.to_str()),
-            path.extension().and_then(|s| s.to_str())
-        ) {
-            let thumbnail_filename = format!("{}_{}.{}", stem, size_suffix, ext);
-            let thumbnail_path = parent.join(&thumbnail_filename);
-            
-            // static/uploads/... 에서 /uploads/... 형태로 변환
-            thumbnail_path.to_string_lossy()
-                .strip_prefix("static")
-                .unwrap_or(&thumbnail_path.to_string_lossy())
-                .to_string()
-        } else {
-            original_file_path.to_string()
-        }
+    /// 썸네일 URL 생성 (/uploads/...)
+    pub fn get_thumbnail_url(&self, original_key: &str, size_suffix: &str) -> String {
+        upload_url(&thumbnail_key(original_key, size_suffix))
     }
 
     /// 게시글 타입에 따른 적절한 썸네일 크기 선택
@@ -201,53 +300,18 @@ impl ThumbnailService {
         }
     }
 
-    /// 원본 파일 경로에서 썸네일 경로 생성
-    pub fn build_thumbnail_path(&self, original_path: &str, size_suffix: &str) -> String {
-        let path = Path::new(original_path);
-        
-        if let (Some(parent), Some(stem), Some(ext)) = (
-            path.parent(),
-            path.file_stem().and_then(|s| s.to_str()),
-            path.extension().and_then(|s| s.to_str())
-        ) {
-            let thumbnail_filename = format!("{}_{}.{}", stem, size_suffix, ext);
-            parent.join(&thumbnail_filename).to_string_lossy().to_string()
-        } else {
-            original_path.to_string()
-        }
-    }
-
     /// 썸네일 파일들 삭제
-    pub async fn delete_thumbnails(&self, original_path: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        // 원본 파일이 이미지인지 확인
-        if !self.is_image_file(original_path) {
-            return Ok(()); // 이미지가 아니면 썸네일이 없으므로 성공으로 처리
+    pub async fn delete_thumbnails(&self, original_key: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        // 원본 파일이 이미지/동영상인지 확인
+        if !self.is_image_file(original_key) && !is_video_key(original_key) {
+            return Ok(()); // 썸네일이 없으므로 성공으로 처리
         }
 
-        let original_dir = Path::new(original_path).parent()
-            .ok_or("Invalid file path")?;
-        
-        let file_stem = Path::new(original_path)
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .ok_or("Invalid filename")?;
-        
-        let extension = Path::new(original_path)
-            .extension()
-            .and_then(|s| s.to_str())
-            .unwrap_or("jpg");
-
-        // 각 크기별 썸네일 삭제
-        for size in &THUMBNAIL_SIZES {
-            let thumbnail_filename = format!("{}_{}.{}", file_stem, size.suffix, extension);
-            let thumbnail_path = original_dir.join(&thumbnail_filename);
-            
-            // 썸네일 파일이 존재하면 삭제
-            if thumbnail_path.exists() {
-                if let Err(e) = fs::remove_file(&thumbnail_path) {
-                    eprintln!("Failed to delete thumbnail {}: {:?}", thumbnail_path.display(), e);
-                    // 개별 썸네일 삭제 실패는 무시하고 계속 진행
-                }
+        // 각 크기/형식별 썸네일 (동영상은 포스터 포함) 삭제
+        for key in thumbnail_variant_keys(original_key) {
+            if let Err(e) = self.storage.delete(&key).await {
+                eprintln!("Failed to delete thumbnail {}: {:?}", key, e);
+                // 개별 썸네일 삭제 실패는 무시하고 계속 진행
             }
         }
 
@@ -255,127 +319,167 @@ impl ThumbnailService {
     }
 
     /// 썸네일 파일이 존재하는지 확인
-    pub fn thumbnail_exists(&self, thumbnail_path: &str) -> bool {
-        Path::new(thumbnail_path).exists()
+    pub async fn thumbnail_exists(&self, thumbnail_key: &str) -> bool {
+        matches!(self.storage.head(thumbnail_key).await, Ok(Some(_)))
     }
 
     /// 원본 파일의 모든 썸네일이 존재하는지 확인
-    pub fn all_thumbnails_exist(&self, original_path: &str) -> bool {
-        if !self.is_image_file(original_path) {
+    pub async fn all_thumbnails_exist(&self, original_key: &str) -> bool {
+        if !self.is_image_file(original_key) {
             return true; // 이미지가 아니면 썸네일 불필요
         }
 
         for size in &THUMBNAIL_SIZES {
-            let thumbnail_path = self.build_thumbnail_path(original_path, size.suffix);
-            if !self.thumbnail_exists(&thumbnail_path) {
+            if !self.thumbnail_exists(&thumbnail_key(original_key, size.suffix)).await {
                 return false;
             }
+            for format in self.alternate_formats(original_key) {
+                if !self.thumbnail_exists(&thumbnail_key_with_format(original_key, size.suffix, Some(format))).await {
+                    return false;
+                }
+            }
         }
         true
     }
 
     /// 누락된 썸네일들을 생성 (비동기 백그라운드)
-    pub async fn ensure_thumbnails_exist(&self, original_path: &str) -> Result<ThumbnailInfo, Box<dyn std::error::Error + Send + Sync>> {
+    pub async fn ensure_thumbnails_exist(&self, original_key: &str) -> Result<ThumbnailInfo, Box<dyn std::error::Error + Send + Sync>> {
         // 원본 파일이 존재하는지 확인
-        if !Path::new(original_path).exists() {
-            return Err(format!("Original file not found: {}", original_path).into());
+        if self.storage.head(original_key).await?.is_none() {
+            return Err(format!("Original file not found: {}", original_key).into());
         }
 
         // 이미지 파일인지 확인
-        if !self.is_image_file(original_path) {
+        if !self.is_image_file(original_key) {
             return Err("Not an image file".into());
         }
 
         // 모든 썸네일이 존재하면 기존 정보 반환
-        if self.all_thumbnails_exist(original_path) {
-            return self.get_existing_thumbnail_info(original_path);
+        if self.all_thumbnails_exist(original_key).await {
+            return self.get_existing_thumbnail_info(original_key).await;
         }
 
         // 누락된 썸네일 생성
-        println!("Creating missing thumbnails for: {}", original_path);
-        self.create_thumbnails(original_path).await
+        println!("Creating missing thumbnails for: {}", original_key);
+        self.create_thumbnails(original_key).await
     }
 
-    /// 기존 썸네일 정보 가져오기
-    fn get_existing_thumbnail_info(&self, original_path: &str) -> Result<ThumbnailInfo, Box<dyn std::error::Error + Send + Sync>> {
+    /// 기존 썸네일 정보 가져오기 (픽셀 크기는 원본 형식 썸네일의 헤더에서 읽음)
+    async fn get_existing_thumbnail_info(&self, original_key: &str) -> Result<ThumbnailInfo, Box<dyn std::error::Error + Send + Sync>> {
         let mut thumbnails = Vec::new();
+        let extension = Path::new(original_key)
+            .extension()
+            .and_then(|s| s.to_str())
+            .unwrap_or("jpg");
 
         for size in &THUMBNAIL_SIZES {
-            let thumbnail_path = self.build_thumbnail_path(original_path, size.suffix);
-            
-            if self.thumbnail_exists(&thumbnail_path) {
-                let file_size = std::fs::metadata(&thumbnail_path)?.len();
-                
-                thumbnails.push(ThumbnailVariant {
-                    size_suffix: size.suffix.to_string(),
-                    path: thumbnail_path,
-                    width: size.width,
-                    height: size.height,
-                    file_size,
-                });
+            let key = thumbnail_key(original_key, size.suffix);
+            let (width, height) = match self.storage.get(&key).await {
+                Ok(data) => image::io::Reader::new(Cursor::new(data))
+                    .with_guessed_format()
+                    .ok()
+                    .and_then(|reader| reader.into_dimensions().ok())
+                    .unwrap_or((size.width, size.height)),
+                Err(_) => continue,
+            };
+
+            let mut formats = vec![(key, image_format_name(extension))];
+            for format in self.alternate_formats(original_key) {
+                formats.push((thumbnail_key_with_format(original_key, size.suffix, Some(format)), format));
+            }
+            for (key, format) in formats {
+                if let Some(meta) = self.storage.head(&key).await? {
+                    thumbnails.push(ThumbnailVariant {
+                        size_suffix: size.suffix.to_string(),
+                        key,
+                        format: format.to_string(),
+                        width,
+                        height,
+                        file_size: meta.size,
+                    });
+                }
             }
         }
 
         Ok(ThumbnailInfo {
-            original_path: original_path.to_string(),
+            original_key: original_key.to_string(),
             thumbnails,
         })
     }
 
     /// 특정 크기의 썸네일만 생성 (누락된 것만)
-    pub async fn create_missing_thumbnail(&self, original_path: &str, size_suffix: &str) -> Result<Option<ThumbnailVariant>, Box<dyn std::error::Error + Send + Sync>> {
-        if !self.is_image_file(original_path) || !Path::new(original_path).exists() {
+    pub async fn create_missing_thumbnail(&self, original_key: &str, size_suffix: &str) -> Result<Option<ThumbnailVariant>, Box<dyn std::error::Error + Send + Sync>> {
+        if !self.is_image_file(original_key) {
             return Ok(None);
         }
+        let size_info = THUMBNAIL_SIZES.iter().find(|s| s.suffix == size_suffix)
+            .ok_or("Invalid size suffix")?;
+
+        let key = thumbnail_key(original_key, size_suffix);
 
-        let thumbnail_path = self.build_thumbnail_path(original_path, size_suffix);
-        
         // 이미 존재하면 기존 것 반환
-        if self.thumbnail_exists(&thumbnail_path) {
-            let file_size = std::fs::metadata(&thumbnail_path)?.len();
-            let size_info = THUMBNAIL_SIZES.iter().find(|s| s.suffix == size_suffix);
-            
-            if let Some(size) = size_info {
-                return Ok(Some(ThumbnailVariant {
-                    size_suffix: size_suffix.to_string(),
-                    path: thumbnail_path,
-                    width: size.width,
-                    height: size.height,
-                    file_size,
-                }));
-            }
+        if let Some(meta) = self.storage.head(&key).await? {
+            let extension = Path::new(original_key)
+                .extension()
+                .and_then(|s| s.to_str())
+                .unwrap_or("jpg");
+            return Ok(Some(ThumbnailVariant {
+                size_suffix: size_suffix.to_string(),
+                key,
+                format: image_format_name(extension).to_string(),
+                width: size_info.width,
+                height: size_info.height,
+                file_size: meta.size,
+            }));
         }
 
         // 썸네일 생성
-        let img = image::open(original_path)?;
-        let size_info = THUMBNAIL_SIZES.iter().find(|s| s.suffix == size_suffix)
-            .ok_or("Invalid size suffix")?;
+        let data = match self.storage.get(original_key).await {
+            Ok(data) => data,
+            Err(_) => return Ok(None),
+        };
+        let img = load_oriented_image(&data)?;
+        let variant = self.render_size(&img, original_key, size_info).await?.into_iter().next();
 
-        let resized_img = self.resize_image(&img, size_info.width, size_info.height);
-        
-        // 디렉토리 생성 (없으면)
-        if let Some(parent) = Path::new(&thumbnail_path).parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-        
-        // 확장자 추출
-        let extension = Path::new(original_path)
-            .extension()
-            .and_then(|s| s.to_str())
-            .unwrap_or("jpg");
-        
-        // 품질 향상된 저장
-        self.save_image_with_quality(&resized_img, Path::new(&thumbnail_path), extension)?;
-        let file_size = std::fs::metadata(&thumbnail_path)?.len();
-
-        println!("Created missing thumbnail: {}", thumbnail_path);
-
-        Ok(Some(ThumbnailVariant {
-            size_suffix: size_suffix.to_string(),
-            path: thumbnail_path,
-            width: size_info.width,
-            height: size_info.height,
-            file_size,
-        }))
+        println!("Created missing thumbnail: {}", key);
+
+        Ok(variant)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thumbnail_variant_keys() {
+        let keys = thumbnail_variant_keys("posts/2026/a.jpg");
+        assert_eq!(keys.len(), THUMBNAIL_SIZES.len() * 3);
+        assert!(keys.contains(&"posts/2026/a_thumb.jpg".to_string()));
+        assert!(keys.contains(&"posts/2026/a_card.webp".to_string()));
+        assert!(keys.contains(&"posts/2026/a_large.avif".to_string()));
+
+        // WebP 원본은 원본 형식 썸네일이 곧 WebP
+        assert_eq!(thumbnail_variant_keys("a.webp").len(), THUMBNAIL_SIZES.len() * 2);
+
+        // 동영상은 포스터와 포스터 썸네일
+        let keys = thumbnail_variant_keys("posts/2026/v.mp4");
+        assert_eq!(keys.len(), THUMBNAIL_SIZES.len() * 3 + 1);
+        assert!(keys.contains(&"posts/2026/v_poster.jpg".to_string()));
+        assert!(keys.contains(&"posts/2026/v_poster_thumb.webp".to_string()));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_build_srcset() {
+        let candidates = vec![
+            ("/uploads/a_large.webp".to_string(), 1200),
+            ("/uploads/a_thumb.webp".to_string(), 250),
+            ("/uploads/a_card.webp".to_string(), 250),
+        ];
+        assert_eq!(
+            build_srcset(&candidates).as_deref(),
+            Some("/uploads/a_thumb.webp 250w, /uploads/a_large.webp 1200w")
+        );
+        assert_eq!(build_srcset(&[]), None);
+    }
+}
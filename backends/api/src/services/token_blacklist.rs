@@ -0,0 +1,271 @@
+use chrono::{DateTime, Duration, Utc};
+use redis::{aio::ConnectionManager, AsyncCommands, Client as RedisClient};
+use sqlx::PgPool;
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::errors::ApiError;
+use crate::utils::auth::{hash_access_token, Claims};
+
+// Redis 미러 키 (토큰별 차단 / 사용자 전체 토큰 차단 시각(밀리초))
+const TOKEN_KEY_PREFIX: &str = "token_blacklist:token:";
+const USER_KEY_PREFIX: &str = "token_blacklist:user_ms:";
+
+// 사용자 전체 토큰 차단 행의 token_jti 접두어 ("user:{user_id}:{폐기 시각(밀리초)}")
+// created_at보다 먼저(밀리초 기준) 발급된 해당 사용자의 토큰을 모두 거부
+pub const USER_REVOCATION_PREFIX: &str = "user:";
+
+// 만료 항목 정리 주기
+const PURGE_INTERVAL_SECONDS: u64 = 3600;
+
+/// 액세스 토큰 폐기 목록 (token_blacklist 테이블 + Redis 미러)
+/// 인증 미들웨어마다 연결을 새로 열지 않도록 AppState에 두고 Redis 연결을 공유
+#[derive(Clone)]
+pub struct TokenBlacklistService {
+    pool: PgPool,
+    redis: RedisClient,
+    connection: Arc<OnceCell<ConnectionManager>>,
+}
+
+impl TokenBlacklistService {
+    pub fn new(pool: PgPool, redis: RedisClient) -> Self {
+        Self { pool, redis, connection: Arc::new(OnceCell::new()) }
+    }
+
+    async fn redis_connection(&self) -> Option<ConnectionManager> {
+        match self
+            .connection
+            .get_or_try_init(|| ConnectionManager::new(self.redis.clone()))
+            .await
+        {
+            Ok(conn) => Some(conn.clone()),
+            Err(e) => {
+                warn!("토큰 폐기 목록 Redis 연결 실패: {}", e);
+                None
+            }
+        }
+    }
+
+    /// 액세스 토큰 폐기 (로그아웃)
+    pub async fn revoke_token(&self, token: &str, claims: &Claims) -> Result<(), ApiError> {
+        let expires_at = DateTime::<Utc>::from_timestamp(claims.exp, 0).unwrap_or_else(Utc::now);
+        if expires_at <= Utc::now() {
+            return Ok(());
+        }
+        let token_hash = hash_access_token(token);
+
+        sqlx::query("INSERT INTO token_blacklist (token_jti, user_id, expires_at) VALUES ($1, $2, $3) ON CONFLICT (token_jti) DO NOTHING")
+            .bind(&token_hash)
+            .bind(claims.sub)
+            .bind(expires_at)
+            .execute(&self.pool)
+            .await?;
+
+        self.mirror_token(&token_hash, expires_at).await;
+        Ok(())
+    }
+
+    /// 사용자의 모든 토큰 폐기 (비밀번호 변경, 관리자 정지)
+    /// 현재 발급된 액세스 토큰은 access_token_expiry 이내에 모두 만료되므로 그 시점까지만 기록
+    pub async fn revoke_all_for_user(&self, user_id: Uuid, access_token_expiry_minutes: i64) -> Result<(), ApiError> {
+        let revoked_at = Utc::now();
+        let expires_at = revoked_at + Duration::minutes(access_token_expiry_minutes);
+
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO token_blacklist (token_jti, user_id, expires_at, created_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (token_jti) DO UPDATE SET expires_at = EXCLUDED.expires_at
+            "#
+        )
+        .bind(format!("{}{}:{}", USER_REVOCATION_PREFIX, user_id, revoked_at.timestamp_millis()))
+        .bind(user_id)
+        .bind(expires_at)
+        .bind(revoked_at)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query("UPDATE refresh_tokens SET is_revoked = TRUE WHERE user_id = $1 AND is_revoked = FALSE")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        self.mirror_user(user_id, revoked_at, expires_at).await;
+        Ok(())
+    }
+
+    /// 폐기된 토큰인지 확인
+    /// Redis에 있으면 바로 거부하고, 없으면 DB로 확인 (Redis 초기화/재시작/키 축출로 미러가 비어도 폐기 유지)
+    pub async fn is_revoked(&self, token: &str, claims: &Claims) -> Result<bool, ApiError> {
+        let token_hash = hash_access_token(token);
+
+        if let Some(mut conn) = self.redis_connection().await {
+            let result: Result<(Option<String>, Option<i64>), redis::RedisError> = redis::pipe()
+                .get(format!("{}{}", TOKEN_KEY_PREFIX, token_hash))
+                .get(format!("{}{}", USER_KEY_PREFIX, claims.sub))
+                .query_async(&mut conn)
+                .await;
+
+            match result {
+                Ok((token_entry, user_revoked_at)) => {
+                    if token_entry.is_some()
+                        || user_revoked_at.is_some_and(|revoked_at_ms| is_issued_before(claims, revoked_at_ms))
+                    {
+                        return Ok(true);
+                    }
+                }
+                Err(e) => warn!("토큰 폐기 목록 Redis 조회 실패, DB로 확인: {}", e),
+            }
+        }
+
+        let token_entry = sqlx::query_scalar::<_, DateTime<Utc>>(
+            "SELECT expires_at FROM token_blacklist WHERE token_jti = $1 AND expires_at > NOW()"
+        )
+        .bind(&token_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        if let Some(expires_at) = token_entry {
+            self.mirror_token(&token_hash, expires_at).await;
+            return Ok(true);
+        }
+
+        let user_entry = sqlx::query_as::<_, (DateTime<Utc>, DateTime<Utc>)>(
+            r#"
+            SELECT created_at, expires_at FROM token_blacklist
+            WHERE user_id = $1 AND token_jti LIKE 'user:%' AND expires_at > NOW() AND created_at IS NOT NULL
+            ORDER BY created_at DESC
+            LIMIT 1
+            "#
+        )
+        .bind(claims.sub)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match user_entry {
+            Some((revoked_at, expires_at)) => {
+                self.mirror_user(claims.sub, revoked_at, expires_at).await;
+                Ok(is_issued_before(claims, revoked_at.timestamp_millis()))
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// 만료된 항목 삭제 (Redis 항목은 TTL로 자동 만료)
+    pub async fn purge_expired(&self) -> Result<u64, ApiError> {
+        let result = sqlx::query("DELETE FROM token_blacklist WHERE expires_at <= NOW()")
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// DB의 유효한 항목을 Redis에 다시 기록 (서버 시작 시)
+    pub async fn warm_cache(&self) -> Result<usize, ApiError> {
+        let entries = sqlx::query_as::<_, (String, Uuid, DateTime<Utc>, Option<DateTime<Utc>>)>(
+            "SELECT token_jti, user_id, expires_at, created_at FROM token_blacklist WHERE expires_at > NOW()"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        for (token_jti, user_id, expires_at, created_at) in &entries {
+            if token_jti.starts_with(USER_REVOCATION_PREFIX) {
+                self.mirror_user(*user_id, created_at.unwrap_or_else(Utc::now), *expires_at).await;
+            } else {
+                self.mirror_token(token_jti, *expires_at).await;
+            }
+        }
+
+        Ok(entries.len())
+    }
+
+    async fn mirror_token(&self, token_hash: &str, expires_at: DateTime<Utc>) {
+        let ttl = (expires_at - Utc::now()).num_seconds();
+        if ttl <= 0 {
+            return;
+        }
+        if let Some(mut conn) = self.redis_connection().await {
+            let result: Result<(), redis::RedisError> = conn
+                .set_ex(format!("{}{}", TOKEN_KEY_PREFIX, token_hash), 1, ttl as u64)
+                .await;
+            if let Err(e) = result {
+                warn!("토큰 폐기 목록 Redis 기록 실패: {}", e);
+            }
+        }
+    }
+
+    async fn mirror_user(&self, user_id: Uuid, revoked_at: DateTime<Utc>, expires_at: DateTime<Utc>) {
+        let ttl = (expires_at - Utc::now()).num_seconds();
+        if ttl <= 0 {
+            return;
+        }
+        let Some(mut conn) = self.redis_connection().await else {
+            return;
+        };
+
+        // 여러 번 폐기된 경우 가장 늦은 시각 유지
+        let key = format!("{}{}", USER_KEY_PREFIX, user_id);
+        let current: Option<i64> = conn.get(&key).await.unwrap_or(None);
+        let revoked_at = current.map_or(revoked_at.timestamp_millis(), |current| current.max(revoked_at.timestamp_millis()));
+
+        let result: Result<(), redis::RedisError> = conn.set_ex(&key, revoked_at, ttl as u64).await;
+        if let Err(e) = result {
+            warn!("토큰 폐기 목록 Redis 기록 실패: {}", e);
+        }
+    }
+}
+
+/// 사용자 전체 폐기 시각(밀리초)보다 먼저 발급된 토큰인지 확인 (폐기 직후 같은 초에 재로그인한 토큰은 허용)
+pub fn is_issued_before(claims: &Claims, revoked_at_ms: i64) -> bool {
+    claims.issued_at_millis() < revoked_at_ms
+}
+
+/// 폐기 목록 캐시 적재 후 만료 항목 주기적 정리
+pub fn spawn_token_blacklist_purger(service: TokenBlacklistService) {
+    tokio::spawn(async move {
+        match service.warm_cache().await {
+            Ok(count) => info!("토큰 폐기 목록 Redis 적재 완료: {}건", count),
+            Err(e) => warn!("토큰 폐기 목록 Redis 적재 실패: {:?}", e),
+        }
+
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(PURGE_INTERVAL_SECONDS));
+        loop {
+            interval.tick().await;
+            match service.purge_expired().await {
+                Ok(0) => {}
+                Ok(count) => info!("만료된 토큰 폐기 항목 정리: {}건", count),
+                Err(e) => warn!("토큰 폐기 항목 정리 실패: {:?}", e),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claims(iat: i64, iat_ms: i64) -> Claims {
+        Claims { sub: Uuid::nil(), role: "user".to_string(), exp: iat + 3600, iat, iat_ms }
+    }
+
+    #[test]
+    fn test_is_issued_before_same_second_relogin() {
+        // 1000.500초에 전체 폐기
+        let revoked_at_ms = 1_000_500;
+
+        // 같은 초 안에서 폐기 전 발급된 토큰은 거부, 폐기 후 재로그인 토큰은 허용
+        assert!(is_issued_before(&claims(1000, 1_000_300), revoked_at_ms));
+        assert!(!is_issued_before(&claims(1000, 1_000_700), revoked_at_ms));
+        assert!(!is_issued_before(&claims(1000, 1_000_500), revoked_at_ms));
+
+        // iat_ms가 없는 이전 토큰은 초 단위로 비교
+        assert!(is_issued_before(&claims(1000, 0), revoked_at_ms));
+        assert!(!is_issued_before(&claims(1001, 0), revoked_at_ms));
+    }
+}
@@ -0,0 +1,235 @@
+use sqlx::{PgExecutor, PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::config::UploadLimitConfig;
+use crate::errors::ApiError;
+use crate::models::file::StorageUsage;
+
+// 일별 업로드 횟수 보관 기간
+const UPLOAD_COUNT_RETENTION_DAYS: i32 = 7;
+
+/// 사용자에게 적용되는 업로드 한도 (None이면 무제한)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UploadLimits {
+    pub storage_quota_bytes: Option<i64>,
+    pub daily_upload_limit: Option<i32>,
+}
+
+/// 사용자별 값(없으면 역할 기본값) 적용, 0 이하는 무제한
+pub fn effective_limit<T: PartialOrd + Default + Copy>(user_value: Option<T>, role_default: T) -> Option<T> {
+    let value = user_value.unwrap_or(role_default);
+    (value > T::default()).then_some(value)
+}
+
+/// 사람이 읽을 수 있는 크기 (1.5 MB)
+pub fn format_bytes(bytes: i64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes.max(0) as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes.max(0))
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// 사용자의 업로드 한도 조회
+pub async fn user_upload_limits<'e>(
+    executor: impl PgExecutor<'e>,
+    config: &UploadLimitConfig,
+    user_id: Uuid,
+) -> Result<UploadLimits, ApiError> {
+    let (role, storage_quota_bytes, daily_upload_limit) = sqlx::query_as::<_, (Option<String>, Option<i64>, Option<i32>)>(
+        "SELECT role::text, storage_quota_bytes, daily_upload_limit FROM users WHERE id = $1"
+    )
+    .bind(user_id)
+    .fetch_optional(executor)
+    .await?
+    .ok_or_else(|| ApiError::NotFound("사용자를 찾을 수 없습니다.".to_string()))?;
+
+    let (quota_mb, daily_uploads) = match role.as_deref() {
+        Some("admin") => (config.admin_storage_quota_mb, config.admin_daily_uploads),
+        _ => (config.user_storage_quota_mb, config.user_daily_uploads),
+    };
+    Ok(UploadLimits {
+        storage_quota_bytes: effective_limit(storage_quota_bytes, quota_mb.saturating_mul(1024 * 1024)),
+        daily_upload_limit: effective_limit(daily_upload_limit, daily_uploads),
+    })
+}
+
+/// 저장 공간 사용량 (업로드한 파일 크기 합계, 썸네일 제외)
+pub async fn storage_usage(pool: &PgPool, config: &UploadLimitConfig, user_id: Uuid) -> Result<StorageUsage, ApiError> {
+    let limits = user_upload_limits(pool, config, user_id).await?;
+    let (used_bytes, file_count) = sqlx::query_as::<_, (i64, i64)>(
+        "SELECT COALESCE(SUM(file_size), 0)::bigint, COUNT(*) FROM files WHERE user_id = $1"
+    )
+    .bind(user_id)
+    .fetch_one(pool)
+    .await?;
+    let uploads_today = sqlx::query_scalar::<_, i32>(
+        "SELECT upload_count FROM user_upload_counts WHERE user_id = $1 AND upload_date = CURRENT_DATE"
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?
+    .unwrap_or(0);
+
+    Ok(StorageUsage {
+        used_bytes,
+        file_count,
+        quota_bytes: limits.storage_quota_bytes,
+        uploads_today,
+        daily_upload_limit: limits.daily_upload_limit,
+    })
+}
+
+/// 같은 사용자의 한도 확인/사용량 기록을 트랜잭션이 끝날 때까지 직렬화
+/// (확인과 files/upload_sessions 기록 사이에 다른 업로드가 끼어들어 한도를 넘지 않도록)
+pub async fn lock_user_uploads(tx: &mut Transaction<'_, Postgres>, user_id: Uuid) -> Result<(), ApiError> {
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+        .bind(format!("upload_quota:{}", user_id))
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
+
+// 저장 공간 확인 (size는 아직 기록되지 않은 크기, 진행 중인 업로드 세션 크기도 사용량에 포함)
+async fn ensure_storage_quota(
+    tx: &mut Transaction<'_, Postgres>,
+    limits: &UploadLimits,
+    user_id: Uuid,
+    size: i64,
+) -> Result<(), ApiError> {
+    let Some(quota) = limits.storage_quota_bytes else {
+        return Ok(());
+    };
+    let used = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT ((SELECT COALESCE(SUM(file_size), 0) FROM files WHERE user_id = $1)
+              + (SELECT COALESCE(SUM(total_size), 0) FROM upload_sessions WHERE user_id = $1 AND status = 'uploading'))::bigint
+        "#
+    )
+    .bind(user_id)
+    .fetch_one(&mut **tx)
+    .await?;
+    if used.saturating_add(size) > quota {
+        return Err(ApiError::PayloadTooLarge(format!(
+            "저장 공간이 부족합니다. (사용 {} / 한도 {}, 업로드 {})",
+            format_bytes(used),
+            format_bytes(quota),
+            format_bytes(size)
+        )));
+    }
+    Ok(())
+}
+
+/// 업로드 전 한도 확인 및 오늘 업로드 횟수 1 증가
+/// 저장 공간 초과는 413, 일일 업로드 횟수 초과는 429
+pub async fn reserve_upload(pool: &PgPool, config: &UploadLimitConfig, user_id: Uuid, size: i64) -> Result<(), ApiError> {
+    let mut tx = pool.begin().await?;
+    reserve_upload_in(&mut tx, config, user_id, size).await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+/// 트랜잭션 안에서 한도 확인 및 오늘 업로드 횟수 증가 (업로드 세션처럼 같은 트랜잭션에서 사용량을 기록하는 경우)
+pub async fn reserve_upload_in(
+    tx: &mut Transaction<'_, Postgres>,
+    config: &UploadLimitConfig,
+    user_id: Uuid,
+    size: i64,
+) -> Result<(), ApiError> {
+    lock_user_uploads(tx, user_id).await?;
+    let limits = user_upload_limits(&mut **tx, config, user_id).await?;
+    ensure_storage_quota(tx, &limits, user_id, size).await?;
+    count_upload_in(tx, &limits, user_id).await
+}
+
+/// 저장 공간 한도 없이 오늘 업로드 횟수만 증가 (files에 기록하지 않는 사이트 공용 파일)
+pub async fn count_upload(pool: &PgPool, config: &UploadLimitConfig, user_id: Uuid) -> Result<(), ApiError> {
+    let mut tx = pool.begin().await?;
+    let limits = user_upload_limits(&mut *tx, config, user_id).await?;
+    count_upload_in(&mut tx, &limits, user_id).await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+/// 실제 크기를 안 뒤 저장 전에 저장 공간 한도만 다시 확인 (횟수는 늘리지 않음)
+pub async fn check_storage_quota(pool: &PgPool, config: &UploadLimitConfig, user_id: Uuid, size: i64) -> Result<(), ApiError> {
+    let mut tx = pool.begin().await?;
+    lock_user_uploads(&mut tx, user_id).await?;
+    let limits = user_upload_limits(&mut *tx, config, user_id).await?;
+    ensure_storage_quota(&mut tx, &limits, user_id, size).await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+/// files에 기록한 트랜잭션 안에서 기록된 파일을 포함한 사용량이 한도 안인지 확인 (초과하면 호출자가 롤백)
+pub async fn verify_storage_quota(
+    tx: &mut Transaction<'_, Postgres>,
+    config: &UploadLimitConfig,
+    user_id: Uuid,
+) -> Result<(), ApiError> {
+    lock_user_uploads(tx, user_id).await?;
+    let limits = user_upload_limits(&mut **tx, config, user_id).await?;
+    ensure_storage_quota(tx, &limits, user_id, 0).await
+}
+
+// 한도 안일 때만 오늘 업로드 횟수 증가 (동시 요청에서도 한도를 넘지 않음)
+async fn count_upload_in(tx: &mut Transaction<'_, Postgres>, limits: &UploadLimits, user_id: Uuid) -> Result<(), ApiError> {
+    let reserved = sqlx::query_scalar::<_, i32>(
+        r#"
+        INSERT INTO user_upload_counts (user_id, upload_date, upload_count)
+        VALUES ($1, CURRENT_DATE, 1)
+        ON CONFLICT (user_id, upload_date)
+        DO UPDATE SET upload_count = user_upload_counts.upload_count + 1
+        WHERE user_upload_counts.upload_count < $2
+        RETURNING upload_count
+        "#
+    )
+    .bind(user_id)
+    .bind(limits.daily_upload_limit.unwrap_or(i32::MAX))
+    .fetch_optional(&mut **tx)
+    .await?;
+    if reserved.is_none() {
+        return Err(ApiError::TooManyRequests(format!(
+            "오늘 업로드할 수 있는 파일 수({}개)를 모두 사용했습니다. 내일 다시 시도해주세요.",
+            limits.daily_upload_limit.unwrap_or_default()
+        )));
+    }
+    Ok(())
+}
+
+/// 오래된 일별 업로드 횟수 정리
+pub async fn purge_upload_counts(pool: &PgPool) -> Result<u64, ApiError> {
+    let result = sqlx::query("DELETE FROM user_upload_counts WHERE upload_date < CURRENT_DATE - $1")
+        .bind(UPLOAD_COUNT_RETENTION_DAYS)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_limit() {
+        assert_eq!(effective_limit(None, 100), Some(100));
+        assert_eq!(effective_limit(Some(5), 100), Some(5));
+        assert_eq!(effective_limit(Some(0), 100), None);
+        assert_eq!(effective_limit(None, 0i64), None);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(1024 * 1024 * 1024), "1.0 GB");
+        assert_eq!(format_bytes(-1), "0 B");
+    }
+}
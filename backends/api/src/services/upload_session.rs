@@ -0,0 +1,223 @@
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::errors::ApiError;
+use crate::services::purge_upload_counts;
+
+// 게시판 지정 없이 올리는 파일의 최대 크기 (일반 업로드와 동일)
+pub const DEFAULT_MAX_UPLOAD_SIZE: i64 = 50 * 1024 * 1024;
+
+// 마지막 조각을 제외한 조각의 최소 크기
+pub const MIN_CHUNK_SIZE: i64 = 256 * 1024;
+
+// 세션당 최대 조각 수
+pub const MAX_UPLOAD_PARTS: i64 = 10_000;
+
+// 합친 파일 이름 (조각 파일은 part-{index})
+const ASSEMBLED_FILE_NAME: &str = "data";
+
+const SWEEP_INTERVAL_SECONDS: u64 = 600;
+
+/// 전체 크기를 조각 크기로 나눈 조각 수
+pub fn part_count(total_size: i64, chunk_size: i64) -> i64 {
+    (total_size + chunk_size - 1) / chunk_size
+}
+
+/// index번째 조각의 크기 (범위를 벗어나면 None)
+pub fn expected_part_size(total_size: i64, chunk_size: i64, index: i64) -> Option<i64> {
+    if index < 0 || index >= part_count(total_size, chunk_size) {
+        return None;
+    }
+    Some((total_size - index * chunk_size).min(chunk_size))
+}
+
+/// 아직 받지 못한 조각 번호 (received는 정렬된 상태여야 함)
+pub fn missing_parts(total_parts: i32, received: &[i32]) -> Vec<i32> {
+    (0..total_parts).filter(|index| received.binary_search(index).is_err()).collect()
+}
+
+/// 소문자 hex SHA-256 문자열인지 확인
+pub fn is_sha256_hex(value: &str) -> bool {
+    value.len() == 64 && value.bytes().all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+}
+
+pub fn sha256_hex(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+/// 게시판 허용 형식(allowed_file_types) 확인
+/// "image/*", "application/pdf" 같은 MIME 패턴과 "pdf", ".hwp" 같은 확장자 모두 지원
+pub fn is_allowed_by_board(allowed: &[String], mime_type: &str, extension: &str) -> bool {
+    if allowed.is_empty() {
+        return true;
+    }
+    allowed.iter().any(|pattern| {
+        let pattern = pattern.trim().to_lowercase();
+        if let Some(prefix) = pattern.strip_suffix("/*") {
+            mime_type.split('/').next() == Some(prefix)
+        } else if pattern.contains('/') {
+            pattern == mime_type
+        } else {
+            pattern.trim_start_matches('.') == extension
+        }
+    })
+}
+
+/// 세션 조각을 모아 두는 로컬 디렉터리 (세션 ID는 서버가 발급한 UUID)
+pub fn session_dir(temp_dir: &str, session_id: Uuid) -> PathBuf {
+    Path::new(temp_dir).join(format!("session-{}", session_id))
+}
+
+pub fn part_path(temp_dir: &str, session_id: Uuid, index: i32) -> PathBuf {
+    session_dir(temp_dir, session_id).join(format!("part-{}", index))
+}
+
+/// 조각을 임시 파일에 쓴 뒤 이름을 바꿔 저장 (같은 조각 재전송 시 덮어씀)
+pub async fn write_part(temp_dir: &str, session_id: Uuid, index: i32, data: &[u8]) -> std::io::Result<()> {
+    let dir = session_dir(temp_dir, session_id);
+    tokio::fs::create_dir_all(&dir).await?;
+    let path = part_path(temp_dir, session_id, index);
+    let partial = dir.join(format!("part-{}.{}.tmp", index, Uuid::new_v4()));
+    tokio::fs::write(&partial, data).await?;
+    tokio::fs::rename(&partial, &path).await
+}
+
+/// 조각을 순서대로 이어 붙여 하나의 파일로 만들고 (경로, 크기, SHA-256) 반환
+pub async fn assemble_parts(temp_dir: &str, session_id: Uuid, total_parts: i32) -> std::io::Result<(PathBuf, u64, String)> {
+    let path = session_dir(temp_dir, session_id).join(ASSEMBLED_FILE_NAME);
+    let mut output = tokio::fs::File::create(&path).await?;
+    let mut hasher = Sha256::new();
+    let mut size = 0u64;
+    let mut buffer = vec![0u8; 64 * 1024];
+
+    for index in 0..total_parts {
+        let mut part = tokio::fs::File::open(part_path(temp_dir, session_id, index)).await?;
+        loop {
+            let n = part.read(&mut buffer).await?;
+            if n == 0 {
+                break;
+            }
+            hasher.update(&buffer[..n]);
+            output.write_all(&buffer[..n]).await?;
+            size += n as u64;
+        }
+    }
+    output.flush().await?;
+
+    Ok((path, size, format!("{:x}", hasher.finalize())))
+}
+
+/// 세션 임시 디렉터리 삭제 (없으면 무시)
+pub async fn remove_session_dir(temp_dir: &str, session_id: Uuid) {
+    let dir = session_dir(temp_dir, session_id);
+    if let Err(e) = tokio::fs::remove_dir_all(&dir).await {
+        if e.kind() != std::io::ErrorKind::NotFound {
+            warn!("업로드 세션 임시 디렉터리 삭제 실패: {:?}, {:?}", dir, e);
+        }
+    }
+}
+
+/// 만료된 업로드 세션과 오래된 임시 디렉터리 정리
+/// 세션에 속하지 않은 디렉터리(기존 청크 업로드 등)는 수정 시각이 ttl보다 오래되면 삭제
+pub async fn sweep_upload_sessions(pool: &PgPool, temp_dir: &str, ttl: Duration) -> Result<usize, ApiError> {
+    let expired = sqlx::query_scalar::<_, Uuid>("DELETE FROM upload_sessions WHERE expires_at < NOW() RETURNING id")
+        .fetch_all(pool)
+        .await?;
+    for session_id in &expired {
+        remove_session_dir(temp_dir, *session_id).await;
+    }
+
+    let active = sqlx::query_scalar::<_, Uuid>("SELECT id FROM upload_sessions WHERE status <> 'completed'")
+        .fetch_all(pool)
+        .await?;
+    let active_dirs: Vec<String> = active.iter().map(|id| format!("session-{}", id)).collect();
+
+    let mut removed = expired.len();
+    let mut entries = match tokio::fs::read_dir(temp_dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(removed),
+        Err(e) => return Err(ApiError::Internal(format!("임시 디렉터리 조회 실패: {}", e))),
+    };
+    let cutoff = SystemTime::now() - ttl;
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if active_dirs.contains(&name) {
+            continue;
+        }
+        let stale = entry
+            .metadata()
+            .await
+            .and_then(|meta| meta.modified())
+            .map(|modified| modified < cutoff)
+            .unwrap_or(false);
+        if !stale {
+            continue;
+        }
+        let result = if entry.path().is_dir() {
+            tokio::fs::remove_dir_all(entry.path()).await
+        } else {
+            tokio::fs::remove_file(entry.path()).await
+        };
+        match result {
+            Ok(()) => removed += 1,
+            Err(e) => warn!("오래된 임시 업로드 삭제 실패: {:?}, {:?}", entry.path(), e),
+        }
+    }
+
+    Ok(removed)
+}
+
+/// 만료된 업로드 세션 (및 지난 일별 업로드 횟수) 주기적 정리 작업
+pub fn spawn_upload_session_sweeper(pool: PgPool, temp_dir: String, ttl_hours: i64) {
+    let ttl = Duration::from_secs(ttl_hours.max(1) as u64 * 3600);
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(SWEEP_INTERVAL_SECONDS));
+        loop {
+            interval.tick().await;
+            match sweep_upload_sessions(&pool, &temp_dir, ttl).await {
+                Ok(0) => {}
+                Ok(count) => info!("만료된 업로드 세션/임시 파일 정리: {}건", count),
+                Err(e) => warn!("업로드 세션 정리 실패: {:?}", e),
+            }
+            // 지난 일별 업로드 횟수도 함께 정리
+            if let Err(e) = purge_upload_counts(&pool).await {
+                warn!("일별 업로드 횟수 정리 실패: {:?}", e);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_sizes() {
+        assert_eq!(part_count(10, 4), 3);
+        assert_eq!(part_count(8, 4), 2);
+        assert_eq!(expected_part_size(10, 4, 0), Some(4));
+        assert_eq!(expected_part_size(10, 4, 2), Some(2));
+        assert_eq!(expected_part_size(10, 4, 3), None);
+        assert_eq!(expected_part_size(10, 4, -1), None);
+        assert_eq!(missing_parts(5, &[0, 2, 3]), vec![1, 4]);
+    }
+
+    #[test]
+    fn test_sha256_and_board_types() {
+        assert_eq!(sha256_hex(b"abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        assert!(is_sha256_hex(&sha256_hex(b"abc")));
+        assert!(!is_sha256_hex("BA7816BF"));
+
+        let allowed = vec!["image/*".to_string(), "application/pdf".to_string(), ".hwp".to_string()];
+        assert!(is_allowed_by_board(&allowed, "image/png", "png"));
+        assert!(is_allowed_by_board(&allowed, "application/pdf", "pdf"));
+        assert!(is_allowed_by_board(&allowed, "application/octet-stream", "hwp"));
+        assert!(!is_allowed_by_board(&allowed, "video/mp4", "mp4"));
+        assert!(is_allowed_by_board(&[], "video/mp4", "mp4"));
+    }
+}
@@ -0,0 +1,260 @@
+use serde_json::Value;
+use std::path::Path;
+use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::process::Command;
+use tracing::warn;
+
+use crate::config::VideoConfig;
+use crate::models::file::VideoMetadata;
+
+// ffmpeg/ffprobe가 없다는 경고는 한 번만 출력
+static TOOL_MISSING_WARNED: AtomicBool = AtomicBool::new(false);
+
+// 포스터 최대 너비 (썸네일 원본으로만 사용)
+const POSTER_MAX_WIDTH: u32 = 1920;
+
+#[derive(Debug)]
+pub enum VideoToolError {
+    Unavailable(String), // 실행 파일 없음
+    Failed(String),      // 실행 실패, 시간 초과, 출력 해석 실패
+}
+
+impl std::fmt::Display for VideoToolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VideoToolError::Unavailable(program) => write!(f, "{}을(를) 찾을 수 없습니다.", program),
+            VideoToolError::Failed(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+/// 동영상 파일인지 확인 (업로드 허용 확장자 기준)
+pub fn is_video_key(key: &str) -> bool {
+    let extension = Path::new(key)
+        .extension()
+        .and_then(|s| s.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+
+    matches!(extension.as_str(), "mp4" | "avi" | "mov" | "wmv")
+}
+
+/// 동영상 포스터 키 (같은 디렉터리의 {이름}_poster.jpg)
+pub fn video_poster_key(original_key: &str) -> String {
+    let path = Path::new(original_key);
+    match (path.parent(), path.file_stem().and_then(|s| s.to_str())) {
+        (Some(parent), Some(stem)) => parent
+            .join(format!("{}_poster.jpg", stem))
+            .to_string_lossy()
+            .replace('\\', "/"),
+        _ => format!("{}_poster.jpg", original_key),
+    }
+}
+
+/// 포스터 프레임 위치 (짧은 영상은 중간 지점)
+pub fn poster_timestamp(poster_seconds: f64, duration_seconds: Option<f64>) -> f64 {
+    let at = poster_seconds.max(0.0);
+    match duration_seconds {
+        Some(duration) if duration > 0.0 => at.min(duration / 2.0),
+        _ => at,
+    }
+}
+
+// "30000/1001", "25/1", "25" 형식의 프레임 레이트
+fn parse_rational(value: &str) -> Option<f64> {
+    let (numerator, denominator) = match value.split_once('/') {
+        Some((n, d)) => (n.trim().parse::<f64>().ok()?, d.trim().parse::<f64>().ok()?),
+        None => (value.trim().parse::<f64>().ok()?, 1.0),
+    };
+    if denominator == 0.0 || numerator <= 0.0 {
+        return None;
+    }
+    Some(numerator / denominator)
+}
+
+// ffprobe는 숫자를 문자열로 출력함
+fn number_field(value: &Value, field: &str) -> Option<f64> {
+    match value.get(field)? {
+        Value::String(s) => s.parse().ok(),
+        Value::Number(n) => n.as_f64(),
+        _ => None,
+    }
+}
+
+// 회전 각도 (구버전은 tags.rotate, 신버전은 side_data_list의 Display Matrix)
+fn stream_rotation(stream: &Value) -> i64 {
+    let from_tags = stream
+        .get("tags")
+        .and_then(|tags| number_field(tags, "rotate"));
+    let from_side_data = stream
+        .get("side_data_list")
+        .and_then(Value::as_array)
+        .and_then(|list| list.iter().find_map(|side| number_field(side, "rotation")));
+    from_tags.or(from_side_data).unwrap_or(0.0) as i64
+}
+
+/// ffprobe JSON 출력 해석 (-show_format -show_streams, 영상 스트림이 없으면 None)
+pub fn parse_ffprobe_output(output: &str) -> Option<VideoMetadata> {
+    let root: Value = serde_json::from_str(output).ok()?;
+    let streams = root.get("streams")?.as_array()?;
+    let codec_type = |stream: &&Value| stream.get("codec_type").and_then(Value::as_str).map(str::to_string);
+    let video = streams.iter().find(|s| codec_type(s).as_deref() == Some("video"))?;
+    let audio = streams.iter().find(|s| codec_type(s).as_deref() == Some("audio"));
+    let format = root.get("format");
+
+    let mut width = video.get("width").and_then(Value::as_i64).map(|w| w as i32);
+    let mut height = video.get("height").and_then(Value::as_i64).map(|h| h as i32);
+    if stream_rotation(video).rem_euclid(180) == 90 {
+        std::mem::swap(&mut width, &mut height);
+    }
+
+    let duration_seconds = format
+        .and_then(|f| number_field(f, "duration"))
+        .or_else(|| number_field(video, "duration"))
+        .filter(|d| d.is_finite() && *d >= 0.0);
+    let frame_rate = ["avg_frame_rate", "r_frame_rate"]
+        .iter()
+        .find_map(|field| video.get(*field).and_then(Value::as_str).and_then(parse_rational))
+        .map(|rate| (rate * 1000.0).round() / 1000.0);
+    let bit_rate = format
+        .and_then(|f| number_field(f, "bit_rate"))
+        .or_else(|| number_field(video, "bit_rate"))
+        .map(|b| b as i64);
+    let codec_name = |stream: &Value| stream.get("codec_name").and_then(Value::as_str).map(str::to_string);
+
+    Some(VideoMetadata {
+        duration_seconds,
+        width,
+        height,
+        video_codec: codec_name(video),
+        audio_codec: audio.and_then(codec_name),
+        frame_rate,
+        bit_rate,
+    })
+}
+
+/// 로컬 ffprobe/ffmpeg로 동영상 정보와 포스터 프레임 추출
+pub struct VideoProcessor {
+    ffmpeg_path: String,
+    ffprobe_path: String,
+    poster_seconds: f64,
+    timeout: Duration,
+}
+
+impl VideoProcessor {
+    pub fn new(config: &VideoConfig) -> Self {
+        Self {
+            ffmpeg_path: config.ffmpeg_path.clone(),
+            ffprobe_path: config.ffprobe_path.clone(),
+            poster_seconds: config.poster_seconds,
+            timeout: Duration::from_secs(config.timeout_seconds.max(1)),
+        }
+    }
+
+    // 외부 명령 실행 후 표준 출력 반환 (시간 초과 시 프로세스 종료)
+    async fn run(&self, program: &str, args: &[&str]) -> Result<Vec<u8>, VideoToolError> {
+        let child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn();
+        let child = match child {
+            Ok(child) => child,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                if !TOOL_MISSING_WARNED.swap(true, Ordering::Relaxed) {
+                    warn!("{}을(를) 찾을 수 없어 동영상 메타데이터/포스터를 만들지 않습니다. (FFMPEG_PATH, FFPROBE_PATH 확인)", program);
+                }
+                return Err(VideoToolError::Unavailable(program.to_string()));
+            }
+            Err(e) => return Err(VideoToolError::Failed(format!("{} 실행 실패: {}", program, e))),
+        };
+
+        let output = tokio::time::timeout(self.timeout, child.wait_with_output())
+            .await
+            .map_err(|_| VideoToolError::Failed(format!("{} 시간 초과 ({}초)", program, self.timeout.as_secs())))?
+            .map_err(|e| VideoToolError::Failed(format!("{} 실행 실패: {}", program, e)))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(VideoToolError::Failed(format!(
+                "{} 종료 코드 {:?}: {}",
+                program,
+                output.status.code(),
+                stderr.trim().chars().take(500).collect::<String>()
+            )));
+        }
+        Ok(output.stdout)
+    }
+
+    /// 동영상 정보 조회 (영상 스트림이 없으면 None)
+    pub async fn probe(&self, input: &Path) -> Result<Option<VideoMetadata>, VideoToolError> {
+        let input = input.to_string_lossy();
+        let stdout = self
+            .run(&self.ffprobe_path, &["-v", "error", "-print_format", "json", "-show_format", "-show_streams", &input])
+            .await?;
+        let output = String::from_utf8_lossy(&stdout);
+        if serde_json::from_str::<Value>(&output).is_err() {
+            return Err(VideoToolError::Failed("ffprobe 출력을 해석할 수 없습니다.".to_string()));
+        }
+        Ok(parse_ffprobe_output(&output))
+    }
+
+    /// 포스터 프레임을 JPEG로 저장 (원본보다 크게 확대하지 않음)
+    pub async fn extract_poster(&self, input: &Path, output: &Path, duration_seconds: Option<f64>) -> Result<(), VideoToolError> {
+        let at = format!("{:.3}", poster_timestamp(self.poster_seconds, duration_seconds));
+        let input = input.to_string_lossy();
+        let output_path = output.to_string_lossy();
+        let scale = format!("scale='min({},iw)':-2", POSTER_MAX_WIDTH);
+        self.run(
+            &self.ffmpeg_path,
+            &["-v", "error", "-y", "-ss", &at, "-i", &input, "-frames:v", "1", "-vf", &scale, "-q:v", "3", &output_path],
+        )
+        .await?;
+
+        match tokio::fs::metadata(output).await {
+            Ok(meta) if meta.len() > 0 => Ok(()),
+            _ => Err(VideoToolError::Failed("포스터 프레임이 생성되지 않았습니다.".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_video_poster_key() {
+        assert!(is_video_key("posts/2026/a.MP4"));
+        assert!(!is_video_key("posts/2026/a.jpg"));
+        assert_eq!(video_poster_key("posts/2026/a.mp4"), "posts/2026/a_poster.jpg");
+        assert_eq!(poster_timestamp(1.0, Some(0.8)), 0.4);
+        assert_eq!(poster_timestamp(1.0, Some(30.0)), 1.0);
+        assert_eq!(poster_timestamp(1.0, None), 1.0);
+    }
+
+    #[test]
+    fn test_parse_ffprobe_output() {
+        let output = r#"{
+            "streams": [
+                {"codec_type": "audio", "codec_name": "aac"},
+                {"codec_type": "video", "codec_name": "h264", "width": 1920, "height": 1080,
+                 "avg_frame_rate": "30000/1001", "side_data_list": [{"rotation": -90}]}
+            ],
+            "format": {"duration": "12.345000", "bit_rate": "4000000"}
+        }"#;
+        let meta = parse_ffprobe_output(output).unwrap();
+        assert_eq!(meta.duration_seconds, Some(12.345));
+        assert_eq!((meta.width, meta.height), (Some(1080), Some(1920)));
+        assert_eq!(meta.video_codec.as_deref(), Some("h264"));
+        assert_eq!(meta.audio_codec.as_deref(), Some("aac"));
+        assert_eq!(meta.frame_rate, Some(29.97));
+        assert_eq!(meta.bit_rate, Some(4_000_000));
+
+        // 영상 스트림이 없는 파일
+        assert_eq!(parse_ffprobe_output(r#"{"streams": [{"codec_type": "audio"}], "format": {}}"#), None);
+        assert_eq!(parse_ffprobe_output("not json"), None);
+    }
+}
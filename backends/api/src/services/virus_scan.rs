@@ -0,0 +1,153 @@
+use async_trait::async_trait;
+use axum::body::Bytes;
+use futures_util::{stream, StreamExt};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+#[cfg(unix)]
+use tokio::net::UnixStream;
+
+use crate::config::VirusScanConfig;
+use crate::errors::ApiError;
+use crate::services::ByteStream;
+
+// clamd INSTREAM 청크 크기 (StreamMaxLength 제한과 별개로 한 번에 보내는 단위)
+const CLAMD_CHUNK_SIZE: usize = 64 * 1024;
+
+// 바이러스 검사 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanResult {
+    Clean,
+    Infected(String), // 탐지된 시그니처 이름
+}
+
+/// 업로드 파일 바이러스 검사 추상화
+#[async_trait]
+pub trait VirusScanner: Send + Sync {
+    async fn scan_stream(&self, data: ByteStream) -> Result<ScanResult, ApiError>;
+
+    async fn scan(&self, data: &[u8]) -> Result<ScanResult, ApiError> {
+        let data = Bytes::copy_from_slice(data);
+        self.scan_stream(stream::once(async move { Ok(data) }).boxed()).await
+    }
+}
+
+/// VIRUS_SCAN 설정에 맞는 검사기 생성 (none이면 검사하지 않음)
+pub fn create_virus_scanner(config: &VirusScanConfig) -> Option<Box<dyn VirusScanner>> {
+    match config.backend.as_str() {
+        "clamd" => Some(Box::new(ClamdScanner {
+            address: config.clamd_address.clone(),
+            timeout: Duration::from_secs(config.timeout_seconds),
+        })),
+        _ => None,
+    }
+}
+
+/// clamd 데몬에 INSTREAM 명령으로 검사 요청
+/// 주소 형식: unix:/var/run/clamav/clamd.ctl 또는 tcp://host:3310 (host:3310)
+pub struct ClamdScanner {
+    address: String,
+    timeout: Duration,
+}
+
+#[async_trait]
+impl VirusScanner for ClamdScanner {
+    async fn scan_stream(&self, data: ByteStream) -> Result<ScanResult, ApiError> {
+        let result = tokio::time::timeout(self.timeout, async {
+            if let Some(path) = self.address.strip_prefix("unix:") {
+                #[cfg(unix)]
+                {
+                    let socket = UnixStream::connect(path).await?;
+                    return instream(socket, data).await;
+                }
+                #[cfg(not(unix))]
+                {
+                    let _ = path;
+                    return Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "unix socket 미지원"));
+                }
+            }
+            let address = self.address.strip_prefix("tcp://").unwrap_or(&self.address);
+            let socket = TcpStream::connect(address).await?;
+            instream(socket, data).await
+        })
+        .await;
+
+        let reply = match result {
+            Ok(Ok(reply)) => reply,
+            Ok(Err(e)) => return Err(ApiError::Internal(format!("clamd 통신 실패 ({}): {}", self.address, e))),
+            Err(_) => return Err(ApiError::Internal(format!("clamd 응답 시간 초과 ({})", self.address))),
+        };
+        parse_clamd_reply(&reply)
+    }
+}
+
+// zINSTREAM: 4바이트 빅엔디언 길이 + 데이터 청크 반복, 길이 0으로 종료
+async fn instream<S>(mut socket: S, mut data: ByteStream) -> std::io::Result<String>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    socket.write_all(b"zINSTREAM\0").await?;
+    while let Some(chunk) = data.next().await {
+        for part in chunk?.chunks(CLAMD_CHUNK_SIZE) {
+            socket.write_all(&(part.len() as u32).to_be_bytes()).await?;
+            socket.write_all(part).await?;
+        }
+    }
+    socket.write_all(&[0, 0, 0, 0]).await?;
+    socket.flush().await?;
+
+    let mut reply = Vec::new();
+    socket.read_to_end(&mut reply).await?;
+    Ok(String::from_utf8_lossy(&reply).to_string())
+}
+
+/// clamd 응답 해석 ("stream: OK", "stream: Eicar-Signature FOUND", "... ERROR")
+pub fn parse_clamd_reply(reply: &str) -> Result<ScanResult, ApiError> {
+    let reply = reply.trim_end_matches(['\0', '\n', '\r']).trim();
+    let status = reply.split_once(": ").map(|(_, status)| status).unwrap_or(reply);
+
+    if status == "OK" {
+        Ok(ScanResult::Clean)
+    } else if let Some(signature) = status.strip_suffix(" FOUND") {
+        Ok(ScanResult::Infected(signature.to_string()))
+    } else {
+        Err(ApiError::Internal(format!("clamd 검사 오류: {}", reply)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_clamd_reply() {
+        assert_eq!(parse_clamd_reply("stream: OK\0").unwrap(), ScanResult::Clean);
+        assert_eq!(
+            parse_clamd_reply("stream: Win.Test.EICAR_HDB-1 FOUND\0").unwrap(),
+            ScanResult::Infected("Win.Test.EICAR_HDB-1".to_string())
+        );
+        assert!(parse_clamd_reply("INSTREAM size limit exceeded. ERROR\0").is_err());
+        assert!(parse_clamd_reply("").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_instream_protocol() {
+        let (client, mut server) = tokio::io::duplex(1024);
+        let daemon = tokio::spawn(async move {
+            let mut request = Vec::new();
+            let mut buf = [0u8; 256];
+            // 명령 + 데이터 청크 + 종료 청크(길이 0)까지 수신
+            while request.len() < 10 + 4 + 5 + 4 {
+                let n = server.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            server.write_all(b"stream: OK\0").await.unwrap();
+            request
+        });
+
+        let data = stream::once(async { Ok(Bytes::from_static(b"hello")) }).boxed();
+        let reply = instream(client, data).await.unwrap();
+        assert_eq!(parse_clamd_reply(&reply).unwrap(), ScanResult::Clean);
+        assert_eq!(daemon.await.unwrap(), b"zINSTREAM\0\0\0\0\x05hello\0\0\0\0".to_vec());
+    }
+}
@@ -23,6 +23,20 @@ pub struct Claims {
     pub role: String,
     pub exp: i64,
     pub iat: i64,
+    // 발급 시각(밀리초), 같은 초 안의 전체 폐기/재로그인 구분용 (이전 토큰에는 없음)
+    #[serde(default)]
+    pub iat_ms: i64,
+}
+
+impl Claims {
+    /// 발급 시각(밀리초), iat_ms가 없는 이전 토큰은 초 단위 iat로 계산
+    pub fn issued_at_millis(&self) -> i64 {
+        if self.iat_ms > 0 {
+            self.iat_ms
+        } else {
+            self.iat * 1000
+        }
+    }
 }
 
 pub fn verify_token(token: &str, config: &Config) -> Result<Claims, jsonwebtoken::errors::Error> {
@@ -60,6 +74,7 @@ pub fn generate_tokens(config: &Config, user_id: Uuid, role: String) -> Result<(
         role: role.clone(),
         exp: (now + Duration::minutes(config.access_token_expiry)).timestamp(),
         iat: now.timestamp(),
+        iat_ms: now.timestamp_millis(),
     };
     
     // Refresh token claims
@@ -68,6 +83,7 @@ pub fn generate_tokens(config: &Config, user_id: Uuid, role: String) -> Result<(
         role,
         exp: (now + Duration::days(config.refresh_token_expiry)).timestamp(),
         iat: now.timestamp(),
+        iat_ms: now.timestamp_millis(),
     };
     
     let access_token = create_token(&access_claims, &config.jwt_secret)?;
@@ -82,6 +98,11 @@ pub fn hash_refresh_token(token: &str) -> String {
     format!("{:x}", hasher.finalize())
 }
 
+/// 액세스 토큰 폐기 목록 저장용 해시 (토큰에 jti가 없어 토큰 전체를 해시)
+pub fn hash_access_token(token: &str) -> String {
+    hash_refresh_token(token)
+}
+
 pub async fn get_current_user(
     State(state): State<AppState>,
     auth_header: Option<TypedHeader<Authorization<Bearer>>>,
@@ -103,6 +124,11 @@ pub async fn get_current_user(
             StatusCode::UNAUTHORIZED
         })?;
 
+    if crate::middleware::is_token_revoked(&state, auth_header.token(), &token_data).await? {
+        println!("❌ 폐기된 토큰: {}", token_data.sub);
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
     println!("✅ 토큰 검증 성공, 사용자 ID: {}", token_data.sub);
 
     let user = sqlx::query_as::<_, User>(
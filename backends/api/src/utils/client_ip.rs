@@ -0,0 +1,192 @@
+use async_trait::async_trait;
+use axum::{
+    extract::{ConnectInfo, FromRequestParts},
+    http::{request::Parts, HeaderMap},
+};
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+use tracing::warn;
+
+use crate::AppState;
+
+/// 신뢰하는 프록시 주소 (단일 IP 또는 CIDR)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustedProxy {
+    network: IpAddr,
+    prefix: u8,
+}
+
+impl TrustedProxy {
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        match (self.network, ip.to_canonical()) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix as u32).unwrap_or(0);
+                u32::from(network) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix as u32).unwrap_or(0);
+                u128::from(network) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+fn parse_trusted_proxy(entry: &str) -> Option<TrustedProxy> {
+    let (address, prefix) = match entry.split_once('/') {
+        Some((address, prefix)) => (address, Some(prefix)),
+        None => (entry, None),
+    };
+    let network = address.trim().parse::<IpAddr>().ok()?.to_canonical();
+    let max_prefix = if network.is_ipv4() { 32 } else { 128 };
+    let prefix = match prefix {
+        Some(prefix) => prefix.trim().parse::<u8>().ok().filter(|prefix| *prefix <= max_prefix)?,
+        None => max_prefix,
+    };
+    Some(TrustedProxy { network, prefix })
+}
+
+/// TRUSTED_PROXIES 값 해석 (쉼표 구분, 잘못된 항목은 경고 후 무시)
+pub fn parse_trusted_proxies(value: &str) -> Vec<TrustedProxy> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .filter_map(|entry| {
+            let proxy = parse_trusted_proxy(entry);
+            if proxy.is_none() {
+                warn!("TRUSTED_PROXIES 항목을 해석할 수 없습니다: {}", entry);
+            }
+            proxy
+        })
+        .collect()
+}
+
+// 헤더의 주소 (포트가 붙은 형식도 허용)
+fn parse_forwarded_ip(value: &str) -> Option<IpAddr> {
+    let value = value.trim();
+    value
+        .parse::<IpAddr>()
+        .or_else(|_| value.parse::<SocketAddr>().map(|addr| addr.ip()))
+        .ok()
+        .map(|ip| ip.to_canonical())
+}
+
+/// 실제 클라이언트 IP
+/// 직접 연결한 주소가 신뢰하는 프록시일 때만 X-Forwarded-For(뒤에서부터 신뢰하지 않는 첫 주소), X-Real-IP 순으로 사용
+pub fn resolve_client_ip(peer: Option<IpAddr>, headers: &HeaderMap, trusted: &[TrustedProxy]) -> Option<IpAddr> {
+    let is_trusted = |ip: &IpAddr| trusted.iter().any(|proxy| proxy.contains(ip));
+    let peer = peer?.to_canonical();
+    if !is_trusted(&peer) {
+        return Some(peer);
+    }
+
+    let forwarded: Vec<&str> = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .collect();
+    let mut closest = None;
+    for hop in forwarded.iter().rev() {
+        match parse_forwarded_ip(hop) {
+            Some(ip) if !is_trusted(&ip) => return Some(ip),
+            Some(ip) => closest = Some(ip),
+            // 해석할 수 없는 값부터는 위조 가능성이 있으므로 중단
+            None => break,
+        }
+    }
+    if closest.is_some() {
+        return closest;
+    }
+
+    headers
+        .get("x-real-ip")
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_forwarded_ip)
+        .or(Some(peer))
+}
+
+/// 공개용 IP (IPv4는 앞 두 자리, IPv6는 앞 두 그룹만 표시)
+pub fn mask_ip(ip: &str) -> String {
+    match ip.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => {
+            let octets = ip.octets();
+            format!("{}.{}.*.*", octets[0], octets[1])
+        }
+        Ok(IpAddr::V6(ip)) => {
+            let segments = ip.segments();
+            format!("{:x}:{:x}:*:*", segments[0], segments[1])
+        }
+        Err(_) => "*".to_string(),
+    }
+}
+
+/// 게시글/댓글 응답의 작성자 IP (관리자는 전체, show_ip 게시판은 일부만, 그 외는 숨김)
+pub fn display_ip(ip: Option<&str>, show_ip: bool, is_admin: bool) -> Option<String> {
+    let ip = ip?;
+    if is_admin {
+        Some(ip.to_string())
+    } else if show_ip {
+        Some(mask_ip(ip))
+    } else {
+        None
+    }
+}
+
+/// 요청한 클라이언트 IP (알 수 없으면 None)
+#[derive(Debug, Clone)]
+pub struct ClientIp(pub Option<String>);
+
+#[async_trait]
+impl FromRequestParts<AppState> for ClientIp {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        let ip = resolve_client_ip(peer, &parts.headers, &state.config.trusted_proxies);
+        Ok(ClientIp(ip.map(|ip| ip.to_string())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    #[test]
+    fn test_resolve_client_ip() {
+        let trusted = parse_trusted_proxies("127.0.0.1, 10.0.0.0/8, ::1, bad, 10.0.0.0/40");
+        assert_eq!(trusted.len(), 3);
+        let ip = |s: &str| s.parse::<IpAddr>().unwrap();
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", HeaderValue::from_static("1.1.1.1, 203.0.113.7, 10.0.0.2"));
+        headers.insert("x-real-ip", HeaderValue::from_static("10.0.0.2"));
+
+        // 신뢰하지 않는 연결은 헤더 무시
+        assert_eq!(resolve_client_ip(Some(ip("198.51.100.1")), &headers, &trusted), Some(ip("198.51.100.1")));
+        // 위조된 왼쪽 값이 아닌 신뢰 구간 바로 앞의 주소
+        assert_eq!(resolve_client_ip(Some(ip("127.0.0.1")), &headers, &trusted), Some(ip("203.0.113.7")));
+        assert_eq!(resolve_client_ip(Some(ip("::ffff:10.1.2.3")), &headers, &trusted), Some(ip("203.0.113.7")));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-real-ip", HeaderValue::from_static("203.0.113.9"));
+        assert_eq!(resolve_client_ip(Some(ip("::1")), &headers, &trusted), Some(ip("203.0.113.9")));
+        assert_eq!(resolve_client_ip(Some(ip("::1")), &HeaderMap::new(), &trusted), Some(ip("::1")));
+        assert_eq!(resolve_client_ip(None, &headers, &trusted), None);
+    }
+
+    #[test]
+    fn test_display_ip() {
+        assert_eq!(mask_ip("123.45.67.89"), "123.45.*.*");
+        assert_eq!(mask_ip("2001:db8::1"), "2001:db8:*:*");
+        assert_eq!(display_ip(Some("123.45.67.89"), true, false).as_deref(), Some("123.45.*.*"));
+        assert_eq!(display_ip(Some("123.45.67.89"), false, true).as_deref(), Some("123.45.67.89"));
+        assert_eq!(display_ip(Some("123.45.67.89"), false, false), None);
+        assert_eq!(display_ip(None, true, true), None);
+    }
+}
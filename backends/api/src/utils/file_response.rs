@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+
+// 요청 Range 헤더 해석 결과
+#[derive(Debug, PartialEq, Eq)]
+pub enum ByteRange {
+    // Range 없음 또는 다중 범위 (전체 전송)
+    Full,
+    // 포함 범위 [start, end]
+    Partial { start: u64, end: u64 },
+    // 만족할 수 없는 범위 (416)
+    Unsatisfiable,
+}
+
+/// Range 헤더 해석 ("bytes=0-499", "bytes=500-", "bytes=-500")
+/// 다중 범위 요청은 지원하지 않으므로 전체 전송으로 처리
+pub fn parse_range(header: Option<&str>, size: u64) -> ByteRange {
+    let Some(spec) = header.and_then(|h| h.trim().strip_prefix("bytes=")) else {
+        return ByteRange::Full;
+    };
+    if spec.contains(',') {
+        return ByteRange::Full;
+    }
+    let Some((start, end)) = spec.trim().split_once('-') else {
+        return ByteRange::Full;
+    };
+
+    let (start, end) = match (start.trim(), end.trim()) {
+        ("", "") => return ByteRange::Full,
+        // 마지막 n바이트
+        ("", suffix) => match suffix.parse::<u64>() {
+            Ok(0) => return ByteRange::Unsatisfiable,
+            Ok(n) => (size.saturating_sub(n), size.saturating_sub(1)),
+            Err(_) => return ByteRange::Full,
+        },
+        (start, "") => match start.parse::<u64>() {
+            Ok(start) => (start, size.saturating_sub(1)),
+            Err(_) => return ByteRange::Full,
+        },
+        (start, end) => match (start.parse::<u64>(), end.parse::<u64>()) {
+            (Ok(start), Ok(end)) if start <= end => (start, end.min(size.saturating_sub(1))),
+            (Ok(_), Ok(_)) => return ByteRange::Unsatisfiable,
+            _ => return ByteRange::Full,
+        },
+    };
+
+    if size == 0 || start >= size {
+        return ByteRange::Unsatisfiable;
+    }
+
+    ByteRange::Partial { start, end }
+}
+
+/// 파일 크기와 수정 시각으로 만든 ETag
+pub fn file_etag(size: u64, modified: DateTime<Utc>) -> String {
+    format!("\"{:x}-{:x}\"", size, modified.timestamp())
+}
+
+/// HTTP 날짜 형식 (Last-Modified 등)
+pub fn http_date(time: DateTime<Utc>) -> String {
+    time.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// If-None-Match 헤더가 ETag와 일치하는지 확인 (약한 비교)
+pub fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    let etag = etag.trim_start_matches("W/");
+    if_none_match
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag)
+}
+
+/// If-Modified-Since 이후 변경되지 않았는지 확인
+pub fn not_modified_since(if_modified_since: &str, modified: DateTime<Utc>) -> bool {
+    DateTime::parse_from_rfc2822(if_modified_since.trim())
+        .map(|since| modified.timestamp() <= since.timestamp())
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range(None, 1000), ByteRange::Full);
+        assert_eq!(parse_range(Some("bytes=0-499"), 1000), ByteRange::Partial { start: 0, end: 499 });
+        assert_eq!(parse_range(Some("bytes=500-"), 1000), ByteRange::Partial { start: 500, end: 999 });
+        assert_eq!(parse_range(Some("bytes=-100"), 1000), ByteRange::Partial { start: 900, end: 999 });
+        assert_eq!(parse_range(Some("bytes=900-5000"), 1000), ByteRange::Partial { start: 900, end: 999 });
+        assert_eq!(parse_range(Some("bytes=1000-"), 1000), ByteRange::Unsatisfiable);
+        assert_eq!(parse_range(Some("bytes=5-1"), 1000), ByteRange::Unsatisfiable);
+        assert_eq!(parse_range(Some("bytes=0-1,5-9"), 1000), ByteRange::Full);
+        assert_eq!(parse_range(Some("items=0-1"), 1000), ByteRange::Full);
+    }
+
+    #[test]
+    fn test_conditional_headers() {
+        let modified = DateTime::parse_from_rfc3339("2026-10-18T09:00:00Z").unwrap().with_timezone(&Utc);
+        let etag = file_etag(1000, modified);
+
+        assert!(etag_matches(&etag, &etag));
+        assert!(etag_matches(&format!("W/{}, \"other\"", etag), &etag));
+        assert!(!etag_matches("\"other\"", &etag));
+
+        assert_eq!(http_date(modified), "Sun, 18 Oct 2026 09:00:00 GMT");
+        assert!(not_modified_since("Sun, 18 Oct 2026 09:00:00 GMT", modified));
+        assert!(!not_modified_since("Sun, 18 Oct 2026 08:59:59 GMT", modified));
+    }
+}
@@ -0,0 +1,189 @@
+// 업로드 파일 내용(매직 바이트) 검사
+// 클라이언트가 보낸 확장자는 신뢰하지 않고, 실제 내용이 확장자와 맞는 경우에만 허용
+
+pub const SVG_MIME_TYPE: &str = "image/svg+xml";
+
+// 내용 판별에 사용하는 앞부분 크기 (텍스트/SVG 판별용)
+const TEXT_SNIFF_LIMIT: usize = 8 * 1024;
+
+// 내용으로 판별한 파일 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SniffedType {
+    Jpeg,
+    Png,
+    Gif,
+    Webp,
+    Bmp,
+    Ico,
+    Pdf,
+    Ole2, // doc, xls, ppt (MS Office 97-2003)
+    Zip,  // docx, xlsx, pptx (OOXML)
+    Mp4,
+    QuickTime,
+    Avi,
+    Asf, // wmv
+    Svg,
+    Text,
+}
+
+/// 파일 앞부분의 매직 바이트로 형식 판별
+pub fn sniff_type(data: &[u8]) -> Option<SniffedType> {
+    if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        return Some(SniffedType::Jpeg);
+    }
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        return Some(SniffedType::Png);
+    }
+    if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        return Some(SniffedType::Gif);
+    }
+    if data.len() >= 12 && data.starts_with(b"RIFF") {
+        match &data[8..12] {
+            b"WEBP" => return Some(SniffedType::Webp),
+            b"AVI " => return Some(SniffedType::Avi),
+            _ => {}
+        }
+    }
+    if data.len() >= 14 && data.starts_with(b"BM") {
+        return Some(SniffedType::Bmp);
+    }
+    if data.len() >= 6 && data.starts_with(&[0x00, 0x00, 0x01, 0x00]) && data[4..6] != [0, 0] {
+        return Some(SniffedType::Ico);
+    }
+    if data.starts_with(b"%PDF-") {
+        return Some(SniffedType::Pdf);
+    }
+    if data.starts_with(&[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1]) {
+        return Some(SniffedType::Ole2);
+    }
+    if data.starts_with(b"PK\x03\x04") {
+        return Some(SniffedType::Zip);
+    }
+    if data.len() >= 12 {
+        match &data[4..8] {
+            b"ftyp" if &data[8..12] == b"qt  " => return Some(SniffedType::QuickTime),
+            b"ftyp" => return Some(SniffedType::Mp4),
+            // ftyp 박스가 없는 오래된 QuickTime 파일
+            b"moov" | b"mdat" | b"wide" | b"free" => return Some(SniffedType::QuickTime),
+            _ => {}
+        }
+    }
+    if data.starts_with(&[0x30, 0x26, 0xB2, 0x75, 0x8E, 0x66, 0xCF, 0x11]) {
+        return Some(SniffedType::Asf);
+    }
+
+    let text = text_prefix(data)?;
+    if is_svg_text(text) {
+        Some(SniffedType::Svg)
+    } else {
+        Some(SniffedType::Text)
+    }
+}
+
+/// 확장자와 실제 내용이 일치하면 내용 기준 MIME 타입 반환
+/// 일치하지 않거나 허용하지 않는 확장자면 None
+pub fn content_mime_type(extension: &str, data: &[u8]) -> Option<&'static str> {
+    let sniffed = sniff_type(data)?;
+    let mime = match (extension.to_lowercase().as_str(), sniffed) {
+        ("jpg" | "jpeg", SniffedType::Jpeg) => "image/jpeg",
+        ("png", SniffedType::Png) => "image/png",
+        ("gif", SniffedType::Gif) => "image/gif",
+        ("webp", SniffedType::Webp) => "image/webp",
+        ("bmp", SniffedType::Bmp) => "image/bmp",
+        ("ico", SniffedType::Ico) => "image/x-icon",
+        ("svg", SniffedType::Svg) => SVG_MIME_TYPE,
+        ("pdf", SniffedType::Pdf) => "application/pdf",
+        ("doc", SniffedType::Ole2) => "application/msword",
+        ("xls", SniffedType::Ole2) => "application/vnd.ms-excel",
+        ("ppt", SniffedType::Ole2) => "application/vnd.ms-powerpoint",
+        ("docx", SniffedType::Zip) => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        ("xlsx", SniffedType::Zip) => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        ("pptx", SniffedType::Zip) => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        ("txt", SniffedType::Text) => "text/plain",
+        // mp4/mov는 같은 컨테이너 계열이므로 내용 기준으로 구분
+        ("mp4" | "mov", SniffedType::Mp4) => "video/mp4",
+        ("mp4" | "mov", SniffedType::QuickTime) => "video/quicktime",
+        ("avi", SniffedType::Avi) => "video/x-msvideo",
+        ("wmv", SniffedType::Asf) => "video/x-ms-wmv",
+        _ => return None,
+    };
+    Some(mime)
+}
+
+/// 인라인으로 표시하면 스크립트가 실행될 수 있는 형식인지 확인
+pub fn is_active_content(mime_type: &str) -> bool {
+    mime_type == SVG_MIME_TYPE
+}
+
+/// 키/파일명의 확장자가 SVG인지 확인 (/uploads 응답 헤더 결정용)
+pub fn has_svg_extension(path: &str) -> bool {
+    path.rsplit_once('.')
+        .map(|(_, ext)| ext.eq_ignore_ascii_case("svg"))
+        .unwrap_or(false)
+}
+
+// 앞부분이 NUL 없는 UTF-8 텍스트인 경우 해당 문자열 반환
+fn text_prefix(data: &[u8]) -> Option<&str> {
+    let head = &data[..data.len().min(TEXT_SNIFF_LIMIT)];
+    if head.is_empty() || head.contains(&0) {
+        return None;
+    }
+    match std::str::from_utf8(head) {
+        Ok(text) => Some(text),
+        // 잘린 위치가 멀티바이트 문자 중간인 경우만 허용
+        Err(e) if e.error_len().is_none() && data.len() > head.len() => {
+            std::str::from_utf8(&head[..e.valid_up_to()]).ok()
+        }
+        Err(_) => None,
+    }
+}
+
+// XML 선언/주석/DOCTYPE 뒤에 <svg 요소가 오는 텍스트
+fn is_svg_text(text: &str) -> bool {
+    let trimmed = text.trim_start_matches('\u{feff}').trim_start();
+    let lower = trimmed.to_ascii_lowercase();
+    (lower.starts_with("<?xml") || lower.starts_with("<svg") || lower.starts_with("<!--") || lower.starts_with("<!doctype svg"))
+        && lower.contains("<svg")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sniff_type() {
+        assert_eq!(sniff_type(&[0xFF, 0xD8, 0xFF, 0xE0, 0, 0x10]), Some(SniffedType::Jpeg));
+        assert_eq!(sniff_type(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"), Some(SniffedType::Png));
+        assert_eq!(sniff_type(b"RIFF\x24\0\0\0WEBPVP8 "), Some(SniffedType::Webp));
+        assert_eq!(sniff_type(b"%PDF-1.7\n"), Some(SniffedType::Pdf));
+        assert_eq!(sniff_type(b"\0\0\0\x18ftypisom\0\0\0\0"), Some(SniffedType::Mp4));
+        assert_eq!(sniff_type(b"\0\0\0\x14ftypqt  \0\0\0\0"), Some(SniffedType::QuickTime));
+        assert_eq!(sniff_type(b"<?xml version=\"1.0\"?>\n<svg xmlns=\"http://www.w3.org/2000/svg\"/>"), Some(SniffedType::Svg));
+        assert_eq!(sniff_type("안녕하세요\n".as_bytes()), Some(SniffedType::Text));
+        assert_eq!(sniff_type(&[0x00, 0x01, 0x02, 0x03]), None);
+        assert_eq!(sniff_type(b""), None);
+    }
+
+    #[test]
+    fn test_content_mime_type() {
+        let jpeg = [0xFF, 0xD8, 0xFF, 0xE0, 0, 0x10];
+        assert_eq!(content_mime_type("JPG", &jpeg), Some("image/jpeg"));
+        // 확장자를 바꾼 파일은 거부
+        assert_eq!(content_mime_type("png", &jpeg), None);
+        assert_eq!(content_mime_type("jpg", b"<html><script>alert(1)</script></html>"), None);
+        assert_eq!(content_mime_type("svg", b"<svg onload=\"alert(1)\"></svg>"), Some(SVG_MIME_TYPE));
+        assert_eq!(content_mime_type("txt", b"<svg></svg>"), None);
+        assert_eq!(content_mime_type("docx", b"PK\x03\x04\x14\0"), Some("application/vnd.openxmlformats-officedocument.wordprocessingml.document"));
+        assert_eq!(content_mime_type("mov", b"\0\0\0\x18ftypisom\0\0\0\0"), Some("video/mp4"));
+        assert_eq!(content_mime_type("exe", b"MZ\x90\0"), None);
+    }
+
+    #[test]
+    fn test_text_prefix_cut_in_multibyte_char() {
+        let mut data = vec![b'a'; TEXT_SNIFF_LIMIT - 1];
+        data.extend_from_slice("가".as_bytes());
+        assert_eq!(sniff_type(&data), Some(SniffedType::Text));
+        assert!(has_svg_extension("posts/images/a_1_logo.SVG"));
+        assert!(!has_svg_extension("posts/images/a_1_logo.png"));
+    }
+}
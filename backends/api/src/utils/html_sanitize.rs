@@ -0,0 +1,195 @@
+use std::borrow::Cow;
+
+use ammonia::{Builder, Url};
+use html5ever::serialize::{serialize, SerializeOpts, TraversalScope};
+use html5ever::tendril::TendrilSink;
+use html5ever::{local_name, namespace_url, ns, parse_fragment, QualName};
+use markup5ever_rcdom::{Handle, NodeData, RcDom, SerializableHandle};
+use pulldown_cmark::{html, CowStr, Event, Options, Parser};
+
+use crate::models::admin::board::{Board, EDITOR_TYPE_MARKDOWN};
+
+// 허용 도메인 iframe에 남길 속성
+const IFRAME_ATTRIBUTES: &[&str] = &["src", "width", "height", "title", "allow", "allowfullscreen", "frameborder"];
+
+/// iframe 주소가 허용 도메인(하위 도메인 포함)의 http/https 주소인지 확인
+pub fn is_allowed_iframe_src(src: &str, allowed_domains: &[String]) -> bool {
+    let Ok(url) = Url::parse(src) else {
+        return false;
+    };
+    if url.scheme() != "https" && url.scheme() != "http" {
+        return false;
+    }
+    let Some(host) = url.host_str() else {
+        return false;
+    };
+    let host = host.to_ascii_lowercase();
+    allowed_domains.iter().any(|domain| {
+        let domain = domain.trim().trim_start_matches("*.").to_ascii_lowercase();
+        !domain.is_empty() && (host == domain || host.ends_with(&format!(".{}", domain)))
+    })
+}
+
+/// Markdown을 HTML로 변환 (allow_html이 false면 본문의 HTML은 글자 그대로 표시)
+pub fn render_markdown(source: &str, allow_html: bool) -> String {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let parser = Parser::new_ext(source, options).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) if !allow_html => Event::Text(CowStr::from(raw.into_string())),
+        event => event,
+    });
+    let mut output = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut output, parser);
+    output
+}
+
+/// 게시판 설정에 따른 HTML 정리 정책
+/// - allow_rich_text가 false면 태그를 모두 제거 (markdown 게시판은 변환된 서식만 허용)
+/// - iframe은 allowed_iframe_domains에 있는 도메인만 허용
+pub fn sanitize_html(html: &str, allow_rich_text: bool, allowed_iframe_domains: &[String]) -> String {
+    if !allow_rich_text {
+        return Builder::empty().clean(html).to_string();
+    }
+
+    if allowed_iframe_domains.is_empty() {
+        // iframe을 허용하지 않으면 대체 내용까지 제거
+        return Builder::default().add_clean_content_tags(&["iframe"]).clean(html).to_string();
+    }
+
+    // 허용 도메인이 아닌 iframe은 DOM에서 먼저 제거하고, 남은 iframe의 src는 ammonia에서 다시 확인
+    let html = remove_disallowed_iframes(html, allowed_iframe_domains);
+    let allowed_domains = allowed_iframe_domains.to_vec();
+    Builder::default()
+        .add_tags(&["iframe"])
+        .add_tag_attributes("iframe", IFRAME_ATTRIBUTES)
+        .attribute_filter(move |element, attribute, value| {
+            if element == "iframe" && attribute == "src" && !is_allowed_iframe_src(value, &allowed_domains) {
+                None
+            } else {
+                Some(Cow::Borrowed(value))
+            }
+        })
+        .clean(&html)
+        .to_string()
+}
+
+/// 허용 도메인 주소가 아닌 iframe을 대체 내용까지 제거
+/// ammonia로 정리하기 전에 원문을 DOM으로 파싱해서 제거하고, 최종 정리는 ammonia가 맡음
+fn remove_disallowed_iframes(html: &str, allowed_domains: &[String]) -> String {
+    let context = QualName::new(None, ns!(html), local_name!("div"));
+    let dom = parse_fragment(RcDom::default(), Default::default(), context, vec![]).one(html);
+    // 조각 파싱 결과는 document > html 아래에 들어감
+    let Some(root) = dom.document.children.borrow().first().cloned() else {
+        return String::new();
+    };
+    remove_iframes_in(&root, allowed_domains);
+
+    let mut output = Vec::new();
+    let options = SerializeOpts {
+        traversal_scope: TraversalScope::ChildrenOnly(None),
+        ..Default::default()
+    };
+    if serialize(&mut output, &SerializableHandle::from(root), options).is_err() {
+        return String::new();
+    }
+    String::from_utf8(output).unwrap_or_default()
+}
+
+fn remove_iframes_in(node: &Handle, allowed_domains: &[String]) {
+    node.children.borrow_mut().retain(|child| !is_disallowed_iframe(child, allowed_domains));
+    for child in node.children.borrow().iter() {
+        remove_iframes_in(child, allowed_domains);
+    }
+    // <template> 내용은 별도 문서 조각에 들어 있음
+    if let NodeData::Element { template_contents, .. } = &node.data {
+        if let Some(contents) = template_contents.borrow().as_ref() {
+            remove_iframes_in(contents, allowed_domains);
+        }
+    }
+}
+
+fn is_disallowed_iframe(node: &Handle, allowed_domains: &[String]) -> bool {
+    match &node.data {
+        NodeData::Element { name, attrs, .. } if name.local == local_name!("iframe") => !attrs
+            .borrow()
+            .iter()
+            .any(|attr| attr.name.local == local_name!("src") && is_allowed_iframe_src(&attr.value, allowed_domains)),
+        _ => false,
+    }
+}
+
+/// 저장할 게시글 본문
+pub struct PostContent {
+    pub content: String,                // 정리한 HTML
+    pub content_source: Option<String>, // markdown 게시판의 Markdown 원문
+}
+
+/// 게시글 본문 정리 (markdown 게시판은 원문을 따로 보관하고 HTML로 변환 후 정리)
+pub fn prepare_post_content(board: &Board, content: &str) -> PostContent {
+    let allowed_iframe_domains = board.allowed_iframe_domains.as_deref().unwrap_or_default();
+    if board.editor_type == EDITOR_TYPE_MARKDOWN {
+        let rendered = render_markdown(content, board.allow_rich_text);
+        return PostContent {
+            content: sanitize_html(&rendered, true, allowed_iframe_domains),
+            content_source: Some(content.to_string()),
+        };
+    }
+    PostContent {
+        content: sanitize_html(content, board.allow_rich_text, allowed_iframe_domains),
+        content_source: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_allowed_iframe_src() {
+        let domains = vec!["youtube.com".to_string(), "*.vimeo.com".to_string()];
+
+        assert!(is_allowed_iframe_src("https://www.youtube.com/embed/abc", &domains));
+        assert!(is_allowed_iframe_src("https://YOUTUBE.com/embed/abc", &domains));
+        assert!(is_allowed_iframe_src("https://player.vimeo.com/video/1", &domains));
+        assert!(!is_allowed_iframe_src("https://evilyoutube.com/embed/abc", &domains));
+        assert!(!is_allowed_iframe_src("https://youtube.com.evil.net/embed/abc", &domains));
+        assert!(!is_allowed_iframe_src("javascript:alert(1)", &domains));
+        assert!(!is_allowed_iframe_src("/embed/abc", &domains));
+        assert!(!is_allowed_iframe_src("https://www.youtube.com/embed/abc", &[]));
+    }
+
+    #[test]
+    fn test_sanitize_html() {
+        let domains = vec!["youtube.com".to_string()];
+        let html = r#"<p>영상</p><iframe src="https://www.youtube.com/embed/abc" width="560" onload="x()"></iframe><iframe title=' src="x' src="https://evil.net/x">대체</iframe><script>alert(1)</script>"#;
+
+        let cleaned = sanitize_html(html, true, &domains);
+        assert_eq!(cleaned, r#"<p>영상</p><iframe src="https://www.youtube.com/embed/abc" width="560"></iframe>"#);
+        assert_eq!(cleaned.matches("<iframe").count(), 1);
+        assert!(!sanitize_html(r#"<iframe src="https://evil.net/x"></iframe>뒤"#, true, &domains).contains("<iframe"));
+        assert_eq!(sanitize_html(html, true, &[]), "<p>영상</p>");
+        assert_eq!(sanitize_html("<p>굵게 <b>글자</b> &lt;b&gt;</p>", false, &domains), "굵게 글자 &lt;b&gt;");
+    }
+
+    #[test]
+    fn test_sanitize_html_iframe_in_attribute() {
+        let domains = vec!["youtube.com".to_string()];
+        let html = r#"<p title="<iframe">a</p><iframe></iframe><a title=" onmouseover=alert(1) x=">b</a>"#;
+
+        // 속성 값 안의 "<iframe"은 글자 그대로 두고 태그끼리 이어 붙이지 않음
+        assert_eq!(
+            sanitize_html(html, true, &domains),
+            r#"<p title="<iframe">a</p><a title=" onmouseover=alert(1) x=" rel="noopener noreferrer">b</a>"#
+        );
+        assert_eq!(
+            sanitize_html(r#"<template><iframe src="https://evil.net/x"></iframe></template><p>a</p>"#, true, &domains),
+            "<p>a</p>"
+        );
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        assert_eq!(render_markdown("**굵게** 글자", true), "<p><strong>굵게</strong> 글자</p>\n");
+        assert_eq!(render_markdown("a <b>b</b>", true), "<p>a <b>b</b></p>\n");
+        assert_eq!(render_markdown("a <b>b</b>", false), "<p>a &lt;b&gt;b&lt;/b&gt;</p>\n");
+    }
+}
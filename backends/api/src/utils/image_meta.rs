@@ -0,0 +1,360 @@
+// 이미지 메타데이터 처리 (EXIF 방향, GPS 등 개인정보 제거)
+// 원본 화질을 유지하기 위해 다시 인코딩하지 않고 JPEG 세그먼트 / PNG 청크 단위로 제거
+
+use image::DynamicImage;
+
+const TAG_ORIENTATION: u16 = 0x0112;
+const EXIF_HEADER: &[u8] = b"Exif\0\0";
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+// 제거하는 PNG 청크 (EXIF, 텍스트 메타데이터, 수정 시각)
+const PNG_METADATA_CHUNKS: [&[u8; 4]; 5] = [b"eXIf", b"tEXt", b"zTXt", b"iTXt", b"tIME"];
+
+/// EXIF 방향 값 (1~8, 방향 정보가 없으면 None)
+/// JPEG APP1, PNG eXIf, WebP EXIF 청크를 지원
+pub fn exif_orientation(data: &[u8]) -> Option<u16> {
+    let tiff = if data.starts_with(&[0xFF, 0xD8]) {
+        jpeg_segments(data)?
+            .into_iter()
+            .find(|(marker, payload)| *marker == 0xE1 && payload.starts_with(EXIF_HEADER))
+            .map(|(_, payload)| &payload[EXIF_HEADER.len()..])?
+    } else if data.starts_with(PNG_SIGNATURE) {
+        png_chunks(data)?
+            .into_iter()
+            .find(|(kind, _, _)| *kind == b"eXIf")
+            .map(|(_, payload, _)| payload)?
+    } else if data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+        let payload = webp_exif_chunk(data)?;
+        payload.strip_prefix(EXIF_HEADER).unwrap_or(payload)
+    } else {
+        return None;
+    };
+    tiff_orientation(tiff)
+}
+
+/// 방향 값에 맞게 픽셀을 회전/반전 (썸네일은 EXIF 없이 올바른 방향으로 저장)
+pub fn apply_orientation(img: DynamicImage, orientation: u16) -> DynamicImage {
+    match orientation {
+        2 => img.fliph(),
+        3 => img.rotate180(),
+        4 => img.flipv(),
+        5 => img.rotate90().fliph(),
+        6 => img.rotate90(),
+        7 => img.rotate270().fliph(),
+        8 => img.rotate270(),
+        _ => img,
+    }
+}
+
+/// 위치/기기 정보 등 메타데이터 제거 (변경이 없거나 지원하지 않는 형식이면 None)
+/// 방향 값은 원본 표시가 바뀌지 않도록 최소 EXIF로 다시 기록
+/// JPEG의 ICC 프로필(APP2)과 Adobe(APP14) 세그먼트는 색 재현에 필요하므로 유지
+pub fn strip_metadata(data: &[u8]) -> Option<Vec<u8>> {
+    if data.starts_with(&[0xFF, 0xD8]) {
+        strip_jpeg_metadata(data)
+    } else if data.starts_with(PNG_SIGNATURE) {
+        strip_png_metadata(data)
+    } else {
+        None
+    }
+}
+
+fn strip_jpeg_metadata(data: &[u8]) -> Option<Vec<u8>> {
+    let orientation = exif_orientation(data).filter(|o| *o != 1);
+    let minimal = orientation.map(orientation_segment);
+    let mut output = Vec::with_capacity(data.len());
+    output.extend_from_slice(&data[..2]);
+
+    let mut pos = 2;
+    let mut removed = false;
+    let mut orientation_written = orientation.is_none();
+    while pos + 4 <= data.len() && data[pos] == 0xFF {
+        let marker = data[pos + 1];
+        // 스캔 시작(SOS) 이후는 압축 데이터이므로 그대로 복사
+        if marker == 0xDA || marker == 0xD9 {
+            break;
+        }
+        let length = u16::from_be_bytes([data[pos + 2], data[pos + 3]]) as usize;
+        let end = pos + 2 + length;
+        if length < 2 || end > data.len() {
+            return None;
+        }
+        let payload = &data[pos + 4..end];
+
+        // 이미 정리된 파일의 방향 EXIF는 그대로 둠
+        if !orientation_written && minimal.as_deref() == Some(&data[pos..end]) {
+            output.extend_from_slice(&data[pos..end]);
+            orientation_written = true;
+            pos = end;
+            continue;
+        }
+
+        let keep = match marker {
+            0xE0 | 0xEE => true,                       // JFIF, Adobe
+            0xE2 => payload.starts_with(b"ICC_PROFILE\0"), // ICC 프로필만 유지
+            0xE1 | 0xE3..=0xED | 0xEF | 0xFE => false, // EXIF/XMP, 제조사 정보, IPTC, 주석
+            _ => true,
+        };
+
+        // 방향 EXIF는 JFIF(APP0) 뒤, 다른 세그먼트 앞에 기록
+        if !orientation_written && marker != 0xE0 {
+            output.extend_from_slice(minimal.as_deref().unwrap_or_default());
+            orientation_written = true;
+        }
+        if keep {
+            output.extend_from_slice(&data[pos..end]);
+        } else {
+            removed = true;
+        }
+        pos = end;
+    }
+
+    if !removed {
+        return None;
+    }
+    output.extend_from_slice(&data[pos..]);
+    Some(output)
+}
+
+fn strip_png_metadata(data: &[u8]) -> Option<Vec<u8>> {
+    let orientation = exif_orientation(data).filter(|o| *o != 1);
+    let minimal = orientation.map(orientation_tiff);
+    let chunks = png_chunks(data)?;
+    let is_metadata = |(kind, payload, _): &PngChunk| {
+        PNG_METADATA_CHUNKS.contains(kind) && !(*kind == b"eXIf" && minimal.as_deref() == Some(*payload))
+    };
+    if !chunks.iter().any(is_metadata) {
+        return None;
+    }
+
+    let mut output = Vec::with_capacity(data.len());
+    output.extend_from_slice(PNG_SIGNATURE);
+    for (kind, _, raw) in chunks {
+        if kind == b"eXIf" {
+            if let Some(minimal) = &minimal {
+                output.extend_from_slice(&png_chunk(b"eXIf", minimal));
+            }
+        } else if !PNG_METADATA_CHUNKS.contains(&kind) {
+            output.extend_from_slice(raw);
+        }
+    }
+    Some(output)
+}
+
+// JPEG 마커 세그먼트 목록 (SOS 전까지, 마커와 길이 필드를 제외한 내용)
+fn jpeg_segments(data: &[u8]) -> Option<Vec<(u8, &[u8])>> {
+    let mut segments = Vec::new();
+    let mut pos = 2;
+    while pos + 4 <= data.len() && data[pos] == 0xFF {
+        let marker = data[pos + 1];
+        if marker == 0xDA || marker == 0xD9 {
+            break;
+        }
+        let length = u16::from_be_bytes([data[pos + 2], data[pos + 3]]) as usize;
+        let end = pos + 2 + length;
+        if length < 2 || end > data.len() {
+            return None;
+        }
+        segments.push((marker, &data[pos + 4..end]));
+        pos = end;
+    }
+    Some(segments)
+}
+
+// PNG 청크 (종류, 내용, 길이/CRC 포함 원본)
+type PngChunk<'a> = (&'a [u8; 4], &'a [u8], &'a [u8]);
+
+fn png_chunks(data: &[u8]) -> Option<Vec<PngChunk<'_>>> {
+    let mut chunks = Vec::new();
+    let mut pos = PNG_SIGNATURE.len();
+    while pos + 12 <= data.len() {
+        let length = u32::from_be_bytes(data[pos..pos + 4].try_into().ok()?) as usize;
+        let end = pos.checked_add(12)?.checked_add(length)?;
+        if end > data.len() {
+            return None;
+        }
+        let kind: &[u8; 4] = data[pos + 4..pos + 8].try_into().ok()?;
+        chunks.push((kind, &data[pos + 8..pos + 8 + length], &data[pos..end]));
+        pos = end;
+        if kind == b"IEND" {
+            break;
+        }
+    }
+    Some(chunks)
+}
+
+// WebP RIFF 컨테이너의 EXIF 청크
+fn webp_exif_chunk(data: &[u8]) -> Option<&[u8]> {
+    let mut pos = 12;
+    while pos + 8 <= data.len() {
+        let size = u32::from_le_bytes(data[pos + 4..pos + 8].try_into().ok()?) as usize;
+        let end = (pos + 8).checked_add(size)?;
+        if end > data.len() {
+            return None;
+        }
+        if &data[pos..pos + 4] == b"EXIF" {
+            return Some(&data[pos + 8..end]);
+        }
+        pos = end + (size & 1);
+    }
+    None
+}
+
+// TIFF 헤더 + IFD0에서 방향 태그 조회
+fn tiff_orientation(tiff: &[u8]) -> Option<u16> {
+    let big_endian = match tiff.get(..2)? {
+        b"MM" => true,
+        b"II" => false,
+        _ => return None,
+    };
+    let read_u16 = |offset: usize| -> Option<u16> {
+        let bytes: [u8; 2] = tiff.get(offset..offset + 2)?.try_into().ok()?;
+        Some(if big_endian { u16::from_be_bytes(bytes) } else { u16::from_le_bytes(bytes) })
+    };
+    let read_u32 = |offset: usize| -> Option<u32> {
+        let bytes: [u8; 4] = tiff.get(offset..offset + 4)?.try_into().ok()?;
+        Some(if big_endian { u32::from_be_bytes(bytes) } else { u32::from_le_bytes(bytes) })
+    };
+
+    if read_u16(2)? != 42 {
+        return None;
+    }
+    let ifd = read_u32(4)? as usize;
+    let count = read_u16(ifd)? as usize;
+    (0..count).find_map(|i| {
+        let entry = ifd + 2 + i * 12;
+        // SHORT(3) 형식, 값은 엔트리 안에 저장
+        if read_u16(entry)? == TAG_ORIENTATION && read_u16(entry + 2)? == 3 {
+            read_u16(entry + 8).filter(|o| (1..=8).contains(o))
+        } else {
+            None
+        }
+    })
+}
+
+// 방향 태그 하나만 담은 TIFF (빅엔디언)
+fn orientation_tiff(orientation: u16) -> Vec<u8> {
+    let mut tiff = Vec::with_capacity(26);
+    tiff.extend_from_slice(b"MM\0\x2a\0\0\0\x08"); // 헤더, IFD0 위치
+    tiff.extend_from_slice(&1u16.to_be_bytes()); // 엔트리 수
+    tiff.extend_from_slice(&TAG_ORIENTATION.to_be_bytes());
+    tiff.extend_from_slice(&3u16.to_be_bytes()); // SHORT
+    tiff.extend_from_slice(&1u32.to_be_bytes()); // 개수
+    tiff.extend_from_slice(&orientation.to_be_bytes());
+    tiff.extend_from_slice(&[0, 0]);
+    tiff.extend_from_slice(&0u32.to_be_bytes()); // 다음 IFD 없음
+    tiff
+}
+
+// 방향 태그만 담은 JPEG APP1 세그먼트
+fn orientation_segment(orientation: u16) -> Vec<u8> {
+    let tiff = orientation_tiff(orientation);
+    let length = (2 + EXIF_HEADER.len() + tiff.len()) as u16;
+    let mut segment = vec![0xFF, 0xE1];
+    segment.extend_from_slice(&length.to_be_bytes());
+    segment.extend_from_slice(EXIF_HEADER);
+    segment.extend_from_slice(&tiff);
+    segment
+}
+
+fn png_chunk(kind: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+    let mut chunk = Vec::with_capacity(payload.len() + 12);
+    chunk.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    chunk.extend_from_slice(kind);
+    chunk.extend_from_slice(payload);
+    let crc = crc32(&chunk[4..]);
+    chunk.extend_from_slice(&crc.to_be_bytes());
+    chunk
+}
+
+// PNG 청크 CRC (CRC-32/ISO-HDLC)
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GenericImageView, ImageOutputFormat, RgbImage};
+    use std::io::Cursor;
+
+    fn encode(img: &DynamicImage, format: ImageOutputFormat) -> Vec<u8> {
+        let mut output = Cursor::new(Vec::new());
+        img.write_to(&mut output, format).unwrap();
+        output.into_inner()
+    }
+
+    // SOI 뒤에 세그먼트 삽입
+    fn with_segment(jpeg: &[u8], marker: u8, payload: &[u8]) -> Vec<u8> {
+        let mut data = jpeg[..2].to_vec();
+        data.extend_from_slice(&[0xFF, marker]);
+        data.extend_from_slice(&((payload.len() + 2) as u16).to_be_bytes());
+        data.extend_from_slice(payload);
+        data.extend_from_slice(&jpeg[2..]);
+        data
+    }
+
+    #[test]
+    fn test_strip_jpeg_metadata_keeps_orientation() {
+        let img = DynamicImage::ImageRgb8(RgbImage::new(8, 4));
+        let jpeg = encode(&img, ImageOutputFormat::Jpeg(90));
+        assert_eq!(exif_orientation(&jpeg), None);
+        assert_eq!(strip_metadata(&jpeg), None);
+
+        // 리틀엔디언 EXIF (방향 6 + GPS IFD 포인터) 와 주석
+        let mut exif = EXIF_HEADER.to_vec();
+        exif.extend_from_slice(b"II\x2a\0\x08\0\0\0\x02\0");
+        exif.extend_from_slice(&[0x12, 0x01, 3, 0, 1, 0, 0, 0, 6, 0, 0, 0]);
+        exif.extend_from_slice(&[0x25, 0x88, 4, 0, 1, 0, 0, 0, 0x26, 0, 0, 0]);
+        exif.extend_from_slice(&[0, 0, 0, 0]);
+        let tagged = with_segment(&with_segment(&jpeg, 0xFE, b"secret comment"), 0xE1, &exif);
+        assert_eq!(exif_orientation(&tagged), Some(6));
+
+        let stripped = strip_metadata(&tagged).unwrap();
+        assert!(!stripped.windows(14).any(|w| w == b"secret comment"));
+        assert!(!stripped.windows(2).any(|w| w == [0x25, 0x88]));
+        assert_eq!(exif_orientation(&stripped), Some(6));
+        assert_eq!(image::load_from_memory(&stripped).unwrap().dimensions(), (8, 4));
+        // 이미 정리된 파일은 변경 없음
+        assert_eq!(strip_metadata(&stripped), None);
+    }
+
+    #[test]
+    fn test_strip_png_metadata() {
+        let img = DynamicImage::ImageRgb8(RgbImage::new(3, 2));
+        let png = encode(&img, ImageOutputFormat::Png);
+        let iend = png.len() - 12;
+        let mut tagged = png[..iend].to_vec();
+        tagged.extend_from_slice(&png_chunk(b"tEXt", b"Comment\0secret"));
+        tagged.extend_from_slice(&png_chunk(b"eXIf", &orientation_tiff(8)));
+        tagged.extend_from_slice(&png[iend..]);
+        assert_eq!(exif_orientation(&tagged), Some(8));
+
+        let stripped = strip_metadata(&tagged).unwrap();
+        assert!(!stripped.windows(6).any(|w| w == b"secret"));
+        assert_eq!(exif_orientation(&stripped), Some(8));
+        assert_eq!(image::load_from_memory(&stripped).unwrap().dimensions(), (3, 2));
+        assert_eq!(strip_metadata(&stripped), None);
+    }
+
+    #[test]
+    fn test_apply_orientation() {
+        let img = DynamicImage::ImageRgb8(RgbImage::new(4, 2));
+        assert_eq!(apply_orientation(img.clone(), 1).dimensions(), (4, 2));
+        assert_eq!(apply_orientation(img.clone(), 3).dimensions(), (4, 2));
+        assert_eq!(apply_orientation(img.clone(), 6).dimensions(), (2, 4));
+        assert_eq!(apply_orientation(img, 7).dimensions(), (2, 4));
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+    }
+}
@@ -2,4 +2,9 @@ pub mod auth;
 pub mod url_id;
 pub mod uuid_compression;
 pub mod url_helpers;
+pub mod file_response;
+pub mod file_sniff;
+pub mod image_meta;
+pub mod client_ip;
+pub mod html_sanitize;
  
\ No newline at end of file